@@ -0,0 +1,46 @@
+#!/usr/bin/env python3
+"""Generate assets/glyphs/nerd-fonts.tsv from the Nerd Fonts glyph names.
+
+Reads `glyphnames.json` from the Nerd Fonts repository and writes one
+`nf-<class><TAB><codepoint>` line per glyph, sorted by name, in the format
+`src/emoji/glyphs.rs` parses.
+
+Usage:
+    scripts/gen-nerd-fonts-tsv.py [glyphnames.json] > assets/glyphs/nerd-fonts.tsv
+
+Without an argument, the file is downloaded from the Nerd Fonts master branch.
+"""
+
+import json
+import sys
+import urllib.request
+
+GLYPHNAMES_URL = "https://raw.githubusercontent.com/ryanoasis/nerd-fonts/master/glyphnames.json"
+
+
+def load(source):
+    if source is None:
+        with urllib.request.urlopen(GLYPHNAMES_URL) as response:
+            return json.load(response)
+    with open(source, encoding="utf-8") as f:
+        return json.load(f)
+
+
+def main():
+    source = sys.argv[1] if len(sys.argv) > 1 else None
+    data = load(source)
+    version = data.pop("METADATA", {}).get("version", "unknown")
+
+    out = sys.stdout
+    out.write("# Nerd Font glyphs for the glyph picker.\n")
+    out.write("#\n")
+    out.write("# One glyph per line: the Nerd Fonts class name and its hex codepoint,\n")
+    out.write("# separated by a tab. Lines starting with `#` are comments.\n")
+    out.write(f"# Generated by scripts/gen-nerd-fonts-tsv.py from Nerd Fonts {version}.\n")
+    out.write("\n")
+    for name, glyph in sorted(data.items()):
+        out.write(f"nf-{name}\t{glyph['code'].lower()}\n")
+
+
+if __name__ == "__main__":
+    main()
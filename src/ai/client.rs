@@ -8,20 +8,75 @@ use llm::builder::{LLMBackend, LLMBuilder};
 use llm::chat::ChatMessage;
 use std::env;
 use std::pin::Pin;
+use thiserror::Error;
 
-fn get_keys() -> Option<(String, LLMBackend)> {
+/// Environment variables holding an AI key, with their backends, in lookup order.
+fn key_vars() -> [(&'static str, LLMBackend); 4] {
     [
         ("OLLAMA_URL", LLMBackend::Ollama),
         ("GEMINI_API_KEY", LLMBackend::Google),
         ("OPENAI_API_KEY", LLMBackend::OpenAI),
         ("OPENROUTER_API_KEY", LLMBackend::OpenRouter),
     ]
-    .iter()
-    .find_map(|(var_name, backend)| {
-        env::var(var_name)
-            .ok()
-            .map(|value| (value, backend.clone()))
-    })
+}
+
+/// Why no AI key could be found.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum MissingKeyError {
+    /// None of the key variables is set.
+    #[error(
+        "No AI API key found. Set GEMINI_API_KEY, OPENAI_API_KEY or OPENROUTER_API_KEY, \
+         or OLLAMA_URL for a local Ollama server, in the environment zlaunch runs in, \
+         then restart the daemon."
+    )]
+    NotSet,
+
+    /// The variable named by `ai_api_key_env` is unset or empty.
+    #[error(
+        "{0} is not set. Set it in the environment zlaunch runs in and restart the \
+         daemon, or point ai_api_key_env in the config at another variable."
+    )]
+    ConfiguredNotSet(String),
+
+    /// `ai_api_key_env` names a variable zlaunch doesn't know a backend for.
+    #[error(
+        "ai_api_key_env is set to {0}, which is not an AI key variable. Use OLLAMA_URL, \
+         GEMINI_API_KEY, OPENAI_API_KEY or OPENROUTER_API_KEY."
+    )]
+    UnknownVariable(String),
+}
+
+/// Find the AI key and its backend.
+///
+/// Only `configured` is read if given, otherwise the first variable that is
+/// set. Empty values count as unset.
+fn find_key(
+    configured: Option<&str>,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<(String, LLMBackend), MissingKeyError> {
+    let read = |name: &str| lookup(name).filter(|value| !value.trim().is_empty());
+
+    match configured {
+        Some(name) => {
+            let (_, backend) = key_vars()
+                .into_iter()
+                .find(|(var_name, _)| *var_name == name)
+                .ok_or_else(|| MissingKeyError::UnknownVariable(name.to_string()))?;
+            read(name)
+                .map(|value| (value, backend))
+                .ok_or_else(|| MissingKeyError::ConfiguredNotSet(name.to_string()))
+        }
+        None => key_vars()
+            .into_iter()
+            .find_map(|(var_name, backend)| read(var_name).map(|value| (value, backend)))
+            .ok_or(MissingKeyError::NotSet),
+    }
+}
+
+/// Find the AI key and its backend from the environment.
+fn get_keys() -> Result<(String, LLMBackend), MissingKeyError> {
+    let config = crate::config::config();
+    find_key(config.ai_api_key_env(), |name| env::var(name).ok())
 }
 
 /// LLM client for AI queries.
@@ -34,7 +89,7 @@ impl LLMClient {
     /// Create a new LLM client.
     /// Returns None if no valid API_KEY environment variable is set.
     pub fn new() -> Option<Self> {
-        let (api_key, backend) = get_keys()?;
+        let (api_key, backend) = get_keys().ok()?;
 
         let mut builder = LLMBuilder::new().backend(backend.clone());
 
@@ -67,7 +122,12 @@ impl LLMClient {
 
     /// Return true if any LLM is configured.
     pub fn is_configured() -> bool {
-        get_keys().is_some()
+        get_keys().is_ok()
+    }
+
+    /// Check that an AI key is set, explaining how to set one if not.
+    pub fn check_key() -> Result<(), MissingKeyError> {
+        get_keys().map(|_| ())
     }
 
     /// Stream a response for the given query.
@@ -125,3 +185,66 @@ impl Default for LLMClient {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: Vec<(String, String)> = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        move |name| {
+            vars.iter()
+                .find(|(var_name, _)| var_name == name)
+                .map(|(_, value)| value.clone())
+        }
+    }
+
+    #[test]
+    fn test_find_key_missing() {
+        assert_eq!(
+            find_key(None, env(&[])).unwrap_err(),
+            MissingKeyError::NotSet
+        );
+        // Empty values count as unset
+        assert_eq!(
+            find_key(None, env(&[("OPENAI_API_KEY", " ")])).unwrap_err(),
+            MissingKeyError::NotSet
+        );
+    }
+
+    #[test]
+    fn test_find_key_first_set() {
+        let (key, backend) = find_key(
+            None,
+            env(&[("OPENROUTER_API_KEY", "or-key"), ("GEMINI_API_KEY", "")]),
+        )
+        .unwrap();
+        assert_eq!(key, "or-key");
+        assert!(backend == LLMBackend::OpenRouter);
+    }
+
+    #[test]
+    fn test_find_key_configured_variable() {
+        let vars = [("GEMINI_API_KEY", "gemini-key"), ("OPENAI_API_KEY", "")];
+        assert_eq!(
+            find_key(Some("OPENAI_API_KEY"), env(&vars)).unwrap_err(),
+            MissingKeyError::ConfiguredNotSet("OPENAI_API_KEY".to_string())
+        );
+        assert_eq!(
+            find_key(Some("MY_KEY"), env(&vars)).unwrap_err(),
+            MissingKeyError::UnknownVariable("MY_KEY".to_string())
+        );
+        let (key, _) = find_key(Some("GEMINI_API_KEY"), env(&vars)).unwrap();
+        assert_eq!(key, "gemini-key");
+    }
+
+    #[test]
+    fn test_missing_key_message_names_variable() {
+        let message = MissingKeyError::ConfiguredNotSet("OPENAI_API_KEY".to_string()).to_string();
+        assert!(message.contains("OPENAI_API_KEY"));
+        assert!(message.contains("ai_api_key_env"));
+    }
+}
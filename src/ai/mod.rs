@@ -5,5 +5,5 @@
 pub mod client;
 pub mod streaming;
 
-pub use client::LLMClient;
-pub use streaming::spawn_stream;
+pub use client::{LLMClient, MissingKeyError};
+pub use streaming::{request_messages, spawn_stream};
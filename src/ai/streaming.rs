@@ -10,11 +10,38 @@
 
 use super::LLMClient;
 use flume::Receiver;
-use llm::chat::ChatMessage;
+use futures::future::{AbortHandle, Abortable};
+use llm::chat::{ChatMessage, ChatRole};
 use tokio::sync::OnceCell;
 
 static CLIENT: OnceCell<LLMClient> = OnceCell::const_new();
 
+/// Get the messages to send for a conversation's latest question.
+///
+/// Keeps the latest question and at most `max_turns` exchanges before it.
+/// Empty answers (the one being streamed, or a stopped one that never got
+/// a token) are left out.
+pub fn request_messages(messages: &[ChatMessage], max_turns: usize) -> Vec<ChatMessage> {
+    let messages: Vec<&ChatMessage> = messages
+        .iter()
+        .filter(|m| !(matches!(m.role, ChatRole::Assistant) && m.content.is_empty()))
+        .collect();
+
+    let mut start = messages.len();
+    let mut questions = 0;
+    for (i, message) in messages.iter().enumerate().rev() {
+        if matches!(message.role, ChatRole::User) {
+            questions += 1;
+            if questions > max_turns + 1 {
+                break;
+            }
+            start = i;
+        }
+    }
+
+    messages[start..].iter().map(|m| (*m).clone()).collect()
+}
+
 /// Spawn an AI streaming task and return a channel receiver for tokens.
 ///
 /// This function handles all the async/tokio complexity internally:
@@ -30,13 +57,19 @@ static CLIENT: OnceCell<LLMClient> = OnceCell::const_new();
 /// - `Ok(token)` for each token received
 /// - `Ok("")` when streaming completes successfully
 /// - `Err(error)` if an error occurs
-pub fn spawn_stream(messages: Vec<ChatMessage>) -> Option<Receiver<Result<String, String>>> {
+///
+/// and a handle that aborts the request. An aborted stream sends nothing
+/// more and closes the channel.
+pub fn spawn_stream(
+    messages: Vec<ChatMessage>,
+) -> Option<(Receiver<Result<String, String>>, AbortHandle)> {
     if !LLMClient::is_configured() {
         return None;
     }
 
     // Create channel for communication between Tokio thread and caller
     let (tx, rx) = flume::unbounded::<Result<String, String>>();
+    let (abort_handle, abort_registration) = AbortHandle::new_pair();
 
     // Spawn Tokio thread for LLM request
     std::thread::spawn(move || {
@@ -46,7 +79,7 @@ pub fn spawn_stream(messages: Vec<ChatMessage>) -> Option<Receiver<Result<String
             .build()
             .unwrap();
 
-        rt.block_on(async move {
+        let stream = async move {
             // Create LLM client if it doesn't exist
             let client = CLIENT
                 .get_or_init(async || LLMClient::new().expect("Failed to create LLM client"))
@@ -81,8 +114,50 @@ pub fn spawn_stream(messages: Vec<ChatMessage>) -> Option<Receiver<Result<String
                     let _ = tx.send(Err(format!("Failed to connect: {}", e)));
                 }
             }
-        });
+        };
+        // Aborting drops the request mid-stream
+        let _ = rt.block_on(Abortable::new(stream, abort_registration));
     });
 
-    Some(rx)
+    Some((rx, abort_handle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conversation(turns: &[(&str, &str)]) -> Vec<ChatMessage> {
+        turns
+            .iter()
+            .flat_map(|(question, answer)| {
+                [
+                    ChatMessage::user().content(*question).build(),
+                    ChatMessage::assistant().content(*answer).build(),
+                ]
+            })
+            .collect()
+    }
+
+    fn contents(messages: &[ChatMessage]) -> Vec<&str> {
+        messages.iter().map(|m| m.content.as_str()).collect()
+    }
+
+    #[test]
+    fn test_request_messages_keeps_thread_without_pending_answer() {
+        let messages = conversation(&[("what is rust", "a language"), ("explain that more", "")]);
+        assert_eq!(
+            contents(&request_messages(&messages, 10)),
+            ["what is rust", "a language", "explain that more"]
+        );
+    }
+
+    #[test]
+    fn test_request_messages_caps_turns() {
+        let messages = conversation(&[("q1", "a1"), ("q2", "a2"), ("q3", "a3"), ("q4", "")]);
+        assert_eq!(
+            contents(&request_messages(&messages, 2)),
+            ["q2", "a2", "q3", "a3", "q4"]
+        );
+        assert_eq!(contents(&request_messages(&messages, 0)), ["q4"]);
+    }
 }
@@ -1,6 +1,6 @@
 //! Event types for daemon communication.
 
-use crate::config::LauncherMode;
+use crate::config::{ConfigModule, LauncherMode};
 use crate::error::IpcError;
 use crate::items::ApplicationItem;
 use tokio::sync::oneshot;
@@ -8,6 +8,9 @@ use tokio::sync::oneshot;
 /// Response type for IPC operations.
 pub type IpcResponse = Result<(), IpcError>;
 
+/// Response type for `zlaunch dmenu`: the chosen line, or None if cancelled.
+pub type DmenuResponse = Result<Option<String>, IpcError>;
+
 /// Events that the UI can send to the daemon.
 #[derive(Debug, Clone, Copy)]
 pub enum WindowEvent {
@@ -26,6 +29,20 @@ pub enum DaemonEvent {
         response_tx: oneshot::Sender<IpcResponse>,
     },
 
+    /// Show the launcher window with the search input filled in
+    OpenWithQuery {
+        query: String,
+        response_tx: oneshot::Sender<IpcResponse>,
+    },
+
+    /// Show `lines` to choose from, answering once one is chosen or the
+    /// launcher is closed
+    Dmenu {
+        lines: Vec<String>,
+        prompt: Option<String>,
+        response_tx: oneshot::Sender<DmenuResponse>,
+    },
+
     /// Hide the launcher window
     Hide {
         response_tx: oneshot::Sender<IpcResponse>,
@@ -48,13 +65,28 @@ pub enum DaemonEvent {
         response_tx: oneshot::Sender<IpcResponse>,
     },
 
+    /// Set the modules shown in the combined view
+    SetCombinedModules {
+        modules: Vec<ConfigModule>,
+        response_tx: oneshot::Sender<IpcResponse>,
+    },
+
+    /// Pin an application to the top of the combined view
+    PinApplication {
+        app_id: String,
+        response_tx: oneshot::Sender<IpcResponse>,
+    },
+
     /// Reload the daemon (restart the process)
     Reload {
         response_tx: oneshot::Sender<IpcResponse>,
     },
 
-    /// Applications have been updated (from file watcher)
+    /// Applications have been updated (from file watcher or a rescan)
     ApplicationsChanged { applications: Vec<ApplicationItem> },
+
+    /// A user theme file changed on disk (from the theme watcher)
+    ThemeFileChanged,
 }
 
 impl From<WindowEvent> for DaemonEvent {
@@ -1,11 +1,15 @@
 use crate::app::{DaemonEvent, DaemonEventSender, WindowEvent};
-use crate::compositor::Compositor;
-use crate::config::{ConfigModule, LauncherMode, get_combined_modules};
-use crate::items::{ApplicationItem, ListItem, WindowItem};
+use crate::compositor::base::count_app_windows;
+use crate::compositor::{Compositor, OutputInfo, WindowInfo};
+use crate::config::{ConfigModule, LauncherMode, TargetOutput, get_combined_modules};
+use crate::items::{
+    ApplicationItem, ListItem, WindowIcons, WindowItem, WorkspaceItem, sort_urgent_first,
+};
 use crate::ui::LauncherView;
+use crate::ui::launcher::fit_panel_size;
 use gpui::{
-    App, AppContext, Bounds, Entity, WindowBackgroundAppearance, WindowBounds, WindowDecorations,
-    WindowHandle, WindowKind, WindowOptions,
+    App, AppContext, Bounds, DisplayId, Entity, WindowBackgroundAppearance, WindowBounds,
+    WindowDecorations, WindowHandle, WindowKind, WindowOptions,
     layer_shell::{Anchor, KeyboardInteractivity, LayerShellOptions},
     point, px, size,
 };
@@ -20,7 +24,7 @@ pub struct LauncherWindow {
 }
 
 pub fn create_and_show_window(
-    applications: Vec<ApplicationItem>,
+    mut applications: Vec<ApplicationItem>,
     compositor: Arc<dyn Compositor>,
     modes: Vec<LauncherMode>,
     event_tx: DaemonEventSender,
@@ -28,18 +32,87 @@ pub fn create_and_show_window(
 ) -> anyhow::Result<LauncherWindow> {
     // Fetch open windows from compositor (if not disabled)
     let combined_modules = get_combined_modules();
-    let windows = if combined_modules.contains(&ConfigModule::Windows) {
-        fetch_windows(compositor.as_ref())
+    let show_windows = combined_modules.contains(&ConfigModule::Windows);
+    let count_windows = {
+        let badges = &crate::config::config().badges;
+        badges.running || badges.window_count
+    };
+    let window_infos = if show_windows || count_windows {
+        list_windows(compositor.as_ref())
+    } else {
+        Vec::new()
+    };
+    if count_windows {
+        for app in &mut applications {
+            app.open_windows =
+                count_app_windows(&window_infos, &app.id, app.startup_wm_class.as_deref());
+        }
+    }
+    let windows = if show_windows {
+        window_items(window_infos, &WindowIcons::from_applications(&applications))
     } else {
         Vec::new()
     };
-    create_and_show_window_impl(applications, compositor, windows, modes, event_tx, cx)
+    // Workspaces are opt-in, so a workspaces mode alone also fetches them
+    let workspaces = if combined_modules.contains(&ConfigModule::Workspaces)
+        || modes.contains(&LauncherMode::Workspaces)
+    {
+        fetch_workspaces(compositor.as_ref())
+    } else {
+        Vec::new()
+    };
+    create_and_show_window_impl(
+        applications,
+        compositor,
+        windows,
+        workspaces,
+        modes,
+        event_tx,
+        cx,
+    )
+}
+
+/// Create and show a launcher window choosing one of `lines`, for
+/// `zlaunch dmenu`.
+///
+/// No applications, windows or workspaces are fetched. `on_select` gets the
+/// chosen line before the window asks to be hidden.
+pub fn create_and_show_dmenu_window(
+    lines: Vec<String>,
+    prompt: Option<String>,
+    on_select: Arc<dyn Fn(String) + Send + Sync>,
+    compositor: Arc<dyn Compositor>,
+    event_tx: DaemonEventSender,
+    cx: &mut App,
+) -> anyhow::Result<LauncherWindow> {
+    let lw = create_and_show_window_impl(
+        Vec::new(),
+        compositor,
+        Vec::new(),
+        Vec::new(),
+        vec![LauncherMode::Combined],
+        event_tx,
+        cx,
+    )?;
+    let entered = lw.handle.update(cx, |_root, window, cx| {
+        lw.launcher_view
+            .update(cx, |launcher: &mut LauncherView, cx| {
+                launcher.enter_dmenu_mode(lines, prompt.as_deref(), on_select, window, cx);
+            });
+    });
+    if let Err(e) = entered {
+        // Don't leave a combined view open that nobody asked for
+        close_window(&lw.handle, cx);
+        return Err(e);
+    }
+    Ok(lw)
 }
 
 fn create_and_show_window_impl(
     applications: Vec<ApplicationItem>,
     compositor: Arc<dyn Compositor>,
     windows: Vec<WindowItem>,
+    workspaces: Vec<WorkspaceItem>,
     modes: Vec<LauncherMode>,
     event_tx: DaemonEventSender,
     cx: &mut App,
@@ -47,17 +120,27 @@ fn create_and_show_window_impl(
     // Combine windows and applications into items list
     // Built-in actions and submenus are added by the delegate
     // Order doesn't matter here - sort_priority in delegate handles display order
-    let mut items: Vec<ListItem> = Vec::with_capacity(windows.len() + applications.len());
+    let mut items: Vec<ListItem> =
+        Vec::with_capacity(windows.len() + workspaces.len() + applications.len());
     items.extend(windows.into_iter().map(ListItem::Window));
+    items.extend(workspaces.into_iter().map(ListItem::Workspace));
     items.extend(applications.into_iter().map(ListItem::Application));
 
     // Get display size based on config
     let config = crate::config::config();
-    let (launcher_w, launcher_h) = config.get_launcher_size();
+    let (launcher_w, launcher_h) = crate::ui::theme::theme().panel_size(&config);
+
+    let display_id = config
+        .target_output()
+        .and_then(|target| target_display(compositor.as_ref(), &target, cx));
 
     let display_size = if !config.enable_backdrop {
-        // No backdrop - window is exactly the launcher panel size
-        size(px(launcher_w), px(launcher_h))
+        // No backdrop - window is exactly the launcher panel size, which
+        // has to fit the display it opens on
+        let screen = display_screen_size(display_id, cx);
+        let (panel_w, panel_h) =
+            fit_panel_size((launcher_w, launcher_h), screen, config.max_screen_fraction);
+        size(px(panel_w), px(panel_h))
     } else if let Some((w, h)) = config.window_size {
         // User-configured window size with backdrop - ensure it's at least as large as launcher panel
         let final_w = w.max(launcher_w);
@@ -84,6 +167,7 @@ fn create_and_show_window_impl(
 
     let options = WindowOptions {
         window_bounds: Some(WindowBounds::Windowed(fullscreen_bounds)),
+        display_id,
         titlebar: None,
         focus: true,
         show: true,
@@ -124,14 +208,19 @@ fn create_and_show_window_impl(
         cx.new(|cx| Root::new(view, window, cx))
     })?;
 
-    window_handle.update(cx, |_root, window, _cx| {
-        window.activate_window();
-    })?;
-
     let launcher_view = launcher_view_cell
         .into_inner()
         .expect("Launcher view should have been created");
 
+    // Re-apply focus once the window is activated, the focus requested while
+    // building the view may not survive the surface being mapped
+    window_handle.update(cx, |_root, window, cx| {
+        window.activate_window();
+        launcher_view.update(cx, |launcher: &mut LauncherView, cx| {
+            launcher.focus(window, cx);
+        });
+    })?;
+
     Ok(LauncherWindow {
         handle: window_handle,
         launcher_view,
@@ -144,28 +233,109 @@ pub fn close_window(handle: &WindowHandle<Root>, cx: &mut App) {
     });
 }
 
-/// Fetch open windows from the compositor and convert to WindowItems.
-fn fetch_windows(compositor: &dyn Compositor) -> Vec<WindowItem> {
-    match compositor.list_windows() {
-        Ok(windows) => windows
-            .into_iter()
-            .map(|info| {
-                // Only resolve icon from class if compositor didn't provide icon data
-                let icon_path = if info.icon_data.is_some() {
-                    None
-                } else {
-                    resolve_window_icon(&info.class)
-                };
-                WindowItem::from_window_info(info, icon_path)
-            })
-            .collect(),
+/// Fetch open windows from the compositor.
+fn list_windows(compositor: &dyn Compositor) -> Vec<WindowInfo> {
+    compositor.list_windows().unwrap_or_else(|e| {
+        warn!(%e, "Failed to list windows");
+        Vec::new()
+    })
+}
+
+/// Convert open windows to WindowItems.
+///
+/// Windows without icon data from the compositor get the icon of their
+/// application, or a themed icon named after their class.
+fn window_items(windows: Vec<WindowInfo>, icons: &WindowIcons) -> Vec<WindowItem> {
+    let mut items: Vec<WindowItem> = windows
+        .into_iter()
+        .map(|info| {
+            let icon_path = if info.icon_data.is_some() {
+                None
+            } else {
+                icons
+                    .get(&info.class)
+                    .cloned()
+                    .or_else(|| resolve_window_icon(&info.class))
+            };
+            WindowItem::from_window_info(info, icon_path)
+        })
+        .collect();
+    if crate::config::config().urgent_windows_first {
+        sort_urgent_first(&mut items);
+    }
+    items
+}
+
+/// Fetch workspaces from the compositor and convert to WorkspaceItems.
+fn fetch_workspaces(compositor: &dyn Compositor) -> Vec<WorkspaceItem> {
+    match compositor.workspaces() {
+        Ok(workspaces) => WorkspaceItem::from_workspaces(workspaces),
         Err(e) => {
-            warn!(%e, "Failed to list windows");
+            warn!(%e, "Failed to list workspaces");
             Vec::new()
         }
     }
 }
 
+/// Find the display the launcher should open on.
+///
+/// Compositor outputs are matched to displays by their position in the
+/// layout. Returns None (the compositor picks) if the output is unknown.
+/// Get the size of the display the window opens on, the primary one if
+/// none is targeted, or zero if it isn't known.
+fn display_screen_size(display_id: Option<DisplayId>, cx: &App) -> (f32, f32) {
+    let display = match display_id {
+        Some(id) => cx.displays().into_iter().find(|display| display.id() == id),
+        None => cx.primary_display(),
+    };
+    display.map_or((0.0, 0.0), |display| {
+        let size = display.bounds().size;
+        (f32::from(size.width), f32::from(size.height))
+    })
+}
+
+fn target_display(
+    compositor: &dyn Compositor,
+    target: &TargetOutput,
+    cx: &App,
+) -> Option<DisplayId> {
+    if *target == TargetOutput::Primary {
+        return cx.primary_display().map(|display| display.id());
+    }
+
+    let outputs = compositor.outputs().unwrap_or_else(|e| {
+        warn!(%e, "Failed to list outputs");
+        Vec::new()
+    });
+    let Some(output) = select_output(&outputs, target) else {
+        warn!(
+            ?target,
+            "Target output not found, leaving placement to {}",
+            compositor.name()
+        );
+        return None;
+    };
+
+    let origin = point(px(output.x as f32), px(output.y as f32));
+    let display = cx
+        .displays()
+        .into_iter()
+        .find(|display| display.bounds().origin == origin);
+    if display.is_none() {
+        warn!(output = %output.name, "No display at the output's position");
+    }
+    display.map(|display| display.id())
+}
+
+/// Pick the output named by `target` from the compositor's outputs.
+fn select_output<'a>(outputs: &'a [OutputInfo], target: &TargetOutput) -> Option<&'a OutputInfo> {
+    match target {
+        TargetOutput::Focused => outputs.iter().find(|output| output.focused),
+        TargetOutput::Named(name) => outputs.iter().find(|output| output.name == *name),
+        TargetOutput::Primary => None,
+    }
+}
+
 /// Try to resolve an icon path for a window based on its app class.
 fn resolve_window_icon(app_class: &str) -> Option<std::path::PathBuf> {
     use crate::ui::icon::resolve_icon_path;
@@ -193,3 +363,28 @@ fn resolve_window_icon(app_class: &str) -> Option<std::path::PathBuf> {
 
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn output(name: &str, focused: bool, x: i32) -> OutputInfo {
+        OutputInfo {
+            name: name.to_string(),
+            focused,
+            x,
+            y: 0,
+        }
+    }
+
+    #[test]
+    fn test_select_output() {
+        let outputs = [output("DP-1", false, 0), output("HDMI-A-1", true, 2560)];
+        let name = |target| select_output(&outputs, &target).map(|o| o.name.as_str());
+
+        assert_eq!(name(TargetOutput::Focused), Some("HDMI-A-1"));
+        assert_eq!(name(TargetOutput::Named("DP-1".to_string())), Some("DP-1"));
+        assert_eq!(name(TargetOutput::Named("DP-2".to_string())), None);
+        assert_eq!(select_output(&[], &TargetOutput::Focused), None);
+    }
+}
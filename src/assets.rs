@@ -65,6 +65,7 @@ pub enum PhosphorIcon {
     File,
     FileText,
     FileImage,
+    Folder,
     Image,
     MagnifyingGlass,
     Globe,
@@ -72,6 +73,7 @@ pub enum PhosphorIcon {
     YoutubeLogo,
     Brain,
     Palette,
+    SquaresFour,
 }
 
 impl PhosphorIcon {
@@ -90,6 +92,7 @@ impl PhosphorIcon {
             Self::File => "icons/file-bold.svg",
             Self::FileText => "icons/file-text-bold.svg",
             Self::FileImage => "icons/file-image-bold.svg",
+            Self::Folder => "icons/folder-bold.svg",
             Self::Image => "icons/image-bold.svg",
             Self::MagnifyingGlass => "icons/magnifying-glass-bold.svg",
             Self::Globe => "icons/globe-bold.svg",
@@ -97,6 +100,7 @@ impl PhosphorIcon {
             Self::YoutubeLogo => "icons/youtube-logo-bold.svg",
             Self::Brain => "icons/brain-bold.svg",
             Self::Palette => "icons/palette-bold.svg",
+            Self::SquaresFour => "icons/squares-four-bold.svg",
         }
     }
 
@@ -115,6 +119,7 @@ impl PhosphorIcon {
             "file" => Some(Self::File),
             "file-text" => Some(Self::FileText),
             "file-image" => Some(Self::FileImage),
+            "folder" => Some(Self::Folder),
             "image" => Some(Self::Image),
             "magnifying-glass" => Some(Self::MagnifyingGlass),
             "globe" => Some(Self::Globe),
@@ -122,6 +127,7 @@ impl PhosphorIcon {
             "youtube-logo" => Some(Self::YoutubeLogo),
             "brain" => Some(Self::Brain),
             "palette" => Some(Self::Palette),
+            "squares-four" => Some(Self::SquaresFour),
             _ => None,
         }
     }
@@ -0,0 +1,89 @@
+//! Self-profiling for `zlaunch bench`.
+//!
+//! Times the startup and search hot paths through the same code the daemon
+//! runs, so numbers reported by users are representative of their system.
+
+use anyhow::Result;
+use std::time::{Duration, Instant};
+
+use crate::config::{config, get_combined_modules, init_config};
+use crate::desktop::cache::DesktopEntryCache;
+use crate::desktop::scan_applications;
+use crate::items::{ApplicationItem, ListItem};
+use crate::ui::delegates::ItemFilter;
+use crate::ui::icon::resolve_icon_path;
+
+/// Run all benchmarks and print the results.
+///
+/// The query is filtered `iterations` times against the scanned
+/// applications and the average time is reported.
+pub fn run(query: &str, iterations: u32) -> Result<()> {
+    init_config();
+
+    let (entries, scan_time) = timed(scan_applications);
+    report(
+        "Application scan",
+        scan_time,
+        &format!("{} entries", entries.len()),
+    );
+
+    let (cache, cache_time) = timed(|| DesktopEntryCache::load().filter(|c| c.is_valid()));
+    let cache_note = match cache {
+        Some(cache) => format!("{} entries", cache.entries.len()),
+        None => "no valid cache".to_string(),
+    };
+    report("Cache load", cache_time, &cache_note);
+
+    let (resolved, icon_time) = timed(|| {
+        entries
+            .iter()
+            .filter_map(|entry| entry.icon.as_deref())
+            .filter_map(resolve_icon_path)
+            .count()
+    });
+    report(
+        "Icon resolution",
+        icon_time,
+        &format!("{} icons found", resolved),
+    );
+
+    let items: Vec<ListItem> = entries
+        .iter()
+        .map(|entry| ApplicationItem::from(entry).into())
+        .collect();
+    let filter = ItemFilter::new(config().fuzzy_match);
+    let modules = get_combined_modules();
+    let iterations = iterations.max(1);
+
+    let (matches, filter_time) = timed(|| {
+        let mut matches = 0;
+        for _ in 0..iterations {
+            matches = filter.filter_with_scores(&items, query, &modules).len();
+        }
+        matches
+    });
+    report(
+        &format!("Filter \"{}\"", query),
+        filter_time / iterations,
+        &format!("{} matches, average of {} runs", matches, iterations),
+    );
+
+    Ok(())
+}
+
+/// Run a closure and measure how long it took.
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Print a single benchmark result line.
+fn report(label: &str, duration: Duration, note: &str) {
+    println!(
+        "{:<24} {:>10.3} ms  ({})",
+        label,
+        duration.as_secs_f64() * 1000.0,
+        note
+    );
+}
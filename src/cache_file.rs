@@ -0,0 +1,64 @@
+//! Small JSON state files in the XDG cache directory.
+//!
+//! Recent launches and learned selections are each kept in a file of their
+//! own next to the application cache, namespaced by the active config
+//! profile. A missing or unreadable file counts as empty state.
+
+use crate::config::profile::{active_profile, profile_dir};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Get the path of the cache file `name` for the active profile.
+pub fn path(name: &str) -> Option<PathBuf> {
+    dirs::cache_dir().map(|d| profile_dir(d.join("zlaunch"), active_profile()).join(name))
+}
+
+/// Load the cache file `name`, or the default if there is none.
+pub fn load<T: DeserializeOwned + Default>(name: &str) -> T {
+    path(name).and_then(|path| read(&path)).unwrap_or_default()
+}
+
+/// Save `value` to the cache file `name`.
+pub fn save<T: Serialize>(name: &str, value: &T) -> anyhow::Result<()> {
+    let path = path(name).ok_or_else(|| anyhow::anyhow!("No cache directory"))?;
+    write(&path, value)
+}
+
+fn read<T: DeserializeOwned>(path: &Path) -> Option<T> {
+    let data = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&data).ok()
+}
+
+fn write<T: Serialize>(path: &Path, value: &T) -> anyhow::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(path, serde_json::to_string(value)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_then_read() {
+        let dir = std::env::temp_dir().join(format!("zlaunch-cache-file-{}", std::process::id()));
+        let path = dir.join("nested").join("state.json");
+
+        assert_eq!(read::<Vec<String>>(&path), None);
+        write(&path, &vec!["firefox".to_string()]).unwrap();
+        assert_eq!(
+            read::<Vec<String>>(&path),
+            Some(vec!["firefox".to_string()])
+        );
+
+        fs::write(&path, "not json").unwrap();
+        assert_eq!(read::<Vec<String>>(&path), None);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
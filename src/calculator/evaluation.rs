@@ -45,12 +45,30 @@ pub fn evaluate_expression(input: &str) -> Result<CalculatorItem, String> {
         Ok(value) => {
             let value = value.get_main_result();
             let calc_value = value.trim_start_matches("approx. ");
+            let (display_result, unit_label) = match split_unit(value) {
+                Some((number, unit)) => (
+                    format!(
+                        "{}{} {}",
+                        if value.starts_with("approx. ") {
+                            "approx. "
+                        } else {
+                            ""
+                        },
+                        format_display(number),
+                        unit
+                    ),
+                    Some(unit_label(&expression, unit)),
+                ),
+                None => (format_display(value), None),
+            };
             Ok(CalculatorItem {
                 id: "calculator-result".to_string(),
                 expression,
-                display_result: format_display(value),
+                display_result,
                 clipboard_result: Some(calc_value.to_string()),
                 is_error: false,
+                unit_label,
+                copied: false,
             })
         }
         Err(err) => {
@@ -61,6 +79,8 @@ pub fn evaluate_expression(input: &str) -> Result<CalculatorItem, String> {
                     display_result: "Infinity".to_string(),
                     clipboard_result: None,
                     is_error: true,
+                    unit_label: None,
+                    copied: false,
                 })
             } else {
                 Err(err.to_string())
@@ -69,6 +89,45 @@ pub fn evaluate_expression(input: &str) -> Result<CalculatorItem, String> {
     }
 }
 
+/// Split a result like "4.828032 km" into its number and unit.
+///
+/// Returns None for results without units (plain numbers, dates, text).
+fn split_unit(value: &str) -> Option<(&str, &str)> {
+    let (number, unit) = value.trim_start_matches("approx. ").split_once(' ')?;
+    let unit = unit.trim();
+    (number.parse::<f64>().is_ok() && !unit.is_empty()).then_some((number, unit))
+}
+
+/// Describe the units of a result.
+///
+/// A conversion ("3 miles in km") shows the source and target units; any
+/// other calculation shows the result's unit.
+fn unit_label(expression: &str, result_unit: &str) -> String {
+    let source = [" to ", " in ", " as "]
+        .iter()
+        .find_map(|keyword| {
+            expression
+                .to_ascii_lowercase()
+                .split_once(keyword)
+                .map(|(s, _)| s.len())
+        })
+        .map(|end| source_unit(&expression[..end]))
+        .filter(|unit| !unit.is_empty());
+
+    match source {
+        Some(source) => format!("{} → {}", source, result_unit),
+        None => result_unit.to_string(),
+    }
+}
+
+/// Get the unit of a quantity like "3 miles", dropping the number.
+fn source_unit(quantity: &str) -> &str {
+    quantity
+        .trim()
+        .trim_start_matches(|c: char| c.is_ascii_digit() || matches!(c, '.' | ',' | '-' | '+'))
+        .trim()
+}
+
 /// Format a number for display with thousand separators.
 fn format_display(value: &str) -> String {
     // Convert to f64, else return the original string
@@ -119,7 +178,7 @@ fn format_with_separators(value: i64) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::evaluate_expression;
+    use super::{evaluate_expression, unit_label};
 
     #[test]
     fn test_basic_evaluation() {
@@ -167,4 +226,32 @@ mod tests {
         let result = evaluate_expression("sin(0)").unwrap();
         assert_eq!(result.display_result, "0");
     }
+
+    #[test]
+    fn test_unit_conversion_label() {
+        let result = evaluate_expression("3 miles in km").unwrap();
+        assert_eq!(result.display_result, "4.828032 km");
+        assert_eq!(result.unit_label.as_deref(), Some("miles → km"));
+        assert_eq!(result.text_for_clipboard(), "4.828032 km");
+    }
+
+    #[test]
+    fn test_unit_calculation_label() {
+        let result = evaluate_expression("1500 m * 2").unwrap();
+        assert_eq!(result.display_result, "3,000 m");
+        assert_eq!(result.unit_label.as_deref(), Some("m"));
+    }
+
+    #[test]
+    fn test_plain_result_has_no_unit() {
+        assert_eq!(evaluate_expression("2 + 2").unwrap().unit_label, None);
+        assert_eq!(evaluate_expression("0x10 + 1").unwrap().unit_label, None);
+    }
+
+    #[test]
+    fn test_unit_label_source_unit() {
+        assert_eq!(unit_label("100 USD to EUR", "EUR"), "USD → EUR");
+        assert_eq!(unit_label("5 in to cm", "cm"), "in → cm");
+        assert_eq!(unit_label("2 to the power of 3", "m"), "m");
+    }
 }
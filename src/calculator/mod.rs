@@ -2,6 +2,7 @@
 //!
 //! This module provides functionality to:
 //! - Evaluate expressions using fend
+//! - Label the units of unit conversions
 
 mod evaluation;
 
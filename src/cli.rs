@@ -1,25 +1,60 @@
 use anyhow::Result;
 use clap::{Parser, Subcommand};
+use std::io::BufRead;
+use std::path::PathBuf;
 
-use crate::config::LauncherMode;
+use crate::config::profile::active_profile;
+use crate::config::{ConfigModule, LauncherMode};
+use crate::daemon::LogFormat;
+use crate::error::IpcClientError;
 use crate::ipc::client;
 
 #[derive(Parser)]
 #[command(name = "zlaunch")]
 #[command(about = "A fast application launcher for Linux")]
 pub struct Cli {
+    /// Config profile to use (reads ~/.config/zlaunch/profiles/<name>/)
+    #[arg(long, global = true, value_name = "NAME")]
+    pub profile: Option<String>,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
 
 #[derive(Subcommand)]
 pub enum Commands {
+    /// Run the daemon (the default without a subcommand)
+    Daemon {
+        /// Stay attached and fail if a daemon is already running, for capturing logs
+        #[arg(long)]
+        foreground: bool,
+        /// Log output format
+        #[arg(long, value_enum, default_value_t = LogFormat::Text)]
+        log_format: LogFormat,
+    },
     /// Show the launcher window
     Show {
         /// Modes to enable (can specify multiple with commas or repeated flags)
         #[arg(short, long, value_delimiter = ',')]
         modes: Option<Vec<LauncherMode>>,
     },
+    /// Show the launcher window with the search input filled in
+    ///
+    /// The text is filtered as if it was typed, so e.g. `zlaunch query 2+2`
+    /// opens on the calculator result.
+    Query {
+        /// Text to fill the search input with
+        text: String,
+    },
+    /// Choose one of the lines read from stdin, like dmenu
+    ///
+    /// The chosen line is printed to stdout. Cancelling prints nothing and
+    /// exits with status 1. Empty lines are skipped.
+    Dmenu {
+        /// Placeholder text of the search input
+        #[arg(short, long)]
+        prompt: Option<String>,
+    },
     /// Hide the launcher window
     Hide,
     /// Toggle the launcher window visibility
@@ -32,11 +67,61 @@ pub enum Commands {
     Quit,
     /// Reload the daemon (fully restart the process)
     Reload,
+    /// Rescan desktop entries, ignoring the application cache
+    Rescan,
+    /// Show daemon health status, warning if the daemon is another version
+    Status,
+    /// Check that the daemon is reachable and report its version
+    Ping,
+    /// Open a URL with the default handler, launched by the daemon
+    OpenUrl {
+        /// URL to open (must include a scheme, e.g. https://)
+        url: String,
+    },
+    /// Open a file with its default application, launched by the daemon
+    OpenFile {
+        /// Path of the file to open
+        path: PathBuf,
+    },
     /// Theme management
     Theme {
         #[command(subcommand)]
         action: Option<ThemeCommands>,
     },
+    /// Combined view module management
+    Modules {
+        #[command(subcommand)]
+        action: Option<ModulesCommands>,
+    },
+    /// Pin an application to the top of the combined view
+    Pin {
+        /// Desktop entry id of the application (e.g. "firefox")
+        app_id: String,
+    },
+    /// Time application scanning, cache loading, icon resolution and filtering
+    ///
+    /// Runs locally without the daemon.
+    Bench {
+        /// Query to filter the applications with
+        #[arg(default_value = "term")]
+        query: String,
+        /// Number of filter runs to average over
+        #[arg(short, long, default_value_t = 100)]
+        iterations: u32,
+    },
+    /// List the applications found in the desktop entry directories
+    ///
+    /// Runs locally without the daemon.
+    Apps {
+        /// Also report desktop files that were skipped, and why
+        #[arg(short, long)]
+        verbose: bool,
+    },
+    /// Serve the IPC commands as newline-delimited JSON-RPC on stdin/stdout
+    ///
+    /// For editor and bar plugins. Requests are forwarded to the daemon,
+    /// which doesn't need to be running yet.
+    RpcStdio,
 }
 
 #[derive(Subcommand)]
@@ -50,16 +135,53 @@ pub enum ThemeCommands {
     },
 }
 
+#[derive(Subcommand)]
+pub enum ModulesCommands {
+    /// Set the modules shown in the combined view, in order
+    Set {
+        /// Modules in display order (comma separated or repeated)
+        #[arg(required = true, value_delimiter = ',')]
+        modules: Vec<ConfigModule>,
+    },
+}
+
 /// Handle a client command by sending it to the running daemon.
 pub fn handle_client_command(cmd: Commands) -> Result<()> {
+    // Benchmarks run in-process and don't need the daemon
+    if let Commands::Bench { query, iterations } = cmd {
+        return crate::bench::run(&query, iterations);
+    }
+    // Scanning runs in-process like the daemon does at startup
+    if let Commands::Apps { verbose } = cmd {
+        list_applications(verbose);
+        return Ok(());
+    }
+    // The stdio server connects per request and reports errors as responses
+    if let Commands::RpcStdio = cmd {
+        return crate::ipc::stdio::run();
+    }
+
     if !client::is_daemon_running() {
-        anyhow::bail!("zlaunch daemon is not running. Start it first by running: zlaunch");
+        return Err(IpcClientError::DaemonNotRunning {
+            profile: active_profile().map(str::to_string),
+        }
+        .into());
     }
 
     match cmd {
         Commands::Show { modes } => {
             client::show(modes)?;
         }
+        Commands::Query { text } => {
+            client::open_with_query(&text)?;
+        }
+        Commands::Dmenu { prompt } => {
+            let lines = read_dmenu_lines(std::io::stdin().lock())?;
+            match client::dmenu(lines, prompt)? {
+                Some(line) => println!("{}", line),
+                None => std::process::exit(1),
+            }
+        }
         Commands::Hide => {
             client::hide()?;
         }
@@ -73,6 +195,36 @@ pub fn handle_client_command(cmd: Commands) -> Result<()> {
             client::reload()?;
             println!("Daemon is reloading...");
         }
+        Commands::Rescan => {
+            let count = client::rescan()?;
+            println!("Found {} applications", count);
+        }
+        Commands::Status => {
+            let status = client::status()?;
+            let monitor = status.clipboard_monitor;
+            println!(
+                "Clipboard monitor: {} (restarts: {})",
+                monitor.health, monitor.restarts
+            );
+            if let Some(err) = monitor.last_error {
+                println!("  Last error: {}", err);
+            }
+            // Only the diagnostic commands check, so others stay one round trip
+            client::warn_on_version_mismatch();
+        }
+        Commands::Ping => {
+            let daemon_version = client::version()?;
+            println!("zlaunch daemon {} is running", daemon_version);
+            if let Some(warning) = client::version_mismatch(&daemon_version) {
+                eprintln!("Warning: {}", warning);
+            }
+        }
+        Commands::OpenUrl { url } => {
+            client::open_url(&url)?;
+        }
+        Commands::OpenFile { path } => {
+            client::open_file(&path)?;
+        }
         Commands::Theme { action } => match action {
             None => {
                 // No subcommand - show current theme
@@ -96,7 +248,67 @@ pub fn handle_client_command(cmd: Commands) -> Result<()> {
                 println!("Theme set to '{}'", name);
             }
         },
+        Commands::Modules { action } => match action {
+            None => {
+                // No subcommand - show current order
+                let modules = client::get_combined_modules()?;
+                println!("Combined modules:");
+                for module in modules {
+                    println!("  {}", LauncherMode::from_module(&module).display_name());
+                }
+            }
+            Some(ModulesCommands::Set { modules }) => {
+                client::set_combined_modules(modules)?;
+                println!("Combined modules updated");
+            }
+        },
+        Commands::Pin { app_id } => {
+            client::pin_application(&app_id)?;
+            println!("Pinned '{}'", app_id);
+        }
+        Commands::Daemon { .. } => unreachable!("the daemon is started by main"),
+        Commands::Bench { .. } => unreachable!("benchmarks are handled before connecting"),
+        Commands::Apps { .. } => unreachable!("applications are listed before connecting"),
+        Commands::RpcStdio => unreachable!("the stdio server is handled before connecting"),
     }
 
     Ok(())
 }
+
+/// Read the lines to choose from, skipping empty ones.
+fn read_dmenu_lines(input: impl BufRead) -> Result<Vec<String>> {
+    let mut lines = Vec::new();
+    for line in input.lines() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            lines.push(line);
+        }
+    }
+    Ok(lines)
+}
+
+/// Print the scanned applications, and with `verbose` the skipped files.
+fn list_applications(verbose: bool) {
+    crate::config::init_config();
+    let report = crate::desktop::scan_applications_with_report();
+    let entries = crate::desktop::scanner::apply_dedup_config(report.entries);
+
+    println!("Applications ({}):", entries.len());
+    for entry in &entries {
+        println!("  {} ({})", entry.name, entry.path.display());
+    }
+
+    if verbose {
+        println!();
+        println!("Skipped desktop files ({}):", report.skipped.len());
+        for skipped in &report.skipped {
+            println!("  {}: {}", skipped.path.display(), skipped.reason);
+        }
+    } else if !report.skipped.is_empty() {
+        println!();
+        println!(
+            "{} desktop files were skipped, run with --verbose to see why",
+            report.skipped.len()
+        );
+    }
+}
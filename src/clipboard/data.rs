@@ -1,14 +1,44 @@
 //! Clipboard history data storage and search.
 
 use super::item::{ClipboardContent, ClipboardItem};
+use crate::config::AppConfig;
 use fuzzy_matcher::FuzzyMatcher;
 use fuzzy_matcher::skim::SkimMatcherV2;
 use std::collections::VecDeque;
 use std::sync::RwLock;
+use std::time::{Duration, SystemTime};
 
 /// Global clipboard history storage.
 static CLIPBOARD_HISTORY: RwLock<Option<VecDeque<ClipboardItem>>> = RwLock::new(None);
 
+/// Limits on the size and age of clipboard history.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HistoryLimits {
+    /// Maximum number of entries (0 for no limit).
+    pub max_entries: usize,
+    /// Maximum age of an entry.
+    pub max_age: Option<Duration>,
+    /// Maximum total size of image entries in bytes (0 for no limit).
+    pub max_image_bytes: usize,
+    /// Maximum age of a sensitive entry.
+    pub sensitive_max_age: Option<Duration>,
+}
+
+impl HistoryLimits {
+    /// Get the limits set in the config.
+    pub fn from_config(config: &AppConfig) -> Self {
+        Self {
+            max_entries: config.clipboard_max_entries,
+            max_age: config
+                .clipboard_max_age_days
+                .map(|days| Duration::from_secs(u64::from(days) * 24 * 60 * 60)),
+            max_image_bytes: config.clipboard_max_image_mib.saturating_mul(1024 * 1024),
+            sensitive_max_age: (config.clipboard_sensitive_expiry_secs > 0)
+                .then(|| Duration::from_secs(config.clipboard_sensitive_expiry_secs)),
+        }
+    }
+}
+
 /// Initialize the clipboard history storage.
 pub fn init() {
     let mut history = CLIPBOARD_HISTORY.write().unwrap();
@@ -17,58 +47,93 @@ pub fn init() {
     }
 }
 
+fn current_limits() -> HistoryLimits {
+    HistoryLimits::from_config(&crate::config::config())
+}
+
 /// Add a new item to clipboard history.
-/// If the item is identical to the most recent one, it won't be added.
-pub fn add_item(content: ClipboardContent) {
+/// If the same content is already in the history, it is moved to the top
+/// with a new timestamp instead of being added again.
+///
+/// `sensitive` marks content copied from a password manager. History is
+/// kept in memory only, so sensitive items never reach the disk.
+pub fn add_item(content: ClipboardContent, sensitive: bool) {
     let mut history = CLIPBOARD_HISTORY.write().unwrap();
     let history = history.as_mut().expect("Clipboard history not initialized");
+    insert_item(history, content, sensitive);
+    prune(history, &current_limits(), SystemTime::now());
+}
 
-    // Don't add duplicate consecutive items
-    if let Some(last) = history.front()
-        && is_same_content(&last.content, &content)
+/// Insert content at the top of `history`, removing an earlier copy of it.
+///
+/// Content that was sensitive stays sensitive when it is copied again, e.g.
+/// from the history itself.
+fn insert_item(history: &mut VecDeque<ClipboardItem>, content: ClipboardContent, sensitive: bool) {
+    let mut item = ClipboardItem::new(content).with_sensitive(sensitive);
+    if let Some(index) = history
+        .iter()
+        .position(|existing| existing.has_content(&item.content, item.hash))
+        && let Some(existing) = history.remove(index)
     {
-        return;
+        item.sensitive |= existing.sensitive;
     }
-
-    let item = ClipboardItem::new(content);
     history.push_front(item);
 }
 
-/// Check if two clipboard contents are the same.
-fn is_same_content(a: &ClipboardContent, b: &ClipboardContent) -> bool {
-    match (a, b) {
-        (ClipboardContent::Text(a), ClipboardContent::Text(b)) => a == b,
-        (
-            ClipboardContent::Image {
-                width: w1,
-                height: h1,
-                rgba_bytes: b1,
-            },
-            ClipboardContent::Image {
-                width: w2,
-                height: h2,
-                rgba_bytes: b2,
-            },
-        ) => w1 == w2 && h1 == h2 && b1 == b2,
-        (ClipboardContent::FilePaths(a), ClipboardContent::FilePaths(b)) => a == b,
-        (
-            ClipboardContent::RichText {
-                plain: p1,
-                html: h1,
-            },
-            ClipboardContent::RichText {
-                plain: p2,
-                html: h2,
-            },
-        ) => p1 == p2 && h1 == h2,
-        _ => false,
+/// Drop entries beyond `limits`, oldest first.
+///
+/// Entries older than the age limit go first, then the oldest images until
+/// the rest fit the image budget, then the oldest entries past the count.
+fn prune(history: &mut VecDeque<ClipboardItem>, limits: &HistoryLimits, now: SystemTime) {
+    let older_than = |item: &ClipboardItem, max_age: Option<Duration>| {
+        max_age.is_some_and(|max_age| {
+            now.duration_since(item.timestamp)
+                .is_ok_and(|age| age > max_age)
+        })
+    };
+    history.retain(|item| {
+        !older_than(item, limits.max_age)
+            && !(item.sensitive && older_than(item, limits.sensitive_max_age))
+    });
+
+    if limits.max_image_bytes > 0 {
+        let mut image_bytes = 0usize;
+        history.retain(|item| {
+            let size = item.content.image_size();
+            image_bytes = image_bytes.saturating_add(size);
+            size == 0 || image_bytes <= limits.max_image_bytes
+        });
+    }
+
+    if limits.max_entries > 0 {
+        history.truncate(limits.max_entries);
     }
 }
 
+/// Remove an item from clipboard history.
+///
+/// Returns whether the item was in the history.
+pub fn remove_item(item: &ClipboardItem) -> bool {
+    let mut history = CLIPBOARD_HISTORY.write().unwrap();
+    history
+        .as_mut()
+        .is_some_and(|history| remove_from(history, item))
+}
+
+/// Remove the entry holding the same content as `item` from `history`.
+fn remove_from(history: &mut VecDeque<ClipboardItem>, item: &ClipboardItem) -> bool {
+    let index = history
+        .iter()
+        .position(|existing| existing.has_content(&item.content, item.hash));
+    index.and_then(|index| history.remove(index)).is_some()
+}
+
 /// Get all clipboard items, optionally filtered by a search query.
 pub fn search_items(query: &str) -> Vec<ClipboardItem> {
-    let history = CLIPBOARD_HISTORY.read().unwrap();
-    let history = history.as_ref().expect("Clipboard history not initialized");
+    let mut history = CLIPBOARD_HISTORY.write().unwrap();
+    let history = history.as_mut().expect("Clipboard history not initialized");
+    // Entries expire while nothing is copied, too
+    prune(history, &current_limits(), SystemTime::now());
 
     if query.is_empty() {
         return history.iter().cloned().collect();
@@ -78,6 +143,10 @@ pub fn search_items(query: &str) -> Vec<ClipboardItem> {
     let mut scored: Vec<(ClipboardItem, i64)> = history
         .iter()
         .filter_map(|item| {
+            // Secrets are not searched by their content
+            if item.sensitive {
+                return None;
+            }
             let search_text = match &item.content {
                 ClipboardContent::Text(text) => text.clone(),
                 ClipboardContent::Image { .. } => "image".to_string(),
@@ -106,10 +175,186 @@ pub fn item_count() -> usize {
 }
 
 /// Clear all clipboard history.
-#[allow(dead_code)]
 pub fn clear_history() {
     let mut history = CLIPBOARD_HISTORY.write().unwrap();
     if let Some(h) = history.as_mut() {
         h.clear();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(history: &VecDeque<ClipboardItem>) -> Vec<String> {
+        history.iter().map(|item| item.full_content()).collect()
+    }
+
+    #[test]
+    fn test_recopy_moves_to_top() {
+        let mut history = VecDeque::new();
+        for text in ["a", "b", "c"] {
+            insert_item(
+                &mut history,
+                ClipboardContent::Text(text.to_string()),
+                false,
+            );
+        }
+        assert_eq!(texts(&history), ["c", "b", "a"]);
+
+        // Age the oldest entry, then copy it again
+        history[2].timestamp = SystemTime::UNIX_EPOCH + Duration::from_secs(1);
+        insert_item(&mut history, ClipboardContent::Text("a".to_string()), false);
+        assert_eq!(texts(&history), ["a", "c", "b"]);
+        assert!(history[0].timestamp > SystemTime::UNIX_EPOCH + Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_recopy_of_latest_keeps_one_entry() {
+        let mut history = VecDeque::new();
+        insert_item(&mut history, ClipboardContent::Text("a".to_string()), false);
+        insert_item(&mut history, ClipboardContent::Text("a".to_string()), false);
+        assert_eq!(texts(&history), ["a"]);
+
+        // Same text as a different content type is a separate entry
+        insert_item(
+            &mut history,
+            ClipboardContent::RichText {
+                plain: "a".to_string(),
+                html: "<p>a</p>".to_string(),
+            },
+            false,
+        );
+        assert_eq!(history.len(), 2);
+    }
+
+    fn limits(
+        max_entries: usize,
+        max_age_secs: Option<u64>,
+        max_image_bytes: usize,
+    ) -> HistoryLimits {
+        HistoryLimits {
+            max_entries,
+            max_age: max_age_secs.map(Duration::from_secs),
+            max_image_bytes,
+            sensitive_max_age: None,
+        }
+    }
+
+    fn image(size: usize) -> ClipboardContent {
+        ClipboardContent::Image {
+            width: 1,
+            height: size / 4,
+            rgba_bytes: vec![size as u8; size],
+            gif_bytes: None,
+        }
+    }
+
+    #[test]
+    fn test_prune_keeps_newest_entries() {
+        let mut history = VecDeque::new();
+        for text in ["a", "b", "c", "d"] {
+            insert_item(
+                &mut history,
+                ClipboardContent::Text(text.to_string()),
+                false,
+            );
+        }
+        prune(&mut history, &limits(2, None, 0), SystemTime::now());
+        assert_eq!(texts(&history), ["d", "c"]);
+
+        prune(&mut history, &limits(0, None, 0), SystemTime::now());
+        assert_eq!(history.len(), 2);
+    }
+
+    #[test]
+    fn test_prune_drops_expired_entries() {
+        let mut history = VecDeque::new();
+        for text in ["old", "new"] {
+            insert_item(
+                &mut history,
+                ClipboardContent::Text(text.to_string()),
+                false,
+            );
+        }
+        let now = history[0].timestamp;
+        history[1].timestamp = now - Duration::from_secs(120);
+
+        prune(&mut history, &limits(0, Some(60), 0), now);
+        assert_eq!(texts(&history), ["new"]);
+    }
+
+    #[test]
+    fn test_prune_drops_oldest_images_over_budget() {
+        let mut history = VecDeque::new();
+        insert_item(&mut history, image(400), false);
+        insert_item(
+            &mut history,
+            ClipboardContent::Text("text".to_string()),
+            false,
+        );
+        insert_item(&mut history, image(800), false);
+        insert_item(&mut history, image(200), false);
+
+        prune(&mut history, &limits(0, None, 1000), SystemTime::now());
+        let sizes: Vec<usize> = history.iter().map(|i| i.content.image_size()).collect();
+        assert_eq!(sizes, [200, 800, 0]);
+    }
+
+    #[test]
+    fn test_remove_item() {
+        let mut history = VecDeque::new();
+        for text in ["a", "b", "c"] {
+            insert_item(
+                &mut history,
+                ClipboardContent::Text(text.to_string()),
+                false,
+            );
+        }
+        let item = history[1].clone();
+
+        assert!(remove_from(&mut history, &item));
+        assert_eq!(texts(&history), ["c", "a"]);
+        assert!(!remove_from(&mut history, &item));
+    }
+
+    #[test]
+    fn test_prune_expires_sensitive_entries_early() {
+        let mut history = VecDeque::new();
+        insert_item(
+            &mut history,
+            ClipboardContent::Text("note".to_string()),
+            false,
+        );
+        insert_item(
+            &mut history,
+            ClipboardContent::Text("hunter2".to_string()),
+            true,
+        );
+        let now = history[0].timestamp + Duration::from_secs(45);
+
+        let limits = HistoryLimits {
+            sensitive_max_age: Some(Duration::from_secs(30)),
+            ..limits(0, None, 0)
+        };
+        prune(&mut history, &limits, now);
+        assert_eq!(texts(&history), ["note"]);
+    }
+
+    #[test]
+    fn test_recopy_keeps_item_sensitive() {
+        let mut history = VecDeque::new();
+        insert_item(
+            &mut history,
+            ClipboardContent::Text("hunter2".to_string()),
+            true,
+        );
+        insert_item(
+            &mut history,
+            ClipboardContent::Text("hunter2".to_string()),
+            false,
+        );
+        assert_eq!(history.len(), 1);
+        assert!(history[0].sensitive);
+    }
+}
@@ -1,13 +1,33 @@
 //! Clipboard item data structures.
 
+use crate::config::ClipboardSortOrder;
+use std::cmp::Ordering;
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::path::PathBuf;
 use std::time::SystemTime;
 
+/// MIME type of animated images kept in their original encoding.
+pub const GIF_MIME_TYPE: &str = "image/gif";
+
+/// Largest GIF (in bytes) kept for animated previews.
+pub const MAX_GIF_BYTES: usize = 8 * 1024 * 1024;
+
+/// Most frames a GIF may have to be kept for animated previews.
+pub const MAX_GIF_FRAMES: usize = 500;
+
+/// Text shown instead of the content of sensitive items.
+pub const MASKED_CONTENT: &str = "••••••";
+
 /// Represents a single clipboard history entry.
 #[derive(Clone, Debug)]
 pub struct ClipboardItem {
     pub content: ClipboardContent,
     pub timestamp: SystemTime,
+    /// Hash of the content, to find earlier copies of the same content
+    pub hash: u64,
+    /// Whether the content was copied from a password manager. Sensitive
+    /// items are masked when shown and expire early.
+    pub sensitive: bool,
 }
 
 /// The content type of a clipboard item.
@@ -20,6 +40,8 @@ pub enum ClipboardContent {
         width: usize,
         height: usize,
         rgba_bytes: Vec<u8>,
+        /// Original GIF encoding of an animated image, for animated previews
+        gif_bytes: Option<Vec<u8>>,
     },
     /// File path(s) copied from file manager
     FilePaths(Vec<PathBuf>),
@@ -31,15 +53,32 @@ impl ClipboardItem {
     /// Create a new clipboard item with the current timestamp.
     pub fn new(content: ClipboardContent) -> Self {
         Self {
+            hash: content.content_hash(),
             content,
             timestamp: SystemTime::now(),
+            sensitive: false,
         }
     }
 
+    /// Builder method to mark the item as sensitive.
+    pub fn with_sensitive(mut self, sensitive: bool) -> Self {
+        self.sensitive = sensitive;
+        self
+    }
+
+    /// Check whether this item holds the same content as `content`.
+    pub fn has_content(&self, content: &ClipboardContent, hash: u64) -> bool {
+        self.hash == hash && self.content.is_same_as(content)
+    }
+
     /// Get a short preview string for display in the list.
     pub fn preview(&self) -> String {
         const MAX_LENGTH: usize = 30;
 
+        if self.sensitive {
+            return MASKED_CONTENT.to_string();
+        }
+
         match &self.content {
             ClipboardContent::Text(text) => {
                 let first_line = text.lines().next().unwrap_or("");
@@ -66,6 +105,9 @@ impl ClipboardItem {
 
     /// Get the full content as a string for preview panel.
     pub fn full_content(&self) -> String {
+        if self.sensitive {
+            return MASKED_CONTENT.to_string();
+        }
         match &self.content {
             ClipboardContent::Text(text) => text.clone(),
             ClipboardContent::Image { .. } => "[Image preview]".to_string(),
@@ -78,6 +120,44 @@ impl ClipboardItem {
         }
     }
 
+    /// Get the URL or path this item can be opened with, if any.
+    ///
+    /// Returns a target for `file://` URLs, web URLs and single file paths,
+    /// which is passed to the system handler by the secondary action.
+    pub fn open_target(&self) -> Option<String> {
+        // Never hand a secret to another application
+        if self.sensitive {
+            return None;
+        }
+        match &self.content {
+            ClipboardContent::Text(text) | ClipboardContent::RichText { plain: text, .. } => {
+                if let Some(path) = parse_file_url(text) {
+                    return Some(path.to_string_lossy().to_string());
+                }
+                let text = text.trim();
+                is_web_url(text).then(|| text.to_string())
+            }
+            ClipboardContent::FilePaths(paths) if paths.len() == 1 => {
+                Some(paths[0].to_string_lossy().to_string())
+            }
+            _ => None,
+        }
+    }
+
+    /// Compare two items for display in the given order.
+    pub fn compare(&self, other: &Self, order: ClipboardSortOrder) -> Ordering {
+        let newest_first = other.timestamp.cmp(&self.timestamp);
+        match order {
+            ClipboardSortOrder::Newest => newest_first,
+            ClipboardSortOrder::Oldest => newest_first.reverse(),
+            ClipboardSortOrder::Type => self
+                .content
+                .type_rank()
+                .cmp(&other.content.type_rank())
+                .then(newest_first),
+        }
+    }
+
     /// Check if this item is a text file that can be previewed.
     pub fn is_previewable_file(&self) -> bool {
         if let ClipboardContent::FilePaths(paths) = &self.content
@@ -105,6 +185,137 @@ impl ClipboardItem {
     }
 }
 
+impl ClipboardContent {
+    /// Hash the content, equal for contents that are the same copy.
+    ///
+    /// Like [`Self::is_same_as`], images are hashed by their pixels only.
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.type_rank().hash(&mut hasher);
+        match self {
+            Self::Text(text) => text.hash(&mut hasher),
+            Self::Image {
+                width,
+                height,
+                rgba_bytes,
+                ..
+            } => {
+                width.hash(&mut hasher);
+                height.hash(&mut hasher);
+                rgba_bytes.hash(&mut hasher);
+            }
+            Self::FilePaths(paths) => paths.hash(&mut hasher),
+            Self::RichText { plain, html } => {
+                plain.hash(&mut hasher);
+                html.hash(&mut hasher);
+            }
+        }
+        hasher.finish()
+    }
+
+    /// Check if two clipboard contents are the same.
+    pub fn is_same_as(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Text(a), Self::Text(b)) => a == b,
+            (
+                Self::Image {
+                    width: w1,
+                    height: h1,
+                    rgba_bytes: b1,
+                    ..
+                },
+                Self::Image {
+                    width: w2,
+                    height: h2,
+                    rgba_bytes: b2,
+                    ..
+                },
+            ) => w1 == w2 && h1 == h2 && b1 == b2,
+            (Self::FilePaths(a), Self::FilePaths(b)) => a == b,
+            (
+                Self::RichText {
+                    plain: p1,
+                    html: h1,
+                },
+                Self::RichText {
+                    plain: p2,
+                    html: h2,
+                },
+            ) => p1 == p2 && h1 == h2,
+            _ => false,
+        }
+    }
+
+    /// Get the number of image bytes held, or 0 for other content.
+    pub fn image_size(&self) -> usize {
+        match self {
+            Self::Image {
+                rgba_bytes,
+                gif_bytes,
+                ..
+            } => rgba_bytes.len() + gif_bytes.as_ref().map_or(0, Vec::len),
+            _ => 0,
+        }
+    }
+
+    /// Position of this content type when sorting by type.
+    fn type_rank(&self) -> u8 {
+        match self {
+            Self::Text(_) => 0,
+            Self::RichText { .. } => 1,
+            Self::Image { .. } => 2,
+            Self::FilePaths(_) => 3,
+        }
+    }
+}
+
+/// Parse a file:// URL and return the path.
+pub fn parse_file_url(text: &str) -> Option<PathBuf> {
+    let text = text.trim();
+
+    // Handle file:// URLs
+    if let Some(path_str) = text.strip_prefix("file://") {
+        // Remove the file:// prefix
+        // URL decode the path (handle %20 for spaces, etc.)
+        if let Ok(decoded) = urlencoding::decode(path_str) {
+            return Some(PathBuf::from(decoded.as_ref()));
+        }
+
+        // Fallback: use the path as-is
+        return Some(PathBuf::from(path_str));
+    }
+
+    None
+}
+
+/// Check if text is a single http(s) URL.
+pub fn is_web_url(text: &str) -> bool {
+    let text = text.trim();
+    let rest = text
+        .strip_prefix("https://")
+        .or_else(|| text.strip_prefix("http://"));
+
+    matches!(rest, Some(rest) if !rest.is_empty() && !rest.contains(char::is_whitespace))
+}
+
+/// Check whether GIF data is an animation small enough to keep.
+///
+/// Single-frame GIFs gain nothing over the RGBA copy, and oversized
+/// animations are dropped to bound memory use.
+pub fn is_keepable_animation(gif_bytes: &[u8]) -> bool {
+    use image::AnimationDecoder;
+    use image::codecs::gif::GifDecoder;
+
+    if gif_bytes.len() > MAX_GIF_BYTES {
+        return false;
+    }
+    let Ok(decoder) = GifDecoder::new(std::io::Cursor::new(gif_bytes)) else {
+        return false;
+    };
+    let frames = decoder.into_frames().take(MAX_GIF_FRAMES + 1).count();
+    (2..=MAX_GIF_FRAMES).contains(&frames)
+}
+
 /// Truncate wihtout splitting emojis
 fn truncate_preview_line(line: &str, max: usize) -> String {
     let truncated: String = line.chars().take(max).collect();
@@ -115,3 +326,191 @@ fn truncate_preview_line(line: &str, max: usize) -> String {
         truncated
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encode a solid-color GIF with the given number of frames.
+    fn gif_with_frames(count: usize) -> Vec<u8> {
+        use image::codecs::gif::GifEncoder;
+        use image::{Frame, Rgba, RgbaImage};
+
+        let mut bytes = Vec::new();
+        {
+            let mut encoder = GifEncoder::new(&mut bytes);
+            let frames = (0..count).map(|i| {
+                let shade = (i * 40 % 256) as u8;
+                Frame::new(RgbaImage::from_pixel(2, 2, Rgba([shade, 0, 0, 255])))
+            });
+            encoder.encode_frames(frames).unwrap();
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_is_web_url() {
+        assert!(is_web_url("https://example.com"));
+        assert!(is_web_url("  http://example.com/a?b=c  "));
+        assert!(!is_web_url("https://"));
+        assert!(!is_web_url("https://example.com and more text"));
+        assert!(!is_web_url("example.com"));
+    }
+
+    #[test]
+    fn test_parse_file_url_decodes() {
+        assert_eq!(
+            parse_file_url("file:///home/user/My%20File.txt"),
+            Some(PathBuf::from("/home/user/My File.txt"))
+        );
+        assert_eq!(parse_file_url("/home/user/file.txt"), None);
+    }
+
+    #[test]
+    fn test_open_target() {
+        let url = ClipboardItem::new(ClipboardContent::Text("https://example.com".into()));
+        assert_eq!(url.open_target().as_deref(), Some("https://example.com"));
+
+        let file = ClipboardItem::new(ClipboardContent::Text("file:///tmp/a%20b.png".into()));
+        assert_eq!(file.open_target().as_deref(), Some("/tmp/a b.png"));
+
+        let paths = ClipboardItem::new(ClipboardContent::FilePaths(vec![PathBuf::from(
+            "/tmp/doc.pdf",
+        )]));
+        assert_eq!(paths.open_target().as_deref(), Some("/tmp/doc.pdf"));
+
+        let text = ClipboardItem::new(ClipboardContent::Text("just some text".into()));
+        assert_eq!(text.open_target(), None);
+    }
+
+    #[test]
+    fn test_sensitive_item_is_masked() {
+        let item = ClipboardItem::new(ClipboardContent::Text("https://hunter2.example".into()))
+            .with_sensitive(true);
+        assert_eq!(item.preview(), MASKED_CONTENT);
+        assert_eq!(item.full_content(), MASKED_CONTENT);
+        assert_eq!(item.open_target(), None);
+    }
+
+    #[test]
+    fn test_animated_gif_is_kept() {
+        assert!(is_keepable_animation(&gif_with_frames(3)));
+    }
+
+    #[test]
+    fn test_static_gif_is_not_kept() {
+        assert!(!is_keepable_animation(&gif_with_frames(1)));
+    }
+
+    #[test]
+    fn test_invalid_or_oversized_gif_is_not_kept() {
+        assert!(!is_keepable_animation(b"not a gif"));
+        assert!(!is_keepable_animation(&gif_with_frames(MAX_GIF_FRAMES + 1)));
+    }
+
+    fn text_copied_at(text: &str, secs: u64) -> ClipboardItem {
+        ClipboardItem {
+            timestamp: SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(secs),
+            ..ClipboardItem::new(ClipboardContent::Text(text.to_string()))
+        }
+    }
+
+    fn sorted(mut items: Vec<ClipboardItem>, order: ClipboardSortOrder) -> Vec<String> {
+        items.sort_by(|a, b| a.compare(b, order));
+        items.iter().map(|item| item.preview()).collect()
+    }
+
+    #[test]
+    fn test_compare_by_time() {
+        let items = vec![
+            text_copied_at("b", 2),
+            text_copied_at("a", 1),
+            text_copied_at("c", 3),
+        ];
+        assert_eq!(
+            sorted(items.clone(), ClipboardSortOrder::Newest),
+            ["c", "b", "a"]
+        );
+        assert_eq!(sorted(items, ClipboardSortOrder::Oldest), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_compare_by_type_keeps_newest_first_within_type() {
+        let file = ClipboardItem {
+            timestamp: SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(5),
+            ..ClipboardItem::new(ClipboardContent::FilePaths(vec![PathBuf::from(
+                "/tmp/notes.txt",
+            )]))
+        };
+        let items = vec![file, text_copied_at("old", 1), text_copied_at("new", 4)];
+        assert_eq!(
+            sorted(items, ClipboardSortOrder::Type),
+            ["new", "old", "notes.txt"]
+        );
+    }
+
+    fn image(rgba_bytes: Vec<u8>, gif_bytes: Option<Vec<u8>>) -> ClipboardContent {
+        ClipboardContent::Image {
+            width: 1,
+            height: 1,
+            rgba_bytes,
+            gif_bytes,
+        }
+    }
+
+    #[test]
+    fn test_content_hash_matches_same_content() {
+        let text = |s: &str| ClipboardContent::Text(s.to_string());
+        assert_eq!(text("hello").content_hash(), text("hello").content_hash());
+        assert_ne!(text("hello").content_hash(), text("world").content_hash());
+
+        let rich = |plain: &str, html: &str| ClipboardContent::RichText {
+            plain: plain.to_string(),
+            html: html.to_string(),
+        };
+        assert_eq!(
+            rich("hi", "<b>hi</b>").content_hash(),
+            rich("hi", "<b>hi</b>").content_hash()
+        );
+        assert_ne!(
+            rich("hi", "<b>hi</b>").content_hash(),
+            rich("hi", "<i>hi</i>").content_hash()
+        );
+
+        let files = |path: &str| ClipboardContent::FilePaths(vec![PathBuf::from(path)]);
+        assert_eq!(
+            files("/tmp/a").content_hash(),
+            files("/tmp/a").content_hash()
+        );
+        assert_ne!(
+            files("/tmp/a").content_hash(),
+            files("/tmp/b").content_hash()
+        );
+
+        // Images compare by pixels, not by their original GIF encoding
+        let pixels = vec![1, 2, 3, 255];
+        let animated = image(pixels.clone(), Some(b"GIF89a".to_vec()));
+        assert_eq!(
+            image(pixels.clone(), None).content_hash(),
+            animated.content_hash()
+        );
+        assert!(image(pixels, None).is_same_as(&animated));
+        assert_ne!(
+            animated.content_hash(),
+            image(vec![0, 0, 0, 255], None).content_hash()
+        );
+    }
+
+    #[test]
+    fn test_content_hash_differs_across_variants() {
+        let text = ClipboardContent::Text("/tmp/a".to_string());
+        let rich = ClipboardContent::RichText {
+            plain: "/tmp/a".to_string(),
+            html: String::new(),
+        };
+        let files = ClipboardContent::FilePaths(vec![PathBuf::from("/tmp/a")]);
+        assert_ne!(text.content_hash(), rich.content_hash());
+        assert_ne!(text.content_hash(), files.content_hash());
+        assert!(!text.is_same_as(&files));
+    }
+}
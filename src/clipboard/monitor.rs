@@ -1,13 +1,22 @@
 //! Clipboard monitoring using Wayland data-control protocol.
+//!
+//! The watcher loop runs under a supervisor thread that restarts it with
+//! exponential backoff if it exits abnormally (e.g. after a compositor
+//! restart), so history keeps updating instead of silently stopping.
 
 use super::data;
-use super::item::ClipboardContent;
+use super::item::{ClipboardContent, GIF_MIME_TYPE, MAX_GIF_BYTES, is_keepable_animation};
 use arboard::Clipboard;
-use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::io::Read;
+use std::os::fd::AsFd;
+use std::panic::{self, AssertUnwindSafe};
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 use std::thread;
-use std::time::Duration;
-use tracing::{debug, error, info};
+use std::time::{Duration, Instant};
+use tracing::{debug, error, info, warn};
 use wayland_client::protocol::{wl_registry, wl_seat};
 use wayland_client::{Connection, Dispatch, QueueHandle};
 use wayland_protocols_wlr::data_control::v1::client::{
@@ -15,6 +24,85 @@ use wayland_protocols_wlr::data_control::v1::client::{
     zwlr_data_control_source_v1,
 };
 
+/// Delay before the first restart attempt.
+const INITIAL_RESTART_DELAY: Duration = Duration::from_millis(500);
+/// Upper bound for the restart delay.
+const MAX_RESTART_DELAY: Duration = Duration::from_secs(30);
+/// A watcher that ran at least this long is considered healthy again,
+/// resetting the backoff.
+const STABLE_RUN_DURATION: Duration = Duration::from_secs(60);
+/// How long the source application may take to send offered data.
+const RECEIVE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// MIME types password managers offer alongside a secret, so clipboard
+/// managers can tell it apart. `x-kde-passwordManagerHint` is set by
+/// KeePassXC and KDE applications.
+const SENSITIVE_MIME_TYPES: &[&str] = &["x-kde-passwordManagerHint"];
+
+/// Health of the clipboard monitor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MonitorHealth {
+    /// The monitor was never started (clipboard module disabled).
+    Inactive,
+    /// The watcher loop is running.
+    Running,
+    /// The watcher loop failed and is waiting to be restarted.
+    Restarting,
+    /// The monitor was stopped.
+    Stopped,
+}
+
+impl fmt::Display for MonitorHealth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Inactive => "inactive",
+            Self::Running => "running",
+            Self::Restarting => "restarting",
+            Self::Stopped => "stopped",
+        };
+        f.write_str(s)
+    }
+}
+
+/// Snapshot of the clipboard monitor's state.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MonitorStatus {
+    /// Current health of the watcher loop.
+    pub health: MonitorHealth,
+    /// Number of times the watcher loop has been restarted.
+    pub restarts: u32,
+    /// The error that caused the most recent restart, if any.
+    pub last_error: Option<String>,
+}
+
+static STATUS: Mutex<MonitorStatus> = Mutex::new(MonitorStatus {
+    health: MonitorHealth::Inactive,
+    restarts: 0,
+    last_error: None,
+});
+
+/// Get a snapshot of the clipboard monitor's health.
+pub fn monitor_status() -> MonitorStatus {
+    STATUS.lock().unwrap().clone()
+}
+
+fn update_status(f: impl FnOnce(&mut MonitorStatus)) {
+    f(&mut STATUS.lock().unwrap());
+}
+
+/// Error for compositors that lack the required Wayland protocols.
+#[derive(Debug)]
+struct Unsupported(&'static str);
+
+impl fmt::Display for Unsupported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl std::error::Error for Unsupported {}
+
 /// State for the Wayland clipboard monitor.
 struct ClipboardMonitorState {
     manager: Option<zwlr_data_control_manager_v1::ZwlrDataControlManagerV1>,
@@ -23,22 +111,107 @@ struct ClipboardMonitorState {
     running: Arc<AtomicBool>,
 }
 
+/// MIME types announced by a clipboard data offer.
+#[derive(Default)]
+struct OfferMimeTypes(Mutex<Vec<String>>);
+
+impl OfferMimeTypes {
+    fn contains(&self, mime_type: &str) -> bool {
+        self.0.lock().unwrap().iter().any(|m| m == mime_type)
+    }
+
+    /// Check whether the offer is marked as a password manager secret.
+    fn is_sensitive(&self) -> bool {
+        is_sensitive_offer(&self.0.lock().unwrap())
+    }
+}
+
+/// Check whether offered MIME types include a password manager hint.
+fn is_sensitive_offer(mime_types: &[String]) -> bool {
+    mime_types
+        .iter()
+        .any(|m| SENSITIVE_MIME_TYPES.contains(&m.as_str()))
+}
+
 /// Start monitoring clipboard changes in a background thread.
+///
+/// The watcher is restarted with backoff whenever it exits with an error
+/// or panics, until the returned flag is cleared.
 pub fn start_monitor() -> Arc<AtomicBool> {
     let running = Arc::new(AtomicBool::new(true));
     let running_clone = running.clone();
 
     thread::spawn(move || {
         info!("Starting clipboard monitor");
-
-        if let Err(e) = run_monitor(running_clone) {
-            error!("Clipboard monitor error: {}", e);
-        }
+        supervise(running_clone);
     });
 
     running
 }
 
+/// Run the watcher loop, restarting it with backoff when it fails.
+fn supervise(running: Arc<AtomicBool>) {
+    let mut attempt = 0;
+
+    while running.load(Ordering::Relaxed) {
+        update_status(|s| s.health = MonitorHealth::Running);
+        let started = Instant::now();
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            run_monitor(running.clone()).map_err(|e| {
+                // Missing protocol support won't fix itself, so don't retry
+                let permanent = e.downcast_ref::<Unsupported>().is_some();
+                (e.to_string(), permanent)
+            })
+        }))
+        .unwrap_or_else(|_| Err(("clipboard monitor panicked".to_string(), false)));
+
+        let err = match result {
+            Ok(()) => break,
+            Err((err, true)) => {
+                error!("Clipboard monitor unavailable: {}", err);
+                update_status(|s| s.last_error = Some(err));
+                break;
+            }
+            Err((err, false)) => err,
+        };
+
+        if !running.load(Ordering::Relaxed) {
+            break;
+        }
+
+        if started.elapsed() >= STABLE_RUN_DURATION {
+            attempt = 0;
+        }
+        let delay = restart_delay(attempt);
+        attempt = attempt.saturating_add(1);
+
+        let restarts = {
+            let mut status = STATUS.lock().unwrap();
+            status.health = MonitorHealth::Restarting;
+            status.restarts += 1;
+            status.last_error = Some(err.clone());
+            status.restarts
+        };
+        warn!(
+            "Clipboard monitor exited: {}; restarting in {:?} (restart #{})",
+            err, delay, restarts
+        );
+
+        thread::sleep(delay);
+    }
+
+    update_status(|s| s.health = MonitorHealth::Stopped);
+    info!("Clipboard monitor stopped");
+}
+
+/// Compute the backoff delay before the given restart attempt (0-based).
+fn restart_delay(attempt: u32) -> Duration {
+    INITIAL_RESTART_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(MAX_RESTART_DELAY)
+}
+
 fn run_monitor(running: Arc<AtomicBool>) -> Result<(), Box<dyn std::error::Error>> {
     // Connect to Wayland
     let conn = Connection::connect_to_env()?;
@@ -60,13 +233,11 @@ fn run_monitor(running: Arc<AtomicBool>) -> Result<(), Box<dyn std::error::Error
     event_queue.roundtrip(&mut state)?;
 
     if state.manager.is_none() {
-        error!("wlr-data-control protocol not available");
-        return Err("wlr-data-control protocol not available".into());
+        return Err(Unsupported("wlr-data-control protocol not available").into());
     }
 
     if state.seat.is_none() {
-        error!("No Wayland seat available");
-        return Err("No Wayland seat available".into());
+        return Err(Unsupported("No Wayland seat available").into());
     }
 
     // Create data control device for the seat
@@ -86,7 +257,6 @@ fn run_monitor(running: Arc<AtomicBool>) -> Result<(), Box<dyn std::error::Error
         event_queue.blocking_dispatch(&mut state)?;
     }
 
-    info!("Clipboard monitor stopped");
     Ok(())
 }
 
@@ -156,15 +326,19 @@ impl Dispatch<zwlr_data_control_device_v1::ZwlrDataControlDeviceV1, ()> for Clip
         _: &zwlr_data_control_device_v1::ZwlrDataControlDeviceV1,
         event: zwlr_data_control_device_v1::Event,
         _: &(),
-        _: &Connection,
+        conn: &Connection,
         _: &QueueHandle<Self>,
     ) {
         match event {
             zwlr_data_control_device_v1::Event::Selection { id } => {
-                if id.is_some() {
+                if let Some(offer) = id {
                     debug!("Clipboard selection changed");
+                    let sensitive = offer
+                        .data::<OfferMimeTypes>()
+                        .is_some_and(OfferMimeTypes::is_sensitive);
+                    let gif_bytes = read_animated_gif(&offer, conn);
                     // Clipboard changed, read the new content
-                    if let Err(e) = read_clipboard_content() {
+                    if let Err(e) = read_clipboard_content(gif_bytes, sensitive) {
                         error!("Failed to read clipboard: {}", e);
                     }
                 }
@@ -182,22 +356,29 @@ impl Dispatch<zwlr_data_control_device_v1::ZwlrDataControlDeviceV1, ()> for Clip
     ) -> std::sync::Arc<dyn wayland_client::backend::ObjectData> {
         match opcode {
             zwlr_data_control_device_v1::EVT_DATA_OFFER_OPCODE => {
-                qhandle.make_data::<zwlr_data_control_offer_v1::ZwlrDataControlOfferV1, _>(())
+                qhandle.make_data::<zwlr_data_control_offer_v1::ZwlrDataControlOfferV1, _>(
+                    OfferMimeTypes::default(),
+                )
             }
             _ => panic!("Unknown opcode {} for zwlr_data_control_device_v1", opcode),
         }
     }
 }
 
-impl Dispatch<zwlr_data_control_offer_v1::ZwlrDataControlOfferV1, ()> for ClipboardMonitorState {
+impl Dispatch<zwlr_data_control_offer_v1::ZwlrDataControlOfferV1, OfferMimeTypes>
+    for ClipboardMonitorState
+{
     fn event(
         _: &mut Self,
         _: &zwlr_data_control_offer_v1::ZwlrDataControlOfferV1,
-        _: zwlr_data_control_offer_v1::Event,
-        _: &(),
+        event: zwlr_data_control_offer_v1::Event,
+        mime_types: &OfferMimeTypes,
         _: &Connection,
         _: &QueueHandle<Self>,
     ) {
+        if let zwlr_data_control_offer_v1::Event::Offer { mime_type } = event {
+            mime_types.0.lock().unwrap().push(mime_type);
+        }
     }
 }
 
@@ -213,8 +394,78 @@ impl Dispatch<zwlr_data_control_source_v1::ZwlrDataControlSourceV1, ()> for Clip
     }
 }
 
+/// Read the original GIF data of a selection, if it offers an animation
+/// within the size and frame limits.
+fn read_animated_gif(
+    offer: &zwlr_data_control_offer_v1::ZwlrDataControlOfferV1,
+    conn: &Connection,
+) -> Option<Vec<u8>> {
+    let offers_gif = offer
+        .data::<OfferMimeTypes>()
+        .is_some_and(|mime_types| mime_types.contains(GIF_MIME_TYPE));
+    if !offers_gif {
+        return None;
+    }
+
+    match receive_offer(offer, GIF_MIME_TYPE, conn, MAX_GIF_BYTES) {
+        Ok(Some(bytes)) if is_keepable_animation(&bytes) => Some(bytes),
+        Ok(_) => {
+            debug!("Clipboard GIF is static or too large, keeping a still frame");
+            None
+        }
+        Err(e) => {
+            warn!("Failed to read clipboard GIF: {}", e);
+            None
+        }
+    }
+}
+
+/// Receive the data of an offer in the given MIME type.
+///
+/// The data is read on a worker thread, so a slow or hung source can't
+/// stall the Wayland dispatch for longer than [`RECEIVE_TIMEOUT`]. Returns
+/// `None` if the data is larger than `limit` bytes.
+fn receive_offer(
+    offer: &zwlr_data_control_offer_v1::ZwlrDataControlOfferV1,
+    mime_type: &str,
+    conn: &Connection,
+    limit: usize,
+) -> std::io::Result<Option<Vec<u8>>> {
+    let (reader, writer) = std::io::pipe()?;
+    offer.receive(mime_type.to_string(), writer.as_fd());
+    conn.flush().map_err(std::io::Error::other)?;
+    // Close our write end so reading ends when the source is done
+    drop(writer);
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        let result = reader
+            .take(limit as u64 + 1)
+            .read_to_end(&mut bytes)
+            .map(|_| bytes);
+        let _ = tx.send(result);
+    });
+
+    // On timeout the worker is left to finish once the source gives up
+    let bytes = rx.recv_timeout(RECEIVE_TIMEOUT).map_err(|_| {
+        std::io::Error::new(
+            std::io::ErrorKind::TimedOut,
+            "clipboard source did not send its data in time",
+        )
+    })??;
+    Ok((bytes.len() <= limit).then_some(bytes))
+}
+
 /// Read the current clipboard content and add it to history.
-fn read_clipboard_content() -> Result<(), Box<dyn std::error::Error>> {
+///
+/// `gif_bytes` is the original encoding of an animated image selection,
+/// kept alongside the decoded pixels. `sensitive` marks a selection that
+/// came from a password manager.
+fn read_clipboard_content(
+    gif_bytes: Option<Vec<u8>>,
+    sensitive: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
     // Small delay to let clipboard settle
     thread::sleep(Duration::from_millis(50));
 
@@ -231,11 +482,15 @@ fn read_clipboard_content() -> Result<(), Box<dyn std::error::Error>> {
             image.height,
             image.bytes.len()
         );
-        data::add_item(ClipboardContent::Image {
-            width: image.width,
-            height: image.height,
-            rgba_bytes: image.bytes.to_vec(),
-        });
+        data::add_item(
+            ClipboardContent::Image {
+                width: image.width,
+                height: image.height,
+                rgba_bytes: image.bytes.to_vec(),
+                gif_bytes,
+            },
+            sensitive,
+        );
         return Ok(());
     }
 
@@ -243,10 +498,50 @@ fn read_clipboard_content() -> Result<(), Box<dyn std::error::Error>> {
     if let Ok(text) = clipboard.get_text()
         && !text.is_empty()
     {
-        debug!("Adding text to clipboard history: {} chars", text.len());
-        data::add_item(ClipboardContent::Text(text));
+        if sensitive {
+            debug!("Adding sensitive text to clipboard history");
+        } else {
+            debug!("Adding text to clipboard history: {} chars", text.len());
+        }
+        data::add_item(ClipboardContent::Text(text), sensitive);
         return Ok(());
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restart_delay_grows_exponentially() {
+        assert_eq!(restart_delay(0), Duration::from_millis(500));
+        assert_eq!(restart_delay(1), Duration::from_secs(1));
+        assert_eq!(restart_delay(2), Duration::from_secs(2));
+        assert_eq!(restart_delay(3), Duration::from_secs(4));
+    }
+
+    #[test]
+    fn test_restart_delay_is_capped() {
+        assert_eq!(restart_delay(10), MAX_RESTART_DELAY);
+        assert_eq!(restart_delay(u32::MAX), MAX_RESTART_DELAY);
+    }
+
+    #[test]
+    fn test_monitor_health_display() {
+        assert_eq!(MonitorHealth::Running.to_string(), "running");
+        assert_eq!(MonitorHealth::Restarting.to_string(), "restarting");
+    }
+
+    #[test]
+    fn test_password_manager_offer_is_sensitive() {
+        let offer =
+            |types: &[&str]| -> Vec<String> { types.iter().map(|t| t.to_string()).collect() };
+        assert!(is_sensitive_offer(&offer(&[
+            "text/plain;charset=utf-8",
+            "x-kde-passwordManagerHint",
+        ])));
+        assert!(!is_sensitive_offer(&offer(&["text/plain", "text/html"])));
+    }
+}
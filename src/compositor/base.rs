@@ -74,9 +74,56 @@ pub fn filter_launcher_windows(windows: Vec<WindowInfo>) -> Vec<WindowInfo> {
         .collect()
 }
 
+/// Find an open window belonging to an application.
+///
+/// A window matches when its class equals the desktop entry's
+/// `StartupWMClass`, its id (desktop file name), or the last segment of a
+/// reverse-DNS id (e.g. "Nautilus" for `org.gnome.Nautilus`), ignoring case.
+pub fn find_app_window<'a>(
+    windows: &'a [WindowInfo],
+    app_id: &str,
+    startup_wm_class: Option<&str>,
+) -> Option<&'a WindowInfo> {
+    let candidates = app_window_classes(app_id, startup_wm_class);
+    windows.iter().find(|w| is_app_window(w, &candidates))
+}
+
+/// Count the open windows belonging to an application.
+///
+/// Windows are matched like in [`find_app_window`].
+pub fn count_app_windows(
+    windows: &[WindowInfo],
+    app_id: &str,
+    startup_wm_class: Option<&str>,
+) -> usize {
+    let candidates = app_window_classes(app_id, startup_wm_class);
+    windows
+        .iter()
+        .filter(|w| is_app_window(w, &candidates))
+        .count()
+}
+
+/// Window classes an application's windows may have.
+fn app_window_classes<'a>(app_id: &'a str, startup_wm_class: Option<&'a str>) -> Vec<&'a str> {
+    let short_id = app_id.rsplit('.').next().unwrap_or(app_id);
+    startup_wm_class
+        .into_iter()
+        .chain([app_id, short_id])
+        .filter(|c| !c.is_empty())
+        .collect()
+}
+
+/// Check whether a window's class is one of `candidates`, ignoring case.
+fn is_app_window(window: &WindowInfo, candidates: &[&str]) -> bool {
+    candidates
+        .iter()
+        .any(|candidate| window.class.eq_ignore_ascii_case(candidate))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::mock_window_info;
 
     #[test]
     fn test_get_display_title() {
@@ -97,21 +144,10 @@ mod tests {
     #[test]
     fn test_filter_launcher_windows() {
         let windows = vec![
+            mock_window_info("1", "firefox"),
             WindowInfo {
-                address: "1".to_string(),
-                title: "Firefox".to_string(),
-                class: "firefox".to_string(),
-                workspace: 1,
-                focused: false,
-                icon_data: None,
-            },
-            WindowInfo {
-                address: "2".to_string(),
-                title: "Launcher".to_string(),
-                class: "zlaunch".to_string(),
-                workspace: 1,
                 focused: true,
-                icon_data: None,
+                ..mock_window_info("2", "zlaunch")
             },
         ];
 
@@ -119,4 +155,55 @@ mod tests {
         assert_eq!(filtered.len(), 1);
         assert_eq!(filtered[0].class, "firefox");
     }
+
+    #[test]
+    fn test_find_app_window_by_startup_wm_class() {
+        let windows = vec![
+            mock_window_info("1", "firefox"),
+            mock_window_info("2", "Google-chrome"),
+        ];
+        let found = find_app_window(&windows, "google-chrome-stable", Some("Google-chrome"));
+        assert_eq!(found.map(|w| w.address.as_str()), Some("2"));
+    }
+
+    #[test]
+    fn test_find_app_window_by_id() {
+        let windows = vec![
+            mock_window_info("1", "org.gnome.Nautilus"),
+            mock_window_info("2", "Firefox"),
+        ];
+        assert_eq!(
+            find_app_window(&windows, "firefox", None).map(|w| w.address.as_str()),
+            Some("2")
+        );
+        assert_eq!(
+            find_app_window(&windows, "org.gnome.Nautilus", None).map(|w| w.address.as_str()),
+            Some("1")
+        );
+    }
+
+    #[test]
+    fn test_find_app_window_by_reverse_dns_suffix() {
+        let windows = vec![mock_window_info("1", "dolphin")];
+        assert!(find_app_window(&windows, "org.kde.dolphin", None).is_some());
+    }
+
+    #[test]
+    fn test_count_app_windows() {
+        let windows = vec![
+            mock_window_info("1", "kitty"),
+            mock_window_info("2", "firefox"),
+            mock_window_info("3", "Kitty"),
+        ];
+        assert_eq!(count_app_windows(&windows, "kitty", None), 2);
+        assert_eq!(count_app_windows(&windows, "org.mozilla.firefox", None), 1);
+        assert_eq!(count_app_windows(&windows, "code", None), 0);
+    }
+
+    #[test]
+    fn test_find_app_window_no_match() {
+        let windows = vec![mock_window_info("1", "firefox")];
+        assert!(find_app_window(&windows, "kitty", Some("kitty")).is_none());
+        assert!(find_app_window(&[], "firefox", None).is_none());
+    }
 }
@@ -4,8 +4,10 @@ use super::Compositor;
 use super::hyprland::HyprlandCompositor;
 use super::kwin::KwinCompositor;
 use super::mangowm::MangowmCompositor;
+use super::mutter::MutterCompositor;
 use super::niri::NiriCompositor;
 use super::noop::NoopCompositor;
+use super::sway::SwayCompositor;
 use tracing::{info, warn};
 
 /// Detect and create the appropriate compositor client.
@@ -15,7 +17,10 @@ use tracing::{info, warn};
 /// 2. KDE/KWin (via KDE_SESSION_VERSION env var)
 /// 3. Niri     (via NIRI_SOCKET env var)
 /// 4. MangoWC  (via MANGO_INSTANCE_SIGNATURE env var)
-/// 5. Fallback to NoopCompositor
+/// 5. Sway     (via SWAYSOCK env var)
+/// 6. Mutter   (via XDG_CURRENT_DESKTOP containing GNOME, needs the
+///    Window Calls extension)
+/// 7. Fallback to NoopCompositor
 ///
 /// The NoopCompositor allows the launcher to function (with applications only)
 /// even on unsupported compositors.
@@ -44,6 +49,18 @@ pub fn detect_compositor() -> Box<dyn Compositor> {
         return Box::new(compositor);
     }
 
+    // Try Sway
+    if let Some(compositor) = SwayCompositor::new() {
+        info!("Detected Sway compositor");
+        return Box::new(compositor);
+    }
+
+    // Try GNOME Mutter
+    if let Some(compositor) = MutterCompositor::new() {
+        info!("Detected GNOME Mutter compositor");
+        return Box::new(compositor);
+    }
+
     // Fallback to no-op
     warn!("No supported compositor detected, window switching disabled");
     Box::new(NoopCompositor)
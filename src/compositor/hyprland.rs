@@ -1,7 +1,7 @@
 //! Hyprland compositor implementation using IPC socket.
 
 use super::base::{CompositorCapabilities, get_display_title, is_launcher_window};
-use super::{Compositor, WindowInfo};
+use super::{Compositor, OutputInfo, WindowInfo, WorkspaceInfo};
 use anyhow::{Context, Result};
 use serde::Deserialize;
 use std::io::{Read, Write};
@@ -46,16 +46,47 @@ impl HyprlandCompositor {
 
         Ok(response)
     }
+
+    /// Fetch all clients (windows) from Hyprland.
+    fn clients(&self) -> Result<Vec<HyprlandClient>> {
+        // j/clients returns JSON output
+        let json = self.send_command("j/clients")?;
+        serde_json::from_str(&json).context("Failed to parse Hyprland clients JSON")
+    }
+
+    /// Fetch all workspaces from Hyprland.
+    fn hyprland_workspaces(&self) -> Result<Vec<HyprlandWorkspace>> {
+        let json = self.send_command("j/workspaces")?;
+        serde_json::from_str(&json).context("Failed to parse Hyprland workspaces JSON")
+    }
+
+    /// Fetch the workspace on the focused monitor.
+    fn active_workspace(&self) -> Result<HyprlandWorkspace> {
+        let json = self.send_command("j/activeworkspace")?;
+        serde_json::from_str(&json).context("Failed to parse Hyprland active workspace JSON")
+    }
+
+    /// Fetch all monitors from Hyprland.
+    fn monitors(&self) -> Result<Vec<HyprlandMonitor>> {
+        let json = self.send_command("j/monitors")?;
+        serde_json::from_str(&json).context("Failed to parse Hyprland monitors JSON")
+    }
+
+    /// Check whether a special workspace is currently shown on any monitor.
+    fn is_special_workspace_visible(&self, name: &str) -> Result<bool> {
+        Ok(self
+            .monitors()?
+            .iter()
+            .any(|m| m.special_workspace.as_ref().is_some_and(|w| w.name == name)))
+    }
 }
 
 impl Compositor for HyprlandCompositor {
     fn list_windows(&self) -> Result<Vec<WindowInfo>> {
-        // j/clients returns JSON output
-        let json = self.send_command("j/clients")?;
-        let clients: Vec<HyprlandClient> =
-            serde_json::from_str(&json).context("Failed to parse Hyprland clients JSON")?;
+        let include_special = crate::config::config().hyprland_special_workspaces;
 
-        let windows = clients
+        let windows = self
+            .clients()?
             .into_iter()
             // Filter out special windows
             .filter(|c| {
@@ -71,18 +102,21 @@ impl Compositor for HyprlandCompositor {
                 if c.class.is_empty() {
                     return false;
                 }
-                true
+                // Exclude scratchpad windows unless configured otherwise
+                include_special || !c.workspace.is_special()
             })
             .map(|c| {
                 let focused = c.is_focused();
-                let workspace = c.workspace.id;
+                let special_workspace = c.workspace.special_name().map(str::to_string);
                 WindowInfo {
                     address: c.address,
                     title: get_display_title(&c.title, &c.class),
                     class: c.class,
-                    workspace,
+                    workspace: c.workspace.id,
                     focused,
+                    special_workspace,
                     icon_data: None,
+                    urgent: false,
                 }
             })
             .collect();
@@ -91,11 +125,68 @@ impl Compositor for HyprlandCompositor {
     }
 
     fn focus_window(&self, window_id: &str) -> Result<()> {
+        // Windows on a hidden special workspace can't be focused until the
+        // workspace is toggled into view
+        let special = self
+            .clients()?
+            .into_iter()
+            .find(|c| c.address == window_id)
+            .filter(|c| c.workspace.is_special())
+            .map(|c| c.workspace);
+
+        if let Some(workspace) = special
+            && !self.is_special_workspace_visible(&workspace.name)?
+        {
+            let cmd = match workspace.special_name() {
+                Some(name) if !name.is_empty() => {
+                    format!("dispatch togglespecialworkspace {}", name)
+                }
+                _ => "dispatch togglespecialworkspace".to_string(),
+            };
+            self.send_command(&cmd)?;
+        }
+
         let cmd = format!("dispatch focuswindow address:{}", window_id);
         self.send_command(&cmd)?;
         Ok(())
     }
 
+    fn workspaces(&self) -> Result<Vec<WorkspaceInfo>> {
+        let active_id = self.active_workspace()?.id;
+        Ok(workspace_infos(self.hyprland_workspaces()?, active_id))
+    }
+
+    fn outputs(&self) -> Result<Vec<OutputInfo>> {
+        Ok(self
+            .monitors()?
+            .into_iter()
+            .map(|m| OutputInfo {
+                name: m.name,
+                focused: m.focused,
+                x: m.x,
+                y: m.y,
+            })
+            .collect())
+    }
+
+    fn switch_workspace(&self, workspace_id: &str) -> Result<()> {
+        let id: i32 = workspace_id
+            .parse()
+            .with_context(|| format!("Invalid Hyprland workspace id: {}", workspace_id))?;
+        // Named workspaces are switched to by name, look it up
+        let name = if id < 0 {
+            self.hyprland_workspaces()?
+                .into_iter()
+                .find(|w| w.id == id)
+                .map(|w| w.name)
+                .with_context(|| format!("Hyprland workspace {} not found", id))?
+        } else {
+            String::new()
+        };
+        self.send_command(&switch_workspace_command(&HyprlandWorkspace { id, name }))?;
+        Ok(())
+    }
+
     fn name(&self) -> &'static str {
         "Hyprland"
     }
@@ -132,6 +223,81 @@ impl HyprlandClient {
 #[derive(Debug, Deserialize)]
 struct HyprlandWorkspace {
     id: i32,
+    #[serde(default)]
+    name: String,
+}
+
+impl HyprlandWorkspace {
+    /// Check if this is a special workspace (scratchpad).
+    ///
+    /// Special workspaces have negative ids and names like "special" or
+    /// "special:term".
+    fn is_special(&self) -> bool {
+        self.name == "special" || self.name.starts_with("special:")
+    }
+
+    /// Get the scratchpad name without the "special:" prefix.
+    ///
+    /// Returns an empty string for the unnamed special workspace and `None`
+    /// for regular workspaces.
+    fn special_name(&self) -> Option<&str> {
+        if !self.is_special() {
+            return None;
+        }
+        Some(self.name.strip_prefix("special:").unwrap_or(""))
+    }
+}
+
+/// Convert Hyprland workspaces to workspace infos, sorted by id.
+///
+/// Special workspaces are left out; they are reached through their windows.
+fn workspace_infos(workspaces: Vec<HyprlandWorkspace>, active_id: i32) -> Vec<WorkspaceInfo> {
+    let mut workspaces: Vec<HyprlandWorkspace> =
+        workspaces.into_iter().filter(|w| !w.is_special()).collect();
+    workspaces.sort_by_key(|w| w.id);
+
+    workspaces
+        .into_iter()
+        .map(|w| WorkspaceInfo {
+            id: w.id.to_string(),
+            name: if w.name.is_empty() {
+                w.id.to_string()
+            } else {
+                w.name
+            },
+            active: w.id == active_id,
+        })
+        .collect()
+}
+
+/// Build the command switching to `workspace`.
+///
+/// Named workspaces have negative ids, which `dispatch workspace` would
+/// read as a relative move, so they are addressed by name.
+fn switch_workspace_command(workspace: &HyprlandWorkspace) -> String {
+    if workspace.id < 0 {
+        format!("dispatch workspace name:{}", workspace.name)
+    } else {
+        format!("dispatch workspace {}", workspace.id)
+    }
+}
+
+/// Hyprland monitor information.
+#[derive(Debug, Deserialize)]
+struct HyprlandMonitor {
+    /// Connector name, e.g. "DP-1"
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    focused: bool,
+    /// Position in the layout, in logical pixels
+    #[serde(default)]
+    x: i32,
+    #[serde(default)]
+    y: i32,
+    /// The special workspace shown on this monitor (empty name if none)
+    #[serde(rename = "specialWorkspace", default)]
+    special_workspace: Option<HyprlandWorkspace>,
 }
 
 /// Apply blur layer rules for zlaunch on Hyprland.
@@ -158,3 +324,104 @@ pub fn apply_blur_layer_rules() -> Result<bool> {
 
     Ok(true)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workspace(id: i32, name: &str) -> HyprlandWorkspace {
+        HyprlandWorkspace {
+            id,
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_regular_workspace_is_not_special() {
+        let ws = workspace(2, "2");
+        assert!(!ws.is_special());
+        assert_eq!(ws.special_name(), None);
+    }
+
+    #[test]
+    fn test_named_special_workspace() {
+        let ws = workspace(-98, "special:term");
+        assert!(ws.is_special());
+        assert_eq!(ws.special_name(), Some("term"));
+    }
+
+    #[test]
+    fn test_unnamed_special_workspace() {
+        let ws = workspace(-99, "special");
+        assert!(ws.is_special());
+        assert_eq!(ws.special_name(), Some(""));
+    }
+
+    #[test]
+    fn test_parse_client_on_special_workspace() {
+        let json = r#"[{
+            "address": "0x5678abcd",
+            "title": "htop",
+            "class": "kitty",
+            "workspace": { "id": -98, "name": "special:term" },
+            "focusHistoryID": 3,
+            "mapped": true,
+            "hidden": false
+        }]"#;
+        let clients: Vec<HyprlandClient> = serde_json::from_str(json).unwrap();
+        assert_eq!(clients[0].workspace.special_name(), Some("term"));
+    }
+
+    #[test]
+    fn test_workspace_infos_sorted_without_special() {
+        let json = r#"[
+            { "id": 3, "name": "web" },
+            { "id": -98, "name": "special:term" },
+            { "id": 1, "name": "1" }
+        ]"#;
+        let workspaces: Vec<HyprlandWorkspace> = serde_json::from_str(json).unwrap();
+        let infos = workspace_infos(workspaces, 3);
+
+        let names: Vec<&str> = infos.iter().map(|w| w.name.as_str()).collect();
+        assert_eq!(names, ["1", "web"]);
+        assert_eq!(infos[1].id, "3");
+        assert!(infos[1].active);
+        assert!(!infos[0].active);
+    }
+
+    #[test]
+    fn test_switch_workspace_command() {
+        assert_eq!(
+            switch_workspace_command(&workspace(3, "3")),
+            "dispatch workspace 3"
+        );
+        assert_eq!(
+            switch_workspace_command(&workspace(-1337, "web")),
+            "dispatch workspace name:web"
+        );
+    }
+
+    #[test]
+    fn test_parse_monitor_without_special_workspace() {
+        let json = r#"[{ "specialWorkspace": { "id": 0, "name": "" } }, {}]"#;
+        let monitors: Vec<HyprlandMonitor> = serde_json::from_str(json).unwrap();
+        assert!(
+            monitors
+                .iter()
+                .all(|m| m.special_workspace.as_ref().is_none_or(|w| !w.is_special()))
+        );
+    }
+
+    #[test]
+    fn test_parse_monitor_output() {
+        let json = r#"[
+            { "id": 0, "name": "DP-1", "x": 0, "y": 0, "focused": false },
+            { "id": 1, "name": "HDMI-A-1", "x": 2560, "y": -200, "focused": true }
+        ]"#;
+        let monitors: Vec<HyprlandMonitor> = serde_json::from_str(json).unwrap();
+        assert_eq!(monitors[1].name, "HDMI-A-1");
+        assert!(monitors[1].focused);
+        assert_eq!((monitors[1].x, monitors[1].y), (2560, -200));
+        assert!(!monitors[0].focused);
+    }
+}
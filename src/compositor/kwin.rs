@@ -3,9 +3,10 @@
 //! Uses KWin's krunner interface via D-Bus to enumerate and focus windows.
 //! This approach uses the /WindowsRunner D-Bus path which provides direct
 //! window listing without needing to capture script print() signals.
+//! Virtual desktops are listed and switched through /VirtualDesktopManager.
 
 use super::base::CompositorCapabilities;
-use super::{Compositor, WindowInfo};
+use super::{Compositor, WindowInfo, WorkspaceInfo};
 use anyhow::{Context, Result};
 use image::{ImageBuffer, ImageFormat, Rgba};
 use std::collections::{HashMap, HashSet};
@@ -25,6 +26,10 @@ type KRunnerMatch = (
     HashMap<String, OwnedValue>,
 );
 
+/// Virtual desktop from the VirtualDesktopManager `desktops` property.
+/// Tuple: (position, id, name)
+type KwinDesktop = (u32, String, String);
+
 /// KWin compositor client using D-Bus WindowsRunner API.
 pub struct KwinCompositor {
     connection: Connection,
@@ -193,7 +198,9 @@ impl KwinCompositor {
                     class,
                     workspace: 1,   // WindowsRunner doesn't expose workspace info
                     focused: false, // We can't easily determine this from krunner
+                    special_workspace: None,
                     icon_data,
+                    urgent: false, // Not exposed by WindowsRunner either
                 })
             })
             .collect();
@@ -223,6 +230,19 @@ impl KwinCompositor {
     }
 }
 
+impl KwinCompositor {
+    /// Create a proxy for the virtual desktop manager.
+    fn desktop_manager(&self) -> Result<Proxy<'_>> {
+        Proxy::new(
+            &self.connection,
+            "org.kde.KWin",
+            "/VirtualDesktopManager",
+            "org.kde.KWin.VirtualDesktopManager",
+        )
+        .context("Failed to create VirtualDesktopManager proxy")
+    }
+}
+
 impl Compositor for KwinCompositor {
     fn list_windows(&self) -> Result<Vec<WindowInfo>> {
         self.list_windows_via_runner()
@@ -253,6 +273,33 @@ impl Compositor for KwinCompositor {
         }
     }
 
+    fn workspaces(&self) -> Result<Vec<WorkspaceInfo>> {
+        let manager = self.desktop_manager()?;
+        let mut desktops: Vec<KwinDesktop> = manager
+            .get_property("desktops")
+            .context("Failed to read virtual desktops")?;
+        let current: String = manager
+            .get_property("current")
+            .context("Failed to read current virtual desktop")?;
+
+        desktops.sort_by_key(|(position, _, _)| *position);
+        Ok(desktops
+            .into_iter()
+            .map(|(_, id, name)| WorkspaceInfo {
+                active: id == current,
+                id,
+                name,
+            })
+            .collect())
+    }
+
+    fn switch_workspace(&self, workspace_id: &str) -> Result<()> {
+        self.desktop_manager()?
+            .set_property("current", workspace_id)
+            .context("Failed to switch virtual desktop")?;
+        Ok(())
+    }
+
     fn name(&self) -> &'static str {
         "KWin"
     }
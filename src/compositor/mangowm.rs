@@ -68,7 +68,9 @@ impl Compositor for MangowmCompositor {
                 class: window.appid,
                 workspace,
                 focused: window.is_focused,
+                special_workspace: None,
                 icon_data: None,
+                urgent: false,
             });
         }
 
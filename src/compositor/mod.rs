@@ -2,7 +2,10 @@
 //!
 //! This module provides a trait-based abstraction for interacting with
 //! Wayland compositors to list windows and switch focus. Implementations
-//! are provided for Hyprland (IPC socket), Niri (IPC socket), and KDE/KWin (DBus).
+//! are provided for Hyprland (IPC socket), Niri (IPC socket), Sway (IPC socket),
+//! KDE/KWin (DBus) and GNOME/Mutter (DBus, through the Window Calls extension).
+//! The same implementations list workspaces and switch between them, and
+//! Hyprland reports its outputs so the launcher can open on a chosen one.
 
 pub mod base;
 mod detect;
@@ -10,8 +13,10 @@ pub mod error;
 pub mod hyprland;
 mod kwin;
 pub mod mangowm;
+mod mutter;
 mod niri;
 mod noop;
+mod sway;
 
 pub use base::CompositorCapabilities;
 pub use detect::detect_compositor;
@@ -30,10 +35,39 @@ pub struct WindowInfo {
     pub class: String,
     /// Workspace number
     pub workspace: i32,
+    /// Name of the special (scratchpad) workspace the window lives on, if any
+    pub special_workspace: Option<String>,
     /// Whether this window is currently focused
     pub focused: bool,
     /// Optional icon as PNG bytes (used when compositor provides icon data directly)
     pub icon_data: Option<Vec<u8>>,
+    /// Whether the window requests attention (false if the compositor
+    /// doesn't report it)
+    pub urgent: bool,
+}
+
+/// Information about a workspace from the compositor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceInfo {
+    /// Workspace identifier used for switching (compositor-specific)
+    pub id: String,
+    /// Workspace name (e.g., "1" or "web")
+    pub name: String,
+    /// Whether this workspace is currently active
+    pub active: bool,
+}
+
+/// Information about an output (monitor) from the compositor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputInfo {
+    /// Connector name (e.g., "DP-1")
+    pub name: String,
+    /// Whether this output is currently focused
+    pub focused: bool,
+    /// Horizontal position in the compositor's layout, in logical pixels
+    pub x: i32,
+    /// Vertical position in the compositor's layout, in logical pixels
+    pub y: i32,
 }
 
 /// Trait for compositor window management operations.
@@ -52,6 +86,33 @@ pub trait Compositor: Send + Sync {
     /// The address format is compositor-specific.
     fn focus_window(&self, window_id: &str) -> anyhow::Result<()>;
 
+    /// List the workspaces, in the order the compositor presents them.
+    ///
+    /// Special workspaces (scratchpads) are not included. Default
+    /// implementation returns no workspaces.
+    fn workspaces(&self) -> anyhow::Result<Vec<WorkspaceInfo>> {
+        Ok(Vec::new())
+    }
+
+    /// List the outputs (monitors).
+    ///
+    /// Default implementation returns no outputs, leaving the launcher's
+    /// placement to the compositor.
+    fn outputs(&self) -> anyhow::Result<Vec<OutputInfo>> {
+        Ok(Vec::new())
+    }
+
+    /// Switch to a workspace by its id.
+    ///
+    /// The id format is compositor-specific.
+    fn switch_workspace(&self, workspace_id: &str) -> anyhow::Result<()> {
+        anyhow::bail!(
+            "{} does not support switching to workspace {}",
+            self.name(),
+            workspace_id
+        )
+    }
+
     /// Get the compositor name for logging/debugging.
     fn name(&self) -> &'static str;
 
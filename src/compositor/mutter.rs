@@ -0,0 +1,194 @@
+//! GNOME Mutter compositor implementation using the Window Calls extension.
+//!
+//! GNOME Shell exposes no window list of its own, and `org.gnome.Shell.Eval`
+//! is restricted to unsafe mode since GNOME 41. The Window Calls extension
+//! (https://github.com/ickyicky/window-calls) publishes the window list and
+//! activation on the session bus instead, so it is required for window
+//! switching on GNOME.
+
+use super::base::{CompositorCapabilities, get_display_title, is_launcher_window};
+use super::{Compositor, WindowInfo};
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use tracing::warn;
+use zbus::blocking::{Connection, Proxy};
+
+/// D-Bus object path of the Window Calls extension.
+const WINDOW_CALLS_PATH: &str = "/org/gnome/Shell/Extensions/Windows";
+
+/// D-Bus interface of the Window Calls extension.
+const WINDOW_CALLS_INTERFACE: &str = "org.gnome.Shell.Extensions.Windows";
+
+/// `Meta.WindowType.NORMAL`, the type of regular application windows.
+const NORMAL_WINDOW_TYPE: i32 = 0;
+
+/// GNOME Mutter compositor client using the Window Calls D-Bus interface.
+pub struct MutterCompositor {
+    connection: Connection,
+}
+
+impl MutterCompositor {
+    /// Create a new Mutter compositor client.
+    ///
+    /// Returns None outside of GNOME sessions, and logs a warning when the
+    /// Window Calls extension isn't available so the launcher falls back to
+    /// applications only.
+    pub fn new() -> Option<Self> {
+        let desktop = std::env::var("XDG_CURRENT_DESKTOP").ok()?;
+        if !desktop.split(':').any(|d| d.eq_ignore_ascii_case("GNOME")) {
+            return None;
+        }
+
+        let connection = Connection::session().ok()?;
+        let compositor = Self { connection };
+        if let Err(e) = compositor.list_json() {
+            warn!(
+                %e,
+                "GNOME detected, but the Window Calls extension is not available; \
+                 install it to enable window switching"
+            );
+            return None;
+        }
+
+        Some(compositor)
+    }
+
+    /// Create a proxy for the Window Calls extension.
+    fn proxy(&self) -> Result<Proxy<'_>> {
+        Proxy::new(
+            &self.connection,
+            "org.gnome.Shell",
+            WINDOW_CALLS_PATH,
+            WINDOW_CALLS_INTERFACE,
+        )
+        .context("Failed to create Window Calls proxy")
+    }
+
+    /// Get the window list as the JSON returned by the extension.
+    fn list_json(&self) -> Result<String> {
+        self.proxy()?
+            .call("List", &())
+            .context("Failed to call Window Calls List")
+    }
+}
+
+impl Compositor for MutterCompositor {
+    fn list_windows(&self) -> Result<Vec<WindowInfo>> {
+        let windows = parse_windows(&self.list_json()?)?;
+
+        // Older extension versions leave the title out of the list
+        let proxy = self.proxy()?;
+        Ok(windows
+            .into_iter()
+            .map(|mut window| {
+                if window.title.is_empty() {
+                    let title: String = window
+                        .address
+                        .parse::<u32>()
+                        .ok()
+                        .and_then(|id| proxy.call("GetTitle", &(id,)).ok())
+                        .unwrap_or_default();
+                    window.title = get_display_title(&title, &window.class);
+                }
+                window
+            })
+            .collect())
+    }
+
+    fn focus_window(&self, window_id: &str) -> Result<()> {
+        let id: u32 = window_id
+            .parse()
+            .with_context(|| format!("Invalid GNOME window id: {}", window_id))?;
+        let _: () = self
+            .proxy()?
+            .call("Activate", &(id,))
+            .context("Failed to call Window Calls Activate")?;
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "Mutter"
+    }
+
+    fn capabilities(&self) -> CompositorCapabilities {
+        CompositorCapabilities {
+            focus_tracking: true,
+            ..CompositorCapabilities::limited()
+        }
+    }
+}
+
+/// Convert the extension's window list to window infos.
+///
+/// Only normal windows are kept; dialogs, menus and the launcher itself are
+/// left out. A missing title is left empty for the caller to look up.
+fn parse_windows(json: &str) -> Result<Vec<WindowInfo>> {
+    let windows: Vec<MutterWindow> =
+        serde_json::from_str(json).context("Failed to parse Window Calls list JSON")?;
+
+    Ok(windows
+        .into_iter()
+        .filter(|w| w.window_type == NORMAL_WINDOW_TYPE)
+        .filter_map(|w| {
+            let class = w.wm_class.filter(|class| !class.is_empty())?;
+            if is_launcher_window(&class) {
+                return None;
+            }
+            let title = match w.title.as_deref() {
+                Some(title) => get_display_title(title, &class),
+                None => String::new(),
+            };
+            Some(WindowInfo {
+                address: w.id.to_string(),
+                title,
+                class,
+                // Window Calls doesn't report the workspace index
+                workspace: 0,
+                special_workspace: None,
+                focused: w.focus,
+                icon_data: None,
+                urgent: false,
+            })
+        })
+        .collect())
+}
+
+/// Window information from the Window Calls `List` method.
+#[derive(Debug, Deserialize)]
+struct MutterWindow {
+    id: u32,
+    wm_class: Option<String>,
+    /// Only included by newer extension versions
+    title: Option<String>,
+    #[serde(default)]
+    window_type: i32,
+    #[serde(default)]
+    focus: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_windows() {
+        let json = r#"[
+            { "id": 101, "wm_class": "firefox", "title": "Mozilla Firefox",
+              "window_type": 0, "focus": true, "pid": 4242 },
+            { "id": 102, "wm_class": "org.gnome.Nautilus", "title": "",
+              "window_type": 0, "focus": false },
+            { "id": 103, "wm_class": "firefox", "title": "Save As",
+              "window_type": 3, "focus": false },
+            { "id": 104, "wm_class": "zlaunch", "title": "zlaunch", "window_type": 0 },
+            { "id": 105, "wm_class": "kitty", "window_type": 0 }
+        ]"#;
+        let windows = parse_windows(json).unwrap();
+
+        let addresses: Vec<&str> = windows.iter().map(|w| w.address.as_str()).collect();
+        assert_eq!(addresses, ["101", "102", "105"]);
+        assert!(windows[0].focused);
+        assert_eq!(windows[0].title, "Mozilla Firefox");
+        assert_eq!(windows[1].title, "org.gnome.Nautilus");
+        assert_eq!(windows[2].title, "");
+    }
+}
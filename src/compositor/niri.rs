@@ -1,5 +1,5 @@
 use super::base::{CompositorCapabilities, get_display_title, is_launcher_window};
-use super::{Compositor, WindowInfo};
+use super::{Compositor, WindowInfo, WorkspaceInfo};
 use anyhow::{Context, Result, anyhow, bail};
 use serde::Deserialize;
 use std::io::{BufRead, Write};
@@ -70,13 +70,48 @@ impl Compositor for NiriCompositor {
                 class: window.app_id,
                 workspace: window.workspace_id as i32,
                 focused: window.is_focused,
+                special_workspace: None,
                 icon_data: None,
+                urgent: window.is_urgent,
             });
         }
 
         Ok(window_info)
     }
 
+    fn workspaces(&self) -> Result<Vec<WorkspaceInfo>> {
+        let json_string = self.send_command("\"Workspaces\"\n")?;
+
+        let niri_result: std::result::Result<NiriWorkspaceReply, serde_json::Value> =
+            serde_json::from_str(&json_string).context("Failed to parse Niri workspaces JSON")?;
+
+        let Ok(niri_reply) = niri_result else {
+            bail!("Niri returned an error to Workspaces request");
+        };
+
+        // Group workspaces by output, in their order on that output
+        let mut workspaces = niri_reply.workspaces;
+        workspaces.sort_by(|a, b| a.output.cmp(&b.output).then(a.idx.cmp(&b.idx)));
+
+        Ok(workspaces
+            .into_iter()
+            .map(|workspace| WorkspaceInfo {
+                id: workspace.id.to_string(),
+                name: workspace.name.unwrap_or_else(|| workspace.idx.to_string()),
+                active: workspace.is_focused,
+            })
+            .collect())
+    }
+
+    fn switch_workspace(&self, workspace_id: &str) -> Result<()> {
+        let newline = "\n";
+        let cmd = format!(
+            r#"{{"Action":{{"FocusWorkspace":{{"reference":{{"Id":{workspace_id}}}}}}}}}{newline}"#
+        );
+        self.send_command(&cmd)?;
+        Ok(())
+    }
+
     fn capabilities(&self) -> CompositorCapabilities {
         CompositorCapabilities::full()
     }
@@ -95,4 +130,23 @@ struct NiriWindow {
     app_id: String,
     workspace_id: i64,
     is_focused: bool,
+    /// Only reported by newer niri versions
+    #[serde(default)]
+    is_urgent: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct NiriWorkspaceReply {
+    #[serde(rename = "Workspaces")]
+    workspaces: Vec<NiriWorkspace>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NiriWorkspace {
+    id: u64,
+    /// Position of the workspace on its output, starting at 1
+    idx: u8,
+    name: Option<String>,
+    output: Option<String>,
+    is_focused: bool,
 }
@@ -1,7 +1,7 @@
 //! No-op compositor implementation for unsupported environments.
 
 use super::base::CompositorCapabilities;
-use super::{Compositor, WindowInfo};
+use super::{Compositor, WindowInfo, WorkspaceInfo};
 
 /// A no-op compositor that returns empty results.
 ///
@@ -19,6 +19,14 @@ impl Compositor for NoopCompositor {
         Ok(())
     }
 
+    fn workspaces(&self) -> anyhow::Result<Vec<WorkspaceInfo>> {
+        Ok(Vec::new())
+    }
+
+    fn switch_workspace(&self, _workspace_id: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn name(&self) -> &'static str {
         "Noop"
     }
@@ -0,0 +1,299 @@
+//! Sway compositor implementation using the i3/sway IPC socket.
+
+use super::base::{CompositorCapabilities, get_display_title, is_launcher_window};
+use super::{Compositor, WindowInfo, WorkspaceInfo};
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+
+/// Magic string starting every IPC message.
+const IPC_MAGIC: &[u8] = b"i3-ipc";
+
+/// Name of the hidden workspace holding scratchpad windows.
+const SCRATCHPAD_WORKSPACE: &str = "__i3_scratch";
+
+/// IPC message types used by the launcher.
+#[derive(Debug, Clone, Copy)]
+#[repr(u32)]
+enum MessageType {
+    RunCommand = 0,
+    GetWorkspaces = 1,
+    GetTree = 4,
+}
+
+/// Sway compositor client using IPC socket communication.
+pub struct SwayCompositor {
+    socket_path: PathBuf,
+}
+
+impl SwayCompositor {
+    /// Create a new Sway compositor client.
+    ///
+    /// Returns None if `SWAYSOCK` is not set.
+    pub fn new() -> Option<Self> {
+        Some(Self {
+            socket_path: std::env::var("SWAYSOCK").ok()?.into(),
+        })
+    }
+
+    /// Send a message to Sway and receive the reply payload.
+    fn send_message(&self, message_type: MessageType, payload: &str) -> Result<String> {
+        let mut stream = UnixStream::connect(&self.socket_path)
+            .with_context(|| format!("Failed to connect to Sway socket: {:?}", self.socket_path))?;
+
+        stream
+            .write_all(&encode_message(message_type, payload))
+            .context("Failed to write message to Sway socket")?;
+
+        let mut header = [0u8; 14];
+        stream
+            .read_exact(&mut header)
+            .context("Failed to read reply header from Sway socket")?;
+        let length = reply_length(&header)?;
+
+        let mut reply = vec![0u8; length];
+        stream
+            .read_exact(&mut reply)
+            .context("Failed to read reply from Sway socket")?;
+
+        String::from_utf8(reply).context("Sway reply is not valid UTF-8")
+    }
+
+    /// Run a Sway command, failing if Sway rejects it.
+    fn run_command(&self, command: &str) -> Result<()> {
+        let json = self.send_message(MessageType::RunCommand, command)?;
+        let results: Vec<SwayCommandResult> =
+            serde_json::from_str(&json).context("Failed to parse Sway command reply JSON")?;
+
+        if let Some(failed) = results.into_iter().find(|r| !r.success) {
+            bail!(
+                "Sway rejected `{}`: {}",
+                command,
+                failed.error.unwrap_or_default()
+            );
+        }
+        Ok(())
+    }
+}
+
+impl Compositor for SwayCompositor {
+    fn list_windows(&self) -> Result<Vec<WindowInfo>> {
+        let json = self.send_message(MessageType::GetTree, "")?;
+        let tree: SwayNode =
+            serde_json::from_str(&json).context("Failed to parse Sway tree JSON")?;
+        Ok(tree_windows(&tree))
+    }
+
+    fn focus_window(&self, window_id: &str) -> Result<()> {
+        self.run_command(&format!("[con_id={}] focus", window_id))
+    }
+
+    fn workspaces(&self) -> Result<Vec<WorkspaceInfo>> {
+        let json = self.send_message(MessageType::GetWorkspaces, "")?;
+        let workspaces: Vec<SwayWorkspace> =
+            serde_json::from_str(&json).context("Failed to parse Sway workspaces JSON")?;
+
+        Ok(workspaces
+            .into_iter()
+            .map(|w| WorkspaceInfo {
+                id: w.name.clone(),
+                name: w.name,
+                active: w.focused,
+            })
+            .collect())
+    }
+
+    fn switch_workspace(&self, workspace_id: &str) -> Result<()> {
+        self.run_command(&format!("workspace {}", quote(workspace_id)))
+    }
+
+    fn name(&self) -> &'static str {
+        "Sway"
+    }
+
+    fn capabilities(&self) -> CompositorCapabilities {
+        CompositorCapabilities::full()
+    }
+}
+
+/// Encode an IPC message: magic, payload length, type, payload.
+fn encode_message(message_type: MessageType, payload: &str) -> Vec<u8> {
+    let mut message = Vec::with_capacity(IPC_MAGIC.len() + 8 + payload.len());
+    message.extend_from_slice(IPC_MAGIC);
+    message.extend_from_slice(&(payload.len() as u32).to_ne_bytes());
+    message.extend_from_slice(&(message_type as u32).to_ne_bytes());
+    message.extend_from_slice(payload.as_bytes());
+    message
+}
+
+/// Get the payload length from a reply header.
+fn reply_length(header: &[u8; 14]) -> Result<usize> {
+    if &header[..IPC_MAGIC.len()] != IPC_MAGIC {
+        bail!("Invalid reply from Sway socket");
+    }
+    let length = u32::from_ne_bytes([header[6], header[7], header[8], header[9]]);
+    Ok(length as usize)
+}
+
+/// Quote a command argument so names with spaces or quotes stay one argument.
+fn quote(arg: &str) -> String {
+    format!("\"{}\"", arg.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Collect the windows of a tree, in tree order.
+///
+/// The tree only holds toplevel windows; layer-shell surfaces (panels, bars
+/// and the launcher's own surface) are never part of it.
+fn tree_windows(tree: &SwayNode) -> Vec<WindowInfo> {
+    let mut windows = Vec::new();
+    collect_windows(tree, None, &mut windows);
+    windows
+}
+
+fn collect_windows<'a>(
+    node: &'a SwayNode,
+    workspace: Option<&'a SwayNode>,
+    windows: &mut Vec<WindowInfo>,
+) {
+    let workspace = if node.node_type == "workspace" {
+        Some(node)
+    } else {
+        workspace
+    };
+
+    if let Some(class) = node.class()
+        && !is_launcher_window(class)
+    {
+        let scratchpad = workspace.is_some_and(|w| w.name.as_deref() == Some(SCRATCHPAD_WORKSPACE));
+        windows.push(WindowInfo {
+            address: node.id.to_string(),
+            title: get_display_title(node.name.as_deref().unwrap_or_default(), class),
+            class: class.to_string(),
+            workspace: workspace.and_then(|w| w.num).unwrap_or(0),
+            special_workspace: scratchpad.then(|| "scratchpad".to_string()),
+            focused: node.focused,
+            icon_data: None,
+            urgent: node.urgent,
+        });
+    }
+
+    for child in node.nodes.iter().chain(&node.floating_nodes) {
+        collect_windows(child, workspace, windows);
+    }
+}
+
+/// A node of the Sway layout tree.
+#[derive(Debug, Deserialize)]
+struct SwayNode {
+    id: i64,
+    #[serde(rename = "type")]
+    node_type: String,
+    name: Option<String>,
+    /// Workspace number, for numbered workspaces
+    num: Option<i32>,
+    #[serde(default)]
+    focused: bool,
+    #[serde(default)]
+    urgent: bool,
+    /// Application id of Wayland windows
+    app_id: Option<String>,
+    /// X11 properties of Xwayland windows
+    window_properties: Option<SwayWindowProperties>,
+    #[serde(default)]
+    nodes: Vec<SwayNode>,
+    #[serde(default)]
+    floating_nodes: Vec<SwayNode>,
+}
+
+impl SwayNode {
+    /// Get the window class, or None if this node is not a window.
+    fn class(&self) -> Option<&str> {
+        if self.node_type != "con" && self.node_type != "floating_con" {
+            return None;
+        }
+        self.app_id
+            .as_deref()
+            .or_else(|| self.window_properties.as_ref()?.class.as_deref())
+            .filter(|class| !class.is_empty())
+    }
+}
+
+/// X11 window properties.
+#[derive(Debug, Deserialize)]
+struct SwayWindowProperties {
+    class: Option<String>,
+}
+
+/// Sway workspace information.
+#[derive(Debug, Deserialize)]
+struct SwayWorkspace {
+    name: String,
+    focused: bool,
+}
+
+/// Result of one command in a RUN_COMMAND reply.
+#[derive(Debug, Deserialize)]
+struct SwayCommandResult {
+    success: bool,
+    error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_round_trip() {
+        let message = encode_message(MessageType::RunCommand, "[con_id=4] focus");
+        let header: [u8; 14] = message[..14].try_into().unwrap();
+        assert_eq!(reply_length(&header).unwrap(), 16);
+        assert_eq!(&message[10..14], &0u32.to_ne_bytes());
+        assert_eq!(&message[14..], b"[con_id=4] focus");
+    }
+
+    #[test]
+    fn test_tree_windows() {
+        let json = r#"{
+            "id": 1, "type": "root", "name": "root",
+            "nodes": [
+                { "id": 2, "type": "output", "name": "__i3",
+                  "nodes": [{ "id": 3, "type": "workspace", "name": "__i3_scratch", "num": -1,
+                      "floating_nodes": [{ "id": 10, "type": "floating_con", "name": "notes",
+                          "app_id": "org.gnome.TextEditor" }] }] },
+                { "id": 4, "type": "output", "name": "DP-1",
+                  "nodes": [{ "id": 5, "type": "workspace", "name": "2: web", "num": 2,
+                      "nodes": [
+                          { "id": 11, "type": "con", "name": "", "app_id": "foot", "focused": true },
+                          { "id": 6, "type": "con", "name": null,
+                            "nodes": [
+                                { "id": 12, "type": "con", "name": "Steam",
+                                  "app_id": null, "window_properties": { "class": "steam" },
+                                  "urgent": true },
+                                { "id": 13, "type": "con", "name": "zlaunch", "app_id": "zlaunch" }
+                            ] }
+                      ] }] }
+            ]
+        }"#;
+        let tree: SwayNode = serde_json::from_str(json).unwrap();
+        let windows = tree_windows(&tree);
+
+        let addresses: Vec<&str> = windows.iter().map(|w| w.address.as_str()).collect();
+        assert_eq!(addresses, ["10", "11", "12"]);
+
+        assert_eq!(windows[0].special_workspace.as_deref(), Some("scratchpad"));
+        assert_eq!(windows[1].title, "foot");
+        assert_eq!(windows[1].workspace, 2);
+        assert!(windows[1].focused);
+        assert_eq!(windows[2].class, "steam");
+        assert!(windows[2].urgent);
+        assert_eq!(windows[2].special_workspace, None);
+    }
+
+    #[test]
+    fn test_quote_workspace_name() {
+        assert_eq!(quote("2: web"), "\"2: web\"");
+        assert_eq!(quote(r#"a "b""#), r#""a \"b\"""#);
+    }
+}
@@ -6,11 +6,13 @@
 //!
 //! # Modules
 //!
+//! - `profile` - Config profile selection and path namespacing
 //! - `service` - Configuration loading, caching, and persistence
 //! - `theme_loader` - Theme discovery and loading
 //! - `types` - Configuration type definitions
 //! - `validation` - Configuration validation utilities
 
+pub mod profile;
 mod service;
 mod theme_loader;
 mod types;
@@ -18,15 +20,19 @@ pub mod validation;
 
 // Re-export types
 pub use types::{
-    AppConfig, ConfigModule, ConfigSearchProvider, FontConfig, FuzzyMatchConfig, LauncherMode,
-    LayerShellLayer,
+    AppConfig, BadgeConfig, ClipboardSortOrder, ConfigCustomAction, ConfigModule,
+    ConfigScriptSource, ConfigSearchProvider, DEFAULT_AI_TRIGGER, DEFAULT_TIMESTAMP_PATTERN,
+    EmojiCopyFormat, EmptyConfirmAction, EmptyStateMessages, FontConfig, FuzzyMatchConfig,
+    LauncherMode, LayerShellLayer, LinkCopyFormat, ScrollAlignment, SectionHeaderVisibility,
+    TargetOutput, TimestampFormat, TitleTruncation,
 };
 
 // Re-export service functions
 pub use service::{
     ConfigProvider, ConfigService, config, config_file_exists, get_combined_modules,
-    get_default_modes, init_config, launcher_size, load_configured_theme, update_config,
+    get_default_modes, icon_override, init_config, launcher_size, load_configured_theme,
+    update_config,
 };
 
 // Re-export theme functions
-pub use theme_loader::{list_all_themes_with_source, list_themes, load_theme};
+pub use theme_loader::{list_all_themes_with_source, list_themes, load_theme, user_themes_dir};
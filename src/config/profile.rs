@@ -0,0 +1,89 @@
+//! Config profile selection.
+//!
+//! A profile namespaces the config directory, the application cache and the
+//! IPC socket, so several independent launcher setups (e.g. "work" and
+//! "personal") can coexist. Without a profile, the default paths are used.
+
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+/// The profile selected at launch, if any.
+static PROFILE: OnceLock<Option<String>> = OnceLock::new();
+
+/// Set the active profile (call once at startup, before any path lookups).
+///
+/// Subsequent calls are ignored.
+pub fn set_profile(profile: Option<String>) {
+    if PROFILE.set(profile).is_err() {
+        tracing::warn!("Config profile already set, ignoring");
+    }
+}
+
+/// Get the active profile name, or `None` for the default profile.
+pub fn active_profile() -> Option<&'static str> {
+    PROFILE.get().and_then(|p| p.as_deref())
+}
+
+/// Check whether a profile name is usable as a single path component.
+pub fn is_valid_profile_name(name: &str) -> bool {
+    !name.is_empty()
+        && name != "."
+        && name != ".."
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+/// Apply the profile namespace to a base zlaunch directory.
+///
+/// Returns `base` unchanged for the default profile, or `base/profiles/<name>`.
+pub fn profile_dir(base: PathBuf, profile: Option<&str>) -> PathBuf {
+    match profile {
+        Some(name) => base.join("profiles").join(name),
+        None => base,
+    }
+}
+
+/// Get the IPC socket file name for a profile.
+pub fn socket_file_name(profile: Option<&str>) -> String {
+    match profile {
+        Some(name) => format!("zlaunch-{}.sock", name),
+        None => "zlaunch.sock".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_dir_default() {
+        let base = PathBuf::from("/home/user/.config/zlaunch");
+        assert_eq!(profile_dir(base.clone(), None), base);
+    }
+
+    #[test]
+    fn test_profile_dir_named() {
+        let base = PathBuf::from("/home/user/.config/zlaunch");
+        assert_eq!(
+            profile_dir(base, Some("work")),
+            PathBuf::from("/home/user/.config/zlaunch/profiles/work")
+        );
+    }
+
+    #[test]
+    fn test_socket_file_name() {
+        assert_eq!(socket_file_name(None), "zlaunch.sock");
+        assert_eq!(socket_file_name(Some("emoji")), "zlaunch-emoji.sock");
+    }
+
+    #[test]
+    fn test_valid_profile_names() {
+        assert!(is_valid_profile_name("work"));
+        assert!(is_valid_profile_name("my_profile-2"));
+        assert!(!is_valid_profile_name(""));
+        assert!(!is_valid_profile_name(".."));
+        assert!(!is_valid_profile_name("a/b"));
+        assert!(!is_valid_profile_name("with space"));
+    }
+}
@@ -155,9 +155,15 @@ fn save_config_to_file(config: &AppConfig) -> anyhow::Result<()> {
     Ok(())
 }
 
-/// Get the configured launcher panel size (width, height).
+/// Get the launcher panel size (width, height), preferring the active
+/// theme's size over the configured one.
 pub fn launcher_size() -> (f32, f32) {
-    config().get_launcher_size()
+    crate::ui::theme::theme().panel_size(&config())
+}
+
+/// Get the configured icon override for an item id, if any.
+pub fn icon_override(id: &str) -> Option<String> {
+    CONFIG.read().unwrap().icon_override(id).map(str::to_string)
 }
 
 /// Load the configured theme, falling back to default if anything fails.
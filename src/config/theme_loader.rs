@@ -1,9 +1,11 @@
 //! Theme loading utilities.
 
+use super::profile::{active_profile, profile_dir};
 use crate::items::ThemeSource;
 use crate::ui::theme::LauncherTheme;
 use rust_embed::RustEmbed;
 use std::path::PathBuf;
+use thiserror::Error;
 
 /// Embedded bundled themes.
 #[derive(RustEmbed)]
@@ -12,36 +14,84 @@ use std::path::PathBuf;
 struct BundledThemes;
 
 /// Get the config directory path.
+///
+/// This is `~/.config/zlaunch`, or `~/.config/zlaunch/profiles/<name>` when a
+/// profile is active.
 pub fn config_dir() -> Option<PathBuf> {
-    dirs::config_dir().map(|p| p.join("zlaunch"))
+    dirs::config_dir().map(|p| profile_dir(p.join("zlaunch"), active_profile()))
+}
+
+/// Get the directory holding user themes (`<config dir>/themes`).
+pub fn user_themes_dir() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("themes"))
+}
+
+/// Key naming the theme a theme file builds on.
+const INHERITS_KEY: &str = "inherits";
+
+/// Errors that can occur while resolving a theme.
+#[derive(Error, Debug)]
+pub enum ThemeError {
+    /// No bundled or user theme has this name.
+    #[error("Theme '{0}' not found")]
+    NotFound(String),
+
+    /// The theme file is not valid TOML or doesn't match the theme schema.
+    #[error("Failed to parse theme '{name}': {source}")]
+    Parse {
+        /// The theme being parsed.
+        name: String,
+        #[source]
+        source: toml::de::Error,
+    },
+
+    /// The `inherits` key is not a theme name.
+    #[error("Theme '{0}' has an `inherits` value that is not a string")]
+    InvalidInherits(String),
+
+    /// Following `inherits` leads back to a theme already in the chain.
+    #[error("Theme inheritance cycle: {}", .0.join(" -> "))]
+    InheritanceCycle(Vec<String>),
 }
 
 /// Load a theme by name.
 ///
 /// First checks bundled themes, then user themes in `~/.config/zlaunch/themes/{name}.toml`.
-/// Returns `None` if the theme is not found.
+/// A theme file may set `inherits = "<name>"` to start from another theme and
+/// only override some of its values. Returns `None` if the theme is not found
+/// or can't be resolved.
 pub fn load_theme(name: &str) -> Option<LauncherTheme> {
-    // Special case: "default" theme is defined in code, not a file
-    if name == "default" {
-        return Some(LauncherTheme::default());
+    match resolve_theme(name, &read_theme_source) {
+        Ok(theme) => {
+            tracing::info!("Loaded theme '{}'", name);
+            if let Some(gradient) = &theme.window_background_gradient
+                && gradient.stops.len() > 2
+            {
+                tracing::warn!(
+                    "Theme '{}' has {} gradient stops; only the first and last are drawn",
+                    name,
+                    gradient.stops.len()
+                );
+            }
+            Some(theme)
+        }
+        Err(ThemeError::NotFound(missing)) if missing == name => {
+            tracing::debug!("Theme '{}' not found in bundled or user themes", name);
+            None
+        }
+        Err(e) => {
+            tracing::warn!("Failed to load theme '{}': {}", name, e);
+            None
+        }
     }
+}
 
-    // First, try to load from bundled themes
+/// Read the TOML source of a theme, preferring bundled themes over user themes.
+fn read_theme_source(name: &str) -> Option<String> {
     let bundled_filename = format!("{}.toml", name);
     if let Some(bundled_file) = BundledThemes::get(&bundled_filename) {
         match std::str::from_utf8(&bundled_file.data) {
-            Ok(content) => match toml::from_str::<LauncherTheme>(content) {
-                Ok(mut theme) => {
-                    // Ensure the theme name matches
-                    theme.name = name.to_string();
-                    tracing::info!("Loaded bundled theme '{}'", name);
-                    return Some(theme);
-                }
-                Err(e) => {
-                    tracing::warn!("Failed to parse bundled theme '{}': {}", name, e);
-                    // Fall through to try user themes
-                }
-            },
+            Ok(content) => return Some(content.to_string()),
             Err(e) => {
                 tracing::warn!("Failed to read bundled theme '{}': {}", name, e);
                 // Fall through to try user themes
@@ -49,32 +99,13 @@ pub fn load_theme(name: &str) -> Option<LauncherTheme> {
         }
     }
 
-    // If not found in bundled themes, try user config directory
-    let themes_dir = config_dir()?.join("themes");
-    let theme_path = themes_dir.join(format!("{}.toml", name));
-
+    let theme_path = user_themes_dir()?.join(bundled_filename);
     if !theme_path.exists() {
-        tracing::debug!(
-            "Theme '{}' not found in bundled themes or at {:?}",
-            name,
-            theme_path
-        );
         return None;
     }
 
     match std::fs::read_to_string(&theme_path) {
-        Ok(content) => match toml::from_str::<LauncherTheme>(&content) {
-            Ok(mut theme) => {
-                // Ensure the theme name matches the file name
-                theme.name = name.to_string();
-                tracing::info!("Loaded user theme '{}' from {:?}", name, theme_path);
-                Some(theme)
-            }
-            Err(e) => {
-                tracing::warn!("Failed to parse theme file at {:?}: {}", theme_path, e);
-                None
-            }
-        },
+        Ok(content) => Some(content),
         Err(e) => {
             tracing::warn!("Failed to read theme file at {:?}: {}", theme_path, e);
             None
@@ -82,6 +113,80 @@ pub fn load_theme(name: &str) -> Option<LauncherTheme> {
     }
 }
 
+/// Resolve a theme and its `inherits` chain using `read` to look up sources.
+fn resolve_theme(
+    name: &str,
+    read: &dyn Fn(&str) -> Option<String>,
+) -> Result<LauncherTheme, ThemeError> {
+    let table = resolve_theme_table(name, read, &mut Vec::new())?;
+    let mut theme: LauncherTheme =
+        toml::Value::Table(table)
+            .try_into()
+            .map_err(|source| ThemeError::Parse {
+                name: name.to_string(),
+                source,
+            })?;
+    // Ensure the theme name matches what was requested
+    theme.name = name.to_string();
+    Ok(theme)
+}
+
+/// Resolve a theme into a table with its base themes merged underneath.
+///
+/// `chain` holds the themes currently being resolved, to detect cycles.
+fn resolve_theme_table(
+    name: &str,
+    read: &dyn Fn(&str) -> Option<String>,
+    chain: &mut Vec<String>,
+) -> Result<toml::Table, ThemeError> {
+    if chain.iter().any(|n| n == name) {
+        let mut cycle = chain.clone();
+        cycle.push(name.to_string());
+        return Err(ThemeError::InheritanceCycle(cycle));
+    }
+
+    // The "default" theme is defined in code, which is what an empty table
+    // deserializes to
+    if name == "default" {
+        return Ok(toml::Table::new());
+    }
+
+    let content = read(name).ok_or_else(|| ThemeError::NotFound(name.to_string()))?;
+    let mut table: toml::Table = toml::from_str(&content).map_err(|source| ThemeError::Parse {
+        name: name.to_string(),
+        source,
+    })?;
+
+    let Some(base) = table.remove(INHERITS_KEY) else {
+        return Ok(table);
+    };
+    let base = base
+        .as_str()
+        .ok_or_else(|| ThemeError::InvalidInherits(name.to_string()))?;
+
+    chain.push(name.to_string());
+    let mut resolved = resolve_theme_table(base, read, chain)?;
+    chain.pop();
+
+    merge_tables(&mut resolved, table);
+    Ok(resolved)
+}
+
+/// Overlay `overlay` onto `base`, merging nested tables key by key.
+fn merge_tables(base: &mut toml::Table, overlay: toml::Table) {
+    for (key, value) in overlay {
+        if let toml::Value::Table(overlay_table) = value {
+            if let Some(toml::Value::Table(base_table)) = base.get_mut(&key) {
+                merge_tables(base_table, overlay_table);
+            } else {
+                base.insert(key, toml::Value::Table(overlay_table));
+            }
+        } else {
+            base.insert(key, value);
+        }
+    }
+}
+
 /// List all available themes (both bundled and user themes).
 pub fn list_themes() -> Vec<String> {
     let mut themes = Vec::new();
@@ -147,3 +252,274 @@ pub fn list_all_themes_with_source() -> Vec<(String, ThemeSource)> {
     themes.sort_by(|a, b| a.0.cmp(&b.0));
     themes
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_themes_deserialize() {
+        let mut count = 0;
+        for filename in BundledThemes::iter() {
+            let file = BundledThemes::get(&filename).expect("bundled theme should exist");
+            let content = std::str::from_utf8(&file.data).expect("theme should be UTF-8");
+            let theme = toml::from_str::<LauncherTheme>(content)
+                .unwrap_or_else(|e| panic!("bundled theme {} failed to parse: {}", filename, e));
+
+            // The declared name must match the file name, since that's what users select
+            let stem = filename.strip_suffix(".toml").expect("theme file is .toml");
+            assert_eq!(theme.name, stem, "theme name mismatch in {}", filename);
+            count += 1;
+        }
+        assert!(count > 0, "no bundled themes found");
+    }
+
+    #[test]
+    fn test_curated_themes_are_bundled() {
+        let themes = list_all_themes_with_source();
+        for name in [
+            "default",
+            "catppuccin-mocha",
+            "catppuccin-latte",
+            "nord",
+            "gruvbox-dark",
+            "dracula",
+        ] {
+            assert!(
+                themes
+                    .iter()
+                    .any(|(n, source)| n == name && *source == ThemeSource::Bundled),
+                "missing bundled theme '{}'",
+                name
+            );
+            assert!(
+                load_theme(name).is_some(),
+                "failed to load theme '{}'",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_list_themes_includes_bundled() {
+        let themes = list_themes();
+        assert!(themes.contains(&"nord".to_string()));
+        assert!(themes.contains(&"dracula".to_string()));
+    }
+
+    #[test]
+    fn test_section_separator_defaults_off() {
+        let theme: LauncherTheme = toml::from_str("name = \"test\"").unwrap();
+        assert!(!theme.layout.section_separator);
+        assert_eq!(theme.layout.section_gap, gpui::px(0.0));
+
+        let theme: LauncherTheme = toml::from_str(
+            "name = \"test\"\n[layout]\nsection_separator = true\nsection_gap = 6.0",
+        )
+        .unwrap();
+        assert!(theme.layout.section_separator);
+        assert_eq!(theme.layout.section_gap, gpui::px(6.0));
+        // Unset layout values keep their defaults
+        assert_eq!(theme.layout.separator_width, gpui::px(1.0));
+    }
+
+    #[test]
+    fn test_window_border_width() {
+        let theme: LauncherTheme = toml::from_str("name = \"test\"").unwrap();
+        assert_eq!(theme.window_border_width, gpui::px(1.0));
+
+        let theme: LauncherTheme =
+            toml::from_str("name = \"test\"\nwindow_border_width = 3.0").unwrap();
+        assert_eq!(theme.window_border_width, gpui::px(3.0));
+
+        let theme: LauncherTheme =
+            toml::from_str("name = \"test\"\nwindow_border_width = 0.0").unwrap();
+        assert_eq!(theme.window_border_width, gpui::px(0.0));
+    }
+
+    #[test]
+    fn test_window_background_gradient() {
+        let theme: LauncherTheme = toml::from_str(
+            r##"
+            name = "test"
+            window_background = "#101010"
+
+            [window_background_gradient]
+            angle = 135.0
+            stops = [
+                { color = "#1e1e2e" },
+                { color = "#313244", position = 0.4 },
+                { color = "#45475a" },
+            ]
+            "##,
+        )
+        .unwrap();
+
+        let gradient = theme.window_background_gradient.as_ref().unwrap();
+        assert_eq!(gradient.angle, 135.0);
+        let ((_, from), (_, to)) = gradient.endpoints().unwrap();
+        // Stops without a position are spread evenly
+        assert_eq!((from, to), (0.0, 1.0));
+        assert!(gradient.to_background(false).is_some());
+    }
+
+    #[test]
+    fn test_window_background_gradient_absent() {
+        let theme: LauncherTheme =
+            toml::from_str("name = \"test\"\nwindow_background = \"#101010\"").unwrap();
+        assert!(theme.window_background_gradient.is_none());
+    }
+
+    #[test]
+    fn test_single_stop_gradient_is_ignored() {
+        let theme: LauncherTheme = toml::from_str(
+            "name = \"test\"\n[window_background_gradient]\nstops = [{ color = \"#ffffff\" }]",
+        )
+        .unwrap();
+        let gradient = theme.window_background_gradient.unwrap();
+        assert!(gradient.endpoints().is_none());
+        assert!(gradient.to_background(false).is_none());
+    }
+
+    #[test]
+    fn test_theme_launcher_size_overrides_config() {
+        let mut config = crate::config::AppConfig::default();
+        config.launcher_size = Some((600.0, 400.0));
+
+        let theme: LauncherTheme =
+            toml::from_str("name = \"test\"\nlauncher_size = [720.0, 480.0]").unwrap();
+        assert_eq!(theme.panel_size(&config), (720.0, 480.0));
+
+        // Themes without a size keep the configured one
+        let theme: LauncherTheme = toml::from_str("name = \"test\"").unwrap();
+        assert_eq!(theme.launcher_size, None);
+        assert_eq!(theme.panel_size(&config), (600.0, 400.0));
+    }
+
+    /// Look up theme sources from a fixed list, falling back to bundled themes.
+    fn sources<'a>(themes: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |name| {
+            themes
+                .iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, content)| content.to_string())
+                .or_else(|| read_theme_source(name))
+        }
+    }
+
+    fn color(hex: &str) -> gpui::Hsla {
+        let theme: LauncherTheme =
+            toml::from_str(&format!("item_title_color = \"{}\"", hex)).unwrap();
+        theme.item_title_color
+    }
+
+    const BASE: &str = r##"
+        name = "base"
+        item_title_color = "#111111"
+        window_border_radius = 4.0
+
+        [action_indicator]
+        width = 80.0
+        key_color = "#222222"
+    "##;
+
+    #[test]
+    fn test_inherits_single_level() {
+        let child = r##"
+            name = "child"
+            inherits = "base"
+            item_title_color = "#333333"
+
+            [action_indicator]
+            width = 120.0
+        "##;
+        let read = sources(&[("base", BASE), ("child", child)]);
+        let theme = resolve_theme("child", &read).unwrap();
+
+        assert_eq!(theme.name, "child");
+        assert_eq!(theme.item_title_color, color("#333333"));
+        assert_eq!(theme.window_border_radius, gpui::px(4.0));
+        // Sub-themes are merged key by key rather than replaced
+        assert_eq!(theme.action_indicator.width, gpui::px(120.0));
+        assert_eq!(theme.action_indicator.key_color, color("#222222"));
+    }
+
+    #[test]
+    fn test_inherits_chained() {
+        let middle = r##"
+            inherits = "base"
+            window_border_radius = 8.0
+
+            [action_indicator]
+            key_color = "#444444"
+        "##;
+        let top = r##"
+            inherits = "middle"
+            item_title_color = "#555555"
+        "##;
+        let read = sources(&[("base", BASE), ("middle", middle), ("top", top)]);
+        let theme = resolve_theme("top", &read).unwrap();
+
+        assert_eq!(theme.name, "top");
+        assert_eq!(theme.item_title_color, color("#555555"));
+        assert_eq!(theme.window_border_radius, gpui::px(8.0));
+        assert_eq!(theme.action_indicator.width, gpui::px(80.0));
+        assert_eq!(theme.action_indicator.key_color, color("#444444"));
+    }
+
+    #[test]
+    fn test_inherits_bundled_theme() {
+        let child = "inherits = \"nord\"\nwindow_border_radius = 2.0";
+        let read = sources(&[("my-nord", child)]);
+        let theme = resolve_theme("my-nord", &read).unwrap();
+        let nord = load_theme("nord").unwrap();
+
+        assert_eq!(theme.name, "my-nord");
+        assert_eq!(theme.window_border_radius, gpui::px(2.0));
+        assert_eq!(theme.item_title_color, nord.item_title_color);
+
+        let read = sources(&[("plain", "inherits = \"default\"")]);
+        let theme = resolve_theme("plain", &read).unwrap();
+        assert_eq!(
+            theme.item_title_color,
+            LauncherTheme::default().item_title_color
+        );
+    }
+
+    #[test]
+    fn test_inherits_cycle_is_an_error() {
+        let read = sources(&[
+            ("a", "inherits = \"b\""),
+            ("b", "inherits = \"c\""),
+            ("c", "inherits = \"a\""),
+        ]);
+        match resolve_theme("a", &read) {
+            Err(ThemeError::InheritanceCycle(cycle)) => assert_eq!(cycle, ["a", "b", "c", "a"]),
+            other => panic!("expected a cycle error, got {:?}", other.map(|t| t.name)),
+        }
+
+        let read = sources(&[("self", "inherits = \"self\"")]);
+        assert!(matches!(
+            resolve_theme("self", &read),
+            Err(ThemeError::InheritanceCycle(_))
+        ));
+    }
+
+    #[test]
+    fn test_inherits_missing_base() {
+        let read = sources(&[("child", "inherits = \"no-such-theme\"")]);
+        match resolve_theme("child", &read) {
+            Err(ThemeError::NotFound(name)) => assert_eq!(name, "no-such-theme"),
+            other => panic!(
+                "expected a not found error, got {:?}",
+                other.map(|t| t.name)
+            ),
+        }
+
+        let read = sources(&[("child", "inherits = 3")]);
+        assert!(matches!(
+            resolve_theme("child", &read),
+            Err(ThemeError::InvalidInherits(_))
+        ));
+    }
+}
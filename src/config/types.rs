@@ -1,8 +1,11 @@
 //! Configuration type definitions.
 
+use crate::desktop::EntrySource;
 use gpui::layer_shell::Layer;
+use gpui::{Hsla, ScrollStrategy};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
 
 /// Wayland layer-shell layer on which the launcher window is placed.
 ///
@@ -29,6 +32,226 @@ impl From<LayerShellLayer> for Layer {
     }
 }
 
+/// Output (monitor) the launcher window is opened on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TargetOutput {
+    /// The output the compositor reports as focused.
+    Focused,
+    /// The primary display.
+    Primary,
+    /// An output by connector name, e.g. `DP-1`.
+    Named(String),
+}
+
+impl TargetOutput {
+    /// Parse a `target_output` value; anything but `focused` and `primary`
+    /// is a connector name.
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "focused" => Self::Focused,
+            "primary" => Self::Primary,
+            name => Self::Named(name.to_string()),
+        }
+    }
+}
+
+/// Where the selected item is scrolled to when navigating lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ScrollAlignment {
+    /// Align the selected item with the top edge. Default.
+    #[default]
+    Top,
+    /// Keep the selected item centered.
+    Center,
+    /// Scroll as little as needed to bring the item into view.
+    Nearest,
+}
+
+impl From<ScrollAlignment> for ScrollStrategy {
+    fn from(value: ScrollAlignment) -> Self {
+        match value {
+            ScrollAlignment::Top => ScrollStrategy::Top,
+            ScrollAlignment::Center => ScrollStrategy::Center,
+            ScrollAlignment::Nearest => ScrollStrategy::Nearest,
+        }
+    }
+}
+
+/// What pressing Enter does when the query matches no items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum EmptyConfirmAction {
+    /// Do nothing. Default.
+    #[default]
+    None,
+    /// Search the query with the first configured search provider.
+    Search,
+    /// Ask the AI about the query.
+    Ai,
+    /// Run the query as a shell command.
+    Command,
+}
+
+/// When to show section headers ("Windows", "Applications", ...) in the list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SectionHeaderVisibility {
+    /// Show every non-empty section's header.
+    Always,
+    /// Show headers only when results span more than one section. Default.
+    #[default]
+    Auto,
+    /// Never show headers (flat list).
+    Never,
+}
+
+/// Text copied when an emoji is selected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum EmojiCopyFormat {
+    /// The literal emoji character. Default.
+    #[default]
+    Unicode,
+    /// A `:shortcode:` (falls back to the character if none exists).
+    Shortcode,
+    /// HTML numeric character references, e.g. `&#x1F600;`.
+    Html,
+    /// Unicode codepoints, e.g. `U+1F600`.
+    Codepoint,
+}
+
+impl EmojiCopyFormat {
+    /// Get the next format, for quick-toggling in the picker.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Unicode => Self::Shortcode,
+            Self::Shortcode => Self::Html,
+            Self::Html => Self::Codepoint,
+            Self::Codepoint => Self::Unicode,
+        }
+    }
+
+    /// Get a human-readable label for this format.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Unicode => "Emoji",
+            Self::Shortcode => "Shortcode",
+            Self::Html => "HTML entity",
+            Self::Codepoint => "Codepoint",
+        }
+    }
+}
+
+/// Link text copied for web search results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LinkCopyFormat {
+    /// A Markdown link, e.g. `[rust](https://...)`. Default.
+    #[default]
+    Markdown,
+    /// The plain URL.
+    Url,
+    /// An Org mode link, e.g. `[[https://...][rust]]`.
+    Org,
+}
+
+impl LinkCopyFormat {
+    /// Format a link to `url` titled `title`.
+    ///
+    /// Characters that would end the title or URL early are escaped.
+    pub fn format(self, title: &str, url: &str) -> String {
+        match self {
+            Self::Markdown => {
+                let title = title
+                    .replace('\\', "\\\\")
+                    .replace('[', "\\[")
+                    .replace(']', "\\]");
+                let url = url
+                    .replace(' ', "%20")
+                    .replace('(', "%28")
+                    .replace(')', "%29");
+                format!("[{}]({})", title, url)
+            }
+            Self::Url => url.to_string(),
+            Self::Org => {
+                let title = title.replace('[', "{").replace(']', "}");
+                let url = url.replace('[', "%5B").replace(']', "%5D");
+                format!("[[{}][{}]]", url, title)
+            }
+        }
+    }
+}
+
+/// Order of the clipboard history list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ClipboardSortOrder {
+    /// Most recently copied first. Default.
+    #[default]
+    Newest,
+    /// Least recently copied first.
+    Oldest,
+    /// Grouped by content type (text, rich text, images, files), newest
+    /// first within each group.
+    Type,
+}
+
+impl ClipboardSortOrder {
+    /// Get the next order, for quick-toggling in the history view.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Newest => Self::Oldest,
+            Self::Oldest => Self::Type,
+            Self::Type => Self::Newest,
+        }
+    }
+
+    /// Get a human-readable label for this order.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Newest => "Newest first",
+            Self::Oldest => "Oldest first",
+            Self::Type => "By type",
+        }
+    }
+}
+
+/// How overlong window titles are shortened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TitleTruncation {
+    /// Cut the end and show an ellipsis there. Default.
+    #[default]
+    End,
+    /// Replace the middle with an ellipsis so both ends stay visible.
+    Middle,
+    /// Clip without an ellipsis.
+    None,
+}
+
+/// How clipboard history timestamps are displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TimestampFormat {
+    /// "5 mins ago" within a day, the date for older entries. Default.
+    #[default]
+    Relative,
+    /// Always the date, formatted with `clipboard_timestamp_pattern`.
+    Absolute,
+    /// Relative time followed by the date.
+    Both,
+}
+
+/// Default strftime pattern for absolute clipboard timestamps.
+pub const DEFAULT_TIMESTAMP_PATTERN: &str = "%Y-%m-%d %H:%M";
+
+/// Default query prefix that sends the rest of the query to the AI.
+pub const DEFAULT_AI_TRIGGER: &str = "!ai";
+
+/// Default keystroke that launches the selected application in a terminal.
+pub const DEFAULT_TERMINAL_CONFIRM_KEY: &str = "ctrl-enter";
+
 /// Configuration for fuzzy matching algorithm.
 ///
 /// These settings control how items are scored during search,
@@ -52,6 +275,10 @@ pub struct FuzzyMatchConfig {
     /// Lower values make description matches rank lower than name matches.
     /// Default: 0.3
     pub description_penalty: f64,
+    /// Multiplier for application-id-only matches (0.0-1.0), e.g. typing
+    /// "nautilus" for `org.gnome.Nautilus` ("Files").
+    /// Default: 0.5
+    pub id_penalty: f64,
     /// Score multiplier for action items in combined mode.
     /// Lower values demote system actions like Shutdown, Logout.
     /// Default: 0.8
@@ -59,11 +286,31 @@ pub struct FuzzyMatchConfig {
     /// Score multiplier for submenu items in combined mode.
     /// Default: 0.9
     pub submenu_score_multiplier: f64,
+    /// Score multiplier for window matches on the title. 0 disables title
+    /// matching.
+    /// Default: 1.0
+    pub window_title_weight: f64,
+    /// Score multiplier for window matches on the application class
+    /// (e.g. "firefox"), so every window of an app is found by its class.
+    /// 0 disables class matching.
+    /// Default: 0.8
+    pub window_class_weight: f64,
     /// Show the best matching item at the top, regardless of module order.
     /// When enabled, if a higher-scoring item exists in a lower-priority module,
     /// it will be promoted to the top of the list.
     /// Default: true
     pub show_best_match: bool,
+    /// Minimum final score for an item to be shown; weaker matches are dropped.
+    /// Scores include the bonuses above: a name prefix match scores at least
+    /// `prefix_match_bonus`, a contiguous match earns up to `contiguity_bonus`,
+    /// and scattered matches score far less. Values around 5000-20000 trim
+    /// tenuous matches for short queries. 0 keeps every match.
+    /// Default: 0
+    pub min_score: i64,
+    /// Bonus score for the item last confirmed for the same query, with
+    /// `learn_selections`. 0 disables the boost.
+    /// Default: 150000
+    pub learned_selection_bonus: i64,
 }
 
 impl FuzzyMatchConfig {
@@ -75,9 +322,14 @@ impl FuzzyMatchConfig {
             word_prefix_bonus: 25_000,
             contiguity_bonus: 10_000,
             description_penalty: 0.3,
+            id_penalty: 0.5,
             action_score_multiplier: 0.8,
             submenu_score_multiplier: 0.9,
+            window_title_weight: 1.0,
+            window_class_weight: 0.8,
             show_best_match: true,
+            min_score: 0,
+            learned_selection_bonus: 150_000,
         }
     }
 }
@@ -88,6 +340,39 @@ impl Default for FuzzyMatchConfig {
     }
 }
 
+/// Badges shown at the end of list items.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BadgeConfig {
+    /// Tag applications installed from Flatpak or Snap.
+    /// Default: true
+    pub source: bool,
+    /// Mark applications that have an open window.
+    /// Default: true
+    pub running: bool,
+    /// Show the number of open windows on running applications with more
+    /// than one.
+    /// Default: true
+    pub window_count: bool,
+}
+
+impl BadgeConfig {
+    /// Const default for static initialization.
+    pub const fn default_const() -> Self {
+        Self {
+            source: true,
+            running: true,
+            window_count: true,
+        }
+    }
+}
+
+impl Default for BadgeConfig {
+    fn default() -> Self {
+        Self::default_const()
+    }
+}
+
 /// Font configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -110,6 +395,47 @@ impl Default for FontConfig {
     }
 }
 
+/// Messages shown when a list has nothing to show, e.g. to translate them.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmptyStateMessages {
+    /// Clipboard history has no entries yet.
+    pub clipboard: Option<String>,
+    /// No themes are available.
+    pub themes: Option<String>,
+    /// The query matches nothing.
+    pub no_matches: Option<String>,
+}
+
+impl EmptyStateMessages {
+    /// Get the message for an empty clipboard history.
+    pub fn clipboard(&self) -> &str {
+        self.clipboard
+            .as_deref()
+            .unwrap_or("No clipboard history yet \u{2014} copy something to see it here")
+    }
+
+    /// Get the message for when no themes are available.
+    pub fn themes(&self) -> &str {
+        self.themes
+            .as_deref()
+            .unwrap_or("No themes found \u{2014} add one to ~/.config/zlaunch/themes")
+    }
+
+    /// Get the message for a query without matches.
+    pub fn no_matches(&self) -> &str {
+        self.no_matches.as_deref().unwrap_or("No matches found")
+    }
+}
+
+/// Default backdrop color (black, dimmed via `backdrop_opacity`).
+const DEFAULT_BACKDROP_COLOR: Hsla = Hsla {
+    h: 0.0,
+    s: 0.0,
+    l: 0.0,
+    a: 1.0,
+};
+
 /// Application configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -128,8 +454,27 @@ pub struct AppConfig {
     /// When false, the window is just the launcher panel with no click-outside behavior.
     /// Default: true
     pub enable_backdrop: bool,
+    /// Color of the backdrop behind the panel.
+    /// Only used when enable_backdrop is true.
+    /// Default: black
+    #[serde(with = "crate::ui::theme::hsla_serde")]
+    pub backdrop_color: Hsla,
+    /// Opacity of the backdrop (0.0 - 1.0), applied on top of the color's own alpha.
+    /// Default: 0.0 (fully transparent)
+    pub backdrop_opacity: f32,
+    /// Largest share of the screen (0.1 - 1.0) the launcher panel may take in
+    /// each dimension; larger panels are scaled down to fit.
+    /// Default: 0.9
+    pub max_screen_fraction: f32,
+    /// Close the launcher when clicking the backdrop outside the panel.
+    /// Only used when enable_backdrop is true.
+    /// Default: true
+    pub close_on_backdrop_click: bool,
     /// Automatically apply blur layer rules on Hyprland.
     pub hyprland_auto_blur: bool,
+    /// List windows on Hyprland special workspaces (scratchpads).
+    /// Default: true
+    pub hyprland_special_workspaces: bool,
     /// Modules that are disabled (DEPRECATED: use combined_modules instead).
     pub disabled_modules: Option<HashSet<ConfigModule>>,
     /// Enable transparency of the window.
@@ -142,13 +487,201 @@ pub struct AppConfig {
     pub combined_modules: Option<Vec<ConfigModule>>,
     /// Fuzzy matching configuration for search scoring.
     pub fuzzy_match: FuzzyMatchConfig,
+    /// Badges shown at the end of list items.
+    pub badges: BadgeConfig,
     /// Wayland layer-shell layer to place the launcher window on.
     /// Default: `Overlay`. Use `Top` if another surface (e.g. an input-method
     /// popup) needs to render above the launcher.
     pub layer_shell_layer: LayerShellLayer,
+    /// Output the launcher opens on: a connector name like "DP-1",
+    /// "focused" for the output the compositor reports as focused, or
+    /// "primary". Unset leaves the choice to the compositor.
+    /// Default: unset
+    pub target_output: Option<String>,
     /// Font configuration for the launcher UI.
     /// Can be overridden by the active theme's `[font]` section.
     pub font: FontConfig,
+    /// Action to run when Enter is pressed and the query matches nothing.
+    /// Default: `None`
+    pub empty_confirm_action: EmptyConfirmAction,
+    /// Per-item icon overrides, keyed by item id (e.g. the desktop file id
+    /// `firefox` for applications). Values are Phosphor icon names, icon
+    /// theme names, or filesystem paths.
+    pub icon_overrides: Option<HashMap<String, String>>,
+    /// Query prefixes that switch the combined view to a mode, keyed by
+    /// prefix (e.g. `">e " = "emojis"`). Only the emojis, glyphs,
+    /// clipboard and themes modes can be switched to. Backspacing past the
+    /// start of the mode's input returns to the combined view.
+    /// Default: `">e "` emojis, `">c "` clipboard, `">t "` themes
+    pub mode_prefixes: Option<HashMap<String, String>>,
+    /// When to show section headers in the combined list.
+    /// Default: `Auto`
+    pub show_section_headers: SectionHeaderVisibility,
+    /// Minimum query length (in characters) before fallback search items
+    /// appear. Explicit triggers like `!g` bypass this.
+    /// Default: 0
+    pub search_min_query_len: usize,
+    /// Maximum number of search providers offered when no trigger matches,
+    /// taken in `search_providers` order. Explicit triggers like `!g` are
+    /// unaffected.
+    /// Default: None (no limit)
+    pub max_fallback_search_providers: Option<usize>,
+    /// Minimum query length (in characters) before the AI item appears.
+    /// The explicit AI trigger bypasses this.
+    /// Default: 0
+    pub ai_min_query_len: usize,
+    /// Query prefix that shows only the AI item, asking the rest of the
+    /// query, e.g. "!ai what is rust" or "?" for quick questions.
+    /// Set to "" to disable.
+    /// Default: "!ai"
+    pub ai_trigger: Option<String>,
+    /// Keystroke that launches the selected application in a terminal,
+    /// regardless of its `Terminal` flag, e.g. "ctrl-enter" or "alt-enter".
+    /// Set to "" to disable.
+    /// Default: "ctrl-enter"
+    pub terminal_confirm_key: Option<String>,
+    /// Format of the text copied from the emoji picker.
+    /// Default: `Unicode`
+    pub emoji_copy_format: EmojiCopyFormat,
+    /// Format of the link copied from a web search result with Shift+Enter.
+    /// Default: `Markdown`
+    pub link_copy_format: LinkCopyFormat,
+    /// How overlong window titles are shortened.
+    /// Default: `End`
+    pub window_title_truncation: TitleTruncation,
+    /// How clipboard history timestamps are displayed.
+    /// Default: `Relative`
+    pub clipboard_timestamp_format: TimestampFormat,
+    /// strftime pattern for absolute clipboard timestamps.
+    /// Default: "%Y-%m-%d %H:%M"
+    pub clipboard_timestamp_pattern: Option<String>,
+    /// Order of the clipboard history list, toggled with Ctrl+O.
+    /// Default: `Newest`
+    pub clipboard_sort: ClipboardSortOrder,
+    /// Maximum number of clipboard history entries. The oldest entries are
+    /// dropped first. Set to 0 for no limit.
+    /// Default: 500
+    pub clipboard_max_entries: usize,
+    /// Drop clipboard history entries older than this many days.
+    /// Default: None (no limit)
+    pub clipboard_max_age_days: Option<u32>,
+    /// Total size (in MiB) of the images kept in clipboard history. The
+    /// oldest images are dropped first. Set to 0 for no limit.
+    /// Default: 256
+    pub clipboard_max_image_mib: usize,
+    /// Drop clipboard entries copied from a password manager this many
+    /// seconds after they were copied. Set to 0 to keep them like other
+    /// entries.
+    /// Default: 30
+    pub clipboard_sensitive_expiry_secs: u64,
+    /// Query prefix that restricts results to applications only
+    /// (no windows, actions, calculator, search or AI). Set to "" to disable.
+    /// Default: "@"
+    pub app_only_prefix: Option<String>,
+    /// Query prefix for browsing applications by desktop entry category,
+    /// e.g. "cat:games steam". Set to "" to disable.
+    /// Default: "cat:"
+    pub category_prefix: Option<String>,
+    /// Query prefix that matches the rest of the query as an exact
+    /// (case-insensitive) substring instead of fuzzily, e.g. "'term".
+    /// Set to "" to disable.
+    /// Default: "'"
+    pub exact_match_prefix: Option<String>,
+    /// Directory that path queries (e.g. "~/projects/foo" or "docs/notes.md")
+    /// are resolved against; only existing paths inside it are offered to
+    /// open. Set to "" to disable.
+    /// Default: "~"
+    pub path_base_dir: Option<String>,
+    /// Directory the `files` module searches, skipping hidden and
+    /// `.gitignore`d files. Set to "" to disable.
+    /// Default: "~"
+    pub file_search_root: Option<String>,
+    /// How many directory levels below `file_search_root` are searched.
+    /// Default: 4
+    pub file_search_max_depth: usize,
+    /// Query prefix that searches files in the combined view, e.g.
+    /// "f:notes". The `files` launcher mode searches without it.
+    /// Set to "" to disable.
+    /// Default: "f:"
+    pub file_search_prefix: Option<String>,
+    /// Where keyboard navigation scrolls the selected item to.
+    /// Default: `Top`
+    pub scroll_strategy: ScrollAlignment,
+    /// Collapse duplicate applications installed from several sources
+    /// (e.g. distro package and Flatpak) into one entry.
+    /// Default: true
+    pub dedup_desktop_entries: bool,
+    /// Preferred sources when collapsing duplicates, most preferred first.
+    /// Default: ["native", "flatpak", "snap"]
+    pub desktop_source_order: Option<Vec<EntrySource>>,
+    /// Maximum height of the AI response area in pixels. When set, the area
+    /// grows with the answer up to this height and scrolls beyond it.
+    /// Default: None (fill the panel)
+    pub ai_response_max_height: Option<f32>,
+    /// Answer the AI item inline, streaming the answer into its row in the
+    /// list instead of opening the full AI view.
+    /// Default: false
+    pub ai_answer_inline: bool,
+    /// Environment variable the AI key is read from: "OLLAMA_URL",
+    /// "GEMINI_API_KEY", "OPENAI_API_KEY" or "OPENROUTER_API_KEY". Without
+    /// a key the AI module is hidden.
+    /// Default: None (the first of these that is set)
+    pub ai_api_key_env: Option<String>,
+    /// Earlier exchanges sent along with a follow-up question in the AI
+    /// view, so it can refer back to them. Older ones stay visible but are
+    /// no longer sent.
+    /// Default: 10
+    pub ai_history_max_turns: usize,
+    /// Focus an application's open window instead of launching another
+    /// instance. Launches normally when no window is found.
+    /// Default: false
+    pub raise_or_launch: bool,
+    /// List windows requesting attention before other windows. Only some
+    /// compositors (currently niri) report this.
+    /// Default: false
+    pub urgent_windows_first: bool,
+    /// Maximum number of rows in the combined list, counting calculator,
+    /// AI, search, script and file items. The best scoring results are kept
+    /// and a hint shows how many were hidden. If those items alone exceed
+    /// it, search, file and script items are cut too.
+    /// Default: None (no limit)
+    pub max_results: Option<usize>,
+    /// Select the top result again on every keystroke. When off, a manually
+    /// selected item stays selected while it still matches the query.
+    /// Default: true
+    pub reselect_on_type: bool,
+    /// Number of recently launched applications shown in a "Recent"
+    /// section before anything is typed. Set to 0 to disable.
+    /// Default: 5
+    pub recent_apps_count: usize,
+    /// Desktop entry ids of applications listed in a "Pinned" section at
+    /// the top of the combined view, whenever they match the query. Add
+    /// one with `zlaunch pin <app-id>`.
+    /// Default: []
+    pub pinned_applications: Vec<String>,
+    /// Remember the item confirmed for each query and rank it higher when
+    /// the same query is typed again. The selections are kept in the cache
+    /// directory.
+    /// Default: true
+    pub learn_selections: bool,
+    /// Messages for empty lists (`[empty_state]` with `clipboard`, `themes`
+    /// and `no_matches`).
+    /// Default: built-in English messages
+    pub empty_state: EmptyStateMessages,
+    /// Hide the launcher after copying a calculator result with Enter. When
+    /// false, the launcher stays open and the result row shows "Copied", so
+    /// the user can keep calculating; Shift+Enter copies and closes.
+    /// Default: false
+    pub calculator_close_on_copy: bool,
+    /// External commands listing items for the query in a "Scripts"
+    /// section (`[[scripts]]` with `name`, `command` and `timeout_ms`).
+    /// Default: none
+    pub scripts: Vec<ConfigScriptSource>,
+    /// User-defined actions listed with the built-in ones
+    /// (`[[custom_actions]]` with `name`, `description`, `icon` and
+    /// `command`). Hidden with the actions module.
+    /// Default: none
+    pub custom_actions: Vec<ConfigCustomAction>,
 }
 
 impl AppConfig {
@@ -159,19 +692,74 @@ impl AppConfig {
             launcher_size: None,
             window_size: None,
             enable_backdrop: true,
+            backdrop_color: DEFAULT_BACKDROP_COLOR,
+            backdrop_opacity: 0.0,
+            max_screen_fraction: 0.9,
+            close_on_backdrop_click: true,
             hyprland_auto_blur: true,
+            hyprland_special_workspaces: true,
             disabled_modules: None,
             enable_transparency: true,
             search_providers: None,
             default_modes: None,
             combined_modules: None,
             fuzzy_match: FuzzyMatchConfig::default_const(),
+            badges: BadgeConfig::default_const(),
             layer_shell_layer: LayerShellLayer::Overlay,
+            target_output: None,
             font: FontConfig {
                 font_family: None,
                 mono_font_family: None,
                 font_size: None,
             },
+            empty_confirm_action: EmptyConfirmAction::None,
+            icon_overrides: None,
+            mode_prefixes: None,
+            show_section_headers: SectionHeaderVisibility::Auto,
+            search_min_query_len: 0,
+            max_fallback_search_providers: None,
+            ai_min_query_len: 0,
+            ai_trigger: None,
+            terminal_confirm_key: None,
+            emoji_copy_format: EmojiCopyFormat::Unicode,
+            link_copy_format: LinkCopyFormat::Markdown,
+            window_title_truncation: TitleTruncation::End,
+            clipboard_timestamp_format: TimestampFormat::Relative,
+            clipboard_timestamp_pattern: None,
+            clipboard_sort: ClipboardSortOrder::Newest,
+            clipboard_max_entries: 500,
+            clipboard_max_age_days: None,
+            clipboard_max_image_mib: 256,
+            clipboard_sensitive_expiry_secs: 30,
+            app_only_prefix: None,
+            category_prefix: None,
+            exact_match_prefix: None,
+            path_base_dir: None,
+            file_search_root: None,
+            file_search_max_depth: 4,
+            file_search_prefix: None,
+            scroll_strategy: ScrollAlignment::Top,
+            dedup_desktop_entries: true,
+            desktop_source_order: None,
+            ai_response_max_height: None,
+            ai_answer_inline: false,
+            ai_api_key_env: None,
+            ai_history_max_turns: 10,
+            raise_or_launch: false,
+            urgent_windows_first: false,
+            max_results: None,
+            reselect_on_type: true,
+            recent_apps_count: 5,
+            pinned_applications: Vec::new(),
+            learn_selections: true,
+            empty_state: EmptyStateMessages {
+                clipboard: None,
+                themes: None,
+                no_matches: None,
+            },
+            calculator_close_on_copy: false,
+            scripts: Vec::new(),
+            custom_actions: Vec::new(),
         }
     }
 
@@ -179,6 +767,117 @@ impl AppConfig {
     pub fn get_launcher_size(&self) -> (f32, f32) {
         self.launcher_size.unwrap_or((600.0, 400.0))
     }
+
+    /// Get the backdrop background color with the configured opacity applied.
+    pub fn backdrop_background(&self) -> Hsla {
+        let opacity = self.backdrop_opacity.clamp(0.0, 1.0);
+        self.backdrop_color.opacity(opacity)
+    }
+
+    /// Get the preferred desktop entry sources for deduplication.
+    pub fn desktop_source_order(&self) -> &[EntrySource] {
+        self.desktop_source_order.as_deref().unwrap_or(&[
+            EntrySource::Native,
+            EntrySource::Flatpak,
+            EntrySource::Snap,
+        ])
+    }
+
+    /// Get the app-only query prefix, if enabled.
+    pub fn app_only_prefix(&self) -> Option<&str> {
+        self.app_only_prefix
+            .as_deref()
+            .filter(|prefix| !prefix.is_empty())
+    }
+
+    /// Get the category browsing query prefix, if enabled.
+    pub fn category_prefix(&self) -> Option<&str> {
+        self.category_prefix
+            .as_deref()
+            .filter(|prefix| !prefix.is_empty())
+    }
+
+    /// Get the AI trigger prefix, if enabled.
+    pub fn ai_trigger(&self) -> Option<&str> {
+        self.ai_trigger
+            .as_deref()
+            .filter(|trigger| !trigger.is_empty())
+    }
+
+    /// Get the configured AI key environment variable, if any.
+    pub fn ai_api_key_env(&self) -> Option<&str> {
+        self.ai_api_key_env
+            .as_deref()
+            .filter(|name| !name.is_empty())
+    }
+
+    /// Get the keystroke that launches applications in a terminal, if enabled.
+    pub fn terminal_confirm_key(&self) -> Option<&str> {
+        self.terminal_confirm_key
+            .as_deref()
+            .filter(|key| !key.is_empty())
+    }
+
+    /// Get the exact substring match query prefix, if enabled.
+    pub fn exact_match_prefix(&self) -> Option<&str> {
+        self.exact_match_prefix
+            .as_deref()
+            .filter(|prefix| !prefix.is_empty())
+    }
+
+    /// Get the base directory for path queries, if enabled.
+    pub fn path_base_dir(&self) -> Option<&str> {
+        self.path_base_dir.as_deref().filter(|dir| !dir.is_empty())
+    }
+
+    /// Get the root directory of the files module, if enabled.
+    pub fn file_search_root(&self) -> Option<&str> {
+        self.file_search_root
+            .as_deref()
+            .filter(|dir| !dir.is_empty())
+    }
+
+    /// Get the file search query prefix, if enabled.
+    pub fn file_search_prefix(&self) -> Option<&str> {
+        self.file_search_prefix
+            .as_deref()
+            .filter(|prefix| !prefix.is_empty())
+    }
+
+    /// Get the output the launcher should open on, if configured.
+    pub fn target_output(&self) -> Option<TargetOutput> {
+        self.target_output
+            .as_deref()
+            .map(str::trim)
+            .filter(|output| !output.is_empty())
+            .map(TargetOutput::parse)
+    }
+
+    /// Get the strftime pattern for absolute clipboard timestamps.
+    pub fn clipboard_timestamp_pattern(&self) -> &str {
+        self.clipboard_timestamp_pattern
+            .as_deref()
+            .unwrap_or(DEFAULT_TIMESTAMP_PATTERN)
+    }
+
+    /// Get the configured icon override for an item id, if any.
+    pub fn icon_override(&self, id: &str) -> Option<&str> {
+        self.icon_overrides
+            .as_ref()
+            .and_then(|overrides| overrides.get(id))
+            .map(String::as_str)
+    }
+
+    /// Find the mode prefix `query` starts with, returning the prefix and
+    /// its mode. The longest prefix wins when several match.
+    pub fn mode_prefix_match(&self, query: &str) -> Option<(&str, LauncherMode)> {
+        self.mode_prefixes
+            .iter()
+            .flatten()
+            .filter(|(prefix, _)| !prefix.is_empty() && query.starts_with(prefix.as_str()))
+            .filter_map(|(prefix, mode)| Some((prefix.as_str(), LauncherMode::parse_str(mode)?)))
+            .max_by_key(|(prefix, _)| prefix.len())
+    }
 }
 
 impl Default for AppConfig {
@@ -188,7 +887,12 @@ impl Default for AppConfig {
             launcher_size: None,
             window_size: None,
             enable_backdrop: true,
+            backdrop_color: DEFAULT_BACKDROP_COLOR,
+            backdrop_opacity: 0.0,
+            max_screen_fraction: 0.9,
+            close_on_backdrop_click: true,
             hyprland_auto_blur: true,
+            hyprland_special_workspaces: true,
             disabled_modules: None,
             enable_transparency: true,
             search_providers: Some(vec![
@@ -220,29 +924,101 @@ impl Default for AppConfig {
             default_modes: None,
             combined_modules: None,
             fuzzy_match: FuzzyMatchConfig::default(),
+            badges: BadgeConfig::default(),
             layer_shell_layer: LayerShellLayer::default(),
+            target_output: None,
             font: FontConfig::default(),
+            empty_confirm_action: EmptyConfirmAction::default(),
+            icon_overrides: None,
+            mode_prefixes: Some(HashMap::from([
+                (">e ".to_string(), "emojis".to_string()),
+                (">c ".to_string(), "clipboard".to_string()),
+                (">t ".to_string(), "themes".to_string()),
+            ])),
+            show_section_headers: SectionHeaderVisibility::default(),
+            search_min_query_len: 0,
+            max_fallback_search_providers: None,
+            ai_min_query_len: 0,
+            ai_trigger: Some(DEFAULT_AI_TRIGGER.to_string()),
+            terminal_confirm_key: Some(DEFAULT_TERMINAL_CONFIRM_KEY.to_string()),
+            emoji_copy_format: EmojiCopyFormat::default(),
+            link_copy_format: LinkCopyFormat::default(),
+            window_title_truncation: TitleTruncation::default(),
+            clipboard_timestamp_format: TimestampFormat::default(),
+            clipboard_timestamp_pattern: None,
+            clipboard_sort: ClipboardSortOrder::default(),
+            clipboard_max_entries: 500,
+            clipboard_max_age_days: None,
+            clipboard_max_image_mib: 256,
+            clipboard_sensitive_expiry_secs: 30,
+            app_only_prefix: Some("@".to_string()),
+            category_prefix: Some("cat:".to_string()),
+            exact_match_prefix: Some("'".to_string()),
+            path_base_dir: Some("~".to_string()),
+            file_search_root: Some("~".to_string()),
+            file_search_max_depth: 4,
+            file_search_prefix: Some("f:".to_string()),
+            scroll_strategy: ScrollAlignment::default(),
+            dedup_desktop_entries: true,
+            desktop_source_order: None,
+            ai_response_max_height: None,
+            ai_answer_inline: false,
+            ai_api_key_env: None,
+            ai_history_max_turns: 10,
+            raise_or_launch: false,
+            urgent_windows_first: false,
+            max_results: None,
+            reselect_on_type: true,
+            recent_apps_count: 5,
+            pinned_applications: Vec::new(),
+            learn_selections: true,
+            empty_state: EmptyStateMessages::default(),
+            calculator_close_on_copy: false,
+            scripts: Vec::new(),
+            custom_actions: Vec::new(),
         }
     }
 }
 
 /// Modules enum - configurable components of the launcher.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, clap::ValueEnum)]
 #[serde(rename_all = "lowercase")]
 pub enum ConfigModule {
+    #[value(alias = "apps", alias = "app")]
     Applications,
     Ai,
+    #[value(alias = "emoji")]
     Emojis,
+    #[value(alias = "calc")]
     Calculator,
     Clipboard,
+    #[value(alias = "action")]
     Actions,
     Search,
+    #[value(alias = "theme")]
     Themes,
+    #[value(alias = "window")]
     Windows,
+    #[value(alias = "script")]
+    Scripts,
+    /// Nerd Font glyph picker. Opt-in: only shown when listed in
+    /// `combined_modules` or `default_modes`.
+    #[value(alias = "glyph", alias = "nerdfont")]
+    Glyphs,
+    /// Workspace switcher. Opt-in: only shown when listed in
+    /// `combined_modules` or `default_modes`.
+    #[value(alias = "workspace")]
+    Workspaces,
+    /// File search below `file_search_root`. Opt-in: only shown when
+    /// listed in `combined_modules` or `default_modes`.
+    #[value(alias = "file")]
+    Files,
 }
 
 impl ConfigModule {
-    /// Returns all module variants in default order.
+    /// Returns the default modules in default order.
+    ///
+    /// Opt-in modules (`Glyphs`, `Workspaces`, `Files`) are not included.
     pub fn all() -> Vec<ConfigModule> {
         vec![
             ConfigModule::Calculator,
@@ -252,6 +1028,7 @@ impl ConfigModule {
             ConfigModule::Actions,
             ConfigModule::Themes,
             ConfigModule::Applications,
+            ConfigModule::Scripts,
             ConfigModule::Ai,
             ConfigModule::Search,
         ]
@@ -279,6 +1056,14 @@ pub enum LauncherMode {
     Themes,
     #[value(alias = "window")]
     Windows,
+    #[value(alias = "script")]
+    Scripts,
+    #[value(alias = "glyph", alias = "nerdfont")]
+    Glyphs,
+    #[value(alias = "workspace")]
+    Workspaces,
+    #[value(alias = "file")]
+    Files,
 }
 
 impl LauncherMode {
@@ -295,6 +1080,10 @@ impl LauncherMode {
             "search" => Some(Self::Search),
             "themes" | "theme" => Some(Self::Themes),
             "windows" | "window" => Some(Self::Windows),
+            "scripts" | "script" => Some(Self::Scripts),
+            "glyphs" | "glyph" | "nerdfont" => Some(Self::Glyphs),
+            "workspaces" | "workspace" => Some(Self::Workspaces),
+            "files" | "file" => Some(Self::Files),
             _ => None,
         }
     }
@@ -312,6 +1101,10 @@ impl LauncherMode {
             Self::Search => "Search",
             Self::Themes => "Themes",
             Self::Windows => "Windows",
+            Self::Scripts => "Scripts",
+            Self::Glyphs => "Glyphs",
+            Self::Workspaces => "Workspaces",
+            Self::Files => "Files",
         }
     }
 
@@ -327,6 +1120,10 @@ impl LauncherMode {
             ConfigModule::Search => Self::Search,
             ConfigModule::Themes => Self::Themes,
             ConfigModule::Windows => Self::Windows,
+            ConfigModule::Scripts => Self::Scripts,
+            ConfigModule::Glyphs => Self::Glyphs,
+            ConfigModule::Workspaces => Self::Workspaces,
+            ConfigModule::Files => Self::Files,
         }
     }
 
@@ -343,6 +1140,10 @@ impl LauncherMode {
             Self::Search => Some(ConfigModule::Search),
             Self::Themes => Some(ConfigModule::Themes),
             Self::Windows => Some(ConfigModule::Windows),
+            Self::Scripts => Some(ConfigModule::Scripts),
+            Self::Glyphs => Some(ConfigModule::Glyphs),
+            Self::Workspaces => Some(ConfigModule::Workspaces),
+            Self::Files => Some(ConfigModule::Files),
         }
     }
 }
@@ -361,6 +1162,58 @@ pub struct ConfigSearchProvider {
     pub icon: String,
 }
 
+/// Time limit for one run of a script source, unless configured.
+pub const DEFAULT_SCRIPT_TIMEOUT_MS: u64 = 1000;
+
+/// External command listing launcher items, see [`crate::script`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigScriptSource {
+    /// Source name, shown as the description of items without one.
+    pub name: String,
+    /// Shell command printing one item per line. Gets the query as `$1`
+    /// and on stdin.
+    pub command: String,
+    /// Time limit for one run in milliseconds.
+    /// Default: 1000
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+}
+
+impl ConfigScriptSource {
+    /// Get the time limit for one run.
+    pub fn timeout(&self) -> Duration {
+        Duration::from_millis(self.timeout_ms.unwrap_or(DEFAULT_SCRIPT_TIMEOUT_MS))
+    }
+}
+
+/// User-defined action running a shell command.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigCustomAction {
+    /// Action name (e.g. "Lock Screen").
+    pub name: String,
+    /// Optional description shown below the name.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Optional icon name (defaults to terminal).
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// Shell command, run detached from the daemon.
+    pub command: String,
+}
+
+impl ConfigCustomAction {
+    /// Get the lowercase, dash-separated words of the name the action's id
+    /// is derived from. Empty if the name has no alphanumerics.
+    pub fn slug(&self) -> String {
+        self.name
+            .to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -368,7 +1221,7 @@ mod tests {
     #[test]
     fn test_config_module_all() {
         let all = ConfigModule::all();
-        assert_eq!(all.len(), 9);
+        assert_eq!(all.len(), 10);
         assert!(all.contains(&ConfigModule::Applications));
         assert!(all.contains(&ConfigModule::Calculator));
         assert!(all.contains(&ConfigModule::Ai));
@@ -722,4 +1575,636 @@ mod tests {
         let layer: Layer = LayerShellLayer::Top.into();
         assert!(matches!(layer, Layer::Top));
     }
+
+    #[test]
+    fn test_empty_confirm_action_default_is_none() {
+        assert_eq!(
+            AppConfig::default().empty_confirm_action,
+            EmptyConfirmAction::None
+        );
+        assert_eq!(
+            AppConfig::default_const().empty_confirm_action,
+            EmptyConfirmAction::None
+        );
+    }
+
+    #[test]
+    fn test_empty_confirm_action_deserialization() {
+        for (value, expected) in [
+            ("none", EmptyConfirmAction::None),
+            ("search", EmptyConfirmAction::Search),
+            ("ai", EmptyConfirmAction::Ai),
+            ("command", EmptyConfirmAction::Command),
+        ] {
+            let toml_str = format!("empty_confirm_action = \"{}\"", value);
+            let config: AppConfig = toml::from_str(&toml_str).expect("Failed to deserialize");
+            assert_eq!(config.empty_confirm_action, expected);
+        }
+    }
+
+    #[test]
+    fn test_icon_overrides_deserialization() {
+        let toml_str = r#"
+            [icon_overrides]
+            firefox = "globe"
+            "action-shutdown" = "/home/user/icons/power.svg"
+        "#;
+        let config: AppConfig = toml::from_str(toml_str).expect("Failed to deserialize");
+        assert_eq!(config.icon_override("firefox"), Some("globe"));
+        assert_eq!(
+            config.icon_override("action-shutdown"),
+            Some("/home/user/icons/power.svg")
+        );
+        assert_eq!(config.icon_override("kitty"), None);
+    }
+
+    #[test]
+    fn test_icon_overrides_missing_is_none() {
+        let config: AppConfig = toml::from_str("").expect("Failed to deserialize");
+        assert!(config.icon_overrides.is_none());
+        assert_eq!(config.icon_override("firefox"), None);
+    }
+
+    #[test]
+    fn test_show_section_headers_deserialization() {
+        assert_eq!(
+            AppConfig::default().show_section_headers,
+            SectionHeaderVisibility::Auto
+        );
+        for (value, expected) in [
+            ("always", SectionHeaderVisibility::Always),
+            ("auto", SectionHeaderVisibility::Auto),
+            ("never", SectionHeaderVisibility::Never),
+        ] {
+            let toml_str = format!("show_section_headers = \"{}\"", value);
+            let config: AppConfig = toml::from_str(&toml_str).expect("Failed to deserialize");
+            assert_eq!(config.show_section_headers, expected);
+        }
+    }
+
+    #[test]
+    fn test_min_query_len_deserialization() {
+        let config = AppConfig::default();
+        assert_eq!(config.search_min_query_len, 0);
+        assert_eq!(config.ai_min_query_len, 0);
+
+        let toml_str = r#"
+            search_min_query_len = 3
+            ai_min_query_len = 5
+        "#;
+        let config: AppConfig = toml::from_str(toml_str).expect("Failed to deserialize");
+        assert_eq!(config.search_min_query_len, 3);
+        assert_eq!(config.ai_min_query_len, 5);
+    }
+
+    #[test]
+    fn test_emoji_copy_format_deserialization() {
+        assert_eq!(
+            AppConfig::default().emoji_copy_format,
+            EmojiCopyFormat::Unicode
+        );
+        for (value, expected) in [
+            ("unicode", EmojiCopyFormat::Unicode),
+            ("shortcode", EmojiCopyFormat::Shortcode),
+            ("html", EmojiCopyFormat::Html),
+            ("codepoint", EmojiCopyFormat::Codepoint),
+        ] {
+            let toml_str = format!("emoji_copy_format = \"{}\"", value);
+            let config: AppConfig = toml::from_str(&toml_str).expect("Failed to deserialize");
+            assert_eq!(config.emoji_copy_format, expected);
+        }
+    }
+
+    #[test]
+    fn test_emoji_copy_format_next_cycles() {
+        let start = EmojiCopyFormat::Unicode;
+        assert_eq!(start.next(), EmojiCopyFormat::Shortcode);
+        assert_eq!(start.next().next(), EmojiCopyFormat::Html);
+        assert_eq!(start.next().next().next(), EmojiCopyFormat::Codepoint);
+        assert_eq!(start.next().next().next().next(), start);
+    }
+
+    #[test]
+    fn test_window_title_truncation_deserialization() {
+        assert_eq!(
+            AppConfig::default().window_title_truncation,
+            TitleTruncation::End
+        );
+        for (value, expected) in [
+            ("end", TitleTruncation::End),
+            ("middle", TitleTruncation::Middle),
+            ("none", TitleTruncation::None),
+        ] {
+            let toml_str = format!("window_title_truncation = \"{}\"", value);
+            let config: AppConfig = toml::from_str(&toml_str).expect("Failed to deserialize");
+            assert_eq!(config.window_title_truncation, expected);
+        }
+    }
+
+    #[test]
+    fn test_backdrop_defaults_to_transparent() {
+        let config = AppConfig::default();
+        assert_eq!(config.backdrop_background().a, 0.0);
+    }
+
+    #[test]
+    fn test_backdrop_deserialization() {
+        let config: AppConfig =
+            toml::from_str("backdrop_color = \"#000000\"\nbackdrop_opacity = 0.4").unwrap();
+        let background = config.backdrop_background();
+        assert_eq!(background.l, 0.0);
+        assert!((background.a - 0.4).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_backdrop_opacity_clamped() {
+        let config = AppConfig {
+            backdrop_opacity: 3.0,
+            ..AppConfig::default()
+        };
+        assert_eq!(config.backdrop_background().a, 1.0);
+    }
+
+    #[test]
+    fn test_clipboard_timestamp_deserialization() {
+        let config = AppConfig::default();
+        assert_eq!(config.clipboard_timestamp_format, TimestampFormat::Relative);
+        assert_eq!(
+            config.clipboard_timestamp_pattern(),
+            DEFAULT_TIMESTAMP_PATTERN
+        );
+
+        let config: AppConfig = toml::from_str(
+            "clipboard_timestamp_format = \"both\"\nclipboard_timestamp_pattern = \"%d.%m. %H:%M\"",
+        )
+        .unwrap();
+        assert_eq!(config.clipboard_timestamp_format, TimestampFormat::Both);
+        assert_eq!(config.clipboard_timestamp_pattern(), "%d.%m. %H:%M");
+    }
+
+    #[test]
+    fn test_app_only_prefix() {
+        assert_eq!(AppConfig::default().app_only_prefix(), Some("@"));
+
+        let config: AppConfig = toml::from_str("app_only_prefix = \"'\"").unwrap();
+        assert_eq!(config.app_only_prefix(), Some("'"));
+
+        let config: AppConfig = toml::from_str("app_only_prefix = \"\"").unwrap();
+        assert_eq!(config.app_only_prefix(), None);
+    }
+
+    #[test]
+    fn test_scroll_strategy_deserialization() {
+        assert_eq!(AppConfig::default().scroll_strategy, ScrollAlignment::Top);
+        for (value, expected) in [
+            ("top", ScrollAlignment::Top),
+            ("center", ScrollAlignment::Center),
+            ("nearest", ScrollAlignment::Nearest),
+        ] {
+            let toml_str = format!("scroll_strategy = \"{}\"", value);
+            let config: AppConfig = toml::from_str(&toml_str).unwrap();
+            assert_eq!(config.scroll_strategy, expected);
+        }
+    }
+
+    #[test]
+    fn test_desktop_source_order_deserialization() {
+        let config = AppConfig::default();
+        assert!(config.dedup_desktop_entries);
+        assert_eq!(config.desktop_source_order()[0], EntrySource::Native);
+
+        let config: AppConfig = toml::from_str(
+            "dedup_desktop_entries = false\ndesktop_source_order = [\"flatpak\", \"native\"]",
+        )
+        .unwrap();
+        assert!(!config.dedup_desktop_entries);
+        assert_eq!(
+            config.desktop_source_order(),
+            [EntrySource::Flatpak, EntrySource::Native]
+        );
+    }
+
+    #[test]
+    fn test_category_prefix() {
+        assert_eq!(AppConfig::default().category_prefix(), Some("cat:"));
+
+        let config: AppConfig = toml::from_str("category_prefix = \"in:\"").unwrap();
+        assert_eq!(config.category_prefix(), Some("in:"));
+
+        let config: AppConfig = toml::from_str("category_prefix = \"\"").unwrap();
+        assert_eq!(config.category_prefix(), None);
+    }
+
+    #[test]
+    fn test_close_on_backdrop_click() {
+        assert!(AppConfig::default().close_on_backdrop_click);
+
+        let config: AppConfig = toml::from_str("close_on_backdrop_click = false").unwrap();
+        assert!(!config.close_on_backdrop_click);
+    }
+
+    #[test]
+    fn test_hyprland_special_workspaces() {
+        assert!(AppConfig::default().hyprland_special_workspaces);
+        assert!(AppConfig::default_const().hyprland_special_workspaces);
+
+        let config: AppConfig = toml::from_str("hyprland_special_workspaces = false").unwrap();
+        assert!(!config.hyprland_special_workspaces);
+    }
+
+    #[test]
+    fn test_raise_or_launch() {
+        assert!(!AppConfig::default().raise_or_launch);
+
+        let config: AppConfig = toml::from_str("raise_or_launch = true").unwrap();
+        assert!(config.raise_or_launch);
+    }
+
+    #[test]
+    fn test_window_match_weights() {
+        let config = FuzzyMatchConfig::default();
+        assert!((config.window_title_weight - 1.0).abs() < f64::EPSILON);
+        assert!((config.window_class_weight - 0.8).abs() < f64::EPSILON);
+
+        let config: AppConfig = toml::from_str("[fuzzy_match]\nwindow_class_weight = 0.0").unwrap();
+        assert_eq!(config.fuzzy_match.window_class_weight, 0.0);
+        assert!((config.fuzzy_match.window_title_weight - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_calculator_close_on_copy() {
+        assert!(!AppConfig::default().calculator_close_on_copy);
+        assert!(!AppConfig::default_const().calculator_close_on_copy);
+
+        let config: AppConfig = toml::from_str("calculator_close_on_copy = true").unwrap();
+        assert!(config.calculator_close_on_copy);
+    }
+
+    #[test]
+    fn test_max_results() {
+        assert_eq!(AppConfig::default().max_results, None);
+        assert_eq!(AppConfig::default_const().max_results, None);
+
+        let config: AppConfig = toml::from_str("max_results = 50").unwrap();
+        assert_eq!(config.max_results, Some(50));
+    }
+
+    #[test]
+    fn test_recent_apps_count() {
+        assert_eq!(AppConfig::default().recent_apps_count, 5);
+        assert_eq!(AppConfig::default_const().recent_apps_count, 5);
+
+        let config: AppConfig = toml::from_str("recent_apps_count = 0").unwrap();
+        assert_eq!(config.recent_apps_count, 0);
+    }
+
+    #[test]
+    fn test_exact_match_prefix() {
+        assert_eq!(AppConfig::default().exact_match_prefix(), Some("'"));
+
+        let config: AppConfig = toml::from_str("exact_match_prefix = \"=\"").unwrap();
+        assert_eq!(config.exact_match_prefix(), Some("="));
+
+        let config: AppConfig = toml::from_str("exact_match_prefix = \"\"").unwrap();
+        assert_eq!(config.exact_match_prefix(), None);
+    }
+
+    #[test]
+    fn test_empty_state_messages() {
+        let config = AppConfig::default();
+        assert!(
+            config
+                .empty_state
+                .clipboard()
+                .starts_with("No clipboard history yet")
+        );
+        assert_eq!(config.empty_state.no_matches(), "No matches found");
+
+        let config: AppConfig = toml::from_str(
+            r#"
+            [empty_state]
+            clipboard = "Noch nichts kopiert"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.empty_state.clipboard(), "Noch nichts kopiert");
+        // Unset messages keep their defaults
+        assert_eq!(config.empty_state.no_matches(), "No matches found");
+        assert!(config.empty_state.themes().starts_with("No themes found"));
+    }
+
+    #[test]
+    fn test_clipboard_sort_deserialization() {
+        assert_eq!(
+            AppConfig::default().clipboard_sort,
+            ClipboardSortOrder::Newest
+        );
+        let config: AppConfig = toml::from_str("clipboard_sort = \"type\"").unwrap();
+        assert_eq!(config.clipboard_sort, ClipboardSortOrder::Type);
+        let start = ClipboardSortOrder::Newest;
+        assert_eq!(start.next().next().next(), start);
+    }
+
+    #[test]
+    fn test_path_base_dir() {
+        assert_eq!(AppConfig::default().path_base_dir(), Some("~"));
+
+        let config: AppConfig = toml::from_str("path_base_dir = \"\"").unwrap();
+        assert_eq!(config.path_base_dir(), None);
+    }
+
+    #[test]
+    fn test_ai_answer_inline_is_opt_in() {
+        assert!(!AppConfig::default().ai_answer_inline);
+        let config: AppConfig = toml::from_str("ai_answer_inline = true").unwrap();
+        assert!(config.ai_answer_inline);
+    }
+
+    #[test]
+    fn test_ai_trigger() {
+        assert_eq!(AppConfig::default().ai_trigger(), Some(DEFAULT_AI_TRIGGER));
+
+        let config: AppConfig = toml::from_str("ai_trigger = \"?\"").unwrap();
+        assert_eq!(config.ai_trigger(), Some("?"));
+
+        let config: AppConfig = toml::from_str("ai_trigger = \"\"").unwrap();
+        assert_eq!(config.ai_trigger(), None);
+    }
+
+    #[test]
+    fn test_max_screen_fraction() {
+        assert_eq!(AppConfig::default().max_screen_fraction, 0.9);
+        let config: AppConfig = toml::from_str("max_screen_fraction = 0.75").unwrap();
+        assert_eq!(config.max_screen_fraction, 0.75);
+    }
+
+    #[test]
+    fn test_scripts_deserialization() {
+        let config: AppConfig = toml::from_str(
+            r#"
+            [[scripts]]
+            name = "Notes"
+            command = "~/bin/notes-search"
+
+            [[scripts]]
+            name = "Slow"
+            command = "slow-search"
+            timeout_ms = 3000
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.scripts.len(), 2);
+        assert_eq!(config.scripts[0].command, "~/bin/notes-search");
+        assert_eq!(config.scripts[0].timeout(), Duration::from_millis(1000));
+        assert_eq!(config.scripts[1].timeout(), Duration::from_millis(3000));
+        assert!(AppConfig::default().scripts.is_empty());
+    }
+
+    #[test]
+    fn test_launcher_mode_parse_scripts() {
+        assert_eq!(
+            LauncherMode::parse_str("script"),
+            Some(LauncherMode::Scripts)
+        );
+        assert_eq!(
+            LauncherMode::from_module(&ConfigModule::Scripts),
+            LauncherMode::Scripts
+        );
+    }
+
+    #[test]
+    fn test_terminal_confirm_key() {
+        assert_eq!(
+            AppConfig::default().terminal_confirm_key(),
+            Some(DEFAULT_TERMINAL_CONFIRM_KEY)
+        );
+
+        let config: AppConfig = toml::from_str("terminal_confirm_key = \"alt-enter\"").unwrap();
+        assert_eq!(config.terminal_confirm_key(), Some("alt-enter"));
+
+        let config: AppConfig = toml::from_str("terminal_confirm_key = \"\"").unwrap();
+        assert_eq!(config.terminal_confirm_key(), None);
+    }
+
+    #[test]
+    fn test_glyphs_module_is_opt_in() {
+        assert!(!ConfigModule::all().contains(&ConfigModule::Glyphs));
+        assert_eq!(
+            LauncherMode::parse_str("nerdfont"),
+            Some(LauncherMode::Glyphs)
+        );
+        assert_eq!(
+            LauncherMode::from_module(&ConfigModule::Glyphs).to_module(),
+            Some(ConfigModule::Glyphs)
+        );
+
+        let config: AppConfig =
+            toml::from_str("combined_modules = [\"applications\", \"glyphs\"]").unwrap();
+        assert_eq!(
+            config.combined_modules,
+            Some(vec![ConfigModule::Applications, ConfigModule::Glyphs])
+        );
+    }
+
+    #[test]
+    fn test_link_copy_format_markdown() {
+        assert_eq!(
+            AppConfig::default().link_copy_format,
+            LinkCopyFormat::Markdown
+        );
+        let url = "https://www.google.com/search?q=rust%20lang";
+        assert_eq!(
+            LinkCopyFormat::Markdown.format("rust lang", url),
+            "[rust lang](https://www.google.com/search?q=rust%20lang)"
+        );
+        assert_eq!(
+            LinkCopyFormat::Markdown.format("a [b] c", "https://x.org/(wiki) page"),
+            "[a \\[b\\] c](https://x.org/%28wiki%29%20page)"
+        );
+    }
+
+    #[test]
+    fn test_link_copy_format_url_and_org() {
+        let url = "https://en.wikipedia.org/wiki/Special:Search?search=rust";
+        assert_eq!(LinkCopyFormat::Url.format("rust", url), url);
+        assert_eq!(
+            LinkCopyFormat::Org.format("rust", url),
+            format!("[[{}][rust]]", url)
+        );
+
+        let config: AppConfig = toml::from_str("link_copy_format = \"org\"").unwrap();
+        assert_eq!(config.link_copy_format, LinkCopyFormat::Org);
+    }
+
+    #[test]
+    fn test_max_fallback_search_providers() {
+        assert_eq!(AppConfig::default().max_fallback_search_providers, None);
+        let config: AppConfig = toml::from_str("max_fallback_search_providers = 2").unwrap();
+        assert_eq!(config.max_fallback_search_providers, Some(2));
+    }
+
+    #[test]
+    fn test_learn_selections() {
+        let config = AppConfig::default();
+        assert!(config.learn_selections);
+        assert_eq!(config.fuzzy_match.learned_selection_bonus, 150_000);
+
+        let config: AppConfig = toml::from_str("learn_selections = false").unwrap();
+        assert!(!config.learn_selections);
+    }
+
+    #[test]
+    fn test_badges_toggle_individually() {
+        assert_eq!(AppConfig::default().badges, BadgeConfig::default());
+
+        let config: AppConfig = toml::from_str("[badges]\nrunning = false").unwrap();
+        assert!(!config.badges.running);
+        assert!(config.badges.source);
+        assert!(config.badges.window_count);
+    }
+
+    #[test]
+    fn test_clipboard_limits() {
+        let config = AppConfig::default();
+        assert_eq!(config.clipboard_max_entries, 500);
+        assert_eq!(config.clipboard_max_age_days, None);
+        assert_eq!(config.clipboard_max_image_mib, 256);
+        assert_eq!(AppConfig::default_const().clipboard_max_entries, 500);
+
+        let config: AppConfig = toml::from_str(
+            "clipboard_max_entries = 0\nclipboard_max_age_days = 7\nclipboard_max_image_mib = 64",
+        )
+        .unwrap();
+        assert_eq!(config.clipboard_max_entries, 0);
+        assert_eq!(config.clipboard_max_age_days, Some(7));
+        assert_eq!(config.clipboard_max_image_mib, 64);
+    }
+
+    #[test]
+    fn test_pinned_applications() {
+        assert!(AppConfig::default().pinned_applications.is_empty());
+        assert!(AppConfig::default_const().pinned_applications.is_empty());
+
+        let config: AppConfig =
+            toml::from_str(r#"pinned_applications = ["firefox", "org.gnome.Nautilus"]"#).unwrap();
+        assert_eq!(
+            config.pinned_applications,
+            ["firefox", "org.gnome.Nautilus"]
+        );
+    }
+
+    #[test]
+    fn test_file_search_config() {
+        let config = AppConfig::default();
+        assert_eq!(config.file_search_root(), Some("~"));
+        assert_eq!(config.file_search_prefix(), Some("f:"));
+        assert_eq!(config.file_search_max_depth, 4);
+        assert!(!ConfigModule::all().contains(&ConfigModule::Files));
+
+        let config: AppConfig = toml::from_str(
+            r#"
+            file_search_root = "~/Documents"
+            file_search_max_depth = 2
+            file_search_prefix = ""
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.file_search_root(), Some("~/Documents"));
+        assert_eq!(config.file_search_max_depth, 2);
+        assert_eq!(config.file_search_prefix(), None);
+        assert_eq!(LauncherMode::parse_str("file"), Some(LauncherMode::Files));
+    }
+
+    #[test]
+    fn test_target_output() {
+        assert_eq!(AppConfig::default().target_output(), None);
+
+        for (value, expected) in [
+            ("focused", Some(TargetOutput::Focused)),
+            ("primary", Some(TargetOutput::Primary)),
+            ("DP-1", Some(TargetOutput::Named("DP-1".to_string()))),
+            ("", None),
+        ] {
+            let config: AppConfig =
+                toml::from_str(&format!("target_output = \"{}\"", value)).unwrap();
+            assert_eq!(config.target_output(), expected, "{}", value);
+        }
+    }
+
+    #[test]
+    fn test_custom_actions() {
+        assert!(AppConfig::default().custom_actions.is_empty());
+
+        let config: AppConfig = toml::from_str(
+            r#"
+            [[custom_actions]]
+            name = "Lock Screen"
+            icon = "lock"
+            command = "swaylock -f"
+
+            [[custom_actions]]
+            name = "Toggle VPN"
+            description = "Connect or disconnect wg0"
+            command = "wg-toggle wg0"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.custom_actions.len(), 2);
+        assert_eq!(config.custom_actions[0].icon.as_deref(), Some("lock"));
+        assert_eq!(config.custom_actions[0].description, None);
+        assert_eq!(config.custom_actions[1].command, "wg-toggle wg0");
+    }
+
+    #[test]
+    fn test_mode_prefix_match() {
+        let config = AppConfig::default();
+        assert_eq!(
+            config.mode_prefix_match(">e smile"),
+            Some((">e ", LauncherMode::Emojis))
+        );
+        assert_eq!(config.mode_prefix_match(">e"), None);
+        assert_eq!(config.mode_prefix_match("fire>c "), None);
+        assert_eq!(AppConfig::default_const().mode_prefix_match(">t "), None);
+
+        let config: AppConfig = toml::from_str(
+            r#"
+            [mode_prefixes]
+            ":" = "emoji"
+            ":g" = "glyphs"
+            "" = "themes"
+            "?" = "unknown"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.mode_prefix_match(":gear"),
+            Some((":g", LauncherMode::Glyphs))
+        );
+        assert_eq!(
+            config.mode_prefix_match(":smile"),
+            Some((":", LauncherMode::Emojis))
+        );
+        assert_eq!(config.mode_prefix_match("?x"), None);
+        assert_eq!(config.mode_prefix_match(">c "), None);
+    }
+
+    #[test]
+    fn test_ai_history_max_turns() {
+        assert_eq!(AppConfig::default().ai_history_max_turns, 10);
+        assert_eq!(AppConfig::default_const().ai_history_max_turns, 10);
+        let config: AppConfig = toml::from_str("ai_history_max_turns = 0").unwrap();
+        assert_eq!(config.ai_history_max_turns, 0);
+    }
+
+    #[test]
+    fn test_clipboard_sensitive_expiry() {
+        assert_eq!(AppConfig::default().clipboard_sensitive_expiry_secs, 30);
+        assert_eq!(
+            AppConfig::default_const().clipboard_sensitive_expiry_secs,
+            30
+        );
+
+        let config: AppConfig = toml::from_str("clipboard_sensitive_expiry_secs = 0").unwrap();
+        assert_eq!(config.clipboard_sensitive_expiry_secs, 0);
+    }
 }
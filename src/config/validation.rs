@@ -4,7 +4,7 @@
 //! non-fatal issues that should be logged but don't prevent startup.
 
 use super::theme_loader::list_themes;
-use super::types::{AppConfig, ConfigSearchProvider};
+use super::types::{AppConfig, ConfigModule, ConfigSearchProvider, LauncherMode};
 
 /// Non-fatal validation warning.
 #[derive(Debug)]
@@ -21,6 +21,8 @@ pub struct ValidationWarning {
 /// - Launcher dimensions outside recommended ranges
 /// - Search provider URLs missing the `{query}` placeholder
 /// - Invalid trigger formats for search providers
+/// - Search provider triggers shadowed by the AI trigger
+/// - Custom action names without letters or digits to derive an id from
 pub fn validate_config(config: &AppConfig) -> Vec<ValidationWarning> {
     let mut warnings = vec![];
 
@@ -70,6 +72,36 @@ pub fn validate_config(config: &AppConfig) -> Vec<ValidationWarning> {
         }
     }
 
+    // The AI trigger is checked first, so it must not swallow search triggers
+    if let Some(ai_trigger) = config.ai_trigger()
+        && let Some(providers) = &config.search_providers
+    {
+        for provider in providers {
+            if !provider.trigger.is_empty() && provider.trigger.starts_with(ai_trigger) {
+                warnings.push(ValidationWarning {
+                    field: "ai_trigger".to_string(),
+                    message: format!(
+                        "AI trigger '{}' conflicts with trigger '{}' of search provider '{}', which will never be used.",
+                        ai_trigger, provider.trigger, provider.name
+                    ),
+                });
+            }
+        }
+    }
+
+    // Custom actions are remembered by an id derived from their name
+    for action in &config.custom_actions {
+        if action.slug().is_empty() {
+            warnings.push(ValidationWarning {
+                field: "custom_actions".to_string(),
+                message: format!(
+                    "Custom action name '{}' has no letters or digits. Its pins and launch history may be mixed up with other actions.",
+                    action.name
+                ),
+            });
+        }
+    }
+
     // Validate theme exists (only if non-default)
     if !config.theme.is_empty() && config.theme != "default" && !validate_theme_name(&config.theme)
     {
@@ -82,6 +114,16 @@ pub fn validate_config(config: &AppConfig) -> Vec<ValidationWarning> {
         });
     }
 
+    if !(0.0..=1.0).contains(&config.backdrop_opacity) {
+        warnings.push(ValidationWarning {
+            field: "backdrop_opacity".to_string(),
+            message: format!(
+                "backdrop_opacity ({}) must be between 0.0 and 1.0. Will be clamped.",
+                config.backdrop_opacity
+            ),
+        });
+    }
+
     // Validate window_size if set (only relevant when enable_backdrop is true)
     if config.enable_backdrop {
         if let Some((w, h)) = config.window_size {
@@ -163,6 +205,24 @@ fn validate_search_provider(provider: &ConfigSearchProvider) -> Vec<ValidationWa
     warnings
 }
 
+/// Validate a combined view module order.
+///
+/// Rejects an empty list and modules listed more than once.
+pub fn validate_combined_modules(modules: &[ConfigModule]) -> Result<(), String> {
+    if modules.is_empty() {
+        return Err("at least one module is required".to_string());
+    }
+
+    for (i, module) in modules.iter().enumerate() {
+        if modules[..i].contains(module) {
+            let name = LauncherMode::from_module(module).display_name();
+            return Err(format!("{} is listed more than once", name));
+        }
+    }
+
+    Ok(())
+}
+
 /// Check if a theme name exists.
 pub fn validate_theme_name(name: &str) -> bool {
     list_themes().contains(&name.to_string())
@@ -171,6 +231,7 @@ pub fn validate_theme_name(name: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::ConfigCustomAction;
 
     #[test]
     fn test_validate_valid_config() {
@@ -383,4 +444,94 @@ mod tests {
         // Should have no window_size warnings since backdrop is disabled
         assert!(!warnings.iter().any(|w| w.field == "window_size"));
     }
+
+    #[test]
+    fn test_validate_backdrop_opacity_out_of_range() {
+        let config = AppConfig {
+            backdrop_opacity: 1.5,
+            ..AppConfig::default()
+        };
+        let warnings = validate_config(&config);
+        assert!(warnings.iter().any(|w| w.field == "backdrop_opacity"));
+    }
+
+    #[test]
+    fn test_validate_combined_modules() {
+        assert!(
+            validate_combined_modules(&[
+                ConfigModule::Applications,
+                ConfigModule::Calculator,
+                ConfigModule::Ai,
+            ])
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_combined_modules_rejects_duplicates() {
+        let err = validate_combined_modules(&[
+            ConfigModule::Applications,
+            ConfigModule::Windows,
+            ConfigModule::Applications,
+        ])
+        .unwrap_err();
+        assert_eq!(err, "Applications is listed more than once");
+    }
+
+    #[test]
+    fn test_validate_combined_modules_rejects_empty() {
+        assert!(validate_combined_modules(&[]).is_err());
+    }
+
+    #[test]
+    fn test_validate_custom_action_without_slug() {
+        let action = |name: &str| ConfigCustomAction {
+            name: name.to_string(),
+            description: None,
+            icon: None,
+            command: "true".to_string(),
+        };
+        let config = AppConfig {
+            custom_actions: vec![action("Lock"), action("🔒 !")],
+            ..AppConfig::default()
+        };
+        let warnings: Vec<_> = validate_config(&config)
+            .into_iter()
+            .filter(|w| w.field == "custom_actions")
+            .collect();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("'🔒 !'"));
+    }
+
+    #[test]
+    fn test_validate_ai_trigger_conflicts_with_search_trigger() {
+        let provider = |trigger: &str| ConfigSearchProvider {
+            name: "Ask".to_string(),
+            trigger: trigger.to_string(),
+            url: "https://example.com/search?q={query}".to_string(),
+            icon: "magnifying-glass".to_string(),
+        };
+        let config = AppConfig {
+            ai_trigger: Some("?".to_string()),
+            search_providers: Some(vec![provider("?a")]),
+            ..AppConfig::default()
+        };
+        let warnings = validate_config(&config);
+        assert!(
+            warnings
+                .iter()
+                .any(|w| w.field == "ai_trigger" && w.message.contains("'?a'"))
+        );
+
+        let config = AppConfig {
+            ai_trigger: Some("?".to_string()),
+            search_providers: Some(vec![provider("!a")]),
+            ..AppConfig::default()
+        };
+        assert!(
+            !validate_config(&config)
+                .iter()
+                .any(|w| w.field == "ai_trigger")
+        );
+    }
 }
@@ -2,9 +2,11 @@
 //!
 //! Processes DaemonEvent messages from IPC and manages window state.
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use tokio::sync::oneshot;
 use tracing::debug;
 
+use crate::app::events::DmenuResponse;
 use crate::app::window::LauncherWindow;
 use crate::app::{DaemonEvent, WindowEvent, window};
 use crate::compositor::Compositor;
@@ -12,8 +14,10 @@ use crate::config::get_default_modes;
 use crate::error::IpcError;
 use crate::items::ApplicationItem;
 
+use super::modules::handle_set_combined_modules;
+use super::pinned::handle_pin_application;
 use super::reload::set_reload_requested;
-use super::theme::handle_set_theme;
+use super::theme::{configure_theme, handle_set_theme, reload_active_theme};
 
 /// Window state manager for the daemon.
 pub struct WindowState {
@@ -21,24 +25,44 @@ pub struct WindowState {
     pub launcher_window: Option<LauncherWindow>,
     /// Whether the window is visible.
     pub visible: bool,
+    /// Responder of the `zlaunch dmenu` request shown in the window, taken
+    /// once a line is chosen.
+    pub dmenu_response: Option<DmenuResponder>,
 }
 
+/// Shared slot for answering a `zlaunch dmenu` request.
+pub type DmenuResponder = Arc<Mutex<Option<oneshot::Sender<DmenuResponse>>>>;
+
 impl WindowState {
     /// Create a new window state.
     pub fn new() -> Self {
         Self {
             launcher_window: None,
             visible: false,
+            dmenu_response: None,
         }
     }
 
     /// Close the window if it exists.
+    ///
+    /// The surface is destroyed rather than hidden, so a hidden launcher holds
+    /// no GPU buffers. The next show opens a fresh window, which picks up the
+    /// current theme and panel size and takes focus again.
+    ///
+    /// A `zlaunch dmenu` request still waiting for a line is answered with
+    /// no selection.
     pub fn close(&mut self, cx: &mut gpui::App) {
         if let Some(ref lw) = self.launcher_window {
             window::close_window(&lw.handle, cx);
         }
         self.launcher_window = None;
         self.visible = false;
+        if let Some(responder) = self.dmenu_response.take()
+            && let Some(response_tx) = responder.lock().unwrap().take()
+            && response_tx.send(Ok(None)).is_err()
+        {
+            debug!("Client disconnected before receiving dmenu response");
+        }
     }
 }
 
@@ -83,6 +107,37 @@ pub async fn run_event_loop(
                 }
             }
 
+            DaemonEvent::OpenWithQuery { query, response_tx } => {
+                let result = handle_show(
+                    &mut window_state,
+                    None,
+                    &applications,
+                    &compositor,
+                    &event_tx,
+                    cx,
+                )
+                .and_then(|()| prefill_query(&window_state, &query, cx));
+                if response_tx.send(result).is_err() {
+                    debug!("Client disconnected before receiving response");
+                }
+            }
+
+            DaemonEvent::Dmenu {
+                lines,
+                prompt,
+                response_tx,
+            } => {
+                handle_dmenu(
+                    &mut window_state,
+                    lines,
+                    prompt,
+                    response_tx,
+                    &compositor,
+                    &event_tx,
+                    cx,
+                );
+            }
+
             DaemonEvent::Hide { response_tx } => {
                 if window_state.visible {
                     let _ = cx.update(|cx| {
@@ -144,6 +199,70 @@ pub async fn run_event_loop(
                 }
             }
 
+            DaemonEvent::ThemeFileChanged => {
+                if reload_active_theme() {
+                    let _ = cx.update(|cx| {
+                        // Fonts may have changed too
+                        configure_theme(cx);
+                        if window_state.visible
+                            && let Some(ref lw) = window_state.launcher_window
+                        {
+                            lw.launcher_view.update(cx, |launcher, cx| {
+                                launcher.refresh_theme(cx);
+                            });
+                        }
+                    });
+                }
+            }
+
+            DaemonEvent::SetCombinedModules {
+                modules,
+                response_tx,
+            } => {
+                let result = handle_set_combined_modules(modules);
+                // If window is open, rebuild the list with the new order
+                if result.is_ok()
+                    && window_state.visible
+                    && let Some(ref lw) = window_state.launcher_window
+                {
+                    let view = lw.launcher_view.clone();
+                    let _ = cx.update(|cx| {
+                        let _ = lw.handle.update(cx, |_, window, cx| {
+                            view.update(cx, |launcher, cx| {
+                                launcher.refresh_modules(window, cx);
+                            });
+                        });
+                    });
+                }
+                if response_tx.send(result).is_err() {
+                    debug!("Client disconnected before receiving modules response");
+                }
+            }
+
+            DaemonEvent::PinApplication {
+                app_id,
+                response_tx,
+            } => {
+                let result = handle_pin_application(&app_id);
+                // If window is open, rebuild the list with the pinned app
+                if result.is_ok()
+                    && window_state.visible
+                    && let Some(ref lw) = window_state.launcher_window
+                {
+                    let view = lw.launcher_view.clone();
+                    let _ = cx.update(|cx| {
+                        let _ = lw.handle.update(cx, |_, window, cx| {
+                            view.update(cx, |launcher, cx| {
+                                launcher.refresh_modules(window, cx);
+                            });
+                        });
+                    });
+                }
+                if response_tx.send(result).is_err() {
+                    debug!("Client disconnected before receiving pin response");
+                }
+            }
+
             DaemonEvent::Reload { response_tx } => {
                 // Send response FIRST so client sees success before we exit
                 if response_tx.send(Ok(())).is_err() {
@@ -231,3 +350,82 @@ fn handle_show(
         }
     })
 }
+
+/// Handle the Dmenu event - show `lines` in a fresh launcher window.
+///
+/// The response is sent once a line is chosen, or with no selection when the
+/// window closes (see [`WindowState::close`]). A launcher already open is
+/// closed first, which also cancels an earlier dmenu request.
+fn handle_dmenu(
+    window_state: &mut WindowState,
+    lines: Vec<String>,
+    prompt: Option<String>,
+    response_tx: oneshot::Sender<DmenuResponse>,
+    compositor: &Arc<dyn Compositor>,
+    event_tx: &flume::Sender<DaemonEvent>,
+    cx: &mut gpui::AsyncApp,
+) {
+    let responder: DmenuResponder = Arc::new(Mutex::new(Some(response_tx)));
+    let on_select = {
+        let responder = responder.clone();
+        Arc::new(move |line: String| {
+            if let Some(response_tx) = responder.lock().unwrap().take()
+                && response_tx.send(Ok(Some(line))).is_err()
+            {
+                debug!("Client disconnected before receiving dmenu response");
+            }
+        })
+    };
+
+    let _ = cx.update(|cx| {
+        if window_state.visible {
+            window_state.close(cx);
+        }
+
+        match window::create_and_show_dmenu_window(
+            lines,
+            prompt,
+            on_select,
+            compositor.clone(),
+            event_tx.clone(),
+            cx,
+        ) {
+            Ok(lw) => {
+                window_state.launcher_window = Some(lw);
+                window_state.visible = true;
+                window_state.dmenu_response = Some(responder);
+            }
+            Err(e) => {
+                tracing::error!(%e, "Failed to create dmenu window");
+                if let Some(response_tx) = responder.lock().unwrap().take() {
+                    let _ = response_tx.send(Err(IpcError::Internal(format!(
+                        "Failed to create window: {}",
+                        e
+                    ))));
+                }
+            }
+        }
+    });
+}
+
+/// Fill the search input of the open launcher window with `query`.
+fn prefill_query(
+    window_state: &WindowState,
+    query: &str,
+    cx: &mut gpui::AsyncApp,
+) -> Result<(), IpcError> {
+    let Some(lw) = window_state.launcher_window.as_ref() else {
+        return Err(IpcError::Internal(
+            "Launcher window is not open".to_string(),
+        ));
+    };
+
+    cx.update(|cx| {
+        lw.handle.update(cx, |_root, window, cx| {
+            lw.launcher_view.update(cx, |launcher, cx| {
+                launcher.prefill_query(query, window, cx);
+            });
+        })
+    })
+    .map_err(|e| IpcError::Internal(format!("Failed to set the query: {}", e)))
+}
@@ -12,8 +12,13 @@ use crate::desktop::cache::load_applications;
 use crate::ipc::{IpcServerHandle, client, prepare_socket, start_server};
 use crate::items::ApplicationItem;
 
+use super::options::LogFormat;
+
 /// Initialize the tracing subscriber for logging.
-pub fn init_logging() {
+///
+/// JSON logs put each event's fields at the top level, next to its
+/// timestamp, level and target, and list the spans it happened in.
+pub fn init_logging(format: LogFormat) {
     use tracing_subscriber::{EnvFilter, fmt, prelude::*};
 
     // By default, only log from zlaunch crate at info level
@@ -21,21 +26,36 @@ pub fn init_logging() {
     let filter =
         EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("zlaunch=info"));
 
-    tracing_subscriber::registry()
-        .with(fmt::layer().with_target(false).without_time())
-        .with(filter)
-        .init();
+    let registry = tracing_subscriber::registry().with(filter);
+    match format {
+        LogFormat::Text => registry
+            .with(fmt::layer().with_target(false).without_time())
+            .init(),
+        LogFormat::Json => registry
+            .with(
+                fmt::layer()
+                    .json()
+                    .flatten_event(true)
+                    .with_current_span(true)
+                    .with_span_list(true),
+            )
+            .init(),
+    }
 }
 
 /// Prepare the IPC socket, checking for existing daemon instances.
 ///
 /// This should be called early, before the GPUI application starts.
-/// Returns Ok if socket is ready, Err if another daemon is running.
-pub fn prepare_ipc_socket() -> Result<()> {
+/// If another daemon is running, this process exits, or with `foreground`
+/// returns an error so the failure shows up in the exit status.
+pub fn prepare_ipc_socket(foreground: bool) -> Result<()> {
     match prepare_socket() {
         Ok(_) => Ok(()),
         Err(e) => {
             if client::is_daemon_running() {
+                if foreground {
+                    anyhow::bail!("Daemon already running");
+                }
                 error!("Daemon already running, exiting");
                 std::process::exit(0);
             }
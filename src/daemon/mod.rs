@@ -5,6 +5,9 @@
 
 mod event_handler;
 mod init;
+mod modules;
+mod options;
+mod pinned;
 mod reload;
 mod theme;
 mod watcher;
@@ -19,13 +22,15 @@ use crate::assets::CombinedAssets;
 use crate::ui::init_launcher;
 
 pub use init::init_logging;
+pub use options::{DaemonOptions, LogFormat};
 
 /// Run the launcher daemon.
 ///
-/// This is the main entry point when no subcommand is provided.
+/// This is the main entry point when no subcommand (or `daemon`) is provided.
 /// It initializes services, starts the GPUI application, and runs the event loop.
-pub fn run() -> Result<()> {
-    init::init_logging();
+pub fn run(options: DaemonOptions) -> Result<()> {
+    options::set_options(options);
+    init::init_logging(options.log_format);
     info!(
         version = env!("CARGO_PKG_VERSION"),
         "Starting zlaunch daemon"
@@ -35,7 +40,7 @@ pub fn run() -> Result<()> {
     let (event_tx, event_rx) = create_daemon_channel();
 
     // Prepare IPC socket (check for existing instance)
-    init::prepare_ipc_socket()?;
+    init::prepare_ipc_socket(options.foreground)?;
 
     // Initialize config from file (single source of truth)
     crate::config::init_config();
@@ -88,6 +93,7 @@ pub fn run() -> Result<()> {
             // Spawn file watcher on shared tokio runtime
             let event_tx_for_watcher = event_tx.clone();
             crate::tokio_runtime::spawn(cx, watcher::run_watcher_loop(event_tx_for_watcher));
+            crate::tokio_runtime::spawn(cx, watcher::run_theme_watcher_loop(event_tx.clone()));
 
             // Main event loop (runs on GPUI executor)
             // Move ipc_handle into the async block to keep it alive for the daemon's lifetime
@@ -0,0 +1,21 @@
+//! Combined view module ordering for the daemon.
+
+use crate::config::ConfigModule;
+use crate::config::validation::validate_combined_modules;
+use crate::error::IpcError;
+
+/// Handle the SetCombinedModules IPC command.
+///
+/// Validates the module list and updates the config, replacing the
+/// deprecated `disabled_modules` which would otherwise take precedence.
+pub fn handle_set_combined_modules(modules: Vec<ConfigModule>) -> Result<(), IpcError> {
+    validate_combined_modules(&modules).map_err(IpcError::InvalidArgument)?;
+
+    // Update config (persists to disk if config file exists)
+    crate::config::update_config(|config| {
+        config.combined_modules = Some(modules);
+        config.disabled_modules = None;
+    });
+
+    Ok(())
+}
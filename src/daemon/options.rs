@@ -0,0 +1,115 @@
+//! Daemon startup options.
+//!
+//! Set by `zlaunch daemon` and kept across reloads.
+
+use std::sync::OnceLock;
+
+/// Options the daemon was started with, kept for reloads.
+static OPTIONS: OnceLock<DaemonOptions> = OnceLock::new();
+
+/// Format of the daemon's log output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum LogFormat {
+    /// Human-readable lines. Default.
+    #[default]
+    Text,
+    /// One JSON object per event, with its fields and spans, for bug reports.
+    Json,
+}
+
+/// Options for running the daemon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DaemonOptions {
+    /// Fail instead of exiting quietly when a daemon is already running, so
+    /// a captured log never comes from the wrong process.
+    pub foreground: bool,
+    /// Format of the log output.
+    pub log_format: LogFormat,
+}
+
+impl DaemonOptions {
+    /// Get the command line arguments that start a daemon with these options.
+    ///
+    /// The default options need none, so a plain `zlaunch` is reloaded as is.
+    pub fn args(&self) -> Vec<&'static str> {
+        if *self == Self::default() {
+            return Vec::new();
+        }
+
+        let mut args = vec!["daemon"];
+        if self.foreground {
+            args.push("--foreground");
+        }
+        if self.log_format == LogFormat::Json {
+            args.extend(["--log-format", "json"]);
+        }
+        args
+    }
+}
+
+/// Remember the options the daemon was started with.
+pub(super) fn set_options(options: DaemonOptions) {
+    let _ = OPTIONS.set(options);
+}
+
+/// Get the options the daemon was started with.
+pub(super) fn options() -> DaemonOptions {
+    OPTIONS.get().copied().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Cli, Commands};
+    use clap::Parser;
+
+    fn parse(args: &[&str]) -> DaemonOptions {
+        let cli = Cli::try_parse_from(std::iter::once("zlaunch").chain(args.iter().copied()))
+            .expect("valid arguments");
+        match cli.command {
+            None => DaemonOptions::default(),
+            Some(Commands::Daemon {
+                foreground,
+                log_format,
+            }) => DaemonOptions {
+                foreground,
+                log_format,
+            },
+            Some(_) => panic!("not a daemon command"),
+        }
+    }
+
+    #[test]
+    fn test_parse_foreground_json() {
+        assert_eq!(
+            parse(&["daemon", "--foreground", "--log-format", "json"]),
+            DaemonOptions {
+                foreground: true,
+                log_format: LogFormat::Json,
+            }
+        );
+        assert_eq!(parse(&["daemon"]), DaemonOptions::default());
+    }
+
+    #[test]
+    fn test_args_round_trip() {
+        for options in [
+            DaemonOptions::default(),
+            DaemonOptions {
+                foreground: true,
+                log_format: LogFormat::Text,
+            },
+            DaemonOptions {
+                foreground: false,
+                log_format: LogFormat::Json,
+            },
+            DaemonOptions {
+                foreground: true,
+                log_format: LogFormat::Json,
+            },
+        ] {
+            assert_eq!(parse(&options.args()), options);
+        }
+        assert!(DaemonOptions::default().args().is_empty());
+    }
+}
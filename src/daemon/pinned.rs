@@ -0,0 +1,47 @@
+//! Pinned applications for the daemon.
+
+use crate::error::IpcError;
+
+/// Handle the PinApplication IPC command.
+///
+/// Appends the application to `pinned_applications` (persisted to disk if
+/// the config file exists). Pinning an application twice keeps one entry.
+pub fn handle_pin_application(app_id: &str) -> Result<(), IpcError> {
+    let app_id = app_id.trim().trim_end_matches(".desktop");
+    if app_id.is_empty() {
+        return Err(IpcError::InvalidArgument(
+            "Application id must not be empty".to_string(),
+        ));
+    }
+
+    crate::config::update_config(|config| pin(&mut config.pinned_applications, app_id));
+    Ok(())
+}
+
+/// Add `app_id` to the end of `pinned`, unless it is already pinned.
+fn pin(pinned: &mut Vec<String>, app_id: &str) {
+    if !pinned.iter().any(|id| id == app_id) {
+        pinned.push(app_id.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pin_appends_once() {
+        let mut pinned = vec!["firefox".to_string()];
+        pin(&mut pinned, "org.gnome.Nautilus");
+        pin(&mut pinned, "firefox");
+        assert_eq!(pinned, ["firefox", "org.gnome.Nautilus"]);
+    }
+
+    #[test]
+    fn test_empty_id_is_rejected() {
+        assert!(matches!(
+            handle_pin_application("  "),
+            Err(IpcError::InvalidArgument(_))
+        ));
+    }
+}
@@ -40,8 +40,13 @@ pub fn exec_reload() -> Result<()> {
     }
 
     // exec() replaces the current process - this never returns on success
-    // zlaunch daemon starts with no arguments
-    let err = std::process::Command::new(&exe).exec();
+    // Restart with the same profile and daemon options
+    let mut command = std::process::Command::new(&exe);
+    if let Some(profile) = crate::config::profile::active_profile() {
+        command.args(["--profile", profile]);
+    }
+    command.args(super::options::options().args());
+    let err = command.exec();
 
     // If we get here, exec failed
     Err(anyhow::anyhow!("Failed to exec: {}", err))
@@ -22,6 +22,28 @@ pub fn handle_set_theme(name: &str) -> Result<(), IpcError> {
     Ok(())
 }
 
+/// Reload the active theme after a user theme file changed on disk.
+///
+/// Returns whether the theme was reloaded. A theme that no longer parses
+/// keeps the last good one instead of falling back to the default.
+pub fn reload_active_theme() -> bool {
+    let name = crate::config::config().theme;
+    if name.is_empty() || name == "default" {
+        return false;
+    }
+
+    match crate::config::load_theme(&name) {
+        Some(theme) => {
+            crate::ui::theme::set_theme(theme);
+            true
+        }
+        None => {
+            tracing::warn!("Keeping the current theme, '{}' failed to reload", name);
+            false
+        }
+    }
+}
+
 /// Configure the global theme for transparent launcher appearance.
 ///
 /// Sets up transparent backgrounds and minimal borders for the overlay look.
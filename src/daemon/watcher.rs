@@ -1,13 +1,16 @@
-//! Background file watcher for automatic application reload.
+//! Background file watchers for automatic application and theme reload.
 //!
 //! Watches XDG application directories for changes and sends
-//! `ApplicationsChanged` events to the daemon event loop.
+//! `ApplicationsChanged` events to the daemon event loop. The user themes
+//! directory is watched too, sending `ThemeFileChanged`.
 
 use std::time::Duration;
 
+use notify::{Event, EventKind, RecursiveMode, Watcher};
 use tracing::{debug, error, info};
 
 use crate::app::DaemonEvent;
+use crate::config::user_themes_dir;
 use crate::desktop::watcher::ApplicationWatcher;
 
 use super::init::load_application_items;
@@ -51,3 +54,91 @@ pub async fn run_watcher_loop(event_tx: flume::Sender<DaemonEvent>) {
         }
     }
 }
+
+/// Run the theme watcher loop as an async task.
+///
+/// Any theme file change reloads the active theme, as it may inherit from
+/// the changed one. Does nothing if there is no user themes directory.
+pub async fn run_theme_watcher_loop(event_tx: flume::Sender<DaemonEvent>) {
+    let Some(dir) = user_themes_dir().filter(|dir| dir.is_dir()) else {
+        debug!("No user themes directory, not watching themes");
+        return;
+    };
+
+    let (tx, rx) = flume::unbounded();
+    let watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+        if let Ok(event) = res
+            && is_theme_file_change(&event)
+        {
+            let _ = tx.send(());
+        }
+    });
+    let mut watcher = match watcher {
+        Ok(w) => w,
+        Err(e) => {
+            error!("Failed to create theme watcher: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = watcher.watch(&dir, RecursiveMode::NonRecursive) {
+        error!("Failed to watch themes directory {:?}: {}", dir, e);
+        return;
+    }
+
+    info!("Watching themes directory: {:?}", dir);
+
+    while rx.recv_async().await.is_ok() {
+        // Editors often write a file in several steps
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        rx.drain();
+
+        debug!("Theme file changed");
+        if event_tx.send(DaemonEvent::ThemeFileChanged).is_err() {
+            debug!("Event channel closed, theme watcher exiting");
+            return;
+        }
+    }
+}
+
+/// Check whether an event writes a theme file.
+///
+/// Editors that save by renaming a temporary file show up as creations.
+fn is_theme_file_change(event: &Event) -> bool {
+    matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_))
+        && event
+            .paths
+            .iter()
+            .any(|path| path.extension().is_some_and(|ext| ext == "toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{CreateKind, ModifyKind, RemoveKind};
+    use std::path::PathBuf;
+
+    fn event(kind: EventKind, path: &str) -> Event {
+        Event::new(kind).add_path(PathBuf::from(path))
+    }
+
+    #[test]
+    fn test_is_theme_file_change() {
+        let theme = "/home/user/.config/zlaunch/themes/mine.toml";
+        assert!(is_theme_file_change(&event(
+            EventKind::Modify(ModifyKind::Any),
+            theme
+        )));
+        assert!(is_theme_file_change(&event(
+            EventKind::Create(CreateKind::File),
+            theme
+        )));
+        assert!(!is_theme_file_change(&event(
+            EventKind::Remove(RemoveKind::File),
+            theme
+        )));
+        assert!(!is_theme_file_change(&event(
+            EventKind::Modify(ModifyKind::Any),
+            "/home/user/.config/zlaunch/themes/.mine.toml.swp"
+        )));
+    }
+}
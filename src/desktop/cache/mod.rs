@@ -6,8 +6,9 @@
 
 mod validation;
 
-use crate::desktop::entry::DesktopEntry;
-use crate::desktop::scanner::scan_applications;
+use crate::desktop::entry::{DesktopAction, DesktopEntry};
+use crate::desktop::parser::current_locales;
+use crate::desktop::scanner::{apply_dedup_config, scan_applications};
 use crate::ui::icon::resolve_icon_path;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -19,7 +20,7 @@ use tracing::{debug, info, warn};
 pub use validation::get_directory_mtimes;
 
 /// Current cache format version.
-const CACHE_VERSION: u32 = 1;
+const CACHE_VERSION: u32 = 6;
 
 /// Cached representation of a desktop entry.
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -29,10 +30,20 @@ pub struct CachedEntry {
     pub exec: String,
     pub icon: Option<String>,
     pub icon_path: Option<PathBuf>,
+    #[serde(default)]
+    pub generic_name: Option<String>,
     pub comment: Option<String>,
     pub categories: Vec<String>,
     pub terminal: bool,
     pub source_path: PathBuf,
+    #[serde(default)]
+    pub startup_wm_class: Option<String>,
+    #[serde(default)]
+    pub dbus_activatable: bool,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    #[serde(default)]
+    pub actions: Vec<DesktopAction>,
     #[serde(with = "system_time_serde")]
     pub mtime: SystemTime,
 }
@@ -45,11 +56,16 @@ impl From<CachedEntry> for DesktopEntry {
             cached.exec,
             cached.icon,
             cached.icon_path,
+            cached.generic_name,
             cached.comment,
             cached.categories,
             cached.terminal,
             cached.source_path,
         )
+        .with_startup_wm_class(cached.startup_wm_class)
+        .with_dbus_activatable(cached.dbus_activatable)
+        .with_keywords(cached.keywords)
+        .with_actions(cached.actions)
     }
 }
 
@@ -65,10 +81,15 @@ impl From<&DesktopEntry> for CachedEntry {
             exec: entry.exec.clone(),
             icon: entry.icon.clone(),
             icon_path: entry.icon_path.clone(),
+            generic_name: entry.generic_name.clone(),
             comment: entry.comment.clone(),
             categories: entry.categories.clone(),
             terminal: entry.terminal,
             source_path: entry.path.clone(),
+            startup_wm_class: entry.startup_wm_class.clone(),
+            dbus_activatable: entry.dbus_activatable,
+            keywords: entry.keywords.clone(),
+            actions: entry.actions.clone(),
             mtime,
         }
     }
@@ -84,6 +105,9 @@ pub struct DesktopEntryCache {
     /// Modification times of scanned directories.
     #[serde(with = "hashmap_system_time_serde")]
     pub dir_mtimes: HashMap<PathBuf, SystemTime>,
+    /// Locales the entries were translated into.
+    #[serde(default)]
+    pub locales: Vec<String>,
 }
 
 impl DesktopEntryCache {
@@ -117,8 +141,26 @@ impl DesktopEntryCache {
         Ok(())
     }
 
-    /// Check if the cache is still valid (no directories have been modified).
+    /// Delete the cache file, if any.
+    pub fn clear() -> anyhow::Result<()> {
+        let Some(path) = Self::cache_path() else {
+            return Ok(());
+        };
+        match fs::remove_file(&path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Check if the cache is still valid (no directories have been modified
+    /// and the locale is unchanged).
     pub fn is_valid(&self) -> bool {
+        if self.locales != current_locales() {
+            debug!("Locale changed, cache invalid");
+            return false;
+        }
+
         let current_mtimes = validation::get_directory_mtimes();
 
         // Check if all directories match
@@ -148,9 +190,9 @@ impl DesktopEntryCache {
         true
     }
 
-    /// Get the cache file path.
+    /// Get the cache file path, namespaced by the active config profile.
     fn cache_path() -> Option<PathBuf> {
-        dirs::cache_dir().map(|d| d.join("zlaunch").join("apps.json"))
+        crate::cache_file::path("apps.json")
     }
 }
 
@@ -163,21 +205,53 @@ fn resolve_all_icon_paths(entries: &mut [DesktopEntry]) {
     }
 }
 
+/// Re-resolve cached icon paths whose file has vanished (e.g. after an
+/// icon theme update), since directory mtimes don't cover icon files.
+fn refresh_stale_icon_paths(entries: &mut [DesktopEntry]) {
+    for entry in entries.iter_mut() {
+        if entry.icon_path.as_ref().is_some_and(|path| !path.exists()) {
+            debug!(id = %entry.id, "Cached icon path vanished, re-resolving");
+            entry.icon_path = None;
+        }
+    }
+    resolve_all_icon_paths(entries);
+}
+
 /// Load applications with caching.
 ///
 /// Attempts to load from cache first. If the cache is invalid or missing,
-/// performs a full scan and saves the result to cache.
+/// performs a full scan and saves the result to cache. The cache holds every
+/// entry; duplicates are collapsed afterwards so the setting applies
+/// without a rescan.
 pub fn load_applications() -> Vec<DesktopEntry> {
     // Try to load from cache
     if let Some(cache) = DesktopEntryCache::load() {
         if cache.is_valid() {
             info!("Loaded {} applications from cache", cache.entries.len());
-            return cache.entries.into_iter().map(DesktopEntry::from).collect();
+            let mut entries: Vec<DesktopEntry> =
+                cache.entries.into_iter().map(DesktopEntry::from).collect();
+            refresh_stale_icon_paths(&mut entries);
+            return apply_dedup_config(entries);
         }
         debug!("Cache is stale, rescanning");
     }
 
-    // Full scan required
+    scan_and_cache()
+}
+
+/// Discard the cache and load applications from a fresh scan.
+///
+/// For changes the directory mtime check misses, e.g. files copied with
+/// preserved mtimes.
+pub fn rescan_applications() -> Vec<DesktopEntry> {
+    if let Err(e) = DesktopEntryCache::clear() {
+        warn!("Failed to remove application cache: {}", e);
+    }
+    scan_and_cache()
+}
+
+/// Scan all application directories and save the result to cache.
+fn scan_and_cache() -> Vec<DesktopEntry> {
     info!("Scanning for desktop applications...");
     let mut entries = scan_applications();
     resolve_all_icon_paths(&mut entries);
@@ -191,13 +265,14 @@ pub fn load_applications() -> Vec<DesktopEntry> {
         version: CACHE_VERSION,
         entries: cached_entries,
         dir_mtimes,
+        locales: current_locales(),
     };
 
     if let Err(e) = cache.save() {
         warn!("Failed to save application cache: {}", e);
     }
 
-    entries
+    apply_dedup_config(entries)
 }
 
 /// Serde support for SystemTime.
@@ -260,3 +335,50 @@ mod hashmap_system_time_serde {
             .collect())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry_with_icon(icon: &str, icon_path: Option<PathBuf>) -> DesktopEntry {
+        DesktopEntry::new(
+            "test".to_string(),
+            "Test".to_string(),
+            "test".to_string(),
+            Some(icon.to_string()),
+            icon_path,
+            None,
+            None,
+            Vec::new(),
+            false,
+            PathBuf::from("/usr/share/applications/test.desktop"),
+        )
+    }
+
+    #[test]
+    fn test_absolute_icon_path_stored() {
+        let path =
+            std::env::temp_dir().join(format!("zlaunch-cache-icon-{}.svg", std::process::id()));
+        fs::write(&path, b"").unwrap();
+
+        let mut entries = [entry_with_icon(path.to_str().unwrap(), None)];
+        resolve_all_icon_paths(&mut entries);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(entries[0].icon_path, Some(path));
+    }
+
+    #[test]
+    fn test_stale_icon_path_re_resolved() {
+        let path =
+            std::env::temp_dir().join(format!("zlaunch-stale-icon-{}.png", std::process::id()));
+        fs::write(&path, b"").unwrap();
+
+        let stale = PathBuf::from("/nonexistent/zlaunch/old-icon.png");
+        let mut entries = [entry_with_icon(path.to_str().unwrap(), Some(stale))];
+        refresh_stale_icon_paths(&mut entries);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(entries[0].icon_path, Some(path));
+    }
+}
@@ -1,4 +1,61 @@
-use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Where a desktop entry was installed from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EntrySource {
+    /// Installed by the system package manager or by the user.
+    Native,
+    /// Exported by Flatpak.
+    Flatpak,
+    /// Exported by Snap.
+    Snap,
+}
+
+impl EntrySource {
+    /// Detect the source from the desktop file path and exec line.
+    pub fn detect(path: &Path, exec: &str) -> Self {
+        let path = path.to_string_lossy();
+        if path.contains("/flatpak/exports/") || is_flatpak_exec(exec) {
+            Self::Flatpak
+        } else if path.contains("/snapd/desktop/") || exec.starts_with("/snap/bin/") {
+            Self::Snap
+        } else {
+            Self::Native
+        }
+    }
+
+    /// Human-readable label for display.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Native => "Native",
+            Self::Flatpak => "Flatpak",
+            Self::Snap => "Snap",
+        }
+    }
+}
+
+/// Check whether an exec line runs a Flatpak app.
+fn is_flatpak_exec(exec: &str) -> bool {
+    let mut parts = exec.split_whitespace();
+    parts
+        .next()
+        .is_some_and(|program| program.rsplit('/').next() == Some("flatpak"))
+        && parts.next() == Some("run")
+}
+
+/// An additional way to launch an application (`[Desktop Action <id>]`),
+/// e.g. Firefox's "New Private Window".
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DesktopAction {
+    /// Action identifier from the `Actions` key
+    pub id: String,
+    /// Name of the action, in the current locale
+    pub name: String,
+    /// Command line launching the action
+    pub exec: String,
+}
 
 #[derive(Clone, Debug)]
 pub struct DesktopEntry {
@@ -8,10 +65,24 @@ pub struct DesktopEntry {
     pub icon: Option<String>,
     /// Pre-resolved icon path for fast rendering
     pub icon_path: Option<PathBuf>,
+    /// Generic name of the application (e.g. "Web Browser")
+    pub generic_name: Option<String>,
     pub comment: Option<String>,
     pub categories: Vec<String>,
     pub terminal: bool,
     pub path: PathBuf,
+    /// Window class the application's windows use (`StartupWMClass`)
+    pub startup_wm_class: Option<String>,
+    /// Whether the application is launched over D-Bus (`DBusActivatable`)
+    pub dbus_activatable: bool,
+    /// Search keywords (`Keywords`), in the current locale
+    pub keywords: Vec<String>,
+    /// Additional launch actions (`Actions`), in file order
+    pub actions: Vec<DesktopAction>,
+    /// Where this entry was installed from
+    pub source: EntrySource,
+    /// Sources of duplicate entries collapsed into this one
+    pub collapsed_sources: Vec<EntrySource>,
 }
 
 impl DesktopEntry {
@@ -22,21 +93,61 @@ impl DesktopEntry {
         exec: String,
         icon: Option<String>,
         icon_path: Option<PathBuf>,
+        generic_name: Option<String>,
         comment: Option<String>,
         categories: Vec<String>,
         terminal: bool,
         path: PathBuf,
     ) -> Self {
+        let source = EntrySource::detect(&path, &exec);
         Self {
             id,
             name,
             exec,
             icon,
             icon_path,
+            generic_name,
             comment,
             categories,
             terminal,
             path,
+            startup_wm_class: None,
+            dbus_activatable: false,
+            keywords: Vec::new(),
+            actions: Vec::new(),
+            source,
+            collapsed_sources: Vec::new(),
         }
     }
+
+    /// Builder method to set the `StartupWMClass` window class.
+    pub fn with_startup_wm_class(mut self, startup_wm_class: Option<String>) -> Self {
+        self.startup_wm_class = startup_wm_class;
+        self
+    }
+
+    /// Builder method to set whether the application is D-Bus activatable.
+    pub fn with_dbus_activatable(mut self, dbus_activatable: bool) -> Self {
+        self.dbus_activatable = dbus_activatable;
+        self
+    }
+
+    /// Builder method to set the search keywords.
+    pub fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// Builder method to set the additional launch actions.
+    pub fn with_actions(mut self, actions: Vec<DesktopAction>) -> Self {
+        self.actions = actions;
+        self
+    }
+
+    /// Get the subtitle shown for this entry.
+    ///
+    /// Prefers `GenericName`, falling back to `Comment`.
+    pub fn description(&self) -> Option<&str> {
+        self.generic_name.as_deref().or(self.comment.as_deref())
+    }
 }
@@ -1,19 +1,105 @@
-use crate::desktop::entry::DesktopEntry;
-use crate::process;
+use crate::desktop::entry::{DesktopAction, DesktopEntry};
+use crate::process::{self, DetachedProcess};
+use std::collections::HashMap;
+use zbus::blocking::{Connection, Proxy};
+use zbus::zvariant::Value;
 
+/// D-Bus interface implemented by `DBusActivatable` applications.
+const APPLICATION_INTERFACE: &str = "org.freedesktop.Application";
+
+/// Launch an application from its desktop entry.
+///
+/// D-Bus activation is a blocking call that an unresponsive service can
+/// hang, so activatable entries are launched on a thread of their own and
+/// their failures are only logged.
 pub fn launch_application(entry: &DesktopEntry) -> anyhow::Result<()> {
-    let exec = clean_exec_string(&entry.exec);
+    if !entry.dbus_activatable || process::dry_run_enabled() {
+        return launch_with(entry, dbus_activate);
+    }
 
-    if entry.terminal {
-        process::launch_in_terminal(&exec)?;
-    } else {
-        process::launch_exec(&exec)?;
+    let entry = entry.clone();
+    std::thread::spawn(move || {
+        if let Err(e) = launch_with(&entry, dbus_activate) {
+            tracing::warn!(%e, id = %entry.id, "Failed to launch application");
+        }
+    });
+    Ok(())
+}
+
+/// Launch `entry`, activating it with `activate` if it is D-Bus activatable.
+///
+/// Falls back to the `Exec` command if activation fails. In dry-run mode
+/// the `Exec` command is logged instead of activating.
+fn launch_with(
+    entry: &DesktopEntry,
+    activate: impl FnOnce(&str) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    if entry.dbus_activatable && !process::dry_run_enabled() {
+        match activate(&entry.id) {
+            Ok(()) => return Ok(()),
+            Err(e) => tracing::warn!(%e, id = %entry.id, "D-Bus activation failed, using Exec"),
+        }
     }
 
+    application_process(entry)?.spawn()?;
+    Ok(())
+}
+
+/// Activate an application through `org.freedesktop.Application`.
+///
+/// The bus name is the desktop file id, as the desktop entry spec requires
+/// for `DBusActivatable` applications.
+fn dbus_activate(id: &str) -> anyhow::Result<()> {
+    let connection = Connection::session()?;
+    let proxy = Proxy::new(
+        &connection,
+        id,
+        application_object_path(id),
+        APPLICATION_INTERFACE,
+    )?;
+    let platform_data: HashMap<&str, Value> = HashMap::new();
+    let _: () = proxy.call("Activate", &(platform_data,))?;
+    Ok(())
+}
+
+/// Get the object path of an application from its id, e.g.
+/// `org.gnome.Nautilus` becomes `/org/gnome/Nautilus`.
+fn application_object_path(id: &str) -> String {
+    format!("/{}", id.replace('.', "/").replace('-', "_"))
+}
+
+/// Build the process that launching `entry` would spawn.
+///
+/// Field codes are stripped from the exec string. With `ZLAUNCH_DRY_RUN` set,
+/// spawning it only logs the resolved command line.
+pub fn application_process(entry: &DesktopEntry) -> anyhow::Result<DetachedProcess> {
+    exec_line_process(&entry.exec, entry.terminal)
+}
+
+/// Launch a desktop action of an application through its own `Exec` line.
+///
+/// `terminal` is the application's `Terminal` flag, which applies to its
+/// actions too.
+pub fn launch_action(action: &DesktopAction, terminal: bool) -> anyhow::Result<()> {
+    exec_line_process(&action.exec, terminal)?.spawn()?;
     Ok(())
 }
 
-fn clean_exec_string(exec: &str) -> String {
+/// Build the process for an exec line, in a terminal if `terminal` is set.
+fn exec_line_process(exec: &str, terminal: bool) -> anyhow::Result<DetachedProcess> {
+    let exec = clean_exec_string(exec);
+
+    let process = if terminal {
+        process::terminal_process(&exec)?
+    } else {
+        process::exec_process(&exec)?
+    };
+
+    Ok(process)
+}
+
+/// Strip field codes like `%U` from an exec string.
+pub(crate) fn clean_exec_string(exec: &str) -> String {
     let mut result = exec.to_string();
 
     for placeholder in [
@@ -24,3 +110,101 @@ fn clean_exec_string(exec: &str) -> String {
 
     result.trim().to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn entry_with_exec(exec: &str) -> DesktopEntry {
+        DesktopEntry::new(
+            "test".to_string(),
+            "Test".to_string(),
+            exec.to_string(),
+            None,
+            None,
+            None,
+            None,
+            Vec::new(),
+            false,
+            PathBuf::from("/usr/share/applications/test.desktop"),
+        )
+    }
+
+    #[test]
+    fn test_field_codes_stripped_from_command_line() {
+        let entry = entry_with_exec("/usr/bin/firefox --name firefox %u");
+        let process = application_process(&entry).unwrap();
+        assert_eq!(process.command_line(), "/usr/bin/firefox --name firefox");
+    }
+
+    #[test]
+    fn test_multiple_field_codes_stripped() {
+        let entry = entry_with_exec("code --new-window %F %i %c");
+        let process = application_process(&entry).unwrap();
+        assert_eq!(process.program(), "code");
+        assert_eq!(process.arguments(), ["--new-window"]);
+    }
+
+    #[test]
+    fn test_dry_run_launch_application() {
+        let entry = entry_with_exec("/nonexistent/zlaunch-test-binary %U");
+        let process = application_process(&entry).unwrap().dry_run(true);
+        assert_eq!(process.command_line(), "/nonexistent/zlaunch-test-binary");
+        assert!(process.spawn().is_ok());
+    }
+
+    #[test]
+    fn test_empty_exec_is_error() {
+        let entry = entry_with_exec("%U");
+        assert!(application_process(&entry).is_err());
+    }
+
+    fn activatable_entry() -> DesktopEntry {
+        let mut entry = entry_with_exec("/nonexistent/zlaunch-test-binary");
+        entry.id = "org.example.App".to_string();
+        entry.with_dbus_activatable(true)
+    }
+
+    #[test]
+    fn test_dbus_activation_used_for_activatable_entries() {
+        let mut activated = None;
+        let result = launch_with(&activatable_entry(), |id| {
+            activated = Some(id.to_string());
+            Ok(())
+        });
+        assert!(result.is_ok());
+        assert_eq!(activated.as_deref(), Some("org.example.App"));
+    }
+
+    #[test]
+    fn test_failed_activation_falls_back_to_exec() {
+        let mut attempted = false;
+        let result = launch_with(&activatable_entry(), |_| {
+            attempted = true;
+            anyhow::bail!("name has no owner")
+        });
+        // The fallback tried to spawn the (missing) Exec binary
+        assert!(attempted);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_regular_entries_are_not_activated() {
+        let entry = activatable_entry().with_dbus_activatable(false);
+        let result = launch_with(&entry, |_| panic!("should not activate"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_application_object_path() {
+        assert_eq!(
+            application_object_path("org.gnome.Nautilus"),
+            "/org/gnome/Nautilus"
+        );
+        assert_eq!(
+            application_object_path("org.example.my-app"),
+            "/org/example/my_app"
+        );
+    }
+}
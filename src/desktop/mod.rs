@@ -3,11 +3,12 @@ pub mod entry;
 pub mod env;
 pub mod exec;
 pub mod parser;
+pub mod recent;
 pub mod scanner;
 pub mod watcher;
 
-pub use cache::load_applications;
-pub use entry::DesktopEntry;
+pub use cache::{load_applications, rescan_applications};
+pub use entry::{DesktopAction, DesktopEntry, EntrySource};
 pub use env::{capture_session_environment, get_session_environment};
-pub use exec::launch_application;
-pub use scanner::scan_applications;
+pub use exec::{launch_action, launch_application};
+pub use scanner::{ScanReport, scan_applications, scan_applications_with_report};
@@ -1,27 +1,122 @@
-use crate::desktop::entry::DesktopEntry;
+use crate::desktop::entry::{DesktopAction, DesktopEntry};
+use crate::desktop::env::get_session_environment;
 use freedesktop_desktop_entry::DesktopEntry as FdEntry;
 use std::path::Path;
+use thiserror::Error;
 
-pub fn parse_desktop_file(path: &Path) -> Option<DesktopEntry> {
-    let content = std::fs::read_to_string(path).ok()?;
-    let fd_entry = FdEntry::from_str(path, &content, None::<&[&str]>).ok()?;
+/// Why a desktop file doesn't produce a launcher entry.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SkipReason {
+    /// The file couldn't be read.
+    #[error("unreadable: {0}")]
+    Unreadable(String),
 
-    let locales: &[&str] = &[];
-    let name = fd_entry.name(locales)?.to_string();
-    let exec = fd_entry.exec()?.to_string();
+    /// The file isn't a valid desktop entry.
+    #[error("parse error: {0}")]
+    Invalid(String),
 
-    if fd_entry.no_display() {
-        return None;
-    }
+    /// The entry has no `Name` key.
+    #[error("missing Name")]
+    MissingName,
+
+    /// The entry has no `Exec` key.
+    #[error("missing Exec")]
+    MissingExec,
+
+    /// The entry sets `NoDisplay=true`.
+    #[error("NoDisplay is set")]
+    NoDisplay,
+
+    /// The entry sets `Hidden=true`, i.e. it was deleted by the user.
+    #[error("Hidden is set")]
+    Hidden,
+
+    /// The `TryExec` program isn't installed.
+    #[error("TryExec program '{0}' not found")]
+    TryExecNotFound(String),
+
+    /// `OnlyShowIn`/`NotShowIn` exclude the current desktop.
+    #[error("not shown in desktop '{0}'")]
+    NotShownIn(String),
 
-    let id = path
-        .file_stem()
+    /// An entry with the same id in a higher-priority directory wins.
+    #[error("overridden by {0}")]
+    Shadowed(String),
+}
+
+/// Get the desktop id of the desktop file at `path`.
+pub fn desktop_id(path: &Path) -> String {
+    path.file_stem()
         .and_then(|s| s.to_str())
         .unwrap_or("unknown")
-        .to_string();
+        .to_string()
+}
+
+/// Parse a desktop file, reporting why it was skipped if it yields no entry.
+pub fn check_desktop_file(path: &Path) -> Result<DesktopEntry, SkipReason> {
+    let content =
+        std::fs::read_to_string(path).map_err(|e| SkipReason::Unreadable(e.to_string()))?;
+    let desktops = current_desktops();
+    let locales = current_locales();
+    parse_desktop_content(path, &content, &desktops, &locales)
+}
+
+/// Parse the contents of a desktop file located at `path`.
+///
+/// `desktops` are the names in `XDG_CURRENT_DESKTOP`, checked against
+/// `OnlyShowIn` and `NotShowIn`. `Name`, `GenericName`, `Comment` and
+/// `Keywords` are taken in the first of `locales` they are translated to.
+fn parse_desktop_content(
+    path: &Path,
+    content: &str,
+    desktops: &[String],
+    locales: &[String],
+) -> Result<DesktopEntry, SkipReason> {
+    let fd_entry = FdEntry::from_str(path, content, None::<&[&str]>)
+        .map_err(|e| SkipReason::Invalid(e.to_string()))?;
+    let translations = LocalizedKeys::parse(content, MAIN_GROUP, locales);
+
+    // Checked first, as a file that only masks another needs no Name or Exec
+    if fd_entry.desktop_entry("Hidden") == Some("true") {
+        return Err(SkipReason::Hidden);
+    }
+    if fd_entry.no_display() {
+        return Err(SkipReason::NoDisplay);
+    }
+
+    let unlocalized: &[&str] = &[];
+    let name = translations
+        .get("Name")
+        .or_else(|| fd_entry.name(unlocalized).map(|s| s.to_string()))
+        .ok_or(SkipReason::MissingName)?;
+    let exec = fd_entry.exec().ok_or(SkipReason::MissingExec)?.to_string();
+
+    if !is_shown_in(
+        fd_entry.desktop_entry("OnlyShowIn"),
+        fd_entry.desktop_entry("NotShowIn"),
+        desktops,
+    ) {
+        return Err(SkipReason::NotShownIn(desktops.join(":")));
+    }
+    if let Some(try_exec) = fd_entry.desktop_entry("TryExec")
+        && !is_program_installed(try_exec)
+    {
+        return Err(SkipReason::TryExecNotFound(try_exec.to_string()));
+    }
+
+    let id = desktop_id(path);
 
     let icon = fd_entry.icon().map(|s| s.to_string());
-    let comment = fd_entry.comment(locales).map(|s| s.to_string());
+    let generic_name = translations
+        .get("GenericName")
+        .or_else(|| fd_entry.generic_name(unlocalized).map(|s| s.to_string()));
+    let comment = translations
+        .get("Comment")
+        .or_else(|| fd_entry.comment(unlocalized).map(|s| s.to_string()));
+    let keywords = translations
+        .get_list("Keywords")
+        .or_else(|| fd_entry.desktop_entry("Keywords").map(split_list))
+        .unwrap_or_default();
 
     let categories: Vec<String> = fd_entry
         .categories()
@@ -29,17 +124,533 @@ pub fn parse_desktop_file(path: &Path) -> Option<DesktopEntry> {
         .unwrap_or_default();
 
     let terminal = fd_entry.terminal();
+    let startup_wm_class = fd_entry.startup_wm_class().map(|s| s.to_string());
+    let dbus_activatable = fd_entry.desktop_entry("DBusActivatable") == Some("true");
+    let actions = parse_actions(&fd_entry, content, locales);
 
     // icon_path is resolved later in cache.rs after all entries are loaded
-    Some(DesktopEntry::new(
+    Ok(DesktopEntry::new(
         id,
         name,
         exec,
         icon,
         None,
+        generic_name,
         comment,
         categories,
         terminal,
         path.to_path_buf(),
-    ))
+    )
+    .with_startup_wm_class(startup_wm_class)
+    .with_dbus_activatable(dbus_activatable)
+    .with_keywords(keywords)
+    .with_actions(actions))
+}
+
+/// Parse the `[Desktop Action <id>]` groups listed in `Actions`.
+///
+/// Actions without a name or an `Exec` line are left out, the latter can
+/// only be activated over D-Bus.
+fn parse_actions(fd_entry: &FdEntry, content: &str, locales: &[String]) -> Vec<DesktopAction> {
+    let Some(ids) = fd_entry.desktop_entry("Actions") else {
+        return Vec::new();
+    };
+
+    split_list(ids)
+        .into_iter()
+        .filter_map(|id| {
+            let group = format!("Desktop Action {}", id);
+            let name = LocalizedKeys::parse(content, &group, locales)
+                .get("Name")
+                .or_else(|| fd_entry.action_entry(&id, "Name").map(|s| s.to_string()))?;
+            let exec = fd_entry.action_entry(&id, "Exec")?.to_string();
+            Some(DesktopAction { id, name, exec })
+        })
+        .collect()
+}
+
+/// Get the locales to look up translations in, most specific first.
+///
+/// Uses the first set of `LC_ALL`, `LC_MESSAGES` and `LANG`, like gettext.
+pub fn current_locales() -> Vec<String> {
+    let env = get_session_environment();
+    locales_from_env(|name| env.get(name).cloned())
+}
+
+/// Get the translation locales for the locale variables `lookup` resolves.
+fn locales_from_env(lookup: impl Fn(&str) -> Option<String>) -> Vec<String> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(lookup)
+        .find(|value| !value.is_empty())
+        .map(|locale| locale_candidates(&locale))
+        .unwrap_or_default()
+}
+
+/// Expand a `lang_COUNTRY.ENCODING@MODIFIER` locale into the locale keys
+/// to try, in the order of the desktop entry spec.
+///
+/// E.g. `sr_YU@Latn` gives `sr_YU@Latn`, `sr_YU`, `sr@Latn` and `sr`. The
+/// encoding is ignored. `C` and `POSIX` have no translations.
+fn locale_candidates(locale: &str) -> Vec<String> {
+    let (rest, modifier) = match locale.split_once('@') {
+        Some((rest, modifier)) => (rest, Some(modifier)),
+        None => (locale, None),
+    };
+    let rest = rest.split('.').next().unwrap_or(rest);
+    let (lang, country) = match rest.split_once('_') {
+        Some((lang, country)) => (lang, Some(country)),
+        None => (rest, None),
+    };
+    if lang.is_empty() || lang == "C" || lang == "POSIX" {
+        return Vec::new();
+    }
+
+    let mut candidates = Vec::new();
+    if let (Some(country), Some(modifier)) = (country, modifier) {
+        candidates.push(format!("{}_{}@{}", lang, country, modifier));
+    }
+    if let Some(country) = country {
+        candidates.push(format!("{}_{}", lang, country));
+    }
+    if let Some(modifier) = modifier {
+        candidates.push(format!("{}@{}", lang, modifier));
+    }
+    candidates.push(lang.to_string());
+    candidates
+}
+
+/// Name of the group holding the application's own keys.
+const MAIN_GROUP: &str = "Desktop Entry";
+
+/// Translated values (`Key[locale]=value`) of one group of a desktop file.
+struct LocalizedKeys<'a> {
+    /// Locales to look up, most specific first
+    locales: &'a [String],
+    /// Key, locale and raw value of the translations into `locales`
+    values: Vec<(&'a str, &'a str, &'a str)>,
+}
+
+impl<'a> LocalizedKeys<'a> {
+    /// Collect the translations into `locales` of the `[group]` group from
+    /// desktop file contents.
+    fn parse(content: &'a str, group: &str, locales: &'a [String]) -> Self {
+        let mut values = Vec::new();
+        let mut in_group = false;
+        for line in content.lines() {
+            let line = line.trim();
+            if line.starts_with('[') {
+                in_group = line
+                    .strip_prefix('[')
+                    .and_then(|line| line.strip_suffix(']'))
+                    == Some(group);
+                continue;
+            }
+            if !in_group || line.starts_with('#') || locales.is_empty() {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            if let Some((key, locale)) = key.trim_end().split_once('[')
+                && let Some(locale) = locale.strip_suffix(']')
+                && locales.iter().any(|wanted| wanted.as_str() == locale)
+            {
+                values.push((key, locale, value.trim_start()));
+            }
+        }
+        Self { locales, values }
+    }
+
+    /// Get the value of `key` in the first locale it is translated to.
+    fn get(&self, key: &str) -> Option<String> {
+        self.raw(key).map(unescape)
+    }
+
+    /// Get the list value of `key` in the first locale it is translated
+    /// to, split at unescaped `;`.
+    fn get_list(&self, key: &str) -> Option<Vec<String>> {
+        self.raw(key).map(split_list)
+    }
+
+    fn raw(&self, key: &str) -> Option<&'a str> {
+        // Like other keys, the first occurrence of a translation wins
+        self.locales.iter().find_map(|locale| {
+            self.values
+                .iter()
+                .find(|(k, l, _)| *k == key && *l == locale.as_str())
+                .map(|(_, _, value)| *value)
+        })
+    }
+}
+
+/// Resolve the escape sequences of a desktop entry string value.
+fn unescape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('s') => result.push(' '),
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some('r') => result.push('\r'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+    result
+}
+
+/// Split a desktop entry list value at unescaped `;`, dropping empty items.
+fn split_list(value: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut current = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(';') => current.push(';'),
+                Some(other) => {
+                    current.push('\\');
+                    current.push(other);
+                }
+                None => current.push('\\'),
+            },
+            ';' => items.push(unescape(&std::mem::take(&mut current))),
+            _ => current.push(c),
+        }
+    }
+    items.push(unescape(&current));
+    items.retain(|item| !item.is_empty());
+    items
+}
+
+/// Get the desktop names from `XDG_CURRENT_DESKTOP`.
+fn current_desktops() -> Vec<String> {
+    get_session_environment()
+        .get("XDG_CURRENT_DESKTOP")
+        .map(|value| {
+            value
+                .split(':')
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Check the `OnlyShowIn`/`NotShowIn` lists against the current desktops.
+///
+/// Without a known desktop, entries are shown regardless of the lists.
+fn is_shown_in(only_show_in: Option<&str>, not_show_in: Option<&str>, desktops: &[String]) -> bool {
+    if desktops.is_empty() {
+        return true;
+    }
+    let contains_desktop = |value: Option<&str>| {
+        value.is_some_and(|list| {
+            list.split(';')
+                .any(|name| desktops.iter().any(|desktop| desktop == name))
+        })
+    };
+
+    if contains_desktop(not_show_in) {
+        return false;
+    }
+    only_show_in.is_none() || contains_desktop(only_show_in)
+}
+
+/// Check whether a `TryExec` program exists, as an absolute path or in `PATH`.
+fn is_program_installed(program: &str) -> bool {
+    let path = Path::new(program);
+    if path.is_absolute() {
+        return path.is_file();
+    }
+    get_session_environment()
+        .get("PATH")
+        .is_some_and(|dirs| std::env::split_paths(dirs).any(|dir| dir.join(program).is_file()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(content: &str) -> Option<DesktopEntry> {
+        parse_with_reason(content).ok()
+    }
+
+    fn parse_with_reason(content: &str) -> Result<DesktopEntry, SkipReason> {
+        parse_desktop_content(
+            Path::new("/usr/share/applications/test.desktop"),
+            content,
+            &["GNOME".to_string()],
+            &[],
+        )
+    }
+
+    const MULTI_LOCALE: &str = "[Desktop Entry]\n\
+        Type=Application\n\
+        Name=Files\n\
+        Name[de]=Dateien\n\
+        Name[de_CH]=Dateie\n\
+        Name[fr]=Fichiers\n\
+        GenericName=File Manager\n\
+        GenericName[de]=Dateiverwaltung\n\
+        Comment=Access and organize files\n\
+        Comment[fr]=Accéder aux fichiers et les organiser\n\
+        Keywords=folder;manager;explore;\n\
+        Keywords[de]=Ordner;Verwaltung;Durchsuchen;\n\
+        Exec=nautilus\n\
+        \n\
+        [Desktop Action new-window]\n\
+        Name=New Window\n\
+        Name[de]=Neues Fenster\n";
+
+    /// Parse [`MULTI_LOCALE`] as if `LANG` were set to `lang`.
+    fn parse_with_lang(lang: &str) -> DesktopEntry {
+        let locales = locales_from_env(|name| (name == "LANG").then(|| lang.to_string()));
+        parse_desktop_content(
+            Path::new("/usr/share/applications/org.gnome.Nautilus.desktop"),
+            MULTI_LOCALE,
+            &[],
+            &locales,
+        )
+        .expect("entry should parse")
+    }
+
+    #[test]
+    fn test_generic_name_preferred_over_comment() {
+        let entry = parse(
+            "[Desktop Entry]\n\
+             Type=Application\n\
+             Name=Firefox\n\
+             GenericName=Web Browser\n\
+             Comment=Browse the World Wide Web\n\
+             Exec=firefox %u\n",
+        )
+        .expect("entry should parse");
+
+        assert_eq!(entry.generic_name.as_deref(), Some("Web Browser"));
+        assert_eq!(entry.comment.as_deref(), Some("Browse the World Wide Web"));
+        assert_eq!(entry.description(), Some("Web Browser"));
+    }
+
+    #[test]
+    fn test_comment_used_without_generic_name() {
+        let entry = parse(
+            "[Desktop Entry]\n\
+             Type=Application\n\
+             Name=Files\n\
+             Comment=Access and organize files\n\
+             Exec=nautilus\n",
+        )
+        .expect("entry should parse");
+
+        assert!(entry.generic_name.is_none());
+        assert_eq!(entry.description(), Some("Access and organize files"));
+    }
+
+    #[test]
+    fn test_startup_wm_class() {
+        let entry = parse(
+            "[Desktop Entry]\n\
+             Type=Application\n\
+             Name=Google Chrome\n\
+             Exec=google-chrome-stable %U\n\
+             StartupWMClass=Google-chrome\n",
+        )
+        .expect("entry should parse");
+
+        assert_eq!(entry.startup_wm_class.as_deref(), Some("Google-chrome"));
+    }
+
+    #[test]
+    fn test_skip_reasons() {
+        let entry = |extra: &str| {
+            parse_with_reason(&format!(
+                "[Desktop Entry]\nType=Application\nName=Settings\nExec=settings\n{}",
+                extra
+            ))
+        };
+
+        assert_eq!(
+            entry("NoDisplay=true\n").unwrap_err(),
+            SkipReason::NoDisplay
+        );
+        assert_eq!(entry("Hidden=true\n").unwrap_err(), SkipReason::Hidden);
+        assert_eq!(
+            entry("OnlyShowIn=KDE;\n").unwrap_err(),
+            SkipReason::NotShownIn("GNOME".to_string())
+        );
+        assert_eq!(
+            entry("TryExec=/nonexistent/zlaunch-test\n").unwrap_err(),
+            SkipReason::TryExecNotFound("/nonexistent/zlaunch-test".to_string())
+        );
+        assert!(entry("OnlyShowIn=GNOME;KDE;\n").is_ok());
+        assert_eq!(
+            parse_with_reason("[Desktop Entry]\nType=Application\nExec=settings\n").unwrap_err(),
+            SkipReason::MissingName
+        );
+        // A masking file needs nothing but Hidden
+        assert_eq!(
+            parse_with_reason("[Desktop Entry]\nHidden=true\n").unwrap_err(),
+            SkipReason::Hidden
+        );
+    }
+
+    #[test]
+    fn test_show_in_lists() {
+        let gnome = ["GNOME".to_string()];
+        assert!(is_shown_in(None, None, &gnome));
+        assert!(is_shown_in(Some("GNOME;"), None, &gnome));
+        assert!(!is_shown_in(Some("KDE;XFCE;"), None, &gnome));
+        assert!(!is_shown_in(None, Some("GNOME;"), &gnome));
+        // Unknown desktop: the lists can't be evaluated
+        assert!(is_shown_in(Some("KDE;"), None, &[]));
+    }
+
+    #[test]
+    fn test_dbus_activatable() {
+        let entry = parse(
+            "[Desktop Entry]\n\
+             Type=Application\n\
+             Name=Files\n\
+             Exec=nautilus --new-window\n\
+             DBusActivatable=true\n",
+        )
+        .expect("entry should parse");
+        assert!(entry.dbus_activatable);
+
+        let entry = parse("[Desktop Entry]\nType=Application\nName=Files\nExec=nautilus\n")
+            .expect("entry should parse");
+        assert!(!entry.dbus_activatable);
+    }
+
+    #[test]
+    fn test_desktop_actions() {
+        let entry = parse_desktop_content(
+            Path::new("/usr/share/applications/firefox.desktop"),
+            "[Desktop Entry]\n\
+             Type=Application\n\
+             Name=Firefox\n\
+             Exec=firefox %u\n\
+             Actions=new-window;new-private-window;profile-manager;\n\
+             \n\
+             [Desktop Action new-window]\n\
+             Name=New Window\n\
+             Name[de]=Neues Fenster\n\
+             Exec=firefox --new-window %u\n\
+             \n\
+             [Desktop Action new-private-window]\n\
+             Name=New Private Window\n\
+             Exec=firefox --private-window %u\n\
+             Icon=firefox-private\n\
+             \n\
+             [Desktop Action profile-manager]\n\
+             Name=Profile Manager\n\
+             \n\
+             [Desktop Action unlisted]\n\
+             Name=Unlisted\n\
+             Exec=firefox\n",
+            &[],
+            &["de".to_string()],
+        )
+        .expect("entry should parse");
+
+        // Without Exec or not listed in Actions: left out
+        let ids: Vec<&str> = entry.actions.iter().map(|a| a.id.as_str()).collect();
+        assert_eq!(ids, ["new-window", "new-private-window"]);
+        assert_eq!(entry.actions[0].name, "Neues Fenster");
+        assert_eq!(entry.actions[0].exec, "firefox --new-window %u");
+        assert_eq!(entry.actions[1].name, "New Private Window");
+        // The action's translation doesn't leak into the entry
+        assert_eq!(entry.name, "Firefox");
+    }
+
+    #[test]
+    fn test_locale_candidates() {
+        assert_eq!(
+            locale_candidates("sr_YU.UTF-8@Latn"),
+            ["sr_YU@Latn", "sr_YU", "sr@Latn", "sr"]
+        );
+        assert_eq!(locale_candidates("de_DE.UTF-8"), ["de_DE", "de"]);
+        assert_eq!(locale_candidates("fr"), ["fr"]);
+        assert!(locale_candidates("C.UTF-8").is_empty());
+        assert!(locale_candidates("POSIX").is_empty());
+    }
+
+    #[test]
+    fn test_locale_variable_precedence() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        assert_eq!(
+            locales_from_env(env(&[("LANG", "de_DE.UTF-8"), ("LC_MESSAGES", "fr_FR")])),
+            ["fr_FR", "fr"]
+        );
+        assert_eq!(
+            locales_from_env(env(&[("LANG", "de_DE.UTF-8"), ("LC_ALL", "")])),
+            ["de_DE", "de"]
+        );
+        assert!(locales_from_env(env(&[])).is_empty());
+    }
+
+    #[test]
+    fn test_localized_keys_fall_back_to_language() {
+        let entry = parse_with_lang("de_DE.UTF-8");
+        assert_eq!(entry.name, "Dateien");
+        assert_eq!(entry.generic_name.as_deref(), Some("Dateiverwaltung"));
+        // Not translated to German
+        assert_eq!(entry.comment.as_deref(), Some("Access and organize files"));
+        assert_eq!(entry.keywords, ["Ordner", "Verwaltung", "Durchsuchen"]);
+    }
+
+    #[test]
+    fn test_localized_keys_prefer_country() {
+        assert_eq!(parse_with_lang("de_CH.UTF-8").name, "Dateie");
+        let entry = parse_with_lang("fr_FR.UTF-8");
+        assert_eq!(entry.name, "Fichiers");
+        assert_eq!(
+            entry.comment.as_deref(),
+            Some("Accéder aux fichiers et les organiser")
+        );
+        assert_eq!(entry.keywords, ["folder", "manager", "explore"]);
+    }
+
+    #[test]
+    fn test_unmatched_locale_uses_unlocalized_values() {
+        let entry = parse_with_lang("ja_JP.UTF-8");
+        assert_eq!(entry.name, "Files");
+        assert_eq!(entry.generic_name.as_deref(), Some("File Manager"));
+
+        let entry = parse_with_lang("C");
+        assert_eq!(entry.name, "Files");
+        assert_eq!(entry.keywords, ["folder", "manager", "explore"]);
+    }
+
+    #[test]
+    fn test_unlocalized_keywords() {
+        let entry = parse(
+            "[Desktop Entry]\n\
+             Type=Application\n\
+             Name=Terminal\n\
+             Exec=foot\n\
+             Keywords=shell;prompt;command;\n",
+        )
+        .expect("entry should parse");
+        assert_eq!(entry.keywords, ["shell", "prompt", "command"]);
+    }
+
+    #[test]
+    fn test_unescape_and_split_list() {
+        assert_eq!(unescape(r"Line\sone\nTwo\\"), "Line one\nTwo\\");
+        assert_eq!(split_list(r"a;b\;c;;d"), ["a", "b;c", "d"]);
+    }
 }
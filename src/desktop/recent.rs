@@ -0,0 +1,94 @@
+//! Recently launched applications.
+//!
+//! Keeps a short, newest-first list of launched application ids for the
+//! "Recent" section shown before any query is typed. The list is stored in
+//! the XDG cache directory next to the application cache.
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+/// Cache file the list is stored in.
+const FILE_NAME: &str = "recent.json";
+
+/// Recently launched application ids, most recent first.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RecentApps {
+    ids: Vec<String>,
+}
+
+impl RecentApps {
+    /// Load the list from disk, or an empty list if there is none.
+    pub fn load() -> Self {
+        crate::cache_file::load(FILE_NAME)
+    }
+
+    /// Save the list to disk.
+    pub fn save(&self) -> anyhow::Result<()> {
+        crate::cache_file::save(FILE_NAME, self)?;
+        debug!("Saved {} recent applications", self.ids.len());
+        Ok(())
+    }
+
+    /// Move `id` to the front, keeping at most `capacity` ids.
+    pub fn push(&mut self, id: &str, capacity: usize) {
+        self.ids.retain(|existing| existing != id);
+        self.ids.insert(0, id.to_string());
+        self.ids.truncate(capacity);
+    }
+
+    /// Get the ids, most recent first.
+    pub fn ids(&self) -> &[String] {
+        &self.ids
+    }
+
+    /// Take the ids, most recent first.
+    pub fn into_ids(self) -> Vec<String> {
+        self.ids
+    }
+}
+
+/// Record a launch of the application `id`, keeping at most `capacity` ids.
+///
+/// Does nothing when `capacity` is 0, i.e. the Recent section is disabled.
+pub fn record_launch(id: &str, capacity: usize) {
+    if capacity == 0 {
+        return;
+    }
+
+    let mut recent = RecentApps::load();
+    recent.push(id, capacity);
+    if let Err(e) = recent.save() {
+        warn!(%e, "Failed to save recent applications");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_moves_to_front() {
+        let mut recent = RecentApps::default();
+        recent.push("firefox", 5);
+        recent.push("code", 5);
+        recent.push("firefox", 5);
+        assert_eq!(recent.ids(), ["firefox", "code"]);
+    }
+
+    #[test]
+    fn test_push_drops_oldest_beyond_capacity() {
+        let mut recent = RecentApps::default();
+        for id in ["a", "b", "c", "d"] {
+            recent.push(id, 3);
+        }
+        assert_eq!(recent.ids(), ["d", "c", "b"]);
+    }
+
+    #[test]
+    fn test_roundtrip_json() {
+        let mut recent = RecentApps::default();
+        recent.push("files", 5);
+        let json = serde_json::to_string(&recent).unwrap();
+        assert_eq!(serde_json::from_str::<RecentApps>(&json).unwrap(), recent);
+    }
+}
@@ -1,19 +1,132 @@
-use crate::desktop::entry::DesktopEntry;
-use crate::desktop::parser::parse_desktop_file;
+use crate::config::config;
+use crate::desktop::entry::{DesktopEntry, EntrySource};
+use crate::desktop::parser::{SkipReason, check_desktop_file, desktop_id};
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+/// A desktop file that didn't produce an entry.
+#[derive(Debug, Clone)]
+pub struct SkippedFile {
+    pub path: PathBuf,
+    pub reason: SkipReason,
+}
+
+/// Result of scanning the application directories.
+#[derive(Debug, Default)]
+pub struct ScanReport {
+    /// Entries found, sorted by name
+    pub entries: Vec<DesktopEntry>,
+    /// Desktop files left out, in scan order
+    pub skipped: Vec<SkippedFile>,
+}
+
 pub fn scan_applications() -> Vec<DesktopEntry> {
+    scan_applications_with_report().entries
+}
+
+/// Scan the application directories, keeping track of skipped files.
+pub fn scan_applications_with_report() -> ScanReport {
     let dirs = get_xdg_application_dirs();
     let mut entries: HashMap<String, DesktopEntry> = HashMap::new();
+    let mut claimed = HashMap::new();
+    let mut skipped = Vec::new();
 
     for dir in dirs {
-        scan_directory(&dir, &mut entries);
+        scan_directory(&dir, &mut entries, &mut claimed, &mut skipped);
     }
 
     let mut result: Vec<DesktopEntry> = entries.into_values().collect();
     result.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
-    result
+    ScanReport {
+        entries: result,
+        skipped,
+    }
+}
+
+/// Collapse duplicate entries if enabled in the config.
+pub fn apply_dedup_config(entries: Vec<DesktopEntry>) -> Vec<DesktopEntry> {
+    let app_config = config();
+    if !app_config.dedup_desktop_entries {
+        return entries;
+    }
+    dedup_entries(entries, app_config.desktop_source_order())
+}
+
+/// Collapse entries for the same application installed from several sources
+/// (e.g. a distro package and a Flatpak).
+///
+/// Entries are duplicates when their normalized name and command match. The
+/// entry whose source comes first in `source_order` is kept and records the
+/// sources it replaced in `collapsed_sources`.
+pub fn dedup_entries(
+    entries: Vec<DesktopEntry>,
+    source_order: &[EntrySource],
+) -> Vec<DesktopEntry> {
+    let rank = |source: EntrySource| {
+        source_order
+            .iter()
+            .position(|s| *s == source)
+            .unwrap_or(usize::MAX)
+    };
+
+    let mut kept: Vec<DesktopEntry> = Vec::with_capacity(entries.len());
+    let mut by_key: HashMap<(String, String), usize> = HashMap::new();
+
+    for entry in entries {
+        let key = dedup_key(&entry);
+        let Some(&index) = by_key.get(&key) else {
+            by_key.insert(key, kept.len());
+            kept.push(entry);
+            continue;
+        };
+
+        let existing = &mut kept[index];
+        if rank(entry.source) < rank(existing.source) {
+            let mut entry = entry;
+            entry.collapsed_sources = std::mem::take(&mut existing.collapsed_sources);
+            entry.collapsed_sources.push(existing.source);
+            *existing = entry;
+        } else {
+            existing.collapsed_sources.push(entry.source);
+        }
+    }
+
+    kept
+}
+
+/// Normalized (name, command) pair identifying an application across sources.
+fn dedup_key(entry: &DesktopEntry) -> (String, String) {
+    (
+        entry.name.trim().to_lowercase(),
+        normalized_command(&entry.exec),
+    )
+}
+
+/// Get the program an exec line runs, without path or arguments.
+///
+/// For `flatpak run`, this is the `--command=` value or the app id's last
+/// segment (`org.mozilla.firefox` -> `firefox`).
+fn normalized_command(exec: &str) -> String {
+    let mut parts = exec.split_whitespace();
+    let program = parts.next().unwrap_or_default();
+    let basename = program.rsplit('/').next().unwrap_or(program);
+
+    if basename == "flatpak" && parts.next() == Some("run") {
+        let mut app_id = None;
+        for part in parts {
+            if let Some(command) = part.strip_prefix("--command=") {
+                return command.rsplit('/').next().unwrap_or(command).to_lowercase();
+            }
+            if app_id.is_none() && !part.starts_with('-') {
+                app_id = Some(part);
+            }
+        }
+        if let Some(app_id) = app_id {
+            return app_id.rsplit('.').next().unwrap_or(app_id).to_lowercase();
+        }
+    }
+
+    basename.to_lowercase()
 }
 
 fn get_xdg_application_dirs() -> Vec<PathBuf> {
@@ -35,7 +148,18 @@ fn get_xdg_application_dirs() -> Vec<PathBuf> {
     dirs
 }
 
-fn scan_directory(dir: &PathBuf, entries: &mut HashMap<String, DesktopEntry>) {
+/// Scan `dir` and its subdirectories for desktop files.
+///
+/// `claimed` maps the desktop ids seen so far to the file that took them.
+/// Directories are scanned in priority order, so the first file with an id
+/// wins, including `Hidden` and `NoDisplay` files that override an entry
+/// further down the list.
+fn scan_directory(
+    dir: &PathBuf,
+    entries: &mut HashMap<String, DesktopEntry>,
+    claimed: &mut HashMap<String, PathBuf>,
+    skipped: &mut Vec<SkippedFile>,
+) {
     let Ok(read_dir) = std::fs::read_dir(dir) else {
         return;
     };
@@ -44,15 +168,169 @@ fn scan_directory(dir: &PathBuf, entries: &mut HashMap<String, DesktopEntry>) {
         let path = entry.path();
 
         if path.is_dir() {
-            scan_directory(&path, entries);
+            scan_directory(&path, entries, claimed, skipped);
+            continue;
+        }
+
+        if path.extension().is_none_or(|ext| ext != "desktop") {
+            continue;
+        }
+
+        let id = desktop_id(&path);
+        if let Some(existing) = claimed.get(&id) {
+            let reason = SkipReason::Shadowed(existing.display().to_string());
+            skipped.push(SkippedFile { path, reason });
             continue;
         }
 
-        if path.extension().is_some_and(|ext| ext == "desktop")
-            && let Some(desktop_entry) = parse_desktop_file(&path)
-            && !entries.contains_key(&desktop_entry.id)
-        {
-            entries.insert(desktop_entry.id.clone(), desktop_entry);
+        match check_desktop_file(&path) {
+            Ok(desktop_entry) => {
+                claimed.insert(id, path);
+                entries.insert(desktop_entry.id.clone(), desktop_entry);
+            }
+            Err(reason) => {
+                if matches!(reason, SkipReason::Hidden | SkipReason::NoDisplay) {
+                    claimed.insert(id, path.clone());
+                }
+                skipped.push(SkippedFile { path, reason });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, exec: &str, path: &str) -> DesktopEntry {
+        DesktopEntry::new(
+            path.rsplit('/')
+                .next()
+                .unwrap()
+                .trim_end_matches(".desktop")
+                .to_string(),
+            name.to_string(),
+            exec.to_string(),
+            None,
+            None,
+            None,
+            None,
+            Vec::new(),
+            false,
+            PathBuf::from(path),
+        )
+    }
+
+    fn firefox_pair() -> Vec<DesktopEntry> {
+        vec![
+            entry(
+                "Firefox",
+                "flatpak run --branch=stable --arch=x86_64 --command=firefox org.mozilla.firefox @@u %u @@",
+                "/var/lib/flatpak/exports/share/applications/org.mozilla.firefox.desktop",
+            ),
+            entry(
+                "Firefox",
+                "/usr/lib/firefox/firefox %u",
+                "/usr/share/applications/firefox.desktop",
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_source_detection() {
+        let [flatpak, native] = firefox_pair().try_into().unwrap();
+        assert_eq!(flatpak.source, EntrySource::Flatpak);
+        assert_eq!(native.source, EntrySource::Native);
+    }
+
+    #[test]
+    fn test_native_preferred_over_flatpak() {
+        let order = [EntrySource::Native, EntrySource::Flatpak];
+        let result = dedup_entries(firefox_pair(), &order);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].source, EntrySource::Native);
+        assert_eq!(result[0].collapsed_sources, vec![EntrySource::Flatpak]);
+    }
+
+    #[test]
+    fn test_flatpak_preferred_when_configured() {
+        let order = [EntrySource::Flatpak, EntrySource::Native];
+        let result = dedup_entries(firefox_pair(), &order);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id, "org.mozilla.firefox");
+        assert_eq!(result[0].collapsed_sources, vec![EntrySource::Native]);
+    }
+
+    #[test]
+    fn test_different_apps_not_collapsed() {
+        let entries = vec![
+            entry(
+                "Firefox",
+                "firefox %u",
+                "/usr/share/applications/firefox.desktop",
+            ),
+            entry(
+                "Firefox",
+                "firefox-developer-edition %u",
+                "/usr/share/applications/firefox-dev.desktop",
+            ),
+        ];
+        assert_eq!(dedup_entries(entries, &[EntrySource::Native]).len(), 2);
+    }
+
+    #[test]
+    fn test_hidden_file_shadows_lower_priority_dirs() {
+        let root = std::env::temp_dir().join(format!("zlaunch-scanner-{}", std::process::id()));
+        let (local, system) = (root.join("local"), root.join("system"));
+        std::fs::create_dir_all(&local).unwrap();
+        std::fs::create_dir_all(&system).unwrap();
+        std::fs::write(
+            local.join("firefox.desktop"),
+            "[Desktop Entry]\nHidden=true\n",
+        )
+        .unwrap();
+        for id in ["firefox", "gimp"] {
+            std::fs::write(
+                system.join(format!("{}.desktop", id)),
+                format!(
+                    "[Desktop Entry]\nType=Application\nName={0}\nExec={0}\n",
+                    id
+                ),
+            )
+            .unwrap();
+        }
+
+        let mut entries = HashMap::new();
+        let mut claimed = HashMap::new();
+        let mut skipped = Vec::new();
+        for dir in [&local, &system] {
+            scan_directory(dir, &mut entries, &mut claimed, &mut skipped);
         }
+
+        assert_eq!(entries.keys().collect::<Vec<_>>(), ["gimp"]);
+        let reasons: Vec<&SkipReason> = skipped.iter().map(|s| &s.reason).collect();
+        assert_eq!(
+            reasons,
+            [
+                &SkipReason::Hidden,
+                &SkipReason::Shadowed(local.join("firefox.desktop").display().to_string()),
+            ]
+        );
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_flatpak_app_id_used_without_command() {
+        assert_eq!(
+            normalized_command("/usr/bin/flatpak run org.gnome.Calculator"),
+            "calculator"
+        );
+        assert_eq!(
+            normalized_command("/usr/bin/gnome-calculator"),
+            "gnome-calculator"
+        );
     }
 }
@@ -11,7 +11,7 @@ lazy_static! {
 /// Load all emojis from the emojis crate.
 fn load_all_emojis() -> Vec<EmojiItem> {
     emojis::iter()
-        .map(|emoji| EmojiItem::new(emoji.as_str(), emoji.name()))
+        .map(|emoji| EmojiItem::new(emoji.as_str(), emoji.name()).with_shortcode(emoji.shortcode()))
         .collect()
 }
 
@@ -0,0 +1,107 @@
+//! Nerd Font glyphs for the glyph picker.
+//!
+//! The dataset in `assets/glyphs/nerd-fonts.tsv` is a hand-curated subset
+//! of commonly used glyphs. `scripts/gen-nerd-fonts-tsv.py` can replace it
+//! with the full set from the upstream Nerd Fonts `glyphnames.json`.
+
+use crate::emoji::EmojiItem;
+use fuzzy_matcher::FuzzyMatcher;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use lazy_static::lazy_static;
+
+/// Bundled Nerd Font glyph dataset (`name<TAB>codepoint` lines).
+const NERD_FONT_GLYPHS: &str = include_str!("../../assets/glyphs/nerd-fonts.tsv");
+
+lazy_static! {
+    /// All glyphs loaded from the bundled dataset.
+    static ref ALL_GLYPHS: Vec<EmojiItem> = parse_glyphs(NERD_FONT_GLYPHS);
+}
+
+/// Parse a glyph dataset.
+///
+/// Each line holds a glyph name and its hex codepoint separated by a tab.
+/// Blank lines, `#` comments and lines with an invalid codepoint are skipped.
+pub fn parse_glyphs(content: &str) -> Vec<EmojiItem> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let (name, codepoint) = line.split_once('\t')?;
+            let glyph = u32::from_str_radix(codepoint.trim(), 16)
+                .ok()
+                .and_then(char::from_u32)?;
+            Some(EmojiItem::new(glyph.to_string(), name.trim()))
+        })
+        .collect()
+}
+
+/// Get all Nerd Font glyphs.
+pub fn all_glyphs() -> &'static [EmojiItem] {
+    &ALL_GLYPHS
+}
+
+/// Search glyphs by name using fuzzy matching.
+/// Returns indices into the all_glyphs() slice, sorted by match score.
+pub fn search_glyphs(query: &str) -> Vec<usize> {
+    if query.is_empty() {
+        return (0..ALL_GLYPHS.len()).collect();
+    }
+
+    let matcher = SkimMatcherV2::default();
+    let mut scored: Vec<(usize, i64)> = ALL_GLYPHS
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, item)| {
+            matcher
+                .fuzzy_match(&item.name, query)
+                .map(|score| (idx, score))
+        })
+        .collect();
+
+    // Sort by score descending
+    scored.sort_by(|a, b| b.1.cmp(&a.1));
+    scored.into_iter().map(|(idx, _)| idx).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_glyphs() {
+        let glyphs = parse_glyphs("# comment\n\nnf-dev-git\te702\nnf-fa-home\tF015\n");
+        assert_eq!(glyphs.len(), 2);
+        assert_eq!(glyphs[0].name, "nf-dev-git");
+        assert_eq!(glyphs[0].emoji, "\u{e702}");
+        assert_eq!(glyphs[1].emoji, "\u{f015}");
+    }
+
+    #[test]
+    fn test_parse_glyphs_skips_invalid_lines() {
+        let glyphs = parse_glyphs("no-codepoint\nnf-bad\tzzzz\nnf-surrogate\td800\nnf-ok\te0a0");
+        assert_eq!(glyphs.len(), 1);
+        assert_eq!(glyphs[0].name, "nf-ok");
+    }
+
+    #[test]
+    fn test_bundled_dataset_loads() {
+        assert!(all_glyphs().len() > 100);
+        assert!(
+            all_glyphs()
+                .iter()
+                .all(|glyph| glyph.name.starts_with("nf-"))
+        );
+    }
+
+    #[test]
+    fn test_search_git_finds_git_glyph() {
+        let names: Vec<&str> = search_glyphs("git")
+            .into_iter()
+            .map(|idx| all_glyphs()[idx].name.as_str())
+            .collect();
+        assert!(names.contains(&"nf-dev-git"));
+        assert!(names.contains(&"nf-oct-git_branch"));
+        assert!(!names.contains(&"nf-fa-home"));
+    }
+}
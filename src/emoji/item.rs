@@ -1,3 +1,5 @@
+use crate::config::EmojiCopyFormat;
+
 /// An emoji item for display in the emoji picker grid.
 #[derive(Clone, Debug)]
 pub struct EmojiItem {
@@ -5,6 +7,8 @@ pub struct EmojiItem {
     pub emoji: String,
     /// The display name of the emoji.
     pub name: String,
+    /// The GitHub-style shortcode (without colons), if any.
+    pub shortcode: Option<String>,
 }
 
 impl EmojiItem {
@@ -13,6 +17,75 @@ impl EmojiItem {
         Self {
             emoji: emoji.into(),
             name: name.into(),
+            shortcode: None,
+        }
+    }
+
+    /// Set the shortcode for this emoji.
+    pub fn with_shortcode(mut self, shortcode: Option<impl Into<String>>) -> Self {
+        self.shortcode = shortcode.map(Into::into);
+        self
+    }
+
+    /// Get the text to copy for this emoji in the given format.
+    pub fn copy_text(&self, format: EmojiCopyFormat) -> String {
+        match format {
+            EmojiCopyFormat::Unicode => self.emoji.clone(),
+            EmojiCopyFormat::Shortcode => match &self.shortcode {
+                Some(code) => format!(":{}:", code),
+                None => self.emoji.clone(),
+            },
+            EmojiCopyFormat::Html => self
+                .emoji
+                .chars()
+                .map(|c| format!("&#x{:X};", c as u32))
+                .collect(),
+            EmojiCopyFormat::Codepoint => self
+                .emoji
+                .chars()
+                .map(|c| format!("U+{:04X}", c as u32))
+                .collect::<Vec<_>>()
+                .join(" "),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_copy_text_unicode() {
+        let item = EmojiItem::new("😀", "grinning face").with_shortcode(Some("grinning"));
+        assert_eq!(item.copy_text(EmojiCopyFormat::Unicode), "😀");
+    }
+
+    #[test]
+    fn test_copy_text_shortcode() {
+        let item = EmojiItem::new("😀", "grinning face").with_shortcode(Some("grinning"));
+        assert_eq!(item.copy_text(EmojiCopyFormat::Shortcode), ":grinning:");
+
+        // Falls back to the character without a shortcode
+        let item = EmojiItem::new("😀", "grinning face");
+        assert_eq!(item.copy_text(EmojiCopyFormat::Shortcode), "😀");
+    }
+
+    #[test]
+    fn test_copy_text_html_multi_codepoint() {
+        let item = EmojiItem::new("😀", "grinning face");
+        assert_eq!(item.copy_text(EmojiCopyFormat::Html), "&#x1F600;");
+
+        // Emoji with a variation selector keep every code point
+        let item = EmojiItem::new("❤\u{FE0F}", "red heart");
+        assert_eq!(item.copy_text(EmojiCopyFormat::Html), "&#x2764;&#xFE0F;");
+    }
+
+    #[test]
+    fn test_copy_text_codepoint() {
+        let item = EmojiItem::new("\u{e702}", "nf-dev-git");
+        assert_eq!(item.copy_text(EmojiCopyFormat::Codepoint), "U+E702");
+
+        let item = EmojiItem::new("❤\u{FE0F}", "red heart");
+        assert_eq!(item.copy_text(EmojiCopyFormat::Codepoint), "U+2764 U+FE0F");
+    }
+}
@@ -1,5 +1,9 @@
 mod data;
+mod glyphs;
 mod item;
+mod set;
 
 pub use data::{all_emojis, search_emojis};
+pub use glyphs::{all_glyphs, parse_glyphs, search_glyphs};
 pub use item::EmojiItem;
+pub use set::PickerSet;
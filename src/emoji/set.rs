@@ -0,0 +1,86 @@
+use crate::config::EmojiCopyFormat;
+use crate::emoji::{EmojiItem, all_emojis, all_glyphs};
+
+/// The items shown in the emoji picker grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PickerSet {
+    /// Unicode emojis.
+    Emojis,
+    /// Nerd Font glyphs from the bundled dataset.
+    Glyphs,
+}
+
+impl PickerSet {
+    /// Get the items of this set.
+    pub fn items(self) -> &'static [EmojiItem] {
+        match self {
+            Self::Emojis => all_emojis(),
+            Self::Glyphs => all_glyphs(),
+        }
+    }
+
+    /// Get the search input placeholder for this set.
+    pub fn placeholder(self) -> &'static str {
+        match self {
+            Self::Emojis => "Search emojis...",
+            Self::Glyphs => "Search Nerd Font glyphs...",
+        }
+    }
+
+    /// Get the copy format a picker session starts with.
+    ///
+    /// Glyphs have no shortcodes, so they always start as the character.
+    pub fn initial_copy_format(self, configured: EmojiCopyFormat) -> EmojiCopyFormat {
+        match self {
+            Self::Emojis => configured,
+            Self::Glyphs => EmojiCopyFormat::Unicode,
+        }
+    }
+
+    /// Get the copy format after `format` when quick-toggling.
+    ///
+    /// Glyphs toggle between the character and its codepoint.
+    pub fn next_copy_format(self, format: EmojiCopyFormat) -> EmojiCopyFormat {
+        match (self, format) {
+            (Self::Emojis, format) => format.next(),
+            (Self::Glyphs, EmojiCopyFormat::Unicode) => EmojiCopyFormat::Codepoint,
+            (Self::Glyphs, _) => EmojiCopyFormat::Unicode,
+        }
+    }
+
+    /// Get a human-readable label for `format` in this set.
+    pub fn format_label(self, format: EmojiCopyFormat) -> &'static str {
+        match (self, format) {
+            (Self::Glyphs, EmojiCopyFormat::Unicode) => "Glyph",
+            (_, format) => format.label(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glyphs_toggle_character_and_codepoint() {
+        let set = PickerSet::Glyphs;
+        let start = set.initial_copy_format(EmojiCopyFormat::Shortcode);
+        assert_eq!(start, EmojiCopyFormat::Unicode);
+        assert_eq!(set.next_copy_format(start), EmojiCopyFormat::Codepoint);
+        assert_eq!(set.next_copy_format(EmojiCopyFormat::Codepoint), start);
+        assert_eq!(set.format_label(start), "Glyph");
+    }
+
+    #[test]
+    fn test_emojis_use_configured_format() {
+        let set = PickerSet::Emojis;
+        assert_eq!(
+            set.initial_copy_format(EmojiCopyFormat::Html),
+            EmojiCopyFormat::Html
+        );
+        assert_eq!(
+            set.next_copy_format(EmojiCopyFormat::Unicode),
+            EmojiCopyFormat::Shortcode
+        );
+    }
+}
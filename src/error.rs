@@ -23,11 +23,97 @@ pub enum IpcError {
     #[error("Theme '{0}' not found")]
     ThemeNotFound(String),
 
+    /// A request argument was rejected.
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+
+    /// Launching a URL or file failed.
+    #[error("Launch failed: {0}")]
+    LaunchFailed(String),
+
     /// A general internal error occurred.
     #[error("{0}")]
     Internal(String),
 }
 
+/// Client-side errors when talking to the daemon over IPC.
+#[derive(Error, Debug)]
+pub enum IpcClientError {
+    /// No daemon is listening on the socket.
+    #[error(
+        "zlaunch daemon is not running. Start it first by running: {}",
+        daemon_start_command(.profile.as_deref())
+    )]
+    DaemonNotRunning {
+        /// The active config profile, if any.
+        profile: Option<String>,
+    },
+
+    /// Connecting to the daemon socket failed for another reason.
+    #[error("Failed to connect to the zlaunch daemon: {0}")]
+    ConnectFailed(#[source] std::io::Error),
+
+    /// The daemon did not answer before the request deadline.
+    #[error("zlaunch daemon did not respond in time")]
+    Timeout,
+
+    /// The connection to the daemon was closed mid-request.
+    #[error("Connection to the zlaunch daemon was lost")]
+    Disconnected,
+
+    /// Any other transport failure.
+    #[error("IPC transport error: {0}")]
+    Transport(String),
+
+    /// A path argument could not be resolved.
+    #[error("Invalid path: {0}")]
+    InvalidPath(#[source] std::io::Error),
+
+    /// The async runtime for the request could not be started.
+    #[error("Failed to start IPC runtime: {0}")]
+    Runtime(#[source] std::io::Error),
+
+    /// The daemon handled the request and reported an error.
+    #[error(transparent)]
+    Daemon(#[from] IpcError),
+}
+
+impl IpcClientError {
+    /// Classify a socket connection error.
+    ///
+    /// A missing socket or a refused connection both mean no daemon is
+    /// listening for this profile.
+    pub fn from_connect_error(error: std::io::Error, profile: Option<&str>) -> Self {
+        match error.kind() {
+            std::io::ErrorKind::NotFound | std::io::ErrorKind::ConnectionRefused => {
+                Self::DaemonNotRunning {
+                    profile: profile.map(str::to_string),
+                }
+            }
+            _ => Self::ConnectFailed(error),
+        }
+    }
+}
+
+impl From<tarpc::client::RpcError> for IpcClientError {
+    fn from(error: tarpc::client::RpcError) -> Self {
+        use tarpc::client::RpcError;
+        match error {
+            RpcError::DeadlineExceeded => Self::Timeout,
+            RpcError::Shutdown => Self::Disconnected,
+            other => Self::Transport(other.to_string()),
+        }
+    }
+}
+
+/// The command that starts the daemon for a profile.
+fn daemon_start_command(profile: Option<&str>) -> String {
+    match profile {
+        Some(name) => format!("zlaunch --profile {}", name),
+        None => "zlaunch".to_string(),
+    }
+}
+
 /// Process execution errors.
 #[derive(Error, Debug)]
 pub enum ProcessError {
@@ -44,6 +130,22 @@ pub enum ProcessError {
     SpawnFailed(#[source] std::io::Error),
 }
 
+/// Errors running an external script item source.
+#[derive(Error, Debug)]
+pub enum ScriptError {
+    /// Failed to start the script.
+    #[error("Failed to run script: {0}")]
+    SpawnFailed(#[source] std::io::Error),
+
+    /// The script did not finish within its time limit.
+    #[error("Script timed out after {}ms", .0.as_millis())]
+    TimedOut(std::time::Duration),
+
+    /// The script exited unsuccessfully.
+    #[error("Script failed with {0}")]
+    Failed(std::process::ExitStatus),
+}
+
 /// Configuration errors.
 #[derive(Error, Debug)]
 pub enum ConfigError {
@@ -86,3 +188,39 @@ impl From<ClipboardError> for String {
         e.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Error, ErrorKind};
+
+    #[test]
+    fn test_refused_connection_means_not_running() {
+        let error =
+            IpcClientError::from_connect_error(Error::from(ErrorKind::ConnectionRefused), None);
+        assert_eq!(
+            error.to_string(),
+            "zlaunch daemon is not running. Start it first by running: zlaunch"
+        );
+    }
+
+    #[test]
+    fn test_not_running_names_profile() {
+        let error =
+            IpcClientError::from_connect_error(Error::from(ErrorKind::NotFound), Some("work"));
+        assert!(error.to_string().ends_with("zlaunch --profile work"));
+    }
+
+    #[test]
+    fn test_other_connect_errors_are_kept() {
+        let error =
+            IpcClientError::from_connect_error(Error::from(ErrorKind::PermissionDenied), None);
+        assert!(matches!(error, IpcClientError::ConnectFailed(_)));
+    }
+
+    #[test]
+    fn test_daemon_errors_display_unchanged() {
+        let error = IpcClientError::from(IpcError::ThemeNotFound("nord".to_string()));
+        assert_eq!(error.to_string(), "Theme 'nord' not found");
+    }
+}
@@ -0,0 +1,195 @@
+//! File search for the `files` module.
+//!
+//! The configured root is walked on demand for each query, up to a
+//! configured depth. Hidden files and anything ignored by a `.gitignore`
+//! or `.ignore` file are skipped. File names are matched fuzzily; a query
+//! containing `/` is matched against the path below the root instead, so
+//! `work/notes` finds `projects/work/notes.md`.
+
+use crate::items::FileItem;
+use fuzzy_matcher::FuzzyMatcher;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use ignore::WalkBuilder;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing::warn;
+
+/// Maximum number of files listed for a query.
+pub const MAX_FILE_RESULTS: usize = 20;
+
+/// Where to search for files.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileSearch {
+    /// Directory to search
+    pub root: PathBuf,
+    /// Directory levels searched below the root
+    pub max_depth: usize,
+}
+
+impl FileSearch {
+    /// Find the files and folders matching `query`, best matches first.
+    ///
+    /// The walk stops as soon as `cancelled` is set, returning no results.
+    pub fn search(&self, query: &str, cancelled: &AtomicBool) -> Vec<FileItem> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let matcher = SkimMatcherV2::default();
+        let match_path = query.contains('/');
+        let mut scored: Vec<(i64, PathBuf, bool)> = WalkBuilder::new(&self.root)
+            .max_depth(Some(self.max_depth))
+            // Honor .gitignore files outside of git repositories too
+            .require_git(false)
+            .build()
+            .take_while(|_| !cancelled.load(Ordering::Relaxed))
+            .filter_map(Result::ok)
+            .filter(|entry| entry.depth() > 0)
+            .filter_map(|entry| {
+                let text = if match_path {
+                    entry
+                        .path()
+                        .strip_prefix(&self.root)
+                        .ok()?
+                        .to_string_lossy()
+                } else {
+                    entry.file_name().to_string_lossy()
+                };
+                let score = matcher.fuzzy_match(&text, query)?;
+                let is_dir = entry.file_type().is_some_and(|t| t.is_dir());
+                Some((score, entry.into_path(), is_dir))
+            })
+            .collect();
+        if cancelled.load(Ordering::Relaxed) {
+            return Vec::new();
+        }
+
+        // Shorter paths win ties, they are usually the one meant
+        scored.sort_by(|a, b| {
+            b.0.cmp(&a.0)
+                .then_with(|| a.1.as_os_str().len().cmp(&b.1.as_os_str().len()))
+        });
+        scored
+            .into_iter()
+            .take(MAX_FILE_RESULTS)
+            .map(|(_, path, is_dir)| FileItem::new(path, is_dir))
+            .collect()
+    }
+}
+
+/// Search for `query` on a blocking thread, so the walk doesn't stall the
+/// async runtime.
+///
+/// Aborting the returned future doesn't stop a blocking thread; set
+/// `cancelled` to end the walk early.
+pub async fn fetch_items(
+    search: FileSearch,
+    query: String,
+    cancelled: Arc<AtomicBool>,
+) -> Vec<FileItem> {
+    match tokio::task::spawn_blocking(move || search.search(&query, &cancelled)).await {
+        Ok(items) => items,
+        Err(e) => {
+            warn!(%e, "File search failed");
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    /// Create a directory tree for a test, removed again when dropped.
+    struct TestTree(PathBuf);
+
+    impl TestTree {
+        fn new(name: &str, files: &[&str]) -> Self {
+            let root =
+                std::env::temp_dir().join(format!("zlaunch-{}-{}", name, std::process::id()));
+            for file in files {
+                let path = root.join(file);
+                fs::create_dir_all(path.parent().unwrap()).unwrap();
+                fs::write(path, file).unwrap();
+            }
+            Self(root)
+        }
+
+        fn search(&self, query: &str, max_depth: usize) -> Vec<String> {
+            FileSearch {
+                root: self.0.clone(),
+                max_depth,
+            }
+            .search(query, &AtomicBool::new(false))
+            .into_iter()
+            .map(|item| {
+                item.path
+                    .strip_prefix(&self.0)
+                    .unwrap()
+                    .display()
+                    .to_string()
+            })
+            .collect()
+        }
+    }
+
+    impl Drop for TestTree {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn test_search_matches_file_names() {
+        let tree = TestTree::new(
+            "files-names",
+            &["notes.md", "projects/work/notes-2024.md", "music/song.ogg"],
+        );
+        assert_eq!(
+            tree.search("notes", 4),
+            ["notes.md", "projects/work/notes-2024.md"]
+        );
+        assert!(tree.search("  ", 4).is_empty());
+    }
+
+    #[test]
+    fn test_search_respects_depth_hidden_and_gitignore() {
+        let tree = TestTree::new(
+            "files-skip",
+            &[
+                ".gitignore",
+                "build/report.txt",
+                ".cache/report.txt",
+                "a/b/c/report.txt",
+                "docs/report.txt",
+            ],
+        );
+        fs::write(tree.0.join(".gitignore"), "build/\n").unwrap();
+        assert_eq!(tree.search("report", 2), ["docs/report.txt"]);
+        assert_eq!(
+            tree.search("report", 4),
+            ["docs/report.txt", "a/b/c/report.txt"]
+        );
+    }
+
+    #[test]
+    fn test_search_with_slash_matches_relative_path() {
+        let tree = TestTree::new("files-path", &["work/todo.md", "home/todo.md"]);
+        assert_eq!(tree.search("work/todo", 4), ["work/todo.md"]);
+        // Folders are listed too
+        assert_eq!(tree.search("work", 4)[0], "work");
+    }
+
+    #[test]
+    fn test_cancelled_search_returns_nothing() {
+        let tree = TestTree::new("files-cancel", &["notes.md"]);
+        let search = FileSearch {
+            root: tree.0.clone(),
+            max_depth: 4,
+        };
+        assert!(search.search("notes", &AtomicBool::new(true)).is_empty());
+    }
+}
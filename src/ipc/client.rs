@@ -1,14 +1,21 @@
 //! tarpc client for communicating with the daemon.
 
-use crate::config::LauncherMode;
-use crate::ipc::commands::{ThemeInfo, ZlaunchServiceClient};
+use crate::config::profile::active_profile;
+use crate::config::{ConfigModule, LauncherMode};
+use crate::error::IpcClientError;
+use crate::ipc::commands::{DaemonStatus, ThemeInfo, ZlaunchServiceClient};
 use crate::ipc::server::get_socket_path;
+use std::path::Path;
+use std::time::{Duration, Instant};
 use tarpc::client;
 use tarpc::context;
 use tarpc::tokio_serde::formats::Json;
 use tokio::net::UnixStream;
 use tokio_util::codec::LengthDelimitedCodec;
 
+/// How long `dmenu` waits for a line to be chosen.
+const DMENU_TIMEOUT: Duration = Duration::from_secs(24 * 60 * 60);
+
 /// Check if the daemon is running.
 pub fn is_daemon_running() -> bool {
     let socket_path = get_socket_path();
@@ -16,9 +23,15 @@ pub fn is_daemon_running() -> bool {
 }
 
 /// Create a tarpc client connected to the daemon.
-async fn connect() -> anyhow::Result<ZlaunchServiceClient> {
-    let socket_path = get_socket_path();
-    let stream = UnixStream::connect(&socket_path).await?;
+pub(crate) async fn connect() -> Result<ZlaunchServiceClient, IpcClientError> {
+    connect_to(&get_socket_path()).await
+}
+
+/// Create a tarpc client connected to the daemon socket at `socket_path`.
+async fn connect_to(socket_path: &Path) -> Result<ZlaunchServiceClient, IpcClientError> {
+    let stream = UnixStream::connect(socket_path)
+        .await
+        .map_err(|e| IpcClientError::from_connect_error(e, active_profile()))?;
 
     let framed = tokio_util::codec::Framed::new(stream, LengthDelimitedCodec::new());
     let transport = tarpc::serde_transport::new(framed, Json::default());
@@ -28,15 +41,43 @@ async fn connect() -> anyhow::Result<ZlaunchServiceClient> {
 }
 
 /// Show the launcher window with optional modes.
-pub fn show(modes: Option<Vec<LauncherMode>>) -> anyhow::Result<()> {
+pub fn show(modes: Option<Vec<LauncherMode>>) -> Result<(), IpcClientError> {
     run_async(async {
         let client = connect().await?;
         Ok(client.show(context::current(), modes).await??)
     })
 }
 
+/// Show the launcher window with the search input filled in.
+pub fn open_with_query(text: &str) -> Result<(), IpcClientError> {
+    let text = text.to_string();
+    run_async(async {
+        let client = connect().await?;
+        Ok(client.open_with_query(context::current(), text).await??)
+    })
+}
+
+/// Show `lines` in the launcher and wait for one to be chosen.
+///
+/// Returns None if the launcher was closed without a selection.
+pub fn dmenu(lines: Vec<String>, prompt: Option<String>) -> Result<Option<String>, IpcClientError> {
+    run_async(async {
+        let client = connect().await?;
+        Ok(client.dmenu(dmenu_context(), lines, prompt).await??)
+    })
+}
+
+/// Request context for `dmenu`, whose reply waits for the user.
+///
+/// The default deadline is far too short for that.
+pub(crate) fn dmenu_context() -> context::Context {
+    let mut ctx = context::current();
+    ctx.deadline = Instant::now() + DMENU_TIMEOUT;
+    ctx
+}
+
 /// Hide the launcher window.
-pub fn hide() -> anyhow::Result<()> {
+pub fn hide() -> Result<(), IpcClientError> {
     run_async(async {
         let client = connect().await?;
         Ok(client.hide(context::current()).await??)
@@ -44,7 +85,7 @@ pub fn hide() -> anyhow::Result<()> {
 }
 
 /// Toggle the launcher window visibility with optional modes.
-pub fn toggle(modes: Option<Vec<LauncherMode>>) -> anyhow::Result<()> {
+pub fn toggle(modes: Option<Vec<LauncherMode>>) -> Result<(), IpcClientError> {
     run_async(async {
         let client = connect().await?;
         Ok(client.toggle(context::current(), modes).await??)
@@ -52,7 +93,7 @@ pub fn toggle(modes: Option<Vec<LauncherMode>>) -> anyhow::Result<()> {
 }
 
 /// Quit the daemon.
-pub fn quit() -> anyhow::Result<()> {
+pub fn quit() -> Result<(), IpcClientError> {
     run_async(async {
         let client = connect().await?;
         Ok(client.quit(context::current()).await??)
@@ -60,15 +101,24 @@ pub fn quit() -> anyhow::Result<()> {
 }
 
 /// Reload the daemon (fully restart the process).
-pub fn reload() -> anyhow::Result<()> {
+pub fn reload() -> Result<(), IpcClientError> {
     run_async(async {
         let client = connect().await?;
         Ok(client.reload(context::current()).await??)
     })
 }
 
+/// Rescan desktop entries, bypassing the cache. Returns the number of
+/// applications found.
+pub fn rescan() -> Result<usize, IpcClientError> {
+    run_async(async {
+        let client = connect().await?;
+        Ok(client.rescan(context::current()).await??)
+    })
+}
+
 /// List all available themes.
-pub fn list_themes() -> anyhow::Result<Vec<ThemeInfo>> {
+pub fn list_themes() -> Result<Vec<ThemeInfo>, IpcClientError> {
     run_async(async {
         let client = connect().await?;
         Ok(client.list_themes(context::current()).await?)
@@ -76,7 +126,7 @@ pub fn list_themes() -> anyhow::Result<Vec<ThemeInfo>> {
 }
 
 /// Get the current theme name.
-pub fn get_current_theme() -> anyhow::Result<String> {
+pub fn get_current_theme() -> Result<String, IpcClientError> {
     run_async(async {
         let client = connect().await?;
         Ok(client.get_current_theme(context::current()).await?)
@@ -84,7 +134,7 @@ pub fn get_current_theme() -> anyhow::Result<String> {
 }
 
 /// Set the active theme.
-pub fn set_theme(name: &str) -> anyhow::Result<()> {
+pub fn set_theme(name: &str) -> Result<(), IpcClientError> {
     let name = name.to_string();
     run_async(async {
         let client = connect().await?;
@@ -92,13 +142,134 @@ pub fn set_theme(name: &str) -> anyhow::Result<()> {
     })
 }
 
+/// Get the modules shown in the combined view.
+pub fn get_combined_modules() -> Result<Vec<ConfigModule>, IpcClientError> {
+    run_async(async {
+        let client = connect().await?;
+        Ok(client.get_combined_modules(context::current()).await?)
+    })
+}
+
+/// Set the modules shown in the combined view, in order.
+pub fn set_combined_modules(modules: Vec<ConfigModule>) -> Result<(), IpcClientError> {
+    run_async(async {
+        let client = connect().await?;
+        Ok(client
+            .set_combined_modules(context::current(), modules)
+            .await??)
+    })
+}
+
+/// Pin an application to the top of the combined view.
+pub fn pin_application(app_id: &str) -> Result<(), IpcClientError> {
+    let app_id = app_id.to_string();
+    run_async(async {
+        let client = connect().await?;
+        Ok(client.pin_application(context::current(), app_id).await??)
+    })
+}
+
+/// Get the daemon's health status.
+pub fn status() -> Result<DaemonStatus, IpcClientError> {
+    run_async(async {
+        let client = connect().await?;
+        Ok(client.status(context::current()).await?)
+    })
+}
+
+/// Open a URL through the daemon.
+pub fn open_url(url: &str) -> Result<(), IpcClientError> {
+    let url = url.to_string();
+    run_async(async {
+        let client = connect().await?;
+        Ok(client.open_url(context::current(), url).await??)
+    })
+}
+
+/// Open a file through the daemon.
+///
+/// Relative paths are resolved against the client's working directory,
+/// since the daemon's differs.
+pub fn open_file(path: &Path) -> Result<(), IpcClientError> {
+    let path = std::path::absolute(path).map_err(IpcClientError::InvalidPath)?;
+    run_async(async {
+        let client = connect().await?;
+        Ok(client.open_file(context::current(), path).await??)
+    })
+}
+
+/// Get the daemon's version.
+pub fn version() -> Result<String, IpcClientError> {
+    run_async(async {
+        let client = connect().await?;
+        Ok(client.version(context::current()).await?)
+    })
+}
+
+/// Describe a version mismatch between this client and the daemon, if any.
+pub fn version_mismatch(daemon_version: &str) -> Option<String> {
+    let client_version = env!("CARGO_PKG_VERSION");
+    (daemon_version != client_version).then(|| {
+        format!(
+            "zlaunch daemon is version {} but this client is {}. Restart the daemon with: zlaunch reload",
+            daemon_version, client_version
+        )
+    })
+}
+
+/// Warn on stderr if the running daemon is a different version.
+///
+/// Daemons predating the version RPC can't answer; that is reported too.
+pub fn warn_on_version_mismatch() {
+    match version() {
+        Ok(daemon_version) => {
+            if let Some(warning) = version_mismatch(&daemon_version) {
+                eprintln!("Warning: {}", warning);
+            }
+        }
+        Err(_) => {
+            eprintln!(
+                "Warning: could not determine the zlaunch daemon version; it may be outdated. Restart it with: zlaunch reload"
+            );
+        }
+    }
+}
+
 /// Run an async operation synchronously using a temporary tokio runtime.
-fn run_async<F, T>(future: F) -> anyhow::Result<T>
+fn run_async<F, T>(future: F) -> Result<T, IpcClientError>
 where
-    F: std::future::Future<Output = anyhow::Result<T>>,
+    F: std::future::Future<Output = Result<T, IpcClientError>>,
 {
     let rt = tokio::runtime::Builder::new_current_thread()
         .enable_all()
-        .build()?;
+        .build()
+        .map_err(IpcClientError::Runtime)?;
     rt.block_on(future)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_version_is_not_a_mismatch() {
+        assert!(version_mismatch(env!("CARGO_PKG_VERSION")).is_none());
+    }
+
+    #[test]
+    fn test_version_mismatch_mentions_both_versions() {
+        let warning = version_mismatch("0.0.1").unwrap();
+        assert!(warning.contains("0.0.1"));
+        assert!(warning.contains(env!("CARGO_PKG_VERSION")));
+    }
+
+    #[test]
+    fn test_missing_socket_reports_daemon_not_running() {
+        let socket_path =
+            std::env::temp_dir().join(format!("zlaunch-test-missing-{}.sock", std::process::id()));
+        let error = run_async(async { connect_to(&socket_path).await.map(|_| ()) }).unwrap_err();
+
+        assert!(matches!(error, IpcClientError::DaemonNotRunning { .. }));
+        assert!(error.to_string().contains("daemon is not running"));
+    }
+}
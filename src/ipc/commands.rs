@@ -1,8 +1,10 @@
 //! tarpc service definition for IPC communication.
 
-use crate::config::LauncherMode;
+use crate::clipboard::monitor::MonitorStatus;
+use crate::config::{ConfigModule, LauncherMode};
 use crate::error::IpcError;
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 /// Theme information returned by the IPC service.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,12 +15,28 @@ pub struct ThemeInfo {
     pub is_bundled: bool,
 }
 
+/// Daemon health information returned by the IPC service.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DaemonStatus {
+    /// State of the clipboard history monitor.
+    pub clipboard_monitor: MonitorStatus,
+}
+
 /// The zlaunch RPC service definition.
 #[tarpc::service]
 pub trait ZlaunchService {
     /// Show the launcher window with optional modes.
     async fn show(modes: Option<Vec<LauncherMode>>) -> Result<(), IpcError>;
 
+    /// Show the launcher window with the search input filled in with `text`,
+    /// filtered as if it was typed.
+    async fn open_with_query(text: String) -> Result<(), IpcError>;
+
+    /// Show `lines` in the launcher to choose from, replacing the prompt
+    /// placeholder with `prompt`. Answers once a line is chosen, or with
+    /// None when the launcher is closed without one.
+    async fn dmenu(lines: Vec<String>, prompt: Option<String>) -> Result<Option<String>, IpcError>;
+
     /// Hide the launcher window.
     async fn hide() -> Result<(), IpcError>;
 
@@ -31,6 +49,10 @@ pub trait ZlaunchService {
     /// Reload the daemon (fully restart the process).
     async fn reload() -> Result<(), IpcError>;
 
+    /// Discard the application cache and rescan desktop entries.
+    /// Returns the number of applications found.
+    async fn rescan() -> Result<usize, IpcError>;
+
     /// List all available themes.
     async fn list_themes() -> Vec<ThemeInfo>;
 
@@ -40,4 +62,28 @@ pub trait ZlaunchService {
     /// Set the active theme by name.
     /// Returns Ok(()) if successful, Err with IpcError if theme not found.
     async fn set_theme(name: String) -> Result<(), IpcError>;
+
+    /// Get the modules shown in the combined view, in order.
+    async fn get_combined_modules() -> Vec<ConfigModule>;
+
+    /// Set the modules shown in the combined view, in order.
+    /// Returns Err with IpcError if the list is empty or has duplicates.
+    async fn set_combined_modules(modules: Vec<ConfigModule>) -> Result<(), IpcError>;
+
+    /// Pin an application (desktop entry id) to the top of the combined view.
+    /// Returns Err with IpcError if the id is empty.
+    async fn pin_application(app_id: String) -> Result<(), IpcError>;
+
+    /// Get the daemon's health status.
+    async fn status() -> DaemonStatus;
+
+    /// Open a URL with the default handler, spawned by the daemon.
+    async fn open_url(url: String) -> Result<(), IpcError>;
+
+    /// Open a file (absolute path) with its default application, spawned
+    /// by the daemon.
+    async fn open_file(path: PathBuf) -> Result<(), IpcError>;
+
+    /// Get the daemon's version (`CARGO_PKG_VERSION`).
+    async fn version() -> String;
 }
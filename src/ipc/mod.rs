@@ -3,6 +3,7 @@
 pub mod client;
 pub mod commands;
 pub mod server;
+pub mod stdio;
 
-pub use commands::{ThemeInfo, ZlaunchServiceClient};
+pub use commands::{DaemonStatus, ThemeInfo, ZlaunchServiceClient};
 pub use server::{IpcServerHandle, get_socket_path, prepare_socket, start_server};
@@ -1,10 +1,11 @@
 //! tarpc server implementation for the IPC daemon.
 
 use crate::app::DaemonEvent;
-use crate::config::LauncherMode;
+use crate::config::profile::{active_profile, socket_file_name};
+use crate::config::{ConfigModule, LauncherMode};
 use crate::error::IpcError;
-use crate::ipc::commands::{ThemeInfo, ZlaunchService};
-use crate::items::ThemeSource;
+use crate::ipc::commands::{DaemonStatus, ThemeInfo, ZlaunchService};
+use crate::items::{ApplicationItem, ThemeSource};
 use futures::prelude::*;
 use std::path::PathBuf;
 use tarpc::context::Context;
@@ -32,11 +33,14 @@ impl Drop for IpcServerHandle {
 }
 
 /// Get the socket path for the IPC server.
+///
+/// Each config profile gets its own socket so daemons for different profiles
+/// can run side by side.
 pub fn get_socket_path() -> PathBuf {
     std::env::var("XDG_RUNTIME_DIR")
         .map(PathBuf::from)
         .unwrap_or_else(|_| PathBuf::from("/tmp"))
-        .join("zlaunch.sock")
+        .join(socket_file_name(active_profile()))
 }
 
 /// Check if another daemon instance is running.
@@ -60,6 +64,34 @@ impl ZlaunchService for ZlaunchServer {
         response_rx.await.unwrap_or(Err(IpcError::ResponseClosed))
     }
 
+    async fn open_with_query(self, _: Context, text: String) -> Result<(), IpcError> {
+        let (response_tx, response_rx) = oneshot::channel();
+        self.event_tx
+            .send(DaemonEvent::OpenWithQuery {
+                query: text,
+                response_tx,
+            })
+            .map_err(|_| IpcError::ChannelClosed)?;
+        response_rx.await.unwrap_or(Err(IpcError::ResponseClosed))
+    }
+
+    async fn dmenu(
+        self,
+        _: Context,
+        lines: Vec<String>,
+        prompt: Option<String>,
+    ) -> Result<Option<String>, IpcError> {
+        let (response_tx, response_rx) = oneshot::channel();
+        self.event_tx
+            .send(DaemonEvent::Dmenu {
+                lines,
+                prompt,
+                response_tx,
+            })
+            .map_err(|_| IpcError::ChannelClosed)?;
+        response_rx.await.unwrap_or(Err(IpcError::ResponseClosed))
+    }
+
     async fn hide(self, _: Context) -> Result<(), IpcError> {
         let (response_tx, response_rx) = oneshot::channel();
         self.event_tx
@@ -92,6 +124,25 @@ impl ZlaunchService for ZlaunchServer {
         response_rx.await.unwrap_or(Err(IpcError::ResponseClosed))
     }
 
+    async fn rescan(self, _: Context) -> Result<usize, IpcError> {
+        // Scan off the async runtime, like the file watcher does
+        let applications: Vec<ApplicationItem> = tokio::task::spawn_blocking(|| {
+            crate::desktop::rescan_applications()
+                .into_iter()
+                .map(Into::into)
+                .collect()
+        })
+        .await
+        .map_err(|e| IpcError::Internal(format!("Rescan failed: {}", e)))?;
+
+        let count = applications.len();
+        tracing::info!("Rescanned {} applications", count);
+        self.event_tx
+            .send(DaemonEvent::ApplicationsChanged { applications })
+            .map_err(|_| IpcError::ChannelClosed)?;
+        Ok(count)
+    }
+
     async fn list_themes(self, _: Context) -> Vec<ThemeInfo> {
         // Read-only operation - can be answered directly
         crate::config::list_all_themes_with_source()
@@ -115,6 +166,75 @@ impl ZlaunchService for ZlaunchServer {
             .map_err(|_| IpcError::ChannelClosed)?;
         response_rx.await.unwrap_or(Err(IpcError::ResponseClosed))
     }
+
+    async fn get_combined_modules(self, _: Context) -> Vec<ConfigModule> {
+        // Read-only operation - can be answered directly
+        crate::config::get_combined_modules()
+    }
+
+    async fn set_combined_modules(
+        self,
+        _: Context,
+        modules: Vec<ConfigModule>,
+    ) -> Result<(), IpcError> {
+        let (response_tx, response_rx) = oneshot::channel();
+        self.event_tx
+            .send(DaemonEvent::SetCombinedModules {
+                modules,
+                response_tx,
+            })
+            .map_err(|_| IpcError::ChannelClosed)?;
+        response_rx.await.unwrap_or(Err(IpcError::ResponseClosed))
+    }
+
+    async fn pin_application(self, _: Context, app_id: String) -> Result<(), IpcError> {
+        let (response_tx, response_rx) = oneshot::channel();
+        self.event_tx
+            .send(DaemonEvent::PinApplication {
+                app_id,
+                response_tx,
+            })
+            .map_err(|_| IpcError::ChannelClosed)?;
+        response_rx.await.unwrap_or(Err(IpcError::ResponseClosed))
+    }
+
+    async fn status(self, _: Context) -> DaemonStatus {
+        // Read-only operation - can be answered directly
+        DaemonStatus {
+            clipboard_monitor: crate::clipboard::monitor::monitor_status(),
+        }
+    }
+
+    async fn open_url(self, _: Context, url: String) -> Result<(), IpcError> {
+        let url = url.trim();
+        if !crate::process::has_url_scheme(url) {
+            return Err(IpcError::InvalidArgument(format!(
+                "'{}' is not a URL (missing scheme such as https://)",
+                url
+            )));
+        }
+        crate::process::open_url(url).map_err(|e| IpcError::LaunchFailed(e.to_string()))
+    }
+
+    async fn open_file(self, _: Context, path: PathBuf) -> Result<(), IpcError> {
+        if !path.is_absolute() {
+            return Err(IpcError::InvalidArgument(format!(
+                "'{}' is not an absolute path",
+                path.display()
+            )));
+        }
+        if !path.exists() {
+            return Err(IpcError::InvalidArgument(format!(
+                "'{}' does not exist",
+                path.display()
+            )));
+        }
+        crate::process::open_path(&path).map_err(|e| IpcError::LaunchFailed(e.to_string()))
+    }
+
+    async fn version(self, _: Context) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
 }
 
 /// Prepare the IPC socket, checking for existing instances.
@@ -0,0 +1,455 @@
+//! JSON-RPC over stdio for editor and bar plugins.
+//!
+//! `zlaunch rpc-stdio` reads one JSON request per line from stdin, forwards
+//! it to the daemon and writes one JSON response per line to stdout, so
+//! integrations don't need to speak tarpc over the Unix socket.
+//!
+//! # Messages
+//!
+//! Requests follow JSON-RPC 2.0; `jsonrpc` may be omitted and `params` may
+//! be omitted when a method takes none:
+//!
+//! ```json
+//! {"jsonrpc": "2.0", "id": 1, "method": "set_theme", "params": {"name": "nord"}}
+//! ```
+//!
+//! Every request gets exactly one response, echoing its `id` (`null` if the
+//! request had none), with either a `result` or an `error`:
+//!
+//! ```json
+//! {"jsonrpc": "2.0", "id": 1, "result": null}
+//! {"jsonrpc": "2.0", "id": 2, "error": {"code": -32000, "message": "Theme 'nrod' not found"}}
+//! ```
+//!
+//! # Methods
+//!
+//! | Method                 | Params                                   | Result                         |
+//! |------------------------|------------------------------------------|--------------------------------|
+//! | `show`                 | `{"modes"?: [mode]}`                     | `null`                         |
+//! | `open_with_query`      | `{"text": string}`                       | `null`                         |
+//! | `dmenu`                | `{"lines": [string], "prompt"?: string}` | chosen line or `null`          |
+//! | `hide`                 |                                          | `null`                         |
+//! | `toggle`               | `{"modes"?: [mode]}`                     | `null`                         |
+//! | `quit`                 |                                          | `null`                         |
+//! | `reload`               |                                          | `null`                         |
+//! | `rescan`               |                                          | number of applications         |
+//! | `list_themes`          |                                          | `[{"name", "is_bundled"}]`     |
+//! | `get_current_theme`    |                                          | theme name                     |
+//! | `set_theme`            | `{"name": string}`                       | `null`                         |
+//! | `get_combined_modules` |                                          | `[module]`                     |
+//! | `set_combined_modules` | `{"modules": [module]}`                  | `null`                         |
+//! | `pin_application`      | `{"app_id": string}`                     | `null`                         |
+//! | `status`               |                                          | `{"clipboard_monitor": {...}}` |
+//! | `open_url`             | `{"url": string}`                        | `null`                         |
+//! | `open_file`            | `{"path": string}`                       | `null`                         |
+//! | `version`              |                                          | daemon version                 |
+//!
+//! `dmenu` only responds once a line was chosen or the launcher was closed,
+//! so later requests wait until then.
+//!
+//! Modes and modules are lowercase names as in the config file, e.g.
+//! `"combined"`, `"emojis"` or `"applications"`.
+//!
+//! # Errors
+//!
+//! | Code     | Meaning                                             |
+//! |----------|-----------------------------------------------------|
+//! | `-32700` | The line is not a valid JSON request                |
+//! | `-32601` | Unknown method                                      |
+//! | `-32602` | Missing or invalid params                           |
+//! | `-32000` | The daemon rejected the request                     |
+//! | `-32001` | The daemon is unreachable (retried on next request) |
+
+use crate::config::{ConfigModule, LauncherMode};
+use crate::error::IpcClientError;
+use crate::ipc::client::{connect, dmenu_context};
+use crate::ipc::commands::ZlaunchServiceClient;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+use tarpc::context;
+
+/// The line is not a valid JSON request.
+const PARSE_ERROR: i64 = -32700;
+/// The method does not exist.
+const METHOD_NOT_FOUND: i64 = -32601;
+/// The params don't match the method.
+const INVALID_PARAMS: i64 = -32602;
+/// The daemon returned an error.
+const DAEMON_ERROR: i64 = -32000;
+/// The daemon could not be reached.
+const CONNECTION_ERROR: i64 = -32001;
+
+/// A request line.
+#[derive(Debug, Deserialize)]
+struct Request {
+    #[serde(default)]
+    id: Value,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+/// A response line.
+#[derive(Debug, Serialize)]
+struct Response {
+    jsonrpc: &'static str,
+    id: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<RpcError>,
+}
+
+impl Response {
+    fn new(id: Value, outcome: Result<Value, RpcError>) -> Self {
+        let (result, error) = match outcome {
+            Ok(result) => (Some(result), None),
+            Err(error) => (None, Some(error)),
+        };
+        Self {
+            jsonrpc: "2.0",
+            id,
+            result,
+            error,
+        }
+    }
+}
+
+/// The error object of a failed request.
+#[derive(Debug, Serialize, PartialEq, Eq)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<IpcClientError> for RpcError {
+    fn from(error: IpcClientError) -> Self {
+        let code = match error {
+            IpcClientError::Daemon(_) => DAEMON_ERROR,
+            _ => CONNECTION_ERROR,
+        };
+        Self::new(code, error.to_string())
+    }
+}
+
+/// A validated call to one of the service methods.
+#[derive(Debug, PartialEq)]
+enum Call {
+    Show(Option<Vec<LauncherMode>>),
+    OpenWithQuery(String),
+    Dmenu(Vec<String>, Option<String>),
+    Hide,
+    Toggle(Option<Vec<LauncherMode>>),
+    Quit,
+    Reload,
+    Rescan,
+    ListThemes,
+    GetCurrentTheme,
+    SetTheme(String),
+    GetCombinedModules,
+    SetCombinedModules(Vec<ConfigModule>),
+    PinApplication(String),
+    Status,
+    OpenUrl(String),
+    OpenFile(PathBuf),
+    Version,
+}
+
+#[derive(Deserialize)]
+struct ModesParams {
+    modes: Option<Vec<LauncherMode>>,
+}
+
+#[derive(Deserialize)]
+struct TextParams {
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct DmenuParams {
+    lines: Vec<String>,
+    prompt: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct NameParams {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct ModulesParams {
+    modules: Vec<ConfigModule>,
+}
+
+#[derive(Deserialize)]
+struct AppIdParams {
+    app_id: String,
+}
+
+#[derive(Deserialize)]
+struct UrlParams {
+    url: String,
+}
+
+#[derive(Deserialize)]
+struct PathParams {
+    path: PathBuf,
+}
+
+impl Call {
+    /// Resolve a method name and its params.
+    fn parse(method: &str, params: Value) -> Result<Self, RpcError> {
+        Ok(match method {
+            "show" => Call::Show(parse_params::<ModesParams>(params)?.modes),
+            "open_with_query" => Call::OpenWithQuery(parse_params::<TextParams>(params)?.text),
+            "dmenu" => {
+                let params = parse_params::<DmenuParams>(params)?;
+                Call::Dmenu(params.lines, params.prompt)
+            }
+            "hide" => Call::Hide,
+            "toggle" => Call::Toggle(parse_params::<ModesParams>(params)?.modes),
+            "quit" => Call::Quit,
+            "reload" => Call::Reload,
+            "rescan" => Call::Rescan,
+            "list_themes" => Call::ListThemes,
+            "get_current_theme" => Call::GetCurrentTheme,
+            "set_theme" => Call::SetTheme(parse_params::<NameParams>(params)?.name),
+            "get_combined_modules" => Call::GetCombinedModules,
+            "set_combined_modules" => {
+                Call::SetCombinedModules(parse_params::<ModulesParams>(params)?.modules)
+            }
+            "pin_application" => Call::PinApplication(parse_params::<AppIdParams>(params)?.app_id),
+            "status" => Call::Status,
+            "open_url" => Call::OpenUrl(parse_params::<UrlParams>(params)?.url),
+            "open_file" => Call::OpenFile(parse_params::<PathParams>(params)?.path),
+            "version" => Call::Version,
+            _ => {
+                return Err(RpcError::new(
+                    METHOD_NOT_FOUND,
+                    format!("Unknown method '{}'", method),
+                ));
+            }
+        })
+    }
+
+    /// Send the call to the daemon and encode its result.
+    async fn execute(self, client: &ZlaunchServiceClient) -> Result<Value, IpcClientError> {
+        let ctx = context::current();
+        let result = match self {
+            Call::Show(modes) => json(client.show(ctx, modes).await??),
+            Call::OpenWithQuery(text) => json(client.open_with_query(ctx, text).await??),
+            Call::Dmenu(lines, prompt) => {
+                json(client.dmenu(dmenu_context(), lines, prompt).await??)
+            }
+            Call::Hide => json(client.hide(ctx).await??),
+            Call::Toggle(modes) => json(client.toggle(ctx, modes).await??),
+            Call::Quit => json(client.quit(ctx).await??),
+            Call::Reload => json(client.reload(ctx).await??),
+            Call::Rescan => json(client.rescan(ctx).await??),
+            Call::ListThemes => json(client.list_themes(ctx).await?),
+            Call::GetCurrentTheme => json(client.get_current_theme(ctx).await?),
+            Call::SetTheme(name) => json(client.set_theme(ctx, name).await??),
+            Call::GetCombinedModules => json(client.get_combined_modules(ctx).await?),
+            Call::SetCombinedModules(modules) => {
+                json(client.set_combined_modules(ctx, modules).await??)
+            }
+            Call::PinApplication(app_id) => json(client.pin_application(ctx, app_id).await??),
+            Call::Status => json(client.status(ctx).await?),
+            Call::OpenUrl(url) => json(client.open_url(ctx, url).await??),
+            Call::OpenFile(path) => json(client.open_file(ctx, path).await??),
+            Call::Version => json(client.version(ctx).await?),
+        };
+        Ok(result)
+    }
+}
+
+/// Decode method params; omitted params count as an empty object.
+fn parse_params<T: DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+    let params = if params.is_null() {
+        Value::Object(Default::default())
+    } else {
+        params
+    };
+    serde_json::from_value(params)
+        .map_err(|e| RpcError::new(INVALID_PARAMS, format!("Invalid params: {}", e)))
+}
+
+/// Encode a result value.
+fn json(value: impl Serialize) -> Value {
+    serde_json::to_value(value).unwrap_or(Value::Null)
+}
+
+/// Serve requests from stdin until it is closed.
+///
+/// The daemon connection is opened on the first request and reopened after
+/// it drops, e.g. when the daemon reloads.
+pub fn run() -> anyhow::Result<()> {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+    let mut client: Option<ZlaunchServiceClient> = None;
+    let mut stdout = std::io::stdout().lock();
+
+    for line in std::io::stdin().lock().lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = match serde_json::from_str::<Request>(&line) {
+            Ok(request) => {
+                let outcome = match Call::parse(&request.method, request.params) {
+                    Ok(call) => rt.block_on(send(call, &mut client)),
+                    Err(error) => Err(error),
+                };
+                Response::new(request.id, outcome)
+            }
+            Err(e) => Response::new(
+                Value::Null,
+                Err(RpcError::new(
+                    PARSE_ERROR,
+                    format!("Invalid request: {}", e),
+                )),
+            ),
+        };
+
+        serde_json::to_writer(&mut stdout, &response)?;
+        writeln!(stdout)?;
+        stdout.flush()?;
+    }
+
+    Ok(())
+}
+
+/// Send a call, connecting first if needed.
+async fn send(call: Call, client: &mut Option<ZlaunchServiceClient>) -> Result<Value, RpcError> {
+    let connected = match client.take() {
+        Some(connected) => connected,
+        None => connect().await?,
+    };
+
+    let result = call.execute(&connected).await;
+    // Keep the connection unless it broke; the next request reconnects
+    if matches!(result, Ok(_) | Err(IpcClientError::Daemon(_))) {
+        *client = Some(connected);
+    }
+    result.map_err(RpcError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::IpcError;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_methods_without_params() {
+        assert_eq!(Call::parse("hide", Value::Null), Ok(Call::Hide));
+        assert_eq!(Call::parse("show", Value::Null), Ok(Call::Show(None)));
+        assert_eq!(Call::parse("version", json!({})), Ok(Call::Version));
+    }
+
+    #[test]
+    fn test_parse_methods_with_params() {
+        assert_eq!(
+            Call::parse("toggle", json!({"modes": ["emojis"]})),
+            Ok(Call::Toggle(Some(vec![LauncherMode::Emojis])))
+        );
+        assert_eq!(
+            Call::parse("set_theme", json!({"name": "nord"})),
+            Ok(Call::SetTheme("nord".to_string()))
+        );
+        assert_eq!(
+            Call::parse(
+                "set_combined_modules",
+                json!({"modules": ["windows", "applications"]})
+            ),
+            Ok(Call::SetCombinedModules(vec![
+                ConfigModule::Windows,
+                ConfigModule::Applications
+            ]))
+        );
+        assert_eq!(
+            Call::parse("pin_application", json!({"app_id": "firefox"})),
+            Ok(Call::PinApplication("firefox".to_string()))
+        );
+        assert_eq!(
+            Call::parse("open_with_query", json!({"text": "2+2"})),
+            Ok(Call::OpenWithQuery("2+2".to_string()))
+        );
+        assert_eq!(
+            Call::parse("dmenu", json!({"lines": ["a", "b"], "prompt": "Pick"})),
+            Ok(Call::Dmenu(
+                vec!["a".to_string(), "b".to_string()],
+                Some("Pick".to_string())
+            ))
+        );
+        assert_eq!(
+            Call::parse("dmenu", json!({"lines": []})),
+            Ok(Call::Dmenu(Vec::new(), None))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Call::parse("launch", Value::Null).unwrap_err().code,
+            METHOD_NOT_FOUND
+        );
+        assert_eq!(
+            Call::parse("set_theme", Value::Null).unwrap_err().code,
+            INVALID_PARAMS
+        );
+        assert_eq!(
+            Call::parse("show", json!({"modes": ["nope"]}))
+                .unwrap_err()
+                .code,
+            INVALID_PARAMS
+        );
+    }
+
+    #[test]
+    fn test_request_defaults() {
+        let request: Request = serde_json::from_str(r#"{"method": "hide"}"#).unwrap();
+        assert_eq!(request.id, Value::Null);
+        assert_eq!(request.params, Value::Null);
+    }
+
+    #[test]
+    fn test_response_encoding() {
+        let ok = Response::new(json!(1), Ok(Value::Null));
+        assert_eq!(
+            serde_json::to_value(&ok).unwrap(),
+            json!({"jsonrpc": "2.0", "id": 1, "result": null})
+        );
+
+        let error = IpcClientError::from(IpcError::ThemeNotFound("nrod".to_string()));
+        let failed = Response::new(json!("a"), Err(RpcError::from(error)));
+        assert_eq!(
+            serde_json::to_value(&failed).unwrap(),
+            json!({
+                "jsonrpc": "2.0",
+                "id": "a",
+                "error": {"code": DAEMON_ERROR, "message": "Theme 'nrod' not found"}
+            })
+        );
+    }
+
+    #[test]
+    fn test_connection_errors_are_distinguished() {
+        let error = RpcError::from(IpcClientError::Disconnected);
+        assert_eq!(error.code, CONNECTION_ERROR);
+    }
+}
@@ -1,6 +1,8 @@
+use std::collections::HashSet;
 use std::process::Command;
 
 use super::traits::{Categorizable, DisplayItem, Executable, IconProvider};
+use crate::config::ConfigCustomAction;
 use crate::process;
 
 /// The kind of action to perform.
@@ -20,6 +22,22 @@ pub enum ActionKind {
     Command(String),
 }
 
+impl ActionKind {
+    /// Get the program and arguments a built-in action runs.
+    ///
+    /// Returns None for custom commands, which run through the shell.
+    fn builtin_command(&self) -> Option<&'static [&'static str]> {
+        match self {
+            Self::Shutdown => Some(&["systemctl", "poweroff"]),
+            Self::Reboot => Some(&["systemctl", "reboot"]),
+            Self::Suspend => Some(&["systemctl", "suspend"]),
+            Self::Lock => Some(&["loginctl", "lock-session"]),
+            Self::Logout => Some(&["loginctl", "terminate-session", "self"]),
+            Self::Command(_) => None,
+        }
+    }
+}
+
 /// An action item representing a functional command (shutdown, reboot, etc.).
 #[derive(Clone, Debug)]
 pub struct ActionItem {
@@ -80,6 +98,48 @@ impl ActionItem {
         }
     }
 
+    /// Create an action item from a `[[custom_actions]]` config entry.
+    ///
+    /// The id is derived from the name, so icon overrides and learned
+    /// selections survive edits to the command.
+    pub fn custom(action: &ConfigCustomAction) -> Self {
+        Self {
+            id: format!("action-custom-{}", action.slug()),
+            name: action.name.clone(),
+            description: action.description.clone(),
+            icon_name: Some(action.icon.as_deref().unwrap_or("terminal").to_string()),
+            kind: ActionKind::Command(action.command.clone()),
+        }
+    }
+
+    /// Create the action items of all `[[custom_actions]]` config entries.
+    ///
+    /// Names that differ only in case or punctuation give the same id, so
+    /// a later one gets its position in the list appended.
+    pub fn customs(actions: &[ConfigCustomAction]) -> Vec<Self> {
+        let mut ids = HashSet::new();
+        actions
+            .iter()
+            .enumerate()
+            .map(|(index, action)| {
+                let mut item = Self::custom(action);
+                if !ids.insert(item.id.clone()) {
+                    item.id = format!("{}-{}", item.id, index);
+                    ids.insert(item.id.clone());
+                }
+                item
+            })
+            .collect()
+    }
+
+    /// Get the command this action runs, as a shell command line.
+    pub fn command_line(&self) -> String {
+        match &self.kind {
+            ActionKind::Command(cmd) => cmd.clone(),
+            builtin => builtin.builtin_command().unwrap_or_default().join(" "),
+        }
+    }
+
     /// Get all built-in action items.
     pub fn builtins() -> Vec<Self> {
         vec![
@@ -119,27 +179,15 @@ impl IconProvider for ActionItem {
 impl Executable for ActionItem {
     fn execute(&self) -> anyhow::Result<()> {
         match &self.kind {
-            ActionKind::Shutdown => {
-                Command::new("systemctl").arg("poweroff").spawn()?;
-            }
-            ActionKind::Reboot => {
-                Command::new("systemctl").arg("reboot").spawn()?;
-            }
-            ActionKind::Suspend => {
-                Command::new("systemctl").arg("suspend").spawn()?;
-            }
-            ActionKind::Lock => {
-                Command::new("loginctl").arg("lock-session").spawn()?;
-            }
-            ActionKind::Logout => {
-                Command::new("loginctl")
-                    .args(["terminate-session", "self"])
-                    .spawn()?;
-            }
             ActionKind::Command(cmd) => {
                 // Custom commands should be disowned from daemon
                 process::run_shell_command(cmd)?;
             }
+            builtin => {
+                if let Some([program, args @ ..]) = builtin.builtin_command() {
+                    Command::new(program).args(args).spawn()?;
+                }
+            }
         }
         Ok(())
     }
@@ -160,3 +208,51 @@ impl From<ActionItem> for super::ListItem {
         Self::Action(item)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_action() {
+        let action = ActionItem::custom(&ConfigCustomAction {
+            name: "Toggle VPN (wg0)".to_string(),
+            description: None,
+            icon: None,
+            command: "wg-toggle wg0".to_string(),
+        });
+        assert_eq!(action.id, "action-custom-toggle-vpn-wg0");
+        assert_eq!(action.icon_name(), Some("terminal"));
+        assert_eq!(action.command_line(), "wg-toggle wg0");
+        assert_eq!(action.section_name(), "Commands");
+    }
+
+    #[test]
+    fn test_custom_action_ids_are_unique() {
+        let action = |name: &str| ConfigCustomAction {
+            name: name.to_string(),
+            description: None,
+            icon: None,
+            command: "true".to_string(),
+        };
+        let actions = ActionItem::customs(&[action("VPN on"), action("VPN-On"), action("Lock")]);
+        let ids: Vec<_> = actions.iter().map(|a| a.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            [
+                "action-custom-vpn-on",
+                "action-custom-vpn-on-1",
+                "action-custom-lock"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_builtin_command_line() {
+        let logout = ActionItem::builtin(ActionKind::Logout);
+        assert_eq!(logout.command_line(), "loginctl terminate-session self");
+        for action in ActionItem::builtins() {
+            assert!(action.kind.builtin_command().is_some());
+        }
+    }
+}
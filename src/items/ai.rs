@@ -63,3 +63,66 @@ impl From<AiItem> for super::ListItem {
         Self::Ai(item)
     }
 }
+
+/// An answer streamed into the AI item's row, with `ai_answer_inline`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InlineAnswer {
+    /// The query being answered
+    pub query: String,
+    /// The answer text received so far
+    pub text: String,
+    /// Whether more text is expected
+    pub streaming: bool,
+    /// Error message if the request failed
+    pub error: Option<String>,
+}
+
+impl InlineAnswer {
+    /// Create an empty answer for a query that is being streamed.
+    pub fn new(query: String) -> Self {
+        Self {
+            query,
+            streaming: true,
+            ..Default::default()
+        }
+    }
+
+    /// Apply a message from [`crate::ai::spawn_stream`].
+    pub fn apply(&mut self, msg: Result<String, String>) {
+        match msg {
+            // An empty token marks the end of the stream
+            Ok(token) if token.is_empty() => self.streaming = false,
+            Ok(token) => self.text.push_str(&token),
+            Err(error) => {
+                self.error = Some(error);
+                self.streaming = false;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inline_answer_streams_tokens() {
+        let mut answer = InlineAnswer::new("capital of france".to_string());
+        answer.apply(Ok("Paris".to_string()));
+        answer.apply(Ok(" is it.".to_string()));
+        assert!(answer.streaming);
+
+        answer.apply(Ok(String::new()));
+        assert_eq!(answer.text, "Paris is it.");
+        assert!(!answer.streaming);
+        assert_eq!(answer.error, None);
+    }
+
+    #[test]
+    fn test_inline_answer_error_ends_stream() {
+        let mut answer = InlineAnswer::new("hi".to_string());
+        answer.apply(Err("Failed to connect".to_string()));
+        assert_eq!(answer.error.as_deref(), Some("Failed to connect"));
+        assert!(!answer.streaming);
+    }
+}
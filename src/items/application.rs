@@ -1,4 +1,4 @@
-use crate::desktop::DesktopEntry;
+use crate::desktop::{DesktopAction, DesktopEntry, EntrySource};
 use std::path::PathBuf;
 
 use super::traits::{Categorizable, DisplayItem, Executable, IconProvider};
@@ -13,6 +13,18 @@ pub struct ApplicationItem {
     pub description: Option<String>,
     pub terminal: bool,
     pub desktop_path: PathBuf,
+    /// Desktop entry categories (e.g. "Development", "Game").
+    pub categories: Vec<String>,
+    /// Window class of the application's windows (`StartupWMClass`).
+    pub startup_wm_class: Option<String>,
+    /// Whether the application is launched over D-Bus (`DBusActivatable`).
+    pub dbus_activatable: bool,
+    /// Where the desktop entry was installed from.
+    pub source: EntrySource,
+    /// Number of open windows of the application, if counted.
+    pub open_windows: usize,
+    /// Additional launch actions of the desktop entry (`Actions`).
+    pub actions: Vec<DesktopAction>,
 }
 
 impl ApplicationItem {
@@ -33,8 +45,50 @@ impl ApplicationItem {
             description,
             terminal,
             desktop_path,
+            categories: Vec::new(),
+            startup_wm_class: None,
+            dbus_activatable: false,
+            source: EntrySource::Native,
+            open_windows: 0,
+            actions: Vec::new(),
         }
     }
+
+    /// Builder method to set the desktop entry categories.
+    pub fn with_categories(mut self, categories: Vec<String>) -> Self {
+        self.categories = categories;
+        self
+    }
+
+    /// Builder method to set the `StartupWMClass` window class.
+    pub fn with_startup_wm_class(mut self, startup_wm_class: Option<String>) -> Self {
+        self.startup_wm_class = startup_wm_class;
+        self
+    }
+
+    /// Builder method to set whether the application is D-Bus activatable.
+    pub fn with_dbus_activatable(mut self, dbus_activatable: bool) -> Self {
+        self.dbus_activatable = dbus_activatable;
+        self
+    }
+
+    /// Builder method to set where the desktop entry was installed from.
+    pub fn with_source(mut self, source: EntrySource) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Builder method to set the number of open windows.
+    pub fn with_open_windows(mut self, open_windows: usize) -> Self {
+        self.open_windows = open_windows;
+        self
+    }
+
+    /// Builder method to set the additional launch actions.
+    pub fn with_actions(mut self, actions: Vec<DesktopAction>) -> Self {
+        self.actions = actions;
+        self
+    }
 }
 
 impl From<DesktopEntry> for ApplicationItem {
@@ -44,9 +98,15 @@ impl From<DesktopEntry> for ApplicationItem {
             name: entry.name,
             exec: entry.exec,
             icon_path: entry.icon_path,
-            description: entry.comment,
+            description: entry.generic_name.or(entry.comment),
             terminal: entry.terminal,
             desktop_path: entry.path,
+            categories: entry.categories,
+            startup_wm_class: entry.startup_wm_class,
+            dbus_activatable: entry.dbus_activatable,
+            source: entry.source,
+            open_windows: 0,
+            actions: entry.actions,
         }
     }
 }
@@ -58,9 +118,15 @@ impl From<&DesktopEntry> for ApplicationItem {
             name: entry.name.clone(),
             exec: entry.exec.clone(),
             icon_path: entry.icon_path.clone(),
-            description: entry.comment.clone(),
+            description: entry.description().map(str::to_string),
             terminal: entry.terminal,
             desktop_path: entry.path.clone(),
+            categories: entry.categories.clone(),
+            startup_wm_class: entry.startup_wm_class.clone(),
+            dbus_activatable: entry.dbus_activatable,
+            source: entry.source,
+            open_windows: 0,
+            actions: entry.actions.clone(),
         }
     }
 }
@@ -0,0 +1,91 @@
+//! Trailing badges shown at the end of list items.
+
+use super::ApplicationItem;
+use crate::config::BadgeConfig;
+use crate::desktop::EntrySource;
+
+/// A small badge giving context about an item.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Badge {
+    /// Where the application was installed from (e.g. "Flatpak").
+    Source(&'static str),
+    /// The application has an open window.
+    Running,
+    /// The application has this many open windows.
+    WindowCount(usize),
+}
+
+/// Get the badges of an application.
+///
+/// Several open windows show as a count instead of the running dot, unless
+/// counts are disabled.
+pub(super) fn application_badges(app: &ApplicationItem, config: &BadgeConfig) -> Vec<Badge> {
+    let mut badges = Vec::new();
+
+    if config.source && app.source != EntrySource::Native {
+        badges.push(Badge::Source(app.source.label()));
+    }
+
+    if config.window_count && app.open_windows > 1 {
+        badges.push(Badge::WindowCount(app.open_windows));
+    } else if config.running && app.open_windows > 0 {
+        badges.push(Badge::Running);
+    }
+
+    badges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::ListItem;
+    use crate::test_utils::mock_application;
+
+    fn badges(app: ApplicationItem, config: &BadgeConfig) -> Vec<Badge> {
+        ListItem::Application(app).badges(config)
+    }
+
+    #[test]
+    fn test_native_app_without_windows_has_no_badges() {
+        assert!(badges(mock_application("Firefox"), &BadgeConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn test_flatpak_running_app() {
+        let app = mock_application("Firefox")
+            .with_source(EntrySource::Flatpak)
+            .with_open_windows(1);
+        assert_eq!(
+            badges(app, &BadgeConfig::default()),
+            [Badge::Source("Flatpak"), Badge::Running]
+        );
+    }
+
+    #[test]
+    fn test_window_count() {
+        let app = mock_application("Kitty").with_open_windows(3);
+        assert_eq!(
+            badges(app.clone(), &BadgeConfig::default()),
+            [Badge::WindowCount(3)]
+        );
+
+        let config = BadgeConfig {
+            window_count: false,
+            ..BadgeConfig::default()
+        };
+        assert_eq!(badges(app, &config), [Badge::Running]);
+    }
+
+    #[test]
+    fn test_disabled_badges() {
+        let app = mock_application("Firefox")
+            .with_source(EntrySource::Snap)
+            .with_open_windows(2);
+        let config = BadgeConfig {
+            source: false,
+            running: false,
+            window_count: false,
+        };
+        assert!(badges(app, &config).is_empty());
+    }
+}
@@ -14,6 +14,12 @@ pub struct CalculatorItem {
     pub clipboard_result: Option<String>,
     /// Whether this is an error result.
     pub is_error: bool,
+    /// The units of a result with units, e.g. "miles → km" for a
+    /// conversion or "km" for a plain unit calculation.
+    pub unit_label: Option<String>,
+    /// Whether the result was just copied with the launcher kept open,
+    /// shown in its row for a moment.
+    pub copied: bool,
 }
 
 impl CalculatorItem {
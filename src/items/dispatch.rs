@@ -15,11 +15,15 @@ macro_rules! dispatch_item {
         match $self {
             Self::Application(item) => item.$method($($arg),*),
             Self::Window(item) => item.$method($($arg),*),
+            Self::Workspace(item) => item.$method($($arg),*),
             Self::Action(item) => item.$method($($arg),*),
             Self::Submenu(item) => item.$method($($arg),*),
             Self::Calculator(item) => item.$method($($arg),*),
             Self::Search(item) => item.$method($($arg),*),
+            Self::Path(item) => item.$method($($arg),*),
+            Self::File(item) => item.$method($($arg),*),
             Self::Ai(item) => item.$method($($arg),*),
+            Self::Script(item) => item.$method($($arg),*),
             Self::Theme(item) => item.$method($($arg),*),
         }
     };
@@ -0,0 +1,229 @@
+//! Items found by the file search.
+//!
+//! See [`crate::files`] for how the search root is walked.
+
+use crate::assets::PhosphorIcon;
+use crate::process;
+use std::path::{Path, PathBuf};
+use zbus::blocking::{Connection, Proxy};
+
+use super::path::tilde_path;
+use super::traits::{Categorizable, DisplayItem, Executable, IconProvider};
+
+/// D-Bus service of the file manager, for revealing files.
+const FILE_MANAGER_SERVICE: &str = "org.freedesktop.FileManager1";
+
+/// Extensions shown with the image icon.
+const IMAGE_EXTENSIONS: &[&str] = &[
+    "png", "jpg", "jpeg", "gif", "webp", "svg", "bmp", "ico", "avif", "tiff",
+];
+
+/// Extensions shown with the text icon.
+const TEXT_EXTENSIONS: &[&str] = &[
+    "txt", "md", "markdown", "rst", "org", "log", "csv", "json", "toml", "yaml", "yml", "ini",
+    "conf", "pdf", "odt", "doc", "docx",
+];
+
+/// What kind of file an item is, for its icon.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileType {
+    /// A directory.
+    Directory,
+    /// An image, by extension.
+    Image,
+    /// A text document, by extension.
+    Document,
+    /// Any other file.
+    Other,
+}
+
+impl FileType {
+    /// Get the type of `path`, guessing files from their extension.
+    pub fn of(path: &Path, is_dir: bool) -> Self {
+        if is_dir {
+            return Self::Directory;
+        }
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_lowercase)
+            .unwrap_or_default();
+        if IMAGE_EXTENSIONS.contains(&extension.as_str()) {
+            Self::Image
+        } else if TEXT_EXTENSIONS.contains(&extension.as_str()) {
+            Self::Document
+        } else {
+            Self::Other
+        }
+    }
+
+    /// Get the icon for this type.
+    pub fn icon(self) -> PhosphorIcon {
+        match self {
+            Self::Directory => PhosphorIcon::Folder,
+            Self::Image => PhosphorIcon::FileImage,
+            Self::Document => PhosphorIcon::FileText,
+            Self::Other => PhosphorIcon::File,
+        }
+    }
+}
+
+/// A file or folder found by the file search.
+#[derive(Clone, Debug)]
+pub struct FileItem {
+    /// Unique identifier for this item
+    pub id: String,
+    /// The file name
+    pub name: String,
+    /// The containing folder, with the home directory shortened to `~`
+    pub description: String,
+    /// The absolute path
+    pub path: PathBuf,
+    /// What kind of file this is
+    pub file_type: FileType,
+}
+
+impl FileItem {
+    /// Create an item for the file or folder at `path`.
+    pub fn new(path: PathBuf, is_dir: bool) -> Self {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.display().to_string());
+        let description = path.parent().map(tilde_path).unwrap_or_default();
+        Self {
+            id: format!("file-{}", path.display()),
+            name,
+            description,
+            file_type: FileType::of(&path, is_dir),
+            path,
+        }
+    }
+
+    /// Get the icon for this item.
+    pub fn icon(&self) -> PhosphorIcon {
+        self.file_type.icon()
+    }
+
+    /// Show the item selected in the file manager.
+    ///
+    /// Uses `org.freedesktop.FileManager1`, falling back to opening the
+    /// containing folder if no file manager implements it. The D-Bus call
+    /// blocks, so call this off the UI thread.
+    pub fn reveal(&self) -> anyhow::Result<()> {
+        if !process::dry_run_enabled() {
+            match show_items(&[file_uri(&self.path)]) {
+                Ok(()) => return Ok(()),
+                Err(e) => tracing::debug!(%e, "FileManager1 unavailable, opening folder"),
+            }
+        }
+
+        let folder = self.path.parent().unwrap_or(&self.path);
+        process::open_path(folder)?;
+        Ok(())
+    }
+}
+
+/// Ask the file manager to show `uris` selected in their folders.
+fn show_items(uris: &[String]) -> anyhow::Result<()> {
+    let connection = Connection::session()?;
+    let proxy = Proxy::new(
+        &connection,
+        FILE_MANAGER_SERVICE,
+        "/org/freedesktop/FileManager1",
+        FILE_MANAGER_SERVICE,
+    )?;
+    let _: () = proxy.call("ShowItems", &(uris, ""))?;
+    Ok(())
+}
+
+/// Get the `file://` URI of an absolute path.
+fn file_uri(path: &Path) -> String {
+    let encoded: Vec<_> = path
+        .to_string_lossy()
+        .split('/')
+        .map(|segment| urlencoding::encode(segment).into_owned())
+        .collect();
+    format!("file://{}", encoded.join("/"))
+}
+
+impl DisplayItem for FileItem {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> Option<&str> {
+        Some(&self.description)
+    }
+
+    fn action_label(&self) -> &'static str {
+        "Open"
+    }
+}
+
+impl IconProvider for FileItem {
+    // Uses Phosphor icons via icon() method
+}
+
+impl Executable for FileItem {
+    fn execute(&self) -> anyhow::Result<()> {
+        // Folders open in the file manager, files in their MIME handler
+        process::open_path(&self.path)?;
+        Ok(())
+    }
+}
+
+impl Categorizable for FileItem {
+    fn section_name(&self) -> &'static str {
+        "Files"
+    }
+
+    fn sort_priority(&self) -> u8 {
+        1
+    }
+}
+
+impl From<FileItem> for super::ListItem {
+    fn from(item: FileItem) -> Self {
+        Self::File(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_type_from_extension() {
+        for (path, is_dir, expected) in [
+            ("/a/photo.JPG", false, FileType::Image),
+            ("/a/notes.md", false, FileType::Document),
+            ("/a/build.sh", false, FileType::Other),
+            ("/a/Makefile", false, FileType::Other),
+            ("/a/photos.png", true, FileType::Directory),
+        ] {
+            assert_eq!(FileType::of(Path::new(path), is_dir), expected, "{}", path);
+        }
+    }
+
+    #[test]
+    fn test_file_item_names_file_and_folder() {
+        let item = FileItem::new(PathBuf::from("/srv/share/notes.md"), false);
+        assert_eq!(item.name, "notes.md");
+        assert_eq!(item.description, "/srv/share");
+        assert_eq!(item.id, "file-/srv/share/notes.md");
+        assert_eq!(item.icon(), PhosphorIcon::FileText);
+    }
+
+    #[test]
+    fn test_file_uri_encodes_segments() {
+        assert_eq!(
+            file_uri(Path::new("/home/user/My Notes/a#1.md")),
+            "file:///home/user/My%20Notes/a%231.md"
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! Selections learned per query.
+//!
+//! Remembers which item was confirmed for a query, so the same query ranks
+//! it higher next time. The newest selections are kept, stored in the XDG
+//! cache directory next to the recent applications.
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+/// Maximum number of queries remembered.
+pub const LEARNED_SELECTIONS_CAPACITY: usize = 500;
+
+/// Cache file the selections are stored in.
+const FILE_NAME: &str = "learned.json";
+
+/// An item confirmed for a query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+struct LearnedSelection {
+    /// The normalized query.
+    query: String,
+    /// Id of the confirmed item.
+    id: String,
+}
+
+/// Learned selections, most recent first, at most one per query.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct LearnedSelections {
+    selections: Vec<LearnedSelection>,
+}
+
+impl LearnedSelections {
+    /// Load the selections from disk, or none if there are none.
+    pub fn load() -> Self {
+        crate::cache_file::load(FILE_NAME)
+    }
+
+    /// Save the selections to disk.
+    pub fn save(&self) -> anyhow::Result<()> {
+        crate::cache_file::save(FILE_NAME, self)?;
+        debug!("Saved {} learned selections", self.selections.len());
+        Ok(())
+    }
+
+    /// Remember `id` as the selection for `query`, keeping at most
+    /// `capacity` queries. Blank queries are ignored.
+    pub fn learn(&mut self, query: &str, id: &str, capacity: usize) {
+        let query = normalize_query(query);
+        if query.is_empty() {
+            return;
+        }
+
+        self.selections.retain(|selection| selection.query != query);
+        self.selections.insert(
+            0,
+            LearnedSelection {
+                query,
+                id: id.to_string(),
+            },
+        );
+        self.selections.truncate(capacity);
+    }
+
+    /// Get the id of the item learned for `query`, if any.
+    pub fn selection(&self, query: &str) -> Option<&str> {
+        let query = normalize_query(query);
+        self.selections
+            .iter()
+            .find(|selection| selection.query == query)
+            .map(|selection| selection.id.as_str())
+    }
+}
+
+/// Queries differing only in case or surrounding whitespace are the same.
+fn normalize_query(query: &str) -> String {
+    query.trim().to_lowercase()
+}
+
+/// Record that the item `id` was confirmed for `query`.
+pub fn record_selection(query: &str, id: &str) {
+    if query.trim().is_empty() {
+        return;
+    }
+
+    let mut learned = LearnedSelections::load();
+    learned.learn(query, id, LEARNED_SELECTIONS_CAPACITY);
+    if let Err(e) = learned.save() {
+        warn!(%e, "Failed to save learned selections");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_learn_replaces_selection_for_query() {
+        let mut learned = LearnedSelections::default();
+        learned.learn("fi", "firefox", 10);
+        learned.learn(" FI ", "files", 10);
+        assert_eq!(learned.selection("fi"), Some("files"));
+        assert_eq!(learned.selections.len(), 1);
+        assert_eq!(learned.selection("f"), None);
+    }
+
+    #[test]
+    fn test_learn_drops_oldest_beyond_capacity() {
+        let mut learned = LearnedSelections::default();
+        for query in ["a", "b", "c"] {
+            learned.learn(query, query, 2);
+        }
+        assert_eq!(learned.selection("a"), None);
+        assert_eq!(learned.selection("c"), Some("c"));
+    }
+
+    #[test]
+    fn test_learn_ignores_blank_query() {
+        let mut learned = LearnedSelections::default();
+        learned.learn("  ", "firefox", 10);
+        assert_eq!(learned, LearnedSelections::default());
+    }
+}
@@ -9,10 +9,14 @@
 //!
 //! - [`ApplicationItem`] - Desktop applications (from .desktop files)
 //! - [`WindowItem`] - Open windows for window switching
+//! - [`WorkspaceItem`] - Workspaces for workspace switching
 //! - [`ActionItem`] - System actions (shutdown, reboot, logout)
 //! - [`CalculatorItem`] - Mathematical calculation results
 //! - [`SearchItem`] - Web search queries
+//! - [`PathItem`] - Files and folders typed as a path
+//! - [`FileItem`] - Files and folders found by the file search
 //! - [`AiItem`] - AI/LLM query interface
+//! - [`ScriptItem`] - Items listed by external script sources
 //! - [`ThemeItem`] - Theme selection entries
 //! - [`SubmenuItem`] - Nested submenus
 //!
@@ -43,27 +47,39 @@
 mod action;
 mod ai;
 mod application;
+mod badge;
 mod calculator;
 mod dispatch;
+mod file;
+mod learned;
+mod path;
+mod script;
 mod search;
 mod submenu;
 mod theme;
 mod traits;
 mod window;
+mod workspace;
 
 use dispatch::dispatch_item;
 
 pub use action::{ActionItem, ActionKind};
-pub use ai::AiItem;
+pub use ai::{AiItem, InlineAnswer};
 pub use application::ApplicationItem;
+pub use badge::Badge;
 pub use calculator::CalculatorItem;
+pub use file::{FileItem, FileType};
+pub use learned::{LEARNED_SELECTIONS_CAPACITY, LearnedSelections, record_selection};
+pub use path::{PathItem, expand_path};
+pub use script::ScriptItem;
 pub use search::SearchItem;
-pub use submenu::{SubmenuItem, SubmenuLayout};
+pub use submenu::{CATEGORY_SUBMENU_PREFIX, SubmenuItem, SubmenuLayout};
 pub use theme::{ThemeItem, ThemeSource};
 pub use traits::{Categorizable, DisplayItem, Executable, IconProvider, Previewable};
-pub use window::WindowItem;
+pub use window::{WindowIcons, WindowItem, sort_urgent_first};
+pub use workspace::WorkspaceItem;
 
-use crate::config::ConfigModule;
+use crate::config::{BadgeConfig, ConfigModule};
 use std::path::PathBuf;
 
 /// A list item that can be displayed in the launcher.
@@ -74,6 +90,8 @@ pub enum ListItem {
     Application(ApplicationItem),
     /// An open window (for window switching)
     Window(WindowItem),
+    /// A workspace (for workspace switching)
+    Workspace(WorkspaceItem),
     /// A functional action (shutdown, reboot, etc.)
     Action(ActionItem),
     /// A submenu that opens a nested view
@@ -82,8 +100,14 @@ pub enum ListItem {
     Calculator(CalculatorItem),
     /// A web search item
     Search(SearchItem),
+    /// A file or folder typed as a path
+    Path(PathItem),
+    /// A file or folder found by the file search
+    File(FileItem),
     /// An AI query item
     Ai(AiItem),
+    /// An item listed by a script source
+    Script(ScriptItem),
     /// A theme item (boxed due to large size)
     Theme(Box<ThemeItem>),
 }
@@ -114,11 +138,25 @@ impl ListItem {
         dispatch_item!(self, icon_name)
     }
 
+    /// Get the identifier to match search queries against, if this item
+    /// has a user-meaningful one (the desktop entry id for applications).
+    pub fn search_id(&self) -> Option<&str> {
+        match self {
+            Self::Application(app) => Some(&app.id),
+            _ => None,
+        }
+    }
+
     /// Check if this item is a submenu.
     pub fn is_submenu(&self) -> bool {
         matches!(self, Self::Submenu(_))
     }
 
+    /// Check if this item is an application category submenu.
+    pub fn is_category_submenu(&self) -> bool {
+        matches!(self, Self::Submenu(item) if item.category_name().is_some())
+    }
+
     /// Check if this item is an application.
     pub fn is_application(&self) -> bool {
         matches!(self, Self::Application(_))
@@ -129,6 +167,11 @@ impl ListItem {
         matches!(self, Self::Window(_))
     }
 
+    /// Check if this item is a workspace.
+    pub fn is_workspace(&self) -> bool {
+        matches!(self, Self::Workspace(_))
+    }
+
     /// Check if this item is an action.
     pub fn is_action(&self) -> bool {
         matches!(self, Self::Action(_))
@@ -155,25 +198,83 @@ impl ListItem {
         dispatch_item!(self, section_name)
     }
 
+    /// Get the text behind this item for copying: the exec line, command,
+    /// URL or result it stands for. Returns `None` for items without one.
+    pub fn copy_details(&self) -> Option<String> {
+        match self {
+            Self::Application(item) => Some(item.exec.clone()),
+            Self::Window(item) => Some(item.app_id.clone()),
+            Self::Action(item) => Some(item.command_line()),
+            Self::Calculator(item) => item.clipboard_result.clone(),
+            Self::Search(item) => Some(item.url.clone()),
+            Self::Path(item) => Some(item.path.display().to_string()),
+            Self::File(item) => Some(item.path.display().to_string()),
+            Self::Ai(item) => Some(item.query.clone()),
+            Self::Script(item) => Some(item.command.clone()),
+            Self::Submenu(_) | Self::Workspace(_) | Self::Theme(_) => None,
+        }
+    }
+
+    /// Get the badges to show at the end of this item, in display order.
+    pub fn badges(&self, config: &BadgeConfig) -> Vec<Badge> {
+        match self {
+            Self::Application(app) => badge::application_badges(app, config),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Get the label of the item's Shift+Enter action, if it has one.
+    pub fn secondary_action_label(&self) -> Option<&'static str> {
+        match self {
+            Self::Application(_) => Some("Copy Path"),
+            Self::Search(_) => Some("Copy Link"),
+            Self::File(_) => Some("Show in Folder"),
+            Self::Calculator(_) => Some("Copy and Close"),
+            _ => None,
+        }
+    }
+
+    /// Get the label of the item's desktop actions submenu, if it has one.
+    pub fn app_actions_label(&self) -> Option<&'static str> {
+        match self {
+            Self::Application(app) if !app.actions.is_empty() => Some("Actions"),
+            _ => None,
+        }
+    }
+
+    /// Get the label of the item's terminal confirm action, if it has one.
+    pub fn terminal_action_label(&self) -> Option<&'static str> {
+        match self {
+            Self::Application(_) => Some("Open in Terminal"),
+            _ => None,
+        }
+    }
+
     /// Get the ConfigModule this item belongs to.
     /// This method has custom logic per variant and cannot use dispatch_item!.
     pub fn config_module(&self) -> ConfigModule {
         match self {
             Self::Application(_) => ConfigModule::Applications,
             Self::Window(_) => ConfigModule::Windows,
+            Self::Workspace(_) => ConfigModule::Workspaces,
             Self::Action(_) => ConfigModule::Actions,
             Self::Submenu(item) => {
                 // Map submenu IDs to their modules
                 match item.id.as_str() {
                     "submenu-emojis" => ConfigModule::Emojis,
+                    "submenu-glyphs" => ConfigModule::Glyphs,
                     "submenu-clipboard" => ConfigModule::Clipboard,
                     "submenu-themes" => ConfigModule::Themes,
+                    _ if item.category_name().is_some() => ConfigModule::Applications,
                     _ => ConfigModule::Actions, // Default fallback
                 }
             }
             Self::Calculator(_) => ConfigModule::Calculator,
-            Self::Search(_) => ConfigModule::Search,
+            // Path items are query-driven like web searches
+            Self::Search(_) | Self::Path(_) => ConfigModule::Search,
+            Self::File(_) => ConfigModule::Files,
             Self::Ai(_) => ConfigModule::Ai,
+            Self::Script(_) => ConfigModule::Scripts,
             Self::Theme(_) => ConfigModule::Themes,
         }
     }
@@ -0,0 +1,246 @@
+//! Filesystem path items.
+//!
+//! A query that looks like a path (`~/projects/foo`, `$XDG_CONFIG_HOME/zlaunch`,
+//! `docs/notes.md`) is expanded and, if it names an existing file or folder
+//! under the configured base directory, offered as an "Open" item.
+
+use crate::assets::PhosphorIcon;
+use crate::process;
+use std::path::{Path, PathBuf};
+
+use super::traits::{Categorizable, DisplayItem, Executable, IconProvider};
+
+/// An item that opens a file or folder typed as the query.
+#[derive(Clone, Debug)]
+pub struct PathItem {
+    /// Unique identifier for this item
+    pub id: String,
+    /// The path as displayed, with the home directory shortened to `~`
+    pub name: String,
+    /// The resolved, absolute path
+    pub path: PathBuf,
+    /// Whether the path is a directory
+    pub is_dir: bool,
+}
+
+impl PathItem {
+    /// Create an item for `query` if it names an existing path under `base`.
+    ///
+    /// Relative paths are resolved against `base`; `~` and environment
+    /// variables are expanded from the process environment.
+    pub fn for_query(query: &str, base: &Path) -> Option<Self> {
+        let path = resolve_path_query(query, base, |name| std::env::var(name).ok())?;
+        let is_dir = path.is_dir();
+        Some(Self {
+            id: format!("path-{}", path.display()),
+            name: tilde_path(&path),
+            path,
+            is_dir,
+        })
+    }
+
+    /// Get the icon for this path item.
+    pub fn icon(&self) -> PhosphorIcon {
+        if self.is_dir {
+            PhosphorIcon::Folder
+        } else {
+            PhosphorIcon::File
+        }
+    }
+}
+
+/// Display a path with the home directory shortened to `~`.
+pub(super) fn tilde_path(path: &Path) -> String {
+    match dirs::home_dir() {
+        Some(home) if path == home => "~".to_string(),
+        Some(home) => match path.strip_prefix(&home) {
+            Ok(rest) => format!("~/{}", rest.display()),
+            Err(_) => path.display().to_string(),
+        },
+        None => path.display().to_string(),
+    }
+}
+
+/// Check whether a query is meant as a path rather than a search term.
+fn looks_like_path(query: &str) -> bool {
+    query.starts_with(['/', '~', '$'])
+        || query.starts_with("./")
+        || query.starts_with("../")
+        || query.contains('/')
+}
+
+/// Expand a leading `~` and `$VAR`/`${VAR}` references in `input`.
+///
+/// `lookup` resolves environment variables, `~` expands to `HOME`. Returns
+/// `None` if a variable is unset or `~user` is used.
+pub fn expand_path(input: &str, lookup: impl Fn(&str) -> Option<String>) -> Option<PathBuf> {
+    let mut expanded = String::with_capacity(input.len());
+    let mut rest = input;
+
+    if let Some(after_tilde) = rest.strip_prefix('~') {
+        if !(after_tilde.is_empty() || after_tilde.starts_with('/')) {
+            return None;
+        }
+        expanded.push_str(&lookup("HOME")?);
+        rest = after_tilde;
+    }
+
+    while let Some(dollar) = rest.find('$') {
+        expanded.push_str(&rest[..dollar]);
+        let after = &rest[dollar + 1..];
+        let (name, remainder) = match after.strip_prefix('{') {
+            Some(braced) => {
+                let end = braced.find('}')?;
+                (&braced[..end], &braced[end + 1..])
+            }
+            None => {
+                let end = after
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(after.len());
+                (&after[..end], &after[end..])
+            }
+        };
+        if name.is_empty() {
+            // A lone `$` is kept as-is
+            expanded.push('$');
+        } else {
+            expanded.push_str(&lookup(name)?);
+        }
+        rest = remainder;
+    }
+    expanded.push_str(rest);
+
+    Some(PathBuf::from(expanded))
+}
+
+/// Resolve a path query to an existing path inside `base`.
+///
+/// Returns `None` for queries that don't look like paths, paths that don't
+/// exist, and paths outside `base` (after resolving `..` and symlinks).
+pub fn resolve_path_query(
+    query: &str,
+    base: &Path,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Option<PathBuf> {
+    let query = query.trim();
+    if !looks_like_path(query) {
+        return None;
+    }
+
+    let expanded = expand_path(query, lookup)?;
+    let path = base.join(expanded).canonicalize().ok()?;
+    let base = base.canonicalize().ok()?;
+    path.starts_with(&base).then_some(path)
+}
+
+impl DisplayItem for PathItem {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> Option<&str> {
+        Some(if self.is_dir { "Folder" } else { "File" })
+    }
+
+    fn action_label(&self) -> &'static str {
+        "Open"
+    }
+}
+
+impl IconProvider for PathItem {
+    // Uses Phosphor icons via icon() method
+}
+
+impl Executable for PathItem {
+    fn execute(&self) -> anyhow::Result<()> {
+        // Folders open in the file manager, files in their MIME handler
+        process::open_path(&self.path)?;
+        Ok(())
+    }
+}
+
+impl Categorizable for PathItem {
+    fn section_name(&self) -> &'static str {
+        "Path"
+    }
+
+    fn sort_priority(&self) -> u8 {
+        0
+    }
+}
+
+impl From<PathItem> for super::ListItem {
+    fn from(item: PathItem) -> Self {
+        Self::Path(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(name: &str) -> Option<String> {
+        match name {
+            "HOME" => Some("/home/user".to_string()),
+            "XDG_CONFIG_HOME" => Some("/home/user/.config".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_expand_tilde_and_variables() {
+        assert_eq!(
+            expand_path("~/projects/foo", env).unwrap(),
+            PathBuf::from("/home/user/projects/foo")
+        );
+        assert_eq!(expand_path("~", env).unwrap(), PathBuf::from("/home/user"));
+        assert_eq!(
+            expand_path("$XDG_CONFIG_HOME/zlaunch", env).unwrap(),
+            PathBuf::from("/home/user/.config/zlaunch")
+        );
+        assert_eq!(
+            expand_path("${HOME}/a$b", |name| env(name).or(Some("-".to_string()))).unwrap(),
+            PathBuf::from("/home/user/a-")
+        );
+        assert_eq!(
+            expand_path("/cost/$", env).unwrap(),
+            PathBuf::from("/cost/$")
+        );
+    }
+
+    #[test]
+    fn test_expand_rejects_unknown() {
+        assert!(expand_path("$UNSET/x", env).is_none());
+        assert!(expand_path("~other/x", env).is_none());
+        assert!(expand_path("${HOME", env).is_none());
+    }
+
+    #[test]
+    fn test_resolve_requires_existing_path_inside_base() {
+        let base = std::env::temp_dir().join(format!("zlaunch-path-{}", std::process::id()));
+        std::fs::create_dir_all(base.join("projects/foo")).unwrap();
+        std::fs::write(base.join("notes.md"), "notes").unwrap();
+        let lookup = |name: &str| (name == "HOME").then(|| base.display().to_string());
+
+        let canonical = base.canonicalize().unwrap();
+        assert_eq!(
+            resolve_path_query("~/projects/foo", &base, lookup),
+            Some(canonical.join("projects/foo"))
+        );
+        assert_eq!(
+            resolve_path_query("./notes.md", &base, lookup),
+            Some(canonical.join("notes.md"))
+        );
+        // Missing paths, plain words and paths outside the base are ignored
+        assert!(resolve_path_query("~/projects/bar", &base, lookup).is_none());
+        assert!(resolve_path_query("notes.md", &base, lookup).is_none());
+        assert!(resolve_path_query("../", &base, lookup).is_none());
+        assert!(resolve_path_query("/", &base, lookup).is_none());
+
+        std::fs::remove_dir_all(&base).unwrap();
+    }
+}
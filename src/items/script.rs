@@ -0,0 +1,141 @@
+//! Items listed by external script sources.
+//!
+//! See [`crate::script`] for how scripts are run and their output format.
+
+use crate::assets::PhosphorIcon;
+use crate::process::DetachedProcess;
+use crate::script::ScriptEntry;
+use std::path::PathBuf;
+
+use super::traits::{Categorizable, DisplayItem, Executable, IconProvider};
+
+/// An item printed by a script source, running a shell command on confirm.
+#[derive(Clone, Debug)]
+pub struct ScriptItem {
+    /// Unique identifier for this item
+    pub id: String,
+    /// Display name
+    pub name: String,
+    /// Subtitle, the source name unless the script set one
+    pub description: String,
+    /// Icon path, for icons given as absolute paths
+    pub icon_path: Option<PathBuf>,
+    /// Phosphor icon name, for other icons
+    pub icon_name: Option<String>,
+    /// Shell command to run
+    pub command: String,
+}
+
+impl ScriptItem {
+    /// Create the item for the `index`th entry printed by `source`.
+    pub fn new(source: &str, index: usize, entry: ScriptEntry) -> Self {
+        let (icon_path, icon_name) = match entry.icon {
+            Some(icon) if icon.starts_with('/') => (Some(PathBuf::from(icon)), None),
+            icon => (None, icon),
+        };
+        Self {
+            id: format!("script-{}-{}", source, index),
+            name: entry.name,
+            description: entry.description.unwrap_or_else(|| source.to_string()),
+            icon_path,
+            icon_name,
+            command: entry.exec,
+        }
+    }
+
+    /// Get the Phosphor icon for this item, defaulting to a terminal.
+    pub fn icon(&self) -> PhosphorIcon {
+        self.icon_name
+            .as_deref()
+            .and_then(PhosphorIcon::from_name)
+            .unwrap_or(PhosphorIcon::Terminal)
+    }
+}
+
+impl DisplayItem for ScriptItem {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> Option<&str> {
+        Some(&self.description)
+    }
+
+    fn action_label(&self) -> &'static str {
+        "Run"
+    }
+}
+
+impl IconProvider for ScriptItem {
+    fn icon_path(&self) -> Option<&PathBuf> {
+        self.icon_path.as_ref()
+    }
+
+    fn icon_name(&self) -> Option<&str> {
+        self.icon_name.as_deref()
+    }
+}
+
+impl Executable for ScriptItem {
+    fn execute(&self) -> anyhow::Result<()> {
+        DetachedProcess::shell(self.command.as_str())
+            .with_session_env()
+            .spawn()?;
+        Ok(())
+    }
+}
+
+impl Categorizable for ScriptItem {
+    fn section_name(&self) -> &'static str {
+        "Scripts"
+    }
+
+    fn sort_priority(&self) -> u8 {
+        1
+    }
+}
+
+impl From<ScriptItem> for super::ListItem {
+    fn from(item: ScriptItem) -> Self {
+        Self::Script(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(icon: Option<&str>) -> ScriptEntry {
+        ScriptEntry {
+            name: "Open notes".to_string(),
+            description: None,
+            icon: icon.map(str::to_string),
+            exec: "xdg-open ~/notes.md".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_source_name_as_description() {
+        let item = ScriptItem::new("Notes", 2, entry(None));
+        assert_eq!(item.id, "script-Notes-2");
+        assert_eq!(item.description, "Notes");
+        assert_eq!(item.icon(), PhosphorIcon::Terminal);
+    }
+
+    #[test]
+    fn test_icon_path_or_name() {
+        let item = ScriptItem::new("Notes", 0, entry(Some("/usr/share/icons/notes.svg")));
+        assert_eq!(
+            item.icon_path,
+            Some(PathBuf::from("/usr/share/icons/notes.svg"))
+        );
+        assert!(item.icon_name.is_none());
+
+        let item = ScriptItem::new("Notes", 0, entry(Some("file-text")));
+        assert_eq!(item.icon(), PhosphorIcon::FileText);
+    }
+}
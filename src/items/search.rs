@@ -1,4 +1,5 @@
 use crate::assets::PhosphorIcon;
+use crate::config::LinkCopyFormat;
 use crate::process;
 use crate::search::SearchProvider;
 
@@ -34,6 +35,11 @@ impl SearchItem {
         }
     }
 
+    /// Get a link to the search results, titled with the query.
+    pub fn link_text(&self, format: LinkCopyFormat) -> String {
+        format.format(&self.query, &self.url)
+    }
+
     /// Get the icon for this search item.
     pub fn icon(&self) -> PhosphorIcon {
         self.provider.icon
@@ -1,5 +1,8 @@
 use super::traits::{Categorizable, DisplayItem, IconProvider};
 
+/// Id prefix of category submenus; the category name follows it.
+pub const CATEGORY_SUBMENU_PREFIX: &str = "submenu-category:";
+
 /// The layout style for a submenu.
 #[derive(Clone, Debug, Default)]
 pub enum SubmenuLayout {
@@ -76,6 +79,16 @@ impl SubmenuItem {
         }
     }
 
+    /// Create a submenu that browses an application category.
+    pub fn category(category: &str) -> Self {
+        Self::list(format!("{}{}", CATEGORY_SUBMENU_PREFIX, category), category)
+    }
+
+    /// Get the category this submenu browses, if it is a category submenu.
+    pub fn category_name(&self) -> Option<&str> {
+        self.id.strip_prefix(CATEGORY_SUBMENU_PREFIX)
+    }
+
     /// Builder method to set a description.
     pub fn with_description(mut self, description: impl Into<String>) -> Self {
         self.description = Some(description.into());
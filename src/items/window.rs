@@ -1,6 +1,8 @@
+use super::ApplicationItem;
 use super::traits::{Categorizable, DisplayItem, Executable, IconProvider};
 use crate::compositor::WindowInfo;
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 /// A window item representing an open window for window switching.
 #[derive(Clone, Debug)]
@@ -25,6 +27,8 @@ pub struct WindowItem {
     pub workspace: i32,
     /// Whether this window is currently focused
     pub focused: bool,
+    /// Whether this window requests attention
+    pub urgent: bool,
 }
 
 impl WindowItem {
@@ -55,13 +59,24 @@ impl WindowItem {
             icon_data,
             workspace,
             focused,
+            urgent: false,
         }
     }
 
+    /// Set whether the window requests attention.
+    pub fn with_urgent(mut self, urgent: bool) -> Self {
+        self.urgent = urgent;
+        self
+    }
+
     /// Create a WindowItem from compositor WindowInfo.
     pub fn from_window_info(info: WindowInfo, icon_path: Option<PathBuf>) -> Self {
         let app_name = titlecase_app_name(&info.class);
-        let description = format!("{} - Workspace {}", app_name, info.workspace);
+        let description = match info.special_workspace.as_deref() {
+            Some("") => format!("{} - Scratchpad", app_name),
+            Some(name) => format!("{} - Scratchpad {}", app_name, name),
+            None => format!("{} - Workspace {}", app_name, info.workspace),
+        };
         Self {
             id: format!("window-{}", info.address),
             address: info.address,
@@ -73,6 +88,7 @@ impl WindowItem {
             icon_data: info.icon_data,
             workspace: info.workspace,
             focused: info.focused,
+            urgent: info.urgent,
         }
     }
 }
@@ -125,6 +141,77 @@ impl From<WindowItem> for super::ListItem {
     }
 }
 
+/// Icons of the installed applications by window class.
+///
+/// Windows only report their class, so their icon is taken from the
+/// application whose `StartupWMClass`, desktop id or executable name matches
+/// it. Classes are compared ignoring case.
+#[derive(Clone, Debug, Default)]
+pub struct WindowIcons {
+    icons: HashMap<String, PathBuf>,
+}
+
+impl WindowIcons {
+    /// Build the lookup from the loaded applications.
+    ///
+    /// An explicit `StartupWMClass` wins over a desktop id, which wins over
+    /// an executable name, when several applications claim the same class.
+    pub fn from_applications(applications: &[ApplicationItem]) -> Self {
+        let mut icons = HashMap::new();
+        let with_icons = || {
+            applications
+                .iter()
+                .filter_map(|app| Some((app, app.icon_path.as_ref()?)))
+        };
+
+        for (app, icon) in with_icons() {
+            if let Some(class) = app.startup_wm_class.as_deref() {
+                insert_class(&mut icons, class, icon);
+            }
+        }
+        for (app, icon) in with_icons() {
+            insert_class(&mut icons, &app.id, icon);
+            if let Some(short_id) = app.id.rsplit('.').next() {
+                insert_class(&mut icons, short_id, icon);
+            }
+        }
+        for (app, icon) in with_icons() {
+            if let Some(name) = exec_name(&app.exec) {
+                insert_class(&mut icons, name, icon);
+            }
+        }
+
+        Self { icons }
+    }
+
+    /// Get the icon of the application owning windows of `class`.
+    pub fn get(&self, class: &str) -> Option<&PathBuf> {
+        self.icons.get(&class.to_lowercase())
+    }
+}
+
+fn insert_class(icons: &mut HashMap<String, PathBuf>, class: &str, icon: &Path) {
+    if !class.is_empty() {
+        icons
+            .entry(class.to_lowercase())
+            .or_insert_with(|| icon.to_path_buf());
+    }
+}
+
+/// Get the file name of the program an exec line runs, skipping `env` and
+/// its variable assignments.
+fn exec_name(exec: &str) -> Option<&str> {
+    let program = exec
+        .split_whitespace()
+        .find(|arg| *arg != "env" && !arg.contains('='))?;
+    Path::new(program).file_name()?.to_str()
+}
+
+/// Move windows requesting attention to the front, keeping the order otherwise.
+pub fn sort_urgent_first(windows: &mut [WindowItem]) {
+    windows.sort_by_key(|window| !window.urgent);
+}
+
 /// Convert an app class to a human-readable name.
 fn titlecase_app_name(class: &str) -> String {
     let name = class.rsplit('.').next().unwrap_or(class);
@@ -134,3 +221,49 @@ fn titlecase_app_name(class: &str) -> String {
         Some(first) => first.to_uppercase().chain(chars).collect(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mock_application, mock_window};
+
+    #[test]
+    fn test_sort_urgent_first_keeps_order() {
+        let mut windows = vec![
+            mock_window("Editor", "code"),
+            mock_window("Chat", "discord").with_urgent(true),
+            mock_window("Terminal", "kitty"),
+            mock_window("Mail", "thunderbird").with_urgent(true),
+        ];
+        sort_urgent_first(&mut windows);
+
+        let titles: Vec<_> = windows.iter().map(|w| w.title.as_str()).collect();
+        assert_eq!(titles, ["Chat", "Mail", "Editor", "Terminal"]);
+    }
+
+    #[test]
+    fn test_window_icons_match_class() {
+        let icon = |name: &str| Some(PathBuf::from(format!("/icons/{}.png", name)));
+
+        let mut dolphin = mock_application("Dolphin");
+        dolphin.id = "org.kde.dolphin".to_string();
+        dolphin.icon_path = icon("dolphin");
+        let mut code = mock_application("Code");
+        code.exec = "env ELECTRON_OZONE=1 /usr/bin/code --new-window %F".to_string();
+        code.icon_path = icon("code");
+        let mut steam = mock_application("Steam").with_startup_wm_class(Some("steam".into()));
+        steam.icon_path = icon("steam");
+        // Its executable name would claim the `steam` class too
+        let mut launcher = mock_application("Steam Launcher");
+        launcher.exec = "steam -silent".to_string();
+        launcher.icon_path = icon("steam-launcher");
+        let no_icon = mock_application("Kitty");
+
+        let icons = WindowIcons::from_applications(&[launcher, dolphin, code, steam, no_icon]);
+        assert_eq!(icons.get("org.kde.dolphin"), icon("dolphin").as_ref());
+        assert_eq!(icons.get("Dolphin"), icon("dolphin").as_ref());
+        assert_eq!(icons.get("code"), icon("code").as_ref());
+        assert_eq!(icons.get("steam"), icon("steam").as_ref());
+        assert_eq!(icons.get("kitty"), None);
+    }
+}
@@ -0,0 +1,129 @@
+use super::traits::{Categorizable, DisplayItem, IconProvider};
+use crate::compositor::WorkspaceInfo;
+
+/// A workspace item for switching to a workspace.
+#[derive(Clone, Debug)]
+pub struct WorkspaceItem {
+    /// Internal ID for the list
+    pub id: String,
+    /// Compositor-specific workspace id (used for switching)
+    pub workspace_id: String,
+    /// Display name (e.g., "Workspace 2" or "web")
+    pub name: String,
+    /// Description, set for the active workspace
+    pub description: Option<String>,
+    /// Whether this workspace is currently active
+    pub active: bool,
+}
+
+impl WorkspaceItem {
+    /// Create a WorkspaceItem from compositor WorkspaceInfo.
+    ///
+    /// Numbered workspaces are shown as "Workspace N", named ones by name.
+    pub fn from_workspace_info(info: WorkspaceInfo) -> Self {
+        let name = if info.name.parse::<i64>().is_ok() {
+            format!("Workspace {}", info.name)
+        } else {
+            info.name
+        };
+        Self {
+            id: format!("workspace-{}", info.id),
+            workspace_id: info.id,
+            name,
+            description: info.active.then(|| "Current workspace".to_string()),
+            active: info.active,
+        }
+    }
+
+    /// Create WorkspaceItems from a compositor workspace list, keeping its order.
+    pub fn from_workspaces(workspaces: Vec<WorkspaceInfo>) -> Vec<Self> {
+        workspaces
+            .into_iter()
+            .map(Self::from_workspace_info)
+            .collect()
+    }
+}
+
+impl DisplayItem for WorkspaceItem {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn action_label(&self) -> &'static str {
+        "Switch"
+    }
+}
+
+impl IconProvider for WorkspaceItem {
+    fn icon_name(&self) -> Option<&str> {
+        Some("squares-four")
+    }
+}
+
+impl Categorizable for WorkspaceItem {
+    fn section_name(&self) -> &'static str {
+        "Workspaces"
+    }
+
+    fn sort_priority(&self) -> u8 {
+        2
+    }
+}
+
+impl From<WorkspaceItem> for super::ListItem {
+    fn from(item: WorkspaceItem) -> Self {
+        Self::Workspace(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workspace(id: &str, name: &str, active: bool) -> WorkspaceInfo {
+        WorkspaceInfo {
+            id: id.to_string(),
+            name: name.to_string(),
+            active,
+        }
+    }
+
+    #[test]
+    fn test_from_workspaces() {
+        let items = WorkspaceItem::from_workspaces(vec![
+            workspace("1", "1", false),
+            workspace("3", "web", true),
+        ]);
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].id, "workspace-1");
+        assert_eq!(items[0].name, "Workspace 1");
+        assert_eq!(items[0].description, None);
+        assert_eq!(items[1].workspace_id, "3");
+        assert_eq!(items[1].name, "web");
+        assert!(items[1].active);
+        assert_eq!(items[1].description.as_deref(), Some("Current workspace"));
+    }
+
+    #[test]
+    fn test_from_workspaces_keeps_compositor_ids() {
+        let items =
+            WorkspaceItem::from_workspaces(vec![workspace("0f1e2d3c-desktop", "Desktop 1", false)]);
+        assert_eq!(items[0].workspace_id, "0f1e2d3c-desktop");
+        assert_eq!(items[0].id, "workspace-0f1e2d3c-desktop");
+        assert_eq!(items[0].name, "Desktop 1");
+    }
+
+    #[test]
+    fn test_from_empty_workspace_list() {
+        assert!(WorkspaceItem::from_workspaces(Vec::new()).is_empty());
+    }
+}
@@ -1,6 +1,8 @@
 pub mod ai;
 pub mod app;
 pub mod assets;
+pub mod bench;
+pub mod cache_file;
 pub mod calculator;
 pub mod cli;
 pub mod clipboard;
@@ -10,9 +12,11 @@ pub mod daemon;
 pub mod desktop;
 pub mod emoji;
 pub mod error;
+pub mod files;
 pub mod ipc;
 pub mod items;
 pub mod process;
+pub mod script;
 pub mod search;
 pub mod tokio_runtime;
 pub mod ui;
@@ -1,13 +1,28 @@
 use anyhow::Result;
 use clap::Parser;
-use zlaunch::cli::{Cli, handle_client_command};
-use zlaunch::daemon;
+use zlaunch::cli::{Cli, Commands, handle_client_command};
+use zlaunch::config::profile;
+use zlaunch::daemon::{self, DaemonOptions};
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
+    if let Some(profile) = &cli.profile
+        && !profile::is_valid_profile_name(profile)
+    {
+        anyhow::bail!("Invalid profile name '{}'", profile);
+    }
+    profile::set_profile(cli.profile);
+
     match cli.command {
+        Some(Commands::Daemon {
+            foreground,
+            log_format,
+        }) => daemon::run(DaemonOptions {
+            foreground,
+            log_format,
+        }),
         Some(cmd) => handle_client_command(cmd),
-        None => daemon::run(),
+        None => daemon::run(DaemonOptions::default()),
     }
 }
@@ -3,12 +3,32 @@
 //! This module provides safe abstractions for spawning processes that outlive
 //! the launcher daemon. All spawned processes are detached using `setsid()`
 //! to create a new session, preventing them from being killed when the daemon exits.
+//!
+//! Setting `ZLAUNCH_DRY_RUN=1` makes every spawn log the fully-resolved command
+//! line instead of executing it, which helps debugging exec parsing.
 
 use crate::desktop::env::get_session_environment;
 use crate::error::ProcessError;
+use std::borrow::Cow;
 use std::ffi::OsStr;
 use std::os::unix::process::CommandExt;
+use std::path::Path;
 use std::process::{Command, Stdio};
+use std::sync::OnceLock;
+
+/// Environment variable that enables dry-run mode.
+pub const DRY_RUN_ENV: &str = "ZLAUNCH_DRY_RUN";
+
+/// Check whether dry-run mode is enabled via `ZLAUNCH_DRY_RUN`.
+///
+/// Any value other than empty, `0` or `false` enables it. The variable is
+/// read once per process.
+pub fn dry_run_enabled() -> bool {
+    static DRY_RUN: OnceLock<bool> = OnceLock::new();
+    *DRY_RUN.get_or_init(|| {
+        std::env::var(DRY_RUN_ENV).is_ok_and(|v| !matches!(v.as_str(), "" | "0" | "false"))
+    })
+}
 
 /// Builder for creating detached processes.
 ///
@@ -27,6 +47,7 @@ pub struct DetachedProcess {
     command: Command,
     use_session_env: bool,
     shell_command: Option<String>,
+    dry_run: bool,
 }
 
 impl DetachedProcess {
@@ -36,6 +57,7 @@ impl DetachedProcess {
             command: Command::new(program),
             use_session_env: false,
             shell_command: None,
+            dry_run: dry_run_enabled(),
         }
     }
 
@@ -48,6 +70,7 @@ impl DetachedProcess {
             command: Command::new("sh"),
             use_session_env: false,
             shell_command: Some(cmd),
+            dry_run: dry_run_enabled(),
         }
     }
 
@@ -77,6 +100,38 @@ impl DetachedProcess {
         self
     }
 
+    /// Log the command instead of executing it when spawned.
+    ///
+    /// Defaults to the `ZLAUNCH_DRY_RUN` environment setting.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Get the program to run.
+    pub fn program(&self) -> &OsStr {
+        self.command.get_program()
+    }
+
+    /// Get the full argument list, including the `-c` of shell commands.
+    pub fn arguments(&self) -> Vec<&OsStr> {
+        let mut args: Vec<&OsStr> = self.command.get_args().collect();
+        if let Some(cmd) = &self.shell_command {
+            args.push(OsStr::new("-c"));
+            args.push(OsStr::new(cmd));
+        }
+        args
+    }
+
+    /// Render the resolved command line, quoting arguments where needed.
+    pub fn command_line(&self) -> String {
+        std::iter::once(self.program())
+            .chain(self.arguments())
+            .map(|arg| shell_quote(&arg.to_string_lossy()).into_owned())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
     /// Spawn the detached process.
     ///
     /// The spawned process:
@@ -88,6 +143,11 @@ impl DetachedProcess {
     /// This function uses `pre_exec` to call `libc::setsid()`, which is
     /// async-signal-safe and therefore safe to use in this context.
     pub fn spawn(mut self) -> Result<(), ProcessError> {
+        if self.dry_run {
+            tracing::info!("Dry run, would execute: {}", self.command_line());
+            return Ok(());
+        }
+
         // Handle shell commands
         if let Some(cmd) = &self.shell_command {
             self.command.args(["-c", cmd]);
@@ -126,6 +186,11 @@ impl DetachedProcess {
 /// The exec string is split on whitespace to extract program and arguments.
 /// Empty exec strings return an error.
 pub fn launch_exec(exec: &str) -> Result<(), ProcessError> {
+    exec_process(exec)?.spawn()
+}
+
+/// Build the process for an executable string without spawning it.
+pub fn exec_process(exec: &str) -> Result<DetachedProcess, ProcessError> {
     let parts: Vec<&str> = exec.split_whitespace().collect();
     if parts.is_empty() {
         return Err(ProcessError::EmptyCommand);
@@ -134,28 +199,55 @@ pub fn launch_exec(exec: &str) -> Result<(), ProcessError> {
     let program = parts[0];
     let args = &parts[1..];
 
-    DetachedProcess::new(program)
+    Ok(DetachedProcess::new(program)
         .args(args.iter().copied())
-        .with_session_env()
-        .spawn()
+        .with_session_env())
 }
 
 /// Launch an application in a terminal emulator.
 ///
 /// Uses the `$TERMINAL` environment variable, falling back to `xterm`.
 pub fn launch_in_terminal(exec: &str) -> Result<(), ProcessError> {
+    terminal_process(exec)?.spawn()
+}
+
+/// Build the terminal process for an executable string without spawning it.
+pub fn terminal_process(exec: &str) -> Result<DetachedProcess, ProcessError> {
     let terminal = get_terminal()?;
 
-    DetachedProcess::new(&terminal)
+    Ok(DetachedProcess::new(&terminal)
         .arg("-e")
         .arg(exec)
+        .with_session_env())
+}
+
+/// Open a URL using the system default handler (`xdg-open`), using the
+/// session environment.
+pub fn open_url(url: &str) -> Result<(), ProcessError> {
+    DetachedProcess::new("xdg-open")
+        .arg(url)
         .with_session_env()
         .spawn()
 }
 
-/// Open a URL using the system default handler (`xdg-open`).
-pub fn open_url(url: &str) -> Result<(), ProcessError> {
-    DetachedProcess::new("xdg-open").arg(url).spawn()
+/// Open a file with its default application (`xdg-open`), using the
+/// session environment.
+pub fn open_path(path: &Path) -> Result<(), ProcessError> {
+    DetachedProcess::new("xdg-open")
+        .arg(path)
+        .with_session_env()
+        .spawn()
+}
+
+/// Check whether a string starts with a URL scheme (e.g. `https:`, `mailto:`).
+pub fn has_url_scheme(url: &str) -> bool {
+    let Some((scheme, rest)) = url.split_once(':') else {
+        return false;
+    };
+    let mut chars = scheme.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        && !rest.is_empty()
 }
 
 /// Execute a shell command in a detached process.
@@ -163,6 +255,20 @@ pub fn run_shell_command(command: &str) -> Result<(), ProcessError> {
     DetachedProcess::shell(command).spawn()
 }
 
+/// Quote an argument for display if it contains shell-special characters.
+fn shell_quote(arg: &str) -> Cow<'_, str> {
+    let is_plain = !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./=:,+@%".contains(c));
+
+    if is_plain {
+        Cow::Borrowed(arg)
+    } else {
+        Cow::Owned(format!("'{}'", arg.replace('\'', r"'\''")))
+    }
+}
+
 /// Get the terminal emulator to use.
 fn get_terminal() -> Result<String, ProcessError> {
     if let Ok(terminal) = std::env::var("TERMINAL") {
@@ -196,4 +302,50 @@ mod tests {
         let result = launch_exec("   ");
         assert!(matches!(result, Err(ProcessError::EmptyCommand)));
     }
+
+    #[test]
+    fn test_exec_process_command_line() {
+        let process = exec_process("firefox --new-window https://example.com").unwrap();
+        assert_eq!(
+            process.command_line(),
+            "firefox --new-window https://example.com"
+        );
+    }
+
+    #[test]
+    fn test_shell_command_line() {
+        let process = DetachedProcess::shell("notify-send 'hi there'");
+        assert_eq!(process.program(), "sh");
+        assert_eq!(
+            process.command_line(),
+            r"sh -c 'notify-send '\''hi there'\'''"
+        );
+    }
+
+    #[test]
+    fn test_dry_run_does_not_spawn() {
+        let result = DetachedProcess::new("/nonexistent/zlaunch-test-binary")
+            .dry_run(true)
+            .spawn();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_shell_quote() {
+        assert_eq!(shell_quote("plain-arg"), "plain-arg");
+        assert_eq!(shell_quote("two words"), "'two words'");
+        assert_eq!(shell_quote(""), "''");
+    }
+
+    #[test]
+    fn test_has_url_scheme() {
+        assert!(has_url_scheme("https://example.com"));
+        assert!(has_url_scheme("mailto:user@example.com"));
+        assert!(has_url_scheme("git+ssh://host/repo"));
+        assert!(!has_url_scheme("example.com"));
+        assert!(!has_url_scheme("/home/user/file.txt"));
+        assert!(!has_url_scheme("https:"));
+        assert!(!has_url_scheme("1http://example.com"));
+        assert!(!has_url_scheme(""));
+    }
 }
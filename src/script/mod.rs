@@ -0,0 +1,224 @@
+//! External script item sources.
+//!
+//! A script source is a shell command from the `[[scripts]]` config that
+//! lists launcher items for the current query, similar to rofi's script
+//! mode. The command runs through `sh -c` with the query as `$1` and on
+//! stdin, and prints one item per line, either as a JSON object or as
+//! tab-separated fields:
+//!
+//! ```text
+//! {"name": "Open notes", "exec": "xdg-open ~/notes.md", "icon": "file-text"}
+//! Lock screen<TAB>loginctl lock-session<TAB>lock
+//! ```
+//!
+//! JSON items may also set a `description`. Lines without a name or an
+//! action command are skipped.
+
+use crate::config::ConfigScriptSource;
+use crate::error::ScriptError;
+use crate::items::ScriptItem;
+use serde::Deserialize;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tracing::{debug, warn};
+
+/// An item printed by a script.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct ScriptEntry {
+    /// Display name
+    pub name: String,
+    /// Optional subtitle
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Phosphor icon name or absolute icon path
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// Shell command run when the item is confirmed
+    #[serde(alias = "command")]
+    pub exec: String,
+}
+
+/// Parse a script's output into items, skipping invalid lines.
+pub fn parse_output(output: &str) -> Vec<ScriptEntry> {
+    output.lines().filter_map(parse_line).collect()
+}
+
+/// Parse one line of script output.
+fn parse_line(line: &str) -> Option<ScriptEntry> {
+    let line = line.trim_end_matches('\r');
+    if line.trim().is_empty() {
+        return None;
+    }
+
+    let entry = if line.trim_start().starts_with('{') {
+        match serde_json::from_str::<ScriptEntry>(line) {
+            Ok(entry) => entry,
+            Err(e) => {
+                debug!(%e, line, "Skipping invalid script item");
+                return None;
+            }
+        }
+    } else {
+        let mut fields = line.split('\t').map(str::trim);
+        ScriptEntry {
+            name: fields.next()?.to_string(),
+            description: None,
+            exec: fields.next()?.to_string(),
+            icon: fields
+                .next()
+                .filter(|icon| !icon.is_empty())
+                .map(str::to_string),
+        }
+    };
+
+    (!entry.name.trim().is_empty() && !entry.exec.trim().is_empty()).then_some(entry)
+}
+
+/// Run a script command for `query` and parse the items it prints.
+///
+/// The script is killed if it runs longer than `timeout` or the returned
+/// future is dropped, so stale runs can be cancelled by aborting their task.
+pub async fn run_script(
+    command: &str,
+    query: &str,
+    timeout: Duration,
+) -> Result<Vec<ScriptEntry>, ScriptError> {
+    let mut child = tokio::process::Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        // Becomes `$0`, so the query is `$1`
+        .arg("zlaunch-script")
+        .arg(query)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(ScriptError::SpawnFailed)?;
+
+    let stdin = child.stdin.take();
+    let run = async move {
+        if let Some(mut stdin) = stdin {
+            // Scripts that ignore stdin may exit before reading it
+            let _ = stdin.write_all(query.as_bytes()).await;
+        }
+        child.wait_with_output().await
+    };
+
+    let output = tokio::time::timeout(timeout, run)
+        .await
+        .map_err(|_| ScriptError::TimedOut(timeout))?
+        .map_err(ScriptError::SpawnFailed)?;
+    if !output.status.success() {
+        return Err(ScriptError::Failed(output.status));
+    }
+
+    Ok(parse_output(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Run all script sources for `query` concurrently and collect their items,
+/// in source order.
+///
+/// Failing sources are logged and contribute no items.
+pub async fn fetch_items(sources: Vec<ConfigScriptSource>, query: String) -> Vec<ScriptItem> {
+    let query = query.as_str();
+    let runs = sources.iter().map(|source| async move {
+        match run_script(&source.command, query, source.timeout()).await {
+            Ok(entries) => entries,
+            Err(e) => {
+                warn!(%e, source = %source.name, "Script source failed");
+                Vec::new()
+            }
+        }
+    });
+    let results = futures::future::join_all(runs).await;
+
+    sources
+        .iter()
+        .zip(results)
+        .flat_map(|(source, entries)| {
+            entries
+                .into_iter()
+                .enumerate()
+                .map(|(index, entry)| ScriptItem::new(&source.name, index, entry))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_json_lines() {
+        let output = concat!(
+            r#"{"name": "Open notes", "exec": "xdg-open ~/notes.md", "icon": "file-text"}"#,
+            "\n",
+            r#"{"name": "Sync", "command": "notes-sync", "description": "Push to remote"}"#,
+            "\n",
+        );
+        let entries = parse_output(output);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].exec, "xdg-open ~/notes.md");
+        assert_eq!(entries[0].icon.as_deref(), Some("file-text"));
+        assert_eq!(entries[1].exec, "notes-sync");
+        assert_eq!(entries[1].description.as_deref(), Some("Push to remote"));
+    }
+
+    #[test]
+    fn test_parse_tab_separated_lines() {
+        let entries =
+            parse_output("Lock screen\tloginctl lock-session\tlock\r\nReboot\tsystemctl reboot\n");
+        assert_eq!(
+            entries,
+            vec![
+                ScriptEntry {
+                    name: "Lock screen".to_string(),
+                    description: None,
+                    icon: Some("lock".to_string()),
+                    exec: "loginctl lock-session".to_string(),
+                },
+                ScriptEntry {
+                    name: "Reboot".to_string(),
+                    description: None,
+                    icon: None,
+                    exec: "systemctl reboot".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_skips_invalid_lines() {
+        let output = "\n   \nno action here\n\tmissing name\n{\"name\": \"broken\"\n{\"name\": \"No exec\"}\nOk\ttrue\n";
+        let entries = parse_output(output);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name, "Ok");
+    }
+
+    #[tokio::test]
+    async fn test_run_script_passes_query() {
+        let entries = run_script(
+            r#"read -r stdin; printf '%s\t%s\n' "$1" "$stdin""#,
+            "hello world",
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+        assert_eq!(entries[0].name, "hello world");
+        assert_eq!(entries[0].exec, "hello world");
+    }
+
+    #[tokio::test]
+    async fn test_run_script_times_out() {
+        let result = run_script("sleep 5", "", Duration::from_millis(50)).await;
+        assert!(matches!(result, Err(ScriptError::TimedOut(_))));
+    }
+
+    #[tokio::test]
+    async fn test_run_script_failure() {
+        let result = run_script("exit 3", "", Duration::from_secs(5)).await;
+        assert!(matches!(result, Err(ScriptError::Failed(_))));
+    }
+}
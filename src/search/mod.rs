@@ -9,4 +9,6 @@ mod detection;
 mod providers;
 
 pub use detection::{SearchDetection, detect_search};
-pub use providers::{SearchProvider, find_provider_by_trigger, get_providers};
+pub use providers::{
+    SearchProvider, find_provider_by_trigger, get_providers, providers_from_config,
+};
@@ -4,7 +4,7 @@
 //! with their triggers, URL templates, and icons.
 
 use crate::assets::PhosphorIcon;
-use crate::config::config;
+use crate::config::{AppConfig, config};
 use tracing::warn;
 
 /// A search provider configuration.
@@ -49,9 +49,14 @@ fn provider_icon(provider_name: &str, icon_name: Option<&String>) -> PhosphorIco
 
 /// Get all available search providers
 pub fn get_providers() -> Vec<SearchProvider> {
+    providers_from_config(&config())
+}
+
+/// Get the search providers defined in a given config.
+pub fn providers_from_config(app_config: &AppConfig) -> Vec<SearchProvider> {
     let mut providers = vec![];
 
-    if let Some(custom) = config().search_providers {
+    if let Some(custom) = app_config.search_providers.clone() {
         for provider in custom {
             let icon = provider_icon(&provider.name, Some(&provider.icon));
 
@@ -3,6 +3,7 @@
 //! This module provides common testing utilities including mock object factories
 //! and test helpers. Only compiled in test builds.
 
+use crate::compositor::WindowInfo;
 use crate::config::{AppConfig, ConfigModule, ConfigSearchProvider};
 use crate::items::{ApplicationItem, ListItem, WindowItem};
 use std::path::PathBuf;
@@ -67,6 +68,21 @@ pub fn mock_window(title: &str, app_id: &str) -> WindowItem {
     )
 }
 
+/// Create a mock WindowInfo as reported by the compositor, titled after
+/// its class.
+pub fn mock_window_info(address: &str, class: &str) -> WindowInfo {
+    WindowInfo {
+        address: address.to_string(),
+        title: class.to_string(),
+        class: class.to_string(),
+        workspace: 1,
+        focused: false,
+        special_workspace: None,
+        icon_data: None,
+        urgent: false,
+    }
+}
+
 /// Create a set of mock ApplicationItems for testing.
 pub fn mock_applications() -> Vec<ApplicationItem> {
     vec![
@@ -1,4 +1,6 @@
+use crate::ui::launcher::CONFIRM_KEY;
 use crate::ui::theme::theme;
+use crate::ui::utils::{icon_file_image, key_badge_text};
 use gpui::{Div, ElementId, SharedString, Stateful, div, img, prelude::*, px};
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -157,14 +159,12 @@ fn render_icon_element(icon: Icon) -> Div {
         .justify_center();
 
     match icon {
-        Icon::Path(path) => {
-            let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
-            if matches!(ext, "png" | "jpg" | "jpeg" | "svg") {
-                icon_container.child(img(path).w(size).h(size).rounded(theme.icon_border_radius))
-            } else {
-                render_placeholder_icon(icon_container, "?")
+        Icon::Path(path) => match icon_file_image(&path, theme.item_title_color) {
+            Some(image) => {
+                icon_container.child(img(image).w(size).h(size).rounded(theme.icon_border_radius))
             }
-        }
+            None => render_placeholder_icon(icon_container, "?"),
+        },
         Icon::Data(image) => {
             icon_container.child(img(image).w(size).h(size).rounded(theme.icon_border_radius))
         }
@@ -237,7 +237,7 @@ fn render_text_content(name: &str, description: Option<&str>, selected: bool) ->
 fn render_action_indicator(label: &str) -> Div {
     let theme = theme();
 
-    div()
+    let indicator = div()
         .absolute()
         .right(theme.action_indicator.right_position)
         .top_0()
@@ -251,19 +251,23 @@ fn render_action_indicator(label: &str) -> Div {
                 .text_xs()
                 .text_color(theme.action_indicator.label_color)
                 .child(SharedString::from(label.to_string())),
-        )
-        .child(
-            div()
-                .px(theme.action_indicator.key_padding_x)
-                .pt(theme.action_indicator.key_padding_top)
-                .pb(theme.action_indicator.key_padding_bottom)
-                .bg(theme.action_indicator.key_background)
-                .border_1()
-                .border_color(theme.action_indicator.key_border)
-                .rounded(theme.action_indicator.key_border_radius)
-                .text_size(theme.action_indicator.key_font_size)
-                .line_height(theme.action_indicator.key_line_height)
-                .text_color(theme.action_indicator.key_color)
-                .child(SharedString::from("↵")),
-        )
+        );
+    if !theme.action_indicator.show_key_badge {
+        return indicator;
+    }
+
+    indicator.child(
+        div()
+            .px(theme.action_indicator.key_padding_x)
+            .pt(theme.action_indicator.key_padding_top)
+            .pb(theme.action_indicator.key_padding_bottom)
+            .bg(theme.action_indicator.key_background)
+            .border_1()
+            .border_color(theme.action_indicator.key_border)
+            .rounded(theme.action_indicator.key_border_radius)
+            .text_size(theme.action_indicator.key_font_size)
+            .line_height(theme.action_indicator.key_line_height)
+            .text_color(theme.action_indicator.key_color)
+            .child(SharedString::from(key_badge_text(CONFIRM_KEY))),
+    )
 }
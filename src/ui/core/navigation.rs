@@ -35,13 +35,15 @@ impl NavigationController {
     /// Scroll to the specified item index in a list.
     ///
     /// This is a helper that delegates call after updating their selected index.
+    /// Uses the configured `scroll_strategy`.
     pub fn scroll_to_item<D: ListDelegate>(
         list_state: &mut ListState<D>,
         index: IndexPath,
         window: &mut Window,
         cx: &mut Context<ListState<D>>,
     ) {
-        list_state.scroll_to_item(index, ScrollStrategy::Top, window, cx);
+        let strategy: ScrollStrategy = crate::config::config().scroll_strategy.into();
+        list_state.scroll_to_item(index, strategy, window, cx);
     }
 
     /// Scroll to an item by flat index (single-section lists).
@@ -168,6 +168,12 @@ impl<T: Clone> BaseDelegate<T> {
         self.selected_index = Some(prev);
     }
 
+    /// Replace all items (caller should then filter again)
+    pub fn set_items(&mut self, items: Vec<T>) {
+        self.items = items;
+        self.reset_filter();
+    }
+
     /// Get all items (for external filtering)
     pub fn items(&self) -> &[T] {
         &self.items
@@ -0,0 +1,168 @@
+//! Application category browsing.
+//!
+//! A query starting with the category prefix (e.g. `cat:games firefox`)
+//! restricts results to applications in a desktop entry category. While
+//! the category itself is still being typed, the list shows one submenu
+//! per category instead; confirming one completes the category.
+
+use std::collections::BTreeMap;
+
+use crate::items::{ListItem, SubmenuItem};
+
+/// A parsed category query.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CategoryQuery {
+    /// The category name is still being typed (no whitespace yet).
+    Picking(String),
+    /// A category was given; the rest of the query matches applications in it.
+    Filtering { category: String, query: String },
+}
+
+impl CategoryQuery {
+    /// Parse a query carrying the category prefix.
+    pub fn parse(query: &str, prefix: &str) -> Option<Self> {
+        let rest = query.strip_prefix(prefix)?;
+        Some(match rest.split_once(char::is_whitespace) {
+            Some((category, query)) => Self::Filtering {
+                category: category.to_string(),
+                query: query.trim_start().to_string(),
+            },
+            None => Self::Picking(rest.to_string()),
+        })
+    }
+
+    /// The part of the query left for fuzzy matching.
+    pub fn search_text(&self) -> &str {
+        match self {
+            Self::Picking(category) => category,
+            Self::Filtering { query, .. } => query,
+        }
+    }
+}
+
+/// Check whether any of `categories` matches a typed category term.
+///
+/// Matching is case-insensitive and by prefix, so `dev` finds
+/// `Development`. A trailing plural `s` is ignored, so `games` finds `Game`.
+pub fn matches_category(categories: &[String], term: &str) -> bool {
+    let term = term.to_lowercase();
+    if term.is_empty() {
+        return false;
+    }
+    let singular = term.strip_suffix('s').filter(|s| !s.is_empty());
+
+    categories.iter().any(|category| {
+        let category = category.to_lowercase();
+        category.starts_with(&term) || singular.is_some_and(|s| category == s)
+    })
+}
+
+/// Build one submenu per application category, sorted by name.
+pub fn category_submenus(items: &[ListItem]) -> Vec<SubmenuItem> {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for item in items {
+        if let ListItem::Application(app) = item {
+            for category in &app.categories {
+                *counts.entry(category.as_str()).or_default() += 1;
+            }
+        }
+    }
+
+    counts
+        .into_iter()
+        .map(|(category, count)| {
+            let noun = if count == 1 {
+                "application"
+            } else {
+                "applications"
+            };
+            SubmenuItem::category(category).with_description(format!("{} {}", count, noun))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock_application;
+
+    fn categories(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_picking() {
+        assert_eq!(
+            CategoryQuery::parse("cat:gam", "cat:"),
+            Some(CategoryQuery::Picking("gam".to_string()))
+        );
+        assert_eq!(
+            CategoryQuery::parse("cat:", "cat:"),
+            Some(CategoryQuery::Picking(String::new()))
+        );
+        assert_eq!(CategoryQuery::parse("catalog", "cat:"), None);
+    }
+
+    #[test]
+    fn test_parse_filtering() {
+        let query = CategoryQuery::parse("cat:games  steam", "cat:").unwrap();
+        assert_eq!(
+            query,
+            CategoryQuery::Filtering {
+                category: "games".to_string(),
+                query: "steam".to_string(),
+            }
+        );
+        assert_eq!(query.search_text(), "steam");
+    }
+
+    #[test]
+    fn test_matches_any_of_multiple_categories() {
+        let gimp = categories(&["Graphics", "2DGraphics", "RasterGraphics"]);
+        let vscode = categories(&["Utility", "TextEditor", "Development", "IDE"]);
+
+        assert!(matches_category(&gimp, "graphics"));
+        assert!(matches_category(&gimp, "raster"));
+        assert!(matches_category(&vscode, "dev"));
+        assert!(matches_category(&vscode, "TextEditor"));
+        assert!(!matches_category(&gimp, "development"));
+        assert!(!matches_category(&vscode, "graphics"));
+    }
+
+    #[test]
+    fn test_matches_plural_category() {
+        let steam = categories(&["Network", "FileTransfer", "Game"]);
+        assert!(matches_category(&steam, "games"));
+        assert!(matches_category(&steam, "GAME"));
+        assert!(!matches_category(&steam, "s"));
+        assert!(!matches_category(&steam, ""));
+    }
+
+    #[test]
+    fn test_category_submenus_count_applications() {
+        let items = vec![
+            ListItem::Application(
+                mock_application("Gimp").with_categories(categories(&["Graphics", "Photo"])),
+            ),
+            ListItem::Application(
+                mock_application("Inkscape").with_categories(categories(&["Graphics"])),
+            ),
+            ListItem::Application(mock_application("Plain")),
+        ];
+
+        let submenus = category_submenus(&items);
+        let summary: Vec<_> = submenus
+            .iter()
+            .map(|s| {
+                (
+                    s.category_name().unwrap(),
+                    s.description.as_deref().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [("Graphics", "2 applications"), ("Photo", "1 application")]
+        );
+    }
+}
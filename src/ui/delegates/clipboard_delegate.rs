@@ -1,8 +1,10 @@
 use crate::clipboard::ClipboardItem;
+use crate::config::{ClipboardSortOrder, config};
+use crate::ui::components::render_empty_preview;
 use crate::ui::delegates::BaseDelegate;
 use crate::ui::theme::theme;
 use crate::ui::views::render_clipboard_item;
-use gpui::{App, Context, SharedString, Task, Window, div, prelude::*};
+use gpui::{App, Context, Task, Window, prelude::*};
 use gpui_component::IndexPath;
 use gpui_component::list::{ListDelegate, ListItem as GpuiListItem, ListState};
 
@@ -12,14 +14,30 @@ use gpui_component::list::{ListDelegate, ListItem as GpuiListItem, ListState};
 pub struct ClipboardListDelegate {
     /// Base delegate handling common behavior
     base: BaseDelegate<ClipboardItem>,
+    /// Order of the visible items
+    sort_order: ClipboardSortOrder,
 }
 
 impl ClipboardListDelegate {
     /// Create a new clipboard list delegate
     pub fn new(items: Vec<ClipboardItem>) -> Self {
-        Self {
+        let mut delegate = Self {
             base: BaseDelegate::new(items),
-        }
+            sort_order: config().clipboard_sort,
+        };
+        delegate.filter_items();
+        delegate
+    }
+
+    /// Get the current sort order
+    pub fn sort_order(&self) -> ClipboardSortOrder {
+        self.sort_order
+    }
+
+    /// Set the sort order and re-sort the visible items
+    pub fn set_sort_order(&mut self, order: ClipboardSortOrder) {
+        self.sort_order = order;
+        self.filter_items();
     }
 
     /// Set the confirm callback (paste clipboard item)
@@ -63,26 +81,23 @@ impl ClipboardListDelegate {
         self.filter_items();
     }
 
-    /// Filter items based on the current query
+    /// Filter items based on the current query, in the current sort order
     fn filter_items(&mut self) {
-        let query = self.base.query();
-        if query.is_empty() {
-            self.base.reset_filter();
-        } else {
-            let items = self.base.items();
-            let query_lower = query.to_lowercase();
-            let filtered_indices: Vec<usize> = items
-                .iter()
-                .enumerate()
-                .filter(|(_, item)| {
-                    // Search in text content (preview and full_content)
-                    item.preview().to_lowercase().contains(&query_lower)
-                        || item.full_content().to_lowercase().contains(&query_lower)
-                })
-                .map(|(idx, _)| idx)
-                .collect();
-            self.base.apply_filtered_indices(filtered_indices);
-        }
+        let items = self.base.items();
+        let query_lower = self.base.query().to_lowercase();
+        let mut filtered_indices: Vec<usize> = items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| {
+                // Search in text content (preview and full_content)
+                query_lower.is_empty()
+                    || item.preview().to_lowercase().contains(&query_lower)
+                    || item.full_content().to_lowercase().contains(&query_lower)
+            })
+            .map(|(idx, _)| idx)
+            .collect();
+        filtered_indices.sort_by(|&a, &b| items[a].compare(&items[b], self.sort_order));
+        self.base.apply_filtered_indices(filtered_indices);
     }
 
     /// Get an item at a filtered index
@@ -95,6 +110,35 @@ impl ClipboardListDelegate {
         self.base.selected_item()
     }
 
+    /// Remove the selected item and select the one that took its place
+    pub fn remove_selected(&mut self) -> Option<ClipboardItem> {
+        let index = self.selected_index()?;
+        let removed = self.selected_item()?.clone();
+
+        let items = self
+            .base
+            .items()
+            .iter()
+            .filter(|item| !item.has_content(&removed.content, removed.hash))
+            .cloned()
+            .collect();
+        self.base.set_items(items);
+        self.filter_items();
+
+        // The next item moved up into the removed row; past the end, keep the last
+        let count = self.filtered_count();
+        if count > 0 {
+            self.set_selected(index.min(count - 1));
+        }
+        Some(removed)
+    }
+
+    /// Remove all items
+    pub fn clear_items(&mut self) {
+        self.base.set_items(Vec::new());
+        self.filter_items();
+    }
+
     /// Execute confirm callback
     pub fn do_confirm(&self) {
         self.base.do_confirm();
@@ -190,18 +234,12 @@ impl ListDelegate for ClipboardListDelegate {
         _window: &mut Window,
         _cx: &mut Context<'_, ListState<Self>>,
     ) -> impl IntoElement {
-        let theme = theme();
-        div()
-            .w_full()
-            .h(theme.empty_state_height)
-            .flex()
-            .items_center()
-            .justify_center()
-            .child(
-                div()
-                    .text_sm()
-                    .text_color(theme.empty_state_color)
-                    .child(SharedString::from("No clipboard history")),
-            )
+        let messages = config().empty_state;
+        let message = if self.base.items().is_empty() {
+            messages.clipboard()
+        } else {
+            messages.no_matches()
+        };
+        render_empty_preview(message).h(theme().empty_state_height)
     }
 }
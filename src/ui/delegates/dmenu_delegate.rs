@@ -0,0 +1,249 @@
+use crate::config::config;
+use crate::ui::components::render_empty_preview;
+use crate::ui::delegates::BaseDelegate;
+use crate::ui::theme::theme;
+use crate::ui::views::{TextMeasure, render_dmenu_item};
+use fuzzy_matcher::FuzzyMatcher;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use gpui::{App, Context, Task, Window, prelude::*};
+use gpui_component::IndexPath;
+use gpui_component::list::{ListDelegate, ListItem as GpuiListItem, ListState};
+use std::sync::Arc;
+
+/// Callback with the input position and text of the chosen line.
+type SelectCallback = Arc<dyn Fn(usize, &String) + Send + Sync>;
+
+/// Delegate for the `zlaunch dmenu` list of lines read from stdin.
+///
+/// Lines are plain strings; none of the main list's detection (calculator,
+/// AI, web search) applies to them.
+pub struct DmenuListDelegate {
+    /// Base delegate handling common behavior
+    base: BaseDelegate<String>,
+    /// Callback when a line is chosen
+    on_confirm: Option<SelectCallback>,
+}
+
+impl DmenuListDelegate {
+    /// Create a new dmenu list delegate
+    pub fn new(lines: Vec<String>) -> Self {
+        Self {
+            base: BaseDelegate::new(lines),
+            on_confirm: None,
+        }
+    }
+
+    /// Set the confirm callback (select the line).
+    ///
+    /// It gets the position of the line in the input besides its text, so
+    /// equal lines can be told apart.
+    pub fn set_on_confirm(&mut self, callback: impl Fn(usize, &String) + Send + Sync + 'static) {
+        self.on_confirm = Some(Arc::new(callback));
+    }
+
+    /// Set the cancel callback
+    pub fn set_on_cancel(&mut self, callback: impl Fn() + Send + Sync + 'static) {
+        self.base.set_on_cancel(callback);
+    }
+
+    /// Get the currently selected index
+    pub fn selected_index(&self) -> Option<usize> {
+        self.base.selected_index()
+    }
+
+    /// Get the total count of filtered items
+    pub fn filtered_count(&self) -> usize {
+        self.base.filtered_count()
+    }
+
+    /// Set the query and filter
+    pub fn set_query(&mut self, query: String) {
+        self.base.set_query(query);
+        self.filter_items();
+    }
+
+    /// Filter lines fuzzily, best matches first.
+    ///
+    /// An empty query keeps the lines in their input order.
+    fn filter_items(&mut self) {
+        let query = self.base.query().trim();
+        if query.is_empty() {
+            self.base.reset_filter();
+            return;
+        }
+
+        let matcher = SkimMatcherV2::default();
+        let mut scored: Vec<(i64, usize)> = self
+            .base
+            .items()
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, line)| Some((matcher.fuzzy_match(line, query)?, idx)))
+            .collect();
+        // Stable sort, so equal scores keep their input order
+        scored.sort_by(|a, b| b.0.cmp(&a.0));
+        self.base
+            .apply_filtered_indices(scored.into_iter().map(|(_, idx)| idx).collect());
+    }
+
+    /// Get the currently selected line
+    pub fn selected_item(&self) -> Option<&String> {
+        self.base.selected_item()
+    }
+
+    /// Get the position in the input of the currently selected line
+    pub fn selected_line_index(&self) -> Option<usize> {
+        self.base
+            .selected_index()
+            .and_then(|idx| self.base.filtered_indices().get(idx).copied())
+    }
+
+    /// Execute confirm callback
+    pub fn do_confirm(&self) {
+        if let Some(index) = self.selected_line_index()
+            && let Some(line) = self.base.items().get(index)
+            && let Some(ref callback) = self.on_confirm
+        {
+            callback(index, line);
+        }
+    }
+
+    /// Execute cancel callback
+    pub fn do_cancel(&self) {
+        self.base.do_cancel();
+    }
+
+    /// Move selection down
+    pub fn select_down(&mut self) {
+        self.base.select_down();
+    }
+
+    /// Move selection up
+    pub fn select_up(&mut self) {
+        self.base.select_up();
+    }
+
+    /// Get all lines
+    pub fn items(&self) -> &[String] {
+        self.base.items()
+    }
+}
+
+/// Implement ListDelegate trait for GPUI integration.
+impl ListDelegate for DmenuListDelegate {
+    type Item = GpuiListItem;
+
+    fn sections_count(&self, _cx: &App) -> usize {
+        1
+    }
+
+    fn items_count(&self, _section: usize, _cx: &App) -> usize {
+        self.filtered_count()
+    }
+
+    fn render_item(
+        &mut self,
+        ix: IndexPath,
+        window: &mut Window,
+        cx: &mut Context<'_, ListState<Self>>,
+    ) -> Option<Self::Item> {
+        let line = self.base.get_filtered_item(ix.row)?;
+        let is_selected = self.base.selected_index() == Some(ix.row);
+
+        let measure = TextMeasure::new(window, cx);
+        let element = render_dmenu_item(line, is_selected, &measure, ix.row);
+
+        // Reset ListItem default padding - we handle all styling ourselves
+        Some(
+            GpuiListItem::new(("dmenu-item", ix.row))
+                .py_0()
+                .px_0()
+                .child(element),
+        )
+    }
+
+    fn set_selected_index(
+        &mut self,
+        ix: Option<IndexPath>,
+        _window: &mut Window,
+        _cx: &mut Context<ListState<Self>>,
+    ) {
+        self.base.set_selected(ix.map(|i| i.row).unwrap_or(0));
+    }
+
+    fn perform_search(
+        &mut self,
+        query: &str,
+        _window: &mut Window,
+        _cx: &mut Context<ListState<Self>>,
+    ) -> Task<()> {
+        self.set_query(query.to_string());
+        Task::ready(())
+    }
+
+    fn confirm(
+        &mut self,
+        _secondary: bool,
+        _window: &mut Window,
+        _cx: &mut Context<ListState<Self>>,
+    ) {
+        self.do_confirm();
+    }
+
+    fn cancel(&mut self, _window: &mut Window, _cx: &mut Context<ListState<Self>>) {
+        self.do_cancel();
+    }
+
+    fn render_empty(
+        &mut self,
+        _window: &mut Window,
+        _cx: &mut Context<'_, ListState<Self>>,
+    ) -> impl IntoElement {
+        render_empty_preview(config().empty_state.no_matches()).h(theme().empty_state_height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(delegate: &DmenuListDelegate) -> Vec<&str> {
+        (0..delegate.filtered_count())
+            .map(|i| delegate.base.get_filtered_item(i).unwrap().as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_filter_ranks_matches_and_keeps_order_without_query() {
+        let mut delegate = DmenuListDelegate::new(vec![
+            "reboot".to_string(),
+            "shutdown".to_string(),
+            "suspend".to_string(),
+        ]);
+        assert_eq!(lines(&delegate), ["reboot", "shutdown", "suspend"]);
+
+        delegate.set_query("sus".to_string());
+        assert_eq!(lines(&delegate), ["suspend"]);
+
+        delegate.set_query(String::new());
+        assert_eq!(lines(&delegate), ["reboot", "shutdown", "suspend"]);
+    }
+
+    #[test]
+    fn test_selected_line_index_points_into_the_input() {
+        let mut delegate = DmenuListDelegate::new(vec![
+            "Open".to_string(),
+            "New Window".to_string(),
+            "New Window".to_string(),
+        ]);
+        assert_eq!(delegate.selected_line_index(), Some(0));
+
+        delegate.set_query("new".to_string());
+        delegate.select_down();
+        assert_eq!(
+            delegate.selected_item().map(String::as_str),
+            Some("New Window")
+        );
+        assert_eq!(delegate.selected_line_index(), Some(2));
+    }
+}
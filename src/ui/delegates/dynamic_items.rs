@@ -1,11 +1,14 @@
-//! Dynamic item detection for calculator, AI, and search.
+//! Dynamic item detection for calculator, AI, search, and paths.
 //!
 //! These items are generated on-the-fly based on the user's query,
-//! rather than being static items in the list.
+//! rather than being static items in the list. Script and file items are
+//! produced asynchronously and handed in once their search finishes.
 
 use crate::calculator::evaluate_expression;
-use crate::items::{AiItem, CalculatorItem, SearchItem};
-use crate::search::{SearchDetection, detect_search, get_providers};
+use crate::config::DEFAULT_AI_TRIGGER;
+use crate::items::{AiItem, CalculatorItem, FileItem, PathItem, ScriptItem, SearchItem};
+use crate::search::{SearchDetection, SearchProvider, detect_search, get_providers};
+use std::path::Path;
 
 /// Container for dynamically generated items based on user query.
 #[derive(Clone, Default)]
@@ -16,12 +19,58 @@ pub struct DynamicItems {
     pub ai_item: Option<AiItem>,
     /// Search provider items (shown when query triggers search).
     pub search_items: Vec<SearchItem>,
+    /// File or folder named by the query (shown when it exists).
+    pub path_item: Option<PathItem>,
+    /// Items listed by script sources for a recent query.
+    pub script_items: Vec<ScriptItem>,
+    /// Files found by the file search for a recent query.
+    pub file_items: Vec<FileItem>,
+    /// Minimum query length before fallback search items appear.
+    search_min_query_len: usize,
+    /// Minimum query length before the untriggered AI item appears.
+    ai_min_query_len: usize,
+    /// Query prefix that shows only the AI item; `None` disables it.
+    ai_trigger: Option<String>,
+    /// Fixed fallback search providers; `None` reads them from the config
+    /// on every query.
+    providers: Option<Vec<SearchProvider>>,
+    /// Maximum number of fallback search providers; `None` shows all.
+    max_fallback_providers: Option<usize>,
 }
 
 impl DynamicItems {
     /// Create a new empty dynamic items container.
     pub fn new() -> Self {
-        Self::default()
+        Self {
+            ai_trigger: Some(DEFAULT_AI_TRIGGER.to_string()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the minimum query lengths for untriggered search and AI items.
+    pub fn with_min_query_lengths(mut self, search: usize, ai: usize) -> Self {
+        self.search_min_query_len = search;
+        self.ai_min_query_len = ai;
+        self
+    }
+
+    /// Set the query prefix that shows only the AI item, or `None` to
+    /// disable it.
+    pub fn with_ai_trigger(mut self, trigger: Option<&str>) -> Self {
+        self.ai_trigger = trigger.map(str::to_string);
+        self
+    }
+
+    /// Limit how many search providers are offered when no trigger matches.
+    pub fn with_max_fallback_providers(mut self, max: Option<usize>) -> Self {
+        self.max_fallback_providers = max;
+        self
+    }
+
+    /// Use a fixed set of fallback search providers instead of the config's.
+    pub fn with_providers(mut self, providers: Vec<SearchProvider>) -> Self {
+        self.providers = Some(providers);
+        self
     }
 
     /// Process a query and detect dynamic items.
@@ -54,18 +103,22 @@ impl DynamicItems {
         }
 
         // Check for trigger phrases
-        let has_ai_trigger = trimmed.starts_with("!ai");
+        let ai_query = self
+            .ai_trigger
+            .as_deref()
+            .and_then(|trigger| trimmed.strip_prefix(trigger));
         let search_detection = detect_search(query);
         let has_search_trigger = matches!(search_detection, SearchDetection::Triggered { .. });
 
         // Logic:
-        // 1. If !ai trigger → only show AI item
+        // 1. If AI trigger (!ai by default) → only show AI item
         // 2. Else if search trigger (!g, !ddg, etc.) → only show that search provider
-        // 3. Else if query not empty → show AI item + all search providers at bottom
+        // 3. Else if query not empty → show AI item + the first
+        //    `max_fallback_providers` search providers at bottom
 
-        if ai_enabled && has_ai_trigger {
-            // Only show AI item when !ai trigger is used
-            let ai_query = trimmed.strip_prefix("!ai").unwrap().trim();
+        if ai_enabled && let Some(ai_query) = ai_query {
+            // Only show AI item when the AI trigger is used
+            let ai_query = ai_query.trim();
             if !ai_query.is_empty() {
                 self.ai_item = Some(AiItem::new(ai_query.to_string()));
             }
@@ -75,13 +128,15 @@ impl DynamicItems {
                 self.search_items.push(SearchItem::new(provider, query));
             }
         } else {
-            // Show AI item and all search providers when query is not empty
-            if ai_enabled {
+            // Show AI item and all search providers once the query is long enough
+            if ai_enabled && meets_min_query_len(trimmed, self.ai_min_query_len) {
                 self.ai_item = Some(AiItem::new(trimmed.to_string()));
             }
-            if search_enabled {
+            if search_enabled && meets_min_query_len(trimmed, self.search_min_query_len) {
                 if let SearchDetection::Fallback { query } = search_detection {
-                    for provider in get_providers() {
+                    let providers = self.providers.clone().unwrap_or_else(get_providers);
+                    let max = self.max_fallback_providers.unwrap_or(usize::MAX);
+                    for provider in providers.into_iter().take(max) {
                         self.search_items
                             .push(SearchItem::new(provider, query.clone()));
                     }
@@ -90,18 +145,60 @@ impl DynamicItems {
         }
     }
 
-    /// Clear all dynamic items.
+    /// Offer the file or folder the query names, if it exists under `base`.
+    ///
+    /// Call after [`Self::process_query`], which clears the previous item.
+    pub fn process_path_query(&mut self, query: &str, base: &Path) {
+        self.path_item = PathItem::for_query(query, base);
+    }
+
+    /// Clear all items derived from the query.
+    ///
+    /// Script and file items are kept until replaced with
+    /// [`Self::set_script_items`] and [`Self::set_file_items`], so they don't
+    /// flicker while the search runs for the new query.
     pub fn clear(&mut self) {
         self.calculator_item = None;
         self.ai_item = None;
         self.search_items.clear();
+        self.path_item = None;
+    }
+
+    /// Replace the script items.
+    pub fn set_script_items(&mut self, items: Vec<ScriptItem>) {
+        self.script_items = items;
+    }
+
+    /// Replace the file items.
+    pub fn set_file_items(&mut self, items: Vec<FileItem>) {
+        self.file_items = items;
     }
 
     /// Get the total count of dynamic items.
     pub fn count(&self) -> usize {
         let calc_count = if self.calculator_item.is_some() { 1 } else { 0 };
         let ai_count = if self.ai_item.is_some() { 1 } else { 0 };
-        calc_count + ai_count + self.search_items.len()
+        let path_count = if self.path_item.is_some() { 1 } else { 0 };
+        calc_count
+            + ai_count
+            + path_count
+            + self.search_items.len()
+            + self.script_items.len()
+            + self.file_items.len()
+    }
+
+    /// Drop items until at most `max` are left, returning how many were
+    /// dropped.
+    ///
+    /// Fallback search items go first, then file and script items, each
+    /// from the end. The calculator, AI and path items are always kept.
+    pub fn truncate(&mut self, max: usize) -> usize {
+        let before = self.count();
+        let mut excess = before.saturating_sub(max);
+        drop_last(&mut self.search_items, &mut excess);
+        drop_last(&mut self.file_items, &mut excess);
+        drop_last(&mut self.script_items, &mut excess);
+        before - self.count()
     }
 
     /// Check if there's a calculator item.
@@ -114,10 +211,37 @@ impl DynamicItems {
         self.ai_item.is_some()
     }
 
+    /// Check if there's a path item.
+    pub fn has_path(&self) -> bool {
+        self.path_item.is_some()
+    }
+
     /// Get the search items count.
     pub fn search_count(&self) -> usize {
         self.search_items.len()
     }
+
+    /// Get the script items count.
+    pub fn script_count(&self) -> usize {
+        self.script_items.len()
+    }
+
+    /// Get the file items count.
+    pub fn file_count(&self) -> usize {
+        self.file_items.len()
+    }
+}
+
+/// Drop up to `excess` items from the end of `items`, counting them off.
+fn drop_last<T>(items: &mut Vec<T>, excess: &mut usize) {
+    let dropped = (*excess).min(items.len());
+    items.truncate(items.len() - dropped);
+    *excess -= dropped;
+}
+
+/// Check whether a query has at least `min_len` characters.
+fn meets_min_query_len(query: &str, min_len: usize) -> bool {
+    query.chars().count() >= min_len
 }
 
 #[cfg(test)]
@@ -142,6 +266,8 @@ mod tests {
             display_result: "4".to_string(),
             clipboard_result: Some("4".to_string()),
             is_error: false,
+            unit_label: None,
+            copied: false,
         });
         assert!(items.has_calculator());
 
@@ -164,4 +290,80 @@ mod tests {
         assert!(items.has_calculator());
         assert!(!items.has_ai());
     }
+
+    #[test]
+    fn test_meets_min_query_len_counts_chars() {
+        assert!(meets_min_query_len("ab", 0));
+        assert!(meets_min_query_len("abc", 3));
+        assert!(!meets_min_query_len("ab", 3));
+        // Multi-byte characters count once
+        assert!(!meets_min_query_len("éé", 3));
+    }
+
+    #[test]
+    fn test_ai_suppressed_below_min_query_len() {
+        let mut items = DynamicItems::new().with_min_query_lengths(3, 3);
+        items.process_query("ab", false, true, true);
+        assert!(!items.has_ai());
+        assert_eq!(items.search_count(), 0);
+
+        items.process_query("abc", false, true, false);
+        assert!(items.has_ai());
+    }
+
+    #[test]
+    fn test_ai_trigger_bypasses_min_query_len() {
+        let mut items = DynamicItems::new().with_min_query_lengths(0, 10);
+        items.process_query("!ai hi", false, true, false);
+        assert!(items.has_ai());
+        assert_eq!(items.ai_item.unwrap().query, "hi");
+    }
+
+    #[test]
+    fn test_custom_ai_trigger() {
+        let mut items = DynamicItems::new().with_ai_trigger(Some("?"));
+        items.process_query("? what is rust", false, true, true);
+        assert_eq!(items.ai_item.as_ref().unwrap().query, "what is rust");
+        assert_eq!(items.search_count(), 0);
+
+        // The default trigger is no longer special
+        items.process_query("!ai hi", false, true, false);
+        assert_eq!(items.ai_item.unwrap().query, "!ai hi");
+    }
+
+    #[test]
+    fn test_disabled_ai_trigger() {
+        let mut items = DynamicItems::new().with_ai_trigger(None);
+        items.process_query("!ai hi", false, true, false);
+        assert_eq!(items.ai_item.unwrap().query, "!ai hi");
+    }
+
+    #[test]
+    fn test_max_fallback_providers_keeps_triggered() {
+        let providers = crate::search::get_providers();
+        assert!(providers.len() > 2);
+        let trigger = providers[1].trigger.clone();
+
+        let mut items = DynamicItems::new()
+            .with_providers(providers.clone())
+            .with_max_fallback_providers(Some(2));
+        items.process_query("rust", false, false, true);
+        let names: Vec<&str> = items
+            .search_items
+            .iter()
+            .map(|item| item.provider.name.as_str())
+            .collect();
+        assert_eq!(
+            names,
+            [providers[0].name.as_str(), providers[1].name.as_str()]
+        );
+
+        // A trigger is honored even when the cap leaves out its provider
+        let mut items = DynamicItems::new().with_max_fallback_providers(Some(0));
+        items.process_query("rust", false, false, true);
+        assert_eq!(items.search_count(), 0);
+        items.process_query(&format!("{} rust", trigger), false, false, true);
+        assert_eq!(items.search_count(), 1);
+        assert_eq!(items.search_items[0].query, "rust");
+    }
 }
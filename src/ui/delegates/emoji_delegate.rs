@@ -1,8 +1,10 @@
+use crate::config::config;
 use crate::emoji::EmojiItem;
+use crate::ui::components::render_empty_preview;
 use crate::ui::delegates::BaseDelegate;
 use crate::ui::theme::theme;
 use crate::ui::views::render_emoji_row;
-use gpui::{App, Context, SharedString, Task, Window, div, prelude::*};
+use gpui::{App, Context, Task, Window, prelude::*};
 use gpui_component::IndexPath;
 use gpui_component::list::{ListDelegate, ListItem as GpuiListItem, ListState};
 
@@ -254,18 +256,6 @@ impl ListDelegate for EmojiGridDelegate {
         _window: &mut Window,
         _cx: &mut Context<'_, ListState<Self>>,
     ) -> impl IntoElement {
-        let theme = theme();
-        div()
-            .w_full()
-            .h(theme.empty_state_height)
-            .flex()
-            .items_center()
-            .justify_center()
-            .child(
-                div()
-                    .text_sm()
-                    .text_color(theme.empty_state_color)
-                    .child(SharedString::from("No emojis found")),
-            )
+        render_empty_preview(config().empty_state.no_matches()).h(theme().empty_state_height)
     }
 }
@@ -1,22 +1,33 @@
 //! Enhanced delegate for the main item list.
 //!
-//! Composes BaseDelegate with dynamic items (calculator, AI, search)
-//! and section management.
+//! Composes BaseDelegate with dynamic items (calculator, AI, search,
+//! scripts, files) and section management.
 
 use crate::ai::LLMClient;
-use crate::config::{ConfigModule, config};
-use crate::items::{ActionItem, ListItem, SubmenuItem};
+use crate::config::{
+    AppConfig, BadgeConfig, ConfigModule, ConfigScriptSource, SectionHeaderVisibility, config,
+};
+use crate::desktop::recent::RecentApps;
+use crate::files::FileSearch;
+use crate::items::{
+    ActionItem, FileItem, InlineAnswer, LearnedSelections, ListItem, ScriptItem, SubmenuItem,
+    expand_path, record_selection,
+};
+use crate::search::providers_from_config;
+use crate::ui::components::render_empty_preview;
 use crate::ui::delegates::BaseDelegate;
 use crate::ui::theme::theme;
-use crate::ui::views::render_item;
+use crate::ui::views::{TextMeasure, render_ai_answer, render_item};
 use gpui::{App, Context, SharedString, Task, Window, div, prelude::*};
 use gpui_component::IndexPath;
 use gpui_component::list::{ListDelegate, ListItem as GpuiListItem, ListState};
+use std::path::PathBuf;
 use std::sync::Arc;
 
+use super::category_filter::{CategoryQuery, category_submenus, matches_category};
 use super::dynamic_items::DynamicItems;
-use super::item_filter::ItemFilter;
-use super::section_manager::{SectionManager, SectionType};
+use super::item_filter::{FilteredItem, ItemFilter};
+use super::section_manager::{ItemSlot, SectionManager};
 
 /// Type alias for confirm callback.
 type ConfirmCallback = Arc<dyn Fn(&ListItem) + Send + Sync>;
@@ -41,15 +52,125 @@ pub struct ItemListDelegate {
     on_confirm: Option<ConfirmCallback>,
     /// Modules enabled in combined view (for filtering).
     combined_modules: Vec<ConfigModule>,
+    /// When to render section headers.
+    section_headers: SectionHeaderVisibility,
+    /// Query prefix restricting results to applications.
+    app_only_prefix: Option<String>,
+    /// Whether the current query carries the app-only prefix.
+    app_only: bool,
+    /// Query prefix for browsing applications by category.
+    category_prefix: Option<String>,
+    /// The category query, if the current query carries the category prefix.
+    category: Option<CategoryQuery>,
+    /// Query prefix forcing exact substring matching.
+    exact_match_prefix: Option<String>,
+    /// Directory path queries are resolved in; `None` disables path items.
+    path_base_dir: Option<PathBuf>,
+    /// Whether AI counts as configured; `None` checks for API keys.
+    ai_configured: Option<bool>,
+    /// Maximum number of rows, including dynamic items.
+    max_results: Option<usize>,
+    /// Number of matches left out by `max_results`.
+    hidden_count: usize,
+    /// Whether typing selects the top result again.
+    reselect_on_type: bool,
+    /// Recently launched application ids, most recent first.
+    recent_ids: Vec<String>,
+    /// Pinned application ids, in display order.
+    pinned_ids: Vec<String>,
+    /// Whether confirming an item remembers it for the query.
+    learn_selections: bool,
+    /// Answer shown in the AI item's row, with `ai_answer_inline`.
+    inline_answer: Option<InlineAnswer>,
+    /// External commands listing items for the query.
+    script_sources: Vec<ConfigScriptSource>,
+    /// Query the script sources should run for; `None` when scripts don't
+    /// apply to the current query.
+    script_query: Option<String>,
+    /// Where the file search looks; `None` disables it.
+    file_search: Option<FileSearch>,
+    /// Query prefix that searches files outside the files mode.
+    file_search_prefix: Option<String>,
+    /// Text to search files for; `None` when file search doesn't apply to
+    /// the current query.
+    file_query: Option<String>,
+    /// Badges shown at the end of items.
+    badges: BadgeConfig,
+}
+
+/// Check once whether the AI module can be offered, so it stays hidden
+/// without an API key instead of failing when asked.
+fn ai_module_available(combined_modules: &[ConfigModule]) -> bool {
+    if !combined_modules.contains(&ConfigModule::Ai) {
+        return false;
+    }
+    match LLMClient::check_key() {
+        Ok(()) => true,
+        Err(e) => {
+            tracing::debug!(%e, "Hiding the AI module");
+            false
+        }
+    }
 }
 
 impl ItemListDelegate {
     /// Create a new item list delegate with specified combined modules.
-    pub fn new(mut items: Vec<ListItem>, combined_modules: Vec<ConfigModule>) -> Self {
+    pub fn new(items: Vec<ListItem>, combined_modules: Vec<ConfigModule>) -> Self {
+        let app_config = config();
+        let mut recent_ids = RecentApps::load().into_ids();
+        recent_ids.truncate(app_config.recent_apps_count);
+        let ai_configured = ai_module_available(&combined_modules);
+        let mut delegate =
+            Self::build(items, combined_modules, &app_config).with_ai_configured(ai_configured);
+        if app_config.learn_selections {
+            delegate
+                .filter
+                .set_learned_selections(LearnedSelections::load());
+        }
+        delegate.with_recent_apps(recent_ids)
+    }
+
+    /// Create a delegate from an explicit config, without global state or
+    /// a GPUI window.
+    ///
+    /// Search providers are taken from `app_config` once. Combine with
+    /// [`Self::with_ai_configured`] to make AI items deterministic, e.g. to
+    /// test item ordering headlessly.
+    pub fn from_config(
+        items: Vec<ListItem>,
+        combined_modules: Vec<ConfigModule>,
+        app_config: &AppConfig,
+    ) -> Self {
+        let mut delegate = Self::build(items, combined_modules, app_config);
+        delegate.dynamic = delegate
+            .dynamic
+            .with_providers(providers_from_config(app_config));
+        delegate
+    }
+
+    /// Override whether AI counts as configured instead of checking for API keys.
+    pub fn with_ai_configured(mut self, configured: bool) -> Self {
+        self.ai_configured = Some(configured);
+        self
+    }
+
+    /// Set the applications listed in the Recent section, most recent first.
+    pub fn with_recent_apps(mut self, recent_ids: Vec<String>) -> Self {
+        self.recent_ids = recent_ids;
+        self.filter_items();
+        self
+    }
+
+    fn build(
+        mut items: Vec<ListItem>,
+        combined_modules: Vec<ConfigModule>,
+        app_config: &AppConfig,
+    ) -> Self {
         // Filter items based on combined_modules
         items.retain(|item| match item {
             ListItem::Application(_) => combined_modules.contains(&ConfigModule::Applications),
             ListItem::Window(_) => combined_modules.contains(&ConfigModule::Windows),
+            ListItem::Workspace(_) => combined_modules.contains(&ConfigModule::Workspaces),
             _ => true, // Keep other items for now
         });
 
@@ -61,6 +182,13 @@ impl ItemListDelegate {
                     .with_icon("smiley"),
             ));
         }
+        if combined_modules.contains(&ConfigModule::Glyphs) {
+            items.push(ListItem::Submenu(
+                SubmenuItem::grid("submenu-glyphs", "Nerd Font Glyphs", 8)
+                    .with_description("Search and copy Nerd Font glyphs")
+                    .with_icon("terminal"),
+            ));
+        }
         if combined_modules.contains(&ConfigModule::Clipboard) {
             items.push(ListItem::Submenu(
                 SubmenuItem::list("submenu-clipboard", "Clipboard History")
@@ -76,11 +204,23 @@ impl ItemListDelegate {
             ));
         }
 
-        // Add built-in action items (shutdown, reboot, etc.)
+        // Add category submenus, shown while a category is being typed
+        if combined_modules.contains(&ConfigModule::Applications)
+            && app_config.category_prefix().is_some()
+        {
+            let categories = category_submenus(&items);
+            items.extend(categories.into_iter().map(ListItem::Submenu));
+        }
+
+        // Add built-in action items (shutdown, reboot, etc.), then the
+        // user's own
         if combined_modules.contains(&ConfigModule::Actions) {
             for action in ActionItem::builtins() {
                 items.push(ListItem::Action(action));
             }
+            for action in ActionItem::customs(&app_config.custom_actions) {
+                items.push(ListItem::Action(action));
+            }
         }
 
         // Sort items by their position in combined_modules
@@ -109,21 +249,56 @@ impl ItemListDelegate {
         }
 
         // Get fuzzy match config from application config
-        let fuzzy_config = config().fuzzy_match.clone();
+        let fuzzy_config = app_config.fuzzy_match.clone();
 
-        let mut sections =
-            SectionManager::new(combined_modules.clone(), fuzzy_config.show_best_match);
-        let filtered_indices: Vec<usize> = (0..items.len()).collect();
-        sections.update(&items, &filtered_indices, false, false, 0);
+        let sections = SectionManager::new(combined_modules.clone(), fuzzy_config.show_best_match);
 
-        Self {
+        let mut delegate = Self {
             base: BaseDelegate::new(items),
             filter: ItemFilter::new(fuzzy_config),
-            dynamic: DynamicItems::new(),
+            dynamic: DynamicItems::new()
+                .with_min_query_lengths(
+                    app_config.search_min_query_len,
+                    app_config.ai_min_query_len,
+                )
+                .with_ai_trigger(app_config.ai_trigger())
+                .with_max_fallback_providers(app_config.max_fallback_search_providers),
             sections,
             on_confirm: None,
             combined_modules,
-        }
+            section_headers: app_config.show_section_headers,
+            app_only_prefix: app_config.app_only_prefix().map(str::to_string),
+            app_only: false,
+            category_prefix: app_config.category_prefix().map(str::to_string),
+            category: None,
+            exact_match_prefix: app_config.exact_match_prefix().map(str::to_string),
+            path_base_dir: app_config
+                .path_base_dir()
+                .and_then(|dir| expand_path(dir, |name| std::env::var(name).ok())),
+            ai_configured: None,
+            max_results: app_config.max_results,
+            hidden_count: 0,
+            reselect_on_type: app_config.reselect_on_type,
+            recent_ids: Vec::new(),
+            pinned_ids: app_config.pinned_applications.clone(),
+            learn_selections: app_config.learn_selections,
+            inline_answer: None,
+            script_sources: app_config.scripts.clone(),
+            script_query: None,
+            file_search: app_config
+                .file_search_root()
+                .and_then(|dir| expand_path(dir, |name| std::env::var(name).ok()))
+                .map(|root| FileSearch {
+                    root,
+                    max_depth: app_config.file_search_max_depth,
+                }),
+            file_search_prefix: app_config.file_search_prefix().map(str::to_string),
+            file_query: None,
+            badges: app_config.badges.clone(),
+        };
+        // Apply the initial (empty) query so the cap holds before typing
+        delegate.filter_items();
+        delegate
     }
 
     /// Set the confirm callback.
@@ -158,37 +333,219 @@ impl ItemListDelegate {
         self.base.query()
     }
 
+    /// Show an answer in the AI item's row.
+    ///
+    /// The answer is dropped once the query no longer asks the same question.
+    pub fn set_inline_answer(&mut self, answer: InlineAnswer) {
+        self.inline_answer = Some(answer);
+    }
+
+    /// Get the inline answer being shown, if any.
+    pub fn inline_answer(&self) -> Option<&InlineAnswer> {
+        self.inline_answer.as_ref()
+    }
+
+    /// Get the inline answer being shown, to stream more text into it.
+    pub fn inline_answer_mut(&mut self) -> Option<&mut InlineAnswer> {
+        self.inline_answer.as_mut()
+    }
+
+    /// Mark the calculator result as copied, or clear the mark.
+    pub fn set_calculator_copied(&mut self, copied: bool) {
+        if let Some(calc) = self.dynamic.calculator_item.as_mut() {
+            calc.copied = copied;
+        }
+    }
+
+    /// Get the script sources and the query to run them for, if script
+    /// items apply to the current query.
+    pub fn script_request(&self) -> Option<(Vec<ConfigScriptSource>, String)> {
+        self.script_query
+            .clone()
+            .map(|query| (self.script_sources.clone(), query))
+    }
+
+    /// Show the items the script sources listed for `query`.
+    ///
+    /// Results for a query other than the current one are stale and
+    /// ignored. The selected item stays selected if it is still listed.
+    pub fn set_script_items(&mut self, query: &str, items: Vec<ScriptItem>) {
+        if self.script_query.as_deref() != Some(query) {
+            return;
+        }
+
+        let previous_id = self.selected_item_id();
+        self.dynamic.set_script_items(items);
+        self.filter_items();
+        self.reselect(previous_id);
+    }
+
+    /// Get where to search files and the text to search for, if file items
+    /// apply to the current query.
+    pub fn file_request(&self) -> Option<(FileSearch, String)> {
+        Some((self.file_search.clone()?, self.file_query.clone()?))
+    }
+
+    /// Show the files found for `query`.
+    ///
+    /// Results for a query other than the current one are stale and
+    /// ignored. The selected item stays selected if it is still listed.
+    pub fn set_file_items(&mut self, query: &str, items: Vec<FileItem>) {
+        if self.file_query.as_deref() != Some(query) {
+            return;
+        }
+
+        let previous_id = self.selected_item_id();
+        self.dynamic.set_file_items(items);
+        self.filter_items();
+        self.reselect(previous_id);
+    }
+
+    /// Get the text to search files for, if file search applies to `query`.
+    ///
+    /// The files mode searches for the whole query, other views only for
+    /// queries starting with the file search prefix.
+    fn file_search_text(&self, query: &str) -> Option<String> {
+        let text = if self.combined_modules == [ConfigModule::Files] {
+            query
+        } else {
+            query.strip_prefix(self.file_search_prefix.as_deref()?)?
+        };
+        let text = text.trim();
+        (!text.is_empty()).then(|| text.to_string())
+    }
+
     /// Clear the query and reset all dynamic items.
     pub fn clear_query(&mut self) {
+        self.app_only = false;
+        self.category = None;
+        self.filter.set_substring_only(false);
         self.dynamic.clear();
+        self.dynamic.set_script_items(Vec::new());
+        self.script_query = None;
+        self.dynamic.set_file_items(Vec::new());
+        self.file_query = None;
+        self.inline_answer = None;
         self.base.clear_query();
         // Re-filter to reset sections
         self.filter_items();
     }
 
     /// Set the query and trigger filtering.
+    ///
+    /// A query starting with the app-only prefix is matched without the
+    /// prefix and only against applications. A query starting with the
+    /// category prefix lists categories, then the applications in one.
+    /// A query starting with the exact match prefix (after the app-only
+    /// prefix, if any) is matched as a substring rather than fuzzily.
     pub fn set_query(&mut self, query: String) {
+        self.category = self
+            .category_prefix
+            .as_deref()
+            .and_then(|prefix| CategoryQuery::parse(&query, prefix));
+        if let Some(category) = &self.category {
+            let query = category.search_text().to_string();
+            self.app_only = false;
+            self.filter.set_substring_only(false);
+            self.base.set_query(query.clone());
+            self.process_query(&query);
+            return;
+        }
+
+        let app_only_query = self
+            .app_only_prefix
+            .as_deref()
+            .and_then(|prefix| query.strip_prefix(prefix))
+            .map(|rest| rest.trim_start().to_string());
+        self.app_only = app_only_query.is_some();
+        let query = app_only_query.unwrap_or(query);
+
+        let exact_query = self
+            .exact_match_prefix
+            .as_deref()
+            .and_then(|prefix| query.strip_prefix(prefix))
+            .map(str::to_string);
+        self.filter.set_substring_only(exact_query.is_some());
+        let query = exact_query.unwrap_or(query);
+
         self.base.set_query(query.clone());
         self.process_query(&query);
     }
 
     /// Process the query to detect special items.
+    ///
+    /// The top result is selected afterwards, unless `reselect_on_type` is
+    /// off and the previously selected item still matches.
     fn process_query(&mut self, query: &str) {
-        let ai_enabled =
-            self.combined_modules.contains(&ConfigModule::Ai) && LLMClient::is_configured();
-        let calculator_enabled = self.combined_modules.contains(&ConfigModule::Calculator);
-        let search_enabled = self.combined_modules.contains(&ConfigModule::Search);
+        let previous_id = if self.reselect_on_type {
+            None
+        } else {
+            self.selected_item_id()
+        };
+
+        let dynamic_enabled = !self.app_only && self.category.is_none();
+        let ai_enabled = dynamic_enabled
+            && self.combined_modules.contains(&ConfigModule::Ai)
+            && self.ai_configured.unwrap_or_else(LLMClient::is_configured);
+        let calculator_enabled =
+            dynamic_enabled && self.combined_modules.contains(&ConfigModule::Calculator);
+        let search_enabled =
+            dynamic_enabled && self.combined_modules.contains(&ConfigModule::Search);
 
         // Process dynamic items
         self.dynamic
             .process_query(query, calculator_enabled, ai_enabled, search_enabled);
+        if dynamic_enabled && let Some(base) = &self.path_base_dir {
+            self.dynamic.process_path_query(query, base);
+        }
+        let scripts_enabled = dynamic_enabled
+            && self.combined_modules.contains(&ConfigModule::Scripts)
+            && !self.script_sources.is_empty()
+            && !query.trim().is_empty();
+        self.script_query = scripts_enabled.then(|| query.trim().to_string());
+        if !scripts_enabled {
+            self.dynamic.set_script_items(Vec::new());
+        }
+        let files_enabled = dynamic_enabled
+            && self.combined_modules.contains(&ConfigModule::Files)
+            && self.file_search.is_some();
+        self.file_query = files_enabled
+            .then(|| self.file_search_text(query))
+            .flatten();
+        if self.file_query.is_none() {
+            self.dynamic.set_file_items(Vec::new());
+        }
+        let ai_query = self.dynamic.ai_item.as_ref().map(|ai| ai.query.as_str());
+        if self
+            .inline_answer
+            .as_ref()
+            .is_some_and(|answer| ai_query != Some(answer.query.as_str()))
+        {
+            self.inline_answer = None;
+        }
 
         // Filter the base items
         self.filter_items();
+        self.reselect(previous_id);
+    }
 
-        // Ensure selection is initialized when we have items
-        if self.base.selected_index().is_none() && self.filtered_count() > 0 {
-            self.base.set_selected_unchecked(0);
+    /// Get the id of the selected item.
+    fn selected_item_id(&self) -> Option<String> {
+        self.selected_index()
+            .and_then(|index| self.get_item_at(index))
+            .map(|item| item.id().to_string())
+    }
+
+    /// Select the item with `previous_id` if it is still listed, otherwise
+    /// the top result.
+    fn reselect(&mut self, previous_id: Option<String>) {
+        let kept = previous_id.and_then(|id| {
+            (0..self.filtered_count())
+                .find(|&index| self.get_item_at(index).is_some_and(|item| item.id() == id))
+        });
+        // Something is always selected, so Enter always does something
+        if let Some(index) = kept.or((self.filtered_count() > 0).then_some(0)) {
+            self.base.set_selected_unchecked(index);
         }
     }
 
@@ -198,15 +555,77 @@ impl ItemListDelegate {
         let items = self.base.items();
 
         // Get filtered items with scores for best-match detection
-        let filtered = self
+        let mut filtered = self
             .filter
             .filter_with_scores(items, query, &self.combined_modules);
+        match &self.category {
+            Some(CategoryQuery::Picking(_)) => {
+                filtered.retain(|f| items[f.index].is_category_submenu());
+            }
+            Some(CategoryQuery::Filtering { category, .. }) => {
+                filtered.retain(|f| match &items[f.index] {
+                    ListItem::Application(app) => matches_category(&app.categories, category),
+                    _ => false,
+                });
+            }
+            None => {
+                filtered.retain(|f| !items[f.index].is_category_submenu());
+                if self.app_only {
+                    filtered.retain(|f| items[f.index].is_application());
+                }
+            }
+        }
+
+        // List recently launched applications first while nothing is typed
+        let show_recent = query.is_empty() && self.category.is_none() && !self.app_only;
+        if show_recent {
+            promote_recent(&mut filtered, items, &self.recent_ids);
+        }
+        // Pinned applications lead the list whenever they match
+        let show_pinned = self.category.is_none();
+        if show_pinned {
+            promote_pinned(&mut filtered, items, &self.pinned_ids);
+        }
+
+        // Keep the best results within the cap. Dynamic items count against
+        // it, and are cut down too when they alone would exceed it.
+        self.hidden_count = match self.max_results {
+            Some(max) => {
+                let dynamic_hidden = self.dynamic.truncate(max);
+                let limit = max.saturating_sub(self.dynamic.count());
+                dynamic_hidden + cap_results(&mut filtered, limit)
+            }
+            None => 0,
+        };
+        let pinned_count = if show_pinned {
+            filtered
+                .iter()
+                .take_while(|f| is_listed_app(&items[f.index], &self.pinned_ids))
+                .count()
+        } else {
+            0
+        };
+        let recent_count = if show_recent {
+            filtered
+                .iter()
+                .filter(|f| {
+                    is_listed_app(&items[f.index], &self.recent_ids)
+                        && !is_listed_app(&items[f.index], &self.pinned_ids)
+                })
+                .count()
+        } else {
+            0
+        };
 
         // Extract indices for base delegate
         let filtered_indices: Vec<usize> = filtered.iter().map(|f| f.index).collect();
         self.base.apply_filtered_indices(filtered_indices);
 
         // Update sections with scores
+        self.sections.set_has_path(self.dynamic.has_path());
+        self.sections.set_script_count(self.dynamic.script_count());
+        self.sections.set_file_count(self.dynamic.file_count());
+        self.sections.set_pinned_count(pinned_count);
         self.sections.update_with_scores(
             self.base.items(),
             &filtered,
@@ -214,6 +633,7 @@ impl ItemListDelegate {
             self.dynamic.has_ai(),
             self.dynamic.search_count(),
         );
+        self.sections.set_recent_count(recent_count);
 
         // Ensure selection is initialized
         if self.base.selected_index().is_none() && self.filtered_count() > 0 {
@@ -221,99 +641,48 @@ impl ItemListDelegate {
         }
     }
 
-    /// Get an item at a global index (including dynamic items).
-    pub fn get_item_at(&self, global_index: usize) -> Option<ListItem> {
-        // Track offset within regular items (excluding best match)
-        let mut regular_item_offset = 0;
-        let mut current_start = 0;
-
-        // Get the best match position if applicable
-        let best_match_pos = self.sections.best_match_filtered_pos();
-
-        for section_type in self.sections.ordered_section_types() {
-            let section_count = self.sections.section_item_count(section_type);
-            let section_end = current_start + section_count;
-
-            if global_index >= current_start && global_index < section_end {
-                let row = global_index - current_start;
-
-                return match section_type {
-                    SectionType::BestMatch => {
-                        // Return the promoted best match item
-                        let best_pos = best_match_pos?;
-                        self.base.get_filtered_item(best_pos).cloned()
-                    }
-                    SectionType::Calculator => self
-                        .dynamic
-                        .calculator_item
-                        .clone()
-                        .map(ListItem::Calculator),
-                    SectionType::Windows | SectionType::Commands | SectionType::Applications => {
-                        // Calculate the actual index, skipping the best match if it was in this section
-                        let base_idx = self.get_adjusted_base_index(
-                            regular_item_offset,
-                            row,
-                            section_type,
-                            best_match_pos,
-                        );
-                        self.base.get_filtered_item(base_idx).cloned()
-                    }
-                    SectionType::SearchAndAi => {
-                        let ai_count = if self.dynamic.has_ai() { 1 } else { 0 };
-                        if row == 0 && self.dynamic.has_ai() {
-                            self.dynamic.ai_item.clone().map(ListItem::Ai)
-                        } else {
-                            let search_idx = row - ai_count;
-                            self.dynamic
-                                .search_items
-                                .get(search_idx)
-                                .cloned()
-                                .map(ListItem::Search)
-                        }
-                    }
-                };
-            }
+    /// Get the number of matches hidden by the `max_results` cap.
+    pub fn hidden_count(&self) -> usize {
+        self.hidden_count
+    }
 
-            // Track offset for regular items (excluding BestMatch and Calculator)
-            if matches!(
-                section_type,
-                SectionType::Windows | SectionType::Commands | SectionType::Applications
-            ) {
-                regular_item_offset += section_count;
-                // Add 1 if best match was from this section (since we subtracted it from count)
-                if self.sections.best_match_original_section() == Some(section_type) {
-                    regular_item_offset += 1;
-                }
-            }
-            current_start = section_end;
-        }
+    /// Get the query that browses `category`, if category browsing is enabled.
+    pub fn category_query(&self, category: &str) -> Option<String> {
+        self.category_prefix
+            .as_ref()
+            .map(|prefix| format!("{}{} ", prefix, category))
+    }
 
-        None
-    }
-
-    /// Get the adjusted base index, accounting for the best match being skipped.
-    fn get_adjusted_base_index(
-        &self,
-        offset: usize,
-        row: usize,
-        section_type: SectionType,
-        best_match_pos: Option<usize>,
-    ) -> usize {
-        let base_idx = offset + row;
-
-        // If best match is from this section and we're past where it would be, add 1
-        if let Some(best_pos) = best_match_pos {
-            if self.sections.best_match_original_section() == Some(section_type) {
-                // The best match was removed from this section
-                // We need to map our row to the correct position in filtered items
-                // by skipping the best match position
-                if base_idx >= best_pos {
-                    return base_idx + 1;
-                }
-            }
+    /// Get an item at a global index (including dynamic items).
+    pub fn get_item_at(&self, global_index: usize) -> Option<ListItem> {
+        match self.sections.resolve(global_index)? {
+            ItemSlot::Filtered(pos) => self.base.get_filtered_item(pos).cloned(),
+            ItemSlot::Calculator => self
+                .dynamic
+                .calculator_item
+                .clone()
+                .map(ListItem::Calculator),
+            ItemSlot::Ai => self.dynamic.ai_item.clone().map(ListItem::Ai),
+            ItemSlot::Path => self.dynamic.path_item.clone().map(ListItem::Path),
+            ItemSlot::Search(index) => self
+                .dynamic
+                .search_items
+                .get(index)
+                .cloned()
+                .map(ListItem::Search),
+            ItemSlot::Script(index) => self
+                .dynamic
+                .script_items
+                .get(index)
+                .cloned()
+                .map(ListItem::Script),
+            ItemSlot::File(index) => self
+                .dynamic
+                .file_items
+                .get(index)
+                .cloned()
+                .map(ListItem::File),
         }
-
-        base_idx
     }
 
     /// Execute confirm callback for the selected item.
@@ -322,10 +691,24 @@ impl ItemListDelegate {
             && let Some(item) = self.get_item_at(idx)
             && let Some(ref callback) = self.on_confirm
         {
+            self.learn_selection(&item);
             callback(&item);
         }
     }
 
+    /// Remember `item` as the selection for the current query.
+    ///
+    /// Only filtered items are learned; dynamic items like calculator
+    /// results are generated from the query and never need a boost.
+    fn learn_selection(&self, item: &ListItem) {
+        if !self.learn_selections || self.query().trim().is_empty() {
+            return;
+        }
+        if self.base.items().iter().any(|i| i.id() == item.id()) {
+            record_selection(self.query(), item.id());
+        }
+    }
+
     /// Execute cancel callback.
     pub fn do_cancel(&self) {
         self.base.do_cancel();
@@ -366,6 +749,83 @@ impl ItemListDelegate {
     }
 }
 
+/// Check whether an item is an application whose id is in `ids`.
+fn is_listed_app(item: &ListItem, ids: &[String]) -> bool {
+    matches!(item, ListItem::Application(app) if ids.contains(&app.id))
+}
+
+/// Move pinned applications to the front of the results, in pinned order.
+fn promote_pinned(filtered: &mut Vec<FilteredItem>, items: &[ListItem], pinned_ids: &[String]) {
+    let pinned_rank = |f: &FilteredItem| match &items[f.index] {
+        ListItem::Application(app) => pinned_ids.iter().position(|id| *id == app.id),
+        _ => None,
+    };
+
+    let mut pinned: Vec<FilteredItem> = filtered
+        .iter()
+        .filter(|f| pinned_rank(f).is_some())
+        .copied()
+        .collect();
+    if pinned.is_empty() {
+        return;
+    }
+    pinned.sort_by_key(pinned_rank);
+
+    filtered.retain(|f| pinned_rank(f).is_none());
+    filtered.splice(0..0, pinned);
+}
+
+/// Move recently launched applications in front of the other applications,
+/// most recent first.
+fn promote_recent(filtered: &mut Vec<FilteredItem>, items: &[ListItem], recent_ids: &[String]) {
+    let Some(first_app) = filtered
+        .iter()
+        .position(|f| items[f.index].is_application())
+    else {
+        return;
+    };
+    let recent_rank = |f: &FilteredItem| match &items[f.index] {
+        ListItem::Application(app) => recent_ids.iter().position(|id| *id == app.id),
+        _ => None,
+    };
+
+    let mut recent: Vec<FilteredItem> = filtered
+        .iter()
+        .filter(|f| recent_rank(f).is_some())
+        .copied()
+        .collect();
+    if recent.is_empty() {
+        return;
+    }
+    recent.sort_by_key(recent_rank);
+
+    // Only applications move, so everything before `first_app` stays put
+    filtered.retain(|f| recent_rank(f).is_none());
+    filtered.splice(first_app..first_app, recent);
+}
+
+/// Keep the `limit` best scoring results, preserving their order.
+///
+/// Ties keep the earlier result, so an empty query keeps the first rows.
+/// Returns the number of results removed.
+fn cap_results(filtered: &mut Vec<FilteredItem>, limit: usize) -> usize {
+    if filtered.len() <= limit {
+        return 0;
+    }
+
+    let mut by_score: Vec<usize> = (0..filtered.len()).collect();
+    by_score.sort_by(|&a, &b| filtered[b].score.cmp(&filtered[a].score));
+    let mut keep = vec![false; filtered.len()];
+    for &pos in &by_score[..limit] {
+        keep[pos] = true;
+    }
+
+    let removed = filtered.len() - limit;
+    let mut keep = keep.into_iter();
+    filtered.retain(|_| keep.next().unwrap_or(false));
+    removed
+}
+
 /// Implement ListDelegate trait for GPUI integration.
 impl ListDelegate for ItemListDelegate {
     type Item = GpuiListItem;
@@ -386,41 +846,101 @@ impl ListDelegate for ItemListDelegate {
         _cx: &mut Context<'_, ListState<Self>>,
     ) -> Option<impl IntoElement> {
         let section_type = self.sections.section_type_at(section);
-        let sections = self.sections.ordered_section_types();
-        let section_count = sections.len();
+        let show_header = self
+            .sections
+            .show_section_header(section_type, self.section_headers);
 
-        // Don't show headers if there's only one section
-        if section_count <= 1 {
+        let theme = theme();
+        let layout = &theme.layout;
+        // Gap and separator go between sections, never above the first one
+        let divided =
+            section > 0 && (layout.section_separator || layout.section_gap > gpui::px(0.0));
+        if !show_header && !divided {
             return None;
         }
 
-        let theme = theme();
-        let title = section_type.title();
-
-        Some(
+        let separator = (section > 0 && layout.section_separator).then(|| {
+            div()
+                .mx(theme.item_margin_x)
+                .h(layout.separator_width)
+                .bg(theme.window_border)
+        });
+        let header = show_header.then(|| {
             div()
-                .w_full()
                 .px(theme.item_margin_x + theme.item_padding_x)
                 .pt(theme.section_header.margin_top)
                 .pb(theme.section_header.margin_bottom)
                 .text_xs()
                 .font_weight(gpui::FontWeight::EXTRA_BOLD)
                 .text_color(theme.section_header.color)
-                .child(SharedString::from(title)),
+                .child(SharedString::from(section_type.title()))
+        });
+
+        Some(
+            div()
+                .w_full()
+                .when(section > 0, |this| this.pt(layout.section_gap))
+                .children(separator)
+                .children(header),
         )
     }
 
-    fn render_item(
+    fn render_section_footer(
         &mut self,
-        ix: IndexPath,
+        section: usize,
         _window: &mut Window,
         _cx: &mut Context<'_, ListState<Self>>,
+    ) -> Option<impl IntoElement> {
+        // Hint below the last section when results were capped
+        if self.hidden_count == 0 || section + 1 != self.sections.sections_count() {
+            return None;
+        }
+
+        let theme = theme();
+        let noun = if self.hidden_count == 1 {
+            "result"
+        } else {
+            "results"
+        };
+        Some(
+            div()
+                .w_full()
+                .py(theme.section_header.margin_top)
+                .flex()
+                .justify_center()
+                .text_xs()
+                .text_color(theme.empty_state_color)
+                .child(SharedString::from(format!(
+                    "{} more {} hidden",
+                    self.hidden_count, noun
+                ))),
+        )
+    }
+
+    fn render_item(
+        &mut self,
+        ix: IndexPath,
+        window: &mut Window,
+        cx: &mut Context<'_, ListState<Self>>,
     ) -> Option<Self::Item> {
         let global_idx = self.sections.section_row_to_global(ix.section, ix.row);
         let selected = self.base.selected_index() == Some(global_idx);
 
         let item = self.get_item_at(global_idx)?;
-        let item_content = render_item(&item, selected, global_idx);
+        let measure = TextMeasure::new(window, cx);
+        let item_content = match (&item, &self.inline_answer) {
+            (ListItem::Ai(ai), Some(answer)) => {
+                render_ai_answer(ai, answer, selected, &measure, global_idx)
+            }
+            _ => render_item(
+                &item,
+                selected,
+                &window.modifiers(),
+                &self.badges,
+                &measure,
+                global_idx,
+            ),
+        };
 
         Some(
             GpuiListItem::new(("list-item", global_idx))
@@ -471,18 +991,695 @@ impl ListDelegate for ItemListDelegate {
         _window: &mut Window,
         _cx: &mut Context<'_, ListState<Self>>,
     ) -> impl IntoElement {
-        let theme = theme();
-        div()
-            .w_full()
-            .h(theme.empty_state_height)
-            .flex()
-            .items_center()
-            .justify_center()
-            .child(
-                div()
-                    .text_sm()
-                    .text_color(theme.empty_state_color)
-                    .child(SharedString::from("No items found")),
-            )
+        render_empty_preview(config().empty_state.no_matches()).h(theme().empty_state_height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::section_manager::SectionType;
+    use super::*;
+    use crate::test_utils::{mock_application, mock_list_items, mock_window};
+
+    fn delegate_with_prefix(prefix: &str) -> ItemListDelegate {
+        let mut items = mock_list_items();
+        items.push(ListItem::Window(mock_window("Firefox - Docs", "firefox")));
+        let mut delegate = ItemListDelegate::new(items, ConfigModule::all());
+        delegate.app_only_prefix = Some(prefix.to_string());
+        delegate
+    }
+
+    fn visible_items(delegate: &ItemListDelegate) -> Vec<ListItem> {
+        (0..delegate.filtered_count())
+            .filter_map(|i| delegate.get_item_at(i))
+            .collect()
+    }
+
+    #[test]
+    fn test_app_only_prefix_shows_only_applications() {
+        let mut delegate = delegate_with_prefix("@");
+        delegate.set_query("@fire".to_string());
+
+        let items = visible_items(&delegate);
+        assert!(!items.is_empty());
+        assert!(items.iter().all(ListItem::is_application));
+        assert_eq!(delegate.query(), "fire");
+    }
+
+    #[test]
+    fn test_app_only_prefix_suppresses_dynamic_items() {
+        let mut delegate = delegate_with_prefix("@");
+        delegate.set_query("@2+2".to_string());
+
+        assert!(!delegate.dynamic.has_calculator());
+        assert_eq!(delegate.dynamic.count(), 0);
+        assert!(
+            visible_items(&delegate)
+                .iter()
+                .all(ListItem::is_application)
+        );
+    }
+
+    #[test]
+    fn test_bare_prefix_lists_all_applications() {
+        let mut delegate = delegate_with_prefix("@");
+        delegate.set_query("@".to_string());
+
+        let items = visible_items(&delegate);
+        assert_eq!(items.len(), mock_list_items().len());
+        assert!(items.iter().all(ListItem::is_application));
+    }
+
+    #[test]
+    fn test_without_prefix_windows_still_match() {
+        let mut delegate = delegate_with_prefix("@");
+        delegate.set_query("fire".to_string());
+
+        assert!(visible_items(&delegate).iter().any(ListItem::is_window));
+    }
+
+    fn mixed_delegate(show_best_match: bool) -> ItemListDelegate {
+        let mut app_config = AppConfig::default();
+        app_config.fuzzy_match.show_best_match = show_best_match;
+        let items = vec![
+            ListItem::Application(mock_application("Firefox")),
+            ListItem::Application(mock_application("2+2 Trainer")),
+        ];
+        ItemListDelegate::from_config(
+            items,
+            vec![
+                ConfigModule::Calculator,
+                ConfigModule::Applications,
+                ConfigModule::Ai,
+                ConfigModule::Search,
+            ],
+            &app_config,
+        )
+        .with_ai_configured(true)
+    }
+
+    fn layout(delegate: &ItemListDelegate) -> Vec<String> {
+        visible_items(delegate)
+            .iter()
+            .map(|item| match item {
+                ListItem::Application(app) => format!("app:{}", app.name),
+                ListItem::Calculator(_) => "calc".to_string(),
+                ListItem::Ai(_) => "ai".to_string(),
+                ListItem::Search(search) => format!("search:{}", search.provider.name),
+                other => format!("other:{}", other.name()),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_mixed_query_ordering() {
+        let mut delegate = mixed_delegate(false);
+        delegate.set_query("2+2".to_string());
+
+        assert_eq!(
+            layout(&delegate),
+            [
+                "calc",
+                "app:2+2 Trainer",
+                "ai",
+                "search:Google",
+                "search:DuckDuckGo",
+                "search:Wikipedia",
+                "search:YouTube",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mixed_query_with_best_match() {
+        let mut delegate = mixed_delegate(true);
+        delegate.set_query("2+2".to_string());
+
+        let layout = layout(&delegate);
+        // The app is promoted above the calculator and not repeated
+        assert_eq!(layout[..3], ["app:2+2 Trainer", "calc", "ai"]);
+        assert_eq!(layout.len(), 7);
+    }
+
+    #[test]
+    fn test_index_paths_cover_every_item() {
+        let mut delegate = mixed_delegate(false);
+        delegate.set_query("2+2".to_string());
+
+        let paths: Vec<_> = (0..delegate.filtered_count())
+            .map(|i| delegate.global_to_index_path(i).unwrap())
+            .collect();
+        assert_eq!(paths[0], IndexPath::new(0).section(0));
+        assert_eq!(paths[1], IndexPath::new(0).section(1));
+        assert_eq!(paths[2], IndexPath::new(0).section(2));
+        assert_eq!(paths[6], IndexPath::new(4).section(2));
+    }
+
+    fn category_delegate() -> ItemListDelegate {
+        let categories = |names: &[&str]| names.iter().map(|n| n.to_string()).collect();
+        let items = vec![
+            ListItem::Application(
+                mock_application("Steam").with_categories(categories(&["Network", "Game"])),
+            ),
+            ListItem::Application(
+                mock_application("Gimp").with_categories(categories(&["Graphics"])),
+            ),
+            ListItem::Application(
+                mock_application("Godot").with_categories(categories(&["Development", "Game"])),
+            ),
+        ];
+        ItemListDelegate::from_config(
+            items,
+            vec![ConfigModule::Applications, ConfigModule::Search],
+            &AppConfig::default(),
+        )
+    }
+
+    fn visible_names(delegate: &ItemListDelegate) -> Vec<String> {
+        visible_items(delegate)
+            .iter()
+            .map(|item| item.name().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_category_submenus_hidden_from_regular_queries() {
+        let mut delegate = category_delegate();
+        assert!(
+            !visible_items(&delegate)
+                .iter()
+                .any(ListItem::is_category_submenu)
+        );
+
+        delegate.set_query("game".to_string());
+        assert!(
+            !visible_items(&delegate)
+                .iter()
+                .any(ListItem::is_category_submenu)
+        );
+    }
+
+    #[test]
+    fn test_category_prefix_lists_categories() {
+        let mut delegate = category_delegate();
+        delegate.set_query("cat:".to_string());
+
+        assert_eq!(
+            visible_names(&delegate),
+            ["Development", "Game", "Graphics", "Network"]
+        );
+        assert_eq!(delegate.dynamic.count(), 0);
+    }
+
+    #[test]
+    fn test_category_prefix_filters_applications() {
+        let mut delegate = category_delegate();
+        delegate.set_query("cat:games ".to_string());
+        assert_eq!(visible_names(&delegate), ["Steam", "Godot"]);
+
+        delegate.set_query("cat:games god".to_string());
+        assert_eq!(visible_names(&delegate), ["Godot"]);
+        assert_eq!(delegate.query(), "god");
+    }
+
+    #[test]
+    fn test_category_query_completion() {
+        let delegate = category_delegate();
+        assert_eq!(
+            delegate.category_query("Game").as_deref(),
+            Some("cat:Game ")
+        );
+    }
+
+    fn large_delegate(max_results: Option<usize>) -> ItemListDelegate {
+        let mut app_config = AppConfig::default();
+        app_config.max_results = max_results;
+        let mut items: Vec<ListItem> = (0..5000)
+            .map(|i| ListItem::Application(mock_application(&format!("Tool {}", i))))
+            .collect();
+        items.push(ListItem::Application(mock_application("2+2 Trainer")));
+        ItemListDelegate::from_config(
+            items,
+            vec![
+                ConfigModule::Calculator,
+                ConfigModule::Applications,
+                ConfigModule::Ai,
+                ConfigModule::Search,
+            ],
+            &app_config,
+        )
+        .with_ai_configured(true)
+    }
+
+    #[test]
+    fn test_max_results_caps_large_item_set() {
+        let mut delegate = large_delegate(Some(50));
+        assert_eq!(delegate.filtered_count(), 50);
+        assert_eq!(delegate.hidden_count(), 4951);
+        // An empty query keeps the list order
+        assert_eq!(visible_names(&delegate)[0], "Tool 0");
+
+        delegate.set_query("tool 9".to_string());
+        assert_eq!(delegate.filtered_count(), 50);
+        assert!(delegate.hidden_count() > 0);
+        // The exact match survives the cap
+        assert_eq!(layout(&delegate)[0], "app:Tool 9");
+    }
+
+    #[test]
+    fn test_max_results_counts_dynamic_items() {
+        let mut delegate = large_delegate(Some(8));
+        delegate.set_query("tool 9".to_string());
+
+        // AI and four search items leave room for three apps
+        let layout = layout(&delegate);
+        assert_eq!(layout.len(), 8);
+        assert_eq!(layout.iter().filter(|l| l.starts_with("app:")).count(), 3);
+        assert_eq!(
+            layout.iter().filter(|l| l.starts_with("search:")).count(),
+            4
+        );
+        assert!(layout.contains(&"ai".to_string()));
+    }
+
+    #[test]
+    fn test_max_results_caps_dynamic_items() {
+        let mut uncapped = large_delegate(None);
+        uncapped.set_query("tool 9".to_string());
+        let matches = uncapped.base.filtered_count();
+
+        // AI and four search items alone exceed the cap, the last search
+        // items give way and no apps are left
+        let mut delegate = large_delegate(Some(3));
+        delegate.set_query("tool 9".to_string());
+        let layout = layout(&delegate);
+        assert_eq!(layout.len(), 3);
+        assert_eq!(layout.iter().filter(|l| l.starts_with("app:")).count(), 0);
+        assert!(layout.contains(&"ai".to_string()));
+        assert_eq!(delegate.filtered_count(), 3);
+        assert_eq!(delegate.hidden_count(), matches + 2);
+    }
+
+    #[test]
+    fn test_max_results_keeps_best_match() {
+        let mut app_config = AppConfig::default();
+        app_config.max_results = Some(3);
+        app_config.fuzzy_match.show_best_match = true;
+        let mut items = vec![ListItem::Window(mock_window(
+            "Toolbox - Firefox",
+            "firefox",
+        ))];
+        items.extend(
+            (0..100).map(|i| ListItem::Application(mock_application(&format!("Tool {}", i)))),
+        );
+        items.push(ListItem::Application(mock_application("Tool")));
+        let mut delegate = ItemListDelegate::from_config(
+            items,
+            vec![ConfigModule::Windows, ConfigModule::Applications],
+            &app_config,
+        );
+        delegate.set_query("tool".to_string());
+
+        // A promoted best match is counted once
+        assert_eq!(delegate.filtered_count(), 3);
+        assert_eq!(visible_names(&delegate)[0], "Tool");
+    }
+
+    #[test]
+    fn test_no_cap_by_default() {
+        let delegate = large_delegate(None);
+        assert_eq!(delegate.filtered_count(), 5001);
+        assert_eq!(delegate.hidden_count(), 0);
+    }
+
+    fn recent_delegate() -> ItemListDelegate {
+        recent_delegate_with(AppConfig::default())
+    }
+
+    #[test]
+    fn test_recent_section_for_empty_query() {
+        let delegate = recent_delegate();
+
+        assert_eq!(
+            delegate.sections.ordered_section_types(),
+            [
+                SectionType::Windows,
+                SectionType::Recent,
+                SectionType::Applications
+            ]
+        );
+        assert_eq!(delegate.sections.section_item_count(SectionType::Recent), 2);
+        // Recent apps come first, most recent first, and are not repeated
+        assert_eq!(
+            visible_names(&delegate),
+            [
+                "Firefox - Docs",
+                "Code",
+                "Firefox",
+                "Chrome",
+                "Terminal",
+                "Files"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_recent_section_hidden_while_typing() {
+        let mut delegate = recent_delegate();
+        delegate.set_query("co".to_string());
+        assert!(
+            !delegate
+                .sections
+                .ordered_section_types()
+                .contains(&SectionType::Recent)
+        );
+
+        delegate.clear_query();
+        assert_eq!(delegate.sections.section_item_count(SectionType::Recent), 2);
+    }
+
+    fn pinned_delegate() -> ItemListDelegate {
+        let config = AppConfig {
+            pinned_applications: vec!["app-files".to_string(), "app-code".to_string()],
+            ..AppConfig::default()
+        };
+        recent_delegate_with(config)
+    }
+
+    fn recent_delegate_with(config: AppConfig) -> ItemListDelegate {
+        let mut items = mock_list_items();
+        items.push(ListItem::Window(mock_window("Firefox - Docs", "firefox")));
+        ItemListDelegate::from_config(
+            items,
+            vec![ConfigModule::Windows, ConfigModule::Applications],
+            &config,
+        )
+        .with_recent_apps(vec!["app-code".to_string(), "app-firefox".to_string()])
+    }
+
+    #[test]
+    fn test_pinned_section_leads_empty_query() {
+        let delegate = pinned_delegate();
+
+        assert_eq!(
+            delegate.sections.ordered_section_types(),
+            [
+                SectionType::Pinned,
+                SectionType::Windows,
+                SectionType::Recent,
+                SectionType::Applications
+            ]
+        );
+        // Pinned apps keep their configured order and aren't repeated
+        assert_eq!(
+            visible_names(&delegate),
+            [
+                "Files",
+                "Code",
+                "Firefox - Docs",
+                "Firefox",
+                "Chrome",
+                "Terminal"
+            ]
+        );
+        assert_eq!(delegate.sections.section_item_count(SectionType::Recent), 1);
+    }
+
+    #[test]
+    fn test_pinned_section_shows_matching_apps() {
+        let mut delegate = pinned_delegate();
+        delegate.set_query("files".to_string());
+
+        assert_eq!(
+            delegate.sections.ordered_section_types(),
+            [SectionType::Pinned]
+        );
+        assert_eq!(visible_names(&delegate), ["Files"]);
+
+        delegate.set_query("term".to_string());
+        assert!(
+            !delegate
+                .sections
+                .ordered_section_types()
+                .contains(&SectionType::Pinned)
+        );
+    }
+
+    #[test]
+    fn test_exact_match_prefix_toggles_substring_matching() {
+        let mut delegate = ItemListDelegate::from_config(
+            mock_list_items(),
+            vec![ConfigModule::Applications],
+            &AppConfig::default(),
+        );
+
+        // "fx" fuzzily matches Firefox but is not a substring of it
+        delegate.set_query("fx".to_string());
+        assert_eq!(visible_names(&delegate), ["Firefox"]);
+        delegate.set_query("'fx".to_string());
+        assert!(visible_names(&delegate).is_empty());
+
+        delegate.set_query("'fox".to_string());
+        assert_eq!(visible_names(&delegate), ["Firefox"]);
+        assert_eq!(delegate.query(), "fox");
+
+        // Dropping the prefix restores fuzzy matching
+        delegate.set_query("fx".to_string());
+        assert_eq!(visible_names(&delegate), ["Firefox"]);
+    }
+
+    #[test]
+    fn test_exact_match_prefix_after_app_only_prefix() {
+        let mut delegate = delegate_with_prefix("@");
+        delegate.exact_match_prefix = Some("'".to_string());
+        delegate.set_query("@'fx".to_string());
+        assert!(visible_items(&delegate).is_empty());
+        assert_eq!(delegate.query(), "fx");
+    }
+
+    fn selection_delegate(reselect_on_type: bool) -> ItemListDelegate {
+        let mut app_config = AppConfig::default();
+        app_config.reselect_on_type = reselect_on_type;
+        app_config.fuzzy_match.show_best_match = false;
+        let items = vec![
+            ListItem::Application(mock_application("Firefox")),
+            ListItem::Application(mock_application("Files")),
+            ListItem::Application(mock_application("Fish")),
+        ];
+        ItemListDelegate::from_config(items, vec![ConfigModule::Applications], &app_config)
+    }
+
+    fn selected_name(delegate: &ItemListDelegate) -> Option<String> {
+        delegate
+            .selected_index()
+            .and_then(|index| delegate.get_item_at(index))
+            .map(|item| item.name().to_string())
+    }
+
+    #[test]
+    fn test_typing_reselects_top_result() {
+        let mut delegate = selection_delegate(true);
+        delegate.set_query("f".to_string());
+        delegate.select_down();
+        assert_eq!(delegate.selected_index(), Some(1));
+
+        delegate.set_query("fi".to_string());
+        assert_eq!(delegate.selected_index(), Some(0));
+    }
+
+    #[test]
+    fn test_typing_keeps_manual_selection() {
+        let mut delegate = selection_delegate(false);
+        delegate.set_query("fi".to_string());
+        delegate.select_down();
+        let picked = selected_name(&delegate).unwrap();
+
+        // Still matching: the selection follows the item
+        delegate.set_query("f".to_string());
+        assert_eq!(selected_name(&delegate).as_deref(), Some(picked.as_str()));
+
+        // No longer matching: the top result is selected
+        let other = ["Firefox", "Files", "Fish"]
+            .into_iter()
+            .find(|name| *name != picked)
+            .unwrap();
+        delegate.set_query(other.to_lowercase());
+        assert_eq!(delegate.selected_index(), Some(0));
+        assert_eq!(selected_name(&delegate).as_deref(), Some(other));
+    }
+
+    #[test]
+    fn test_selection_never_empty_with_results() {
+        let mut delegate = selection_delegate(false);
+        delegate.set_query("zzz".to_string());
+        assert_eq!(delegate.filtered_count(), 0);
+
+        delegate.set_query("fish".to_string());
+        assert_eq!(delegate.selected_index(), Some(0));
+    }
+
+    #[test]
+    fn test_inline_answer_follows_ai_query() {
+        let mut delegate = mixed_delegate(false);
+        delegate.set_query("what is rust".to_string());
+        delegate.set_inline_answer(InlineAnswer::new("what is rust".to_string()));
+
+        // The same question through the AI trigger keeps the answer
+        delegate.set_query("!ai what is rust".to_string());
+        assert!(delegate.inline_answer().is_some());
+
+        // Asking something else drops it
+        delegate.set_query("what is rus".to_string());
+        assert!(delegate.inline_answer().is_none());
+
+        delegate.set_query("what is rust".to_string());
+        delegate.set_inline_answer(InlineAnswer::new("what is rust".to_string()));
+        delegate.clear_query();
+        assert!(delegate.inline_answer().is_none());
+    }
+
+    #[test]
+    fn test_custom_ai_trigger_routes_to_ai() {
+        let mut app_config = AppConfig::default();
+        app_config.ai_trigger = Some("?".to_string());
+        let mut delegate = ItemListDelegate::from_config(
+            vec![ListItem::Application(mock_application("Firefox"))],
+            vec![
+                ConfigModule::Applications,
+                ConfigModule::Ai,
+                ConfigModule::Search,
+            ],
+            &app_config,
+        )
+        .with_ai_configured(true);
+
+        delegate.set_query("?weather".to_string());
+        let items = visible_items(&delegate);
+        assert!(
+            matches!(items.as_slice(), [ListItem::Ai(ai)] if ai.query == "weather"),
+            "{:?}",
+            items
+                .iter()
+                .map(|item| item.id().to_string())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    fn script_delegate() -> ItemListDelegate {
+        let mut app_config = AppConfig::default();
+        app_config.scripts = vec![ConfigScriptSource {
+            name: "Notes".to_string(),
+            command: "notes-search".to_string(),
+            timeout_ms: None,
+        }];
+        ItemListDelegate::from_config(
+            vec![ListItem::Application(mock_application("Notes App"))],
+            vec![ConfigModule::Scripts, ConfigModule::Applications],
+            &app_config,
+        )
+    }
+
+    fn script_item(name: &str, index: usize) -> ScriptItem {
+        ScriptItem::new(
+            "Notes",
+            index,
+            crate::script::ScriptEntry {
+                name: name.to_string(),
+                description: None,
+                icon: None,
+                exec: format!("open-note {}", name),
+            },
+        )
+    }
+
+    #[test]
+    fn test_script_items_for_current_query() {
+        let mut delegate = script_delegate();
+        assert!(delegate.script_request().is_none());
+
+        delegate.set_query("notes".to_string());
+        let (sources, query) = delegate.script_request().unwrap();
+        assert_eq!(sources[0].name, "Notes");
+        assert_eq!(query, "notes");
+
+        // Results for an earlier query are dropped
+        delegate.set_script_items("note", vec![script_item("stale", 0)]);
+        assert_eq!(delegate.filtered_count(), 1);
+
+        delegate.set_script_items(
+            "notes",
+            vec![script_item("todo", 0), script_item("ideas", 1)],
+        );
+        let names: Vec<String> = visible_items(&delegate)
+            .iter()
+            .map(|item| item.name().to_string())
+            .collect();
+        assert_eq!(names, ["todo", "ideas", "Notes App"]);
+    }
+
+    #[test]
+    fn test_script_items_keep_selection() {
+        let mut delegate = script_delegate();
+        delegate.set_query("notes".to_string());
+        assert_eq!(
+            delegate.get_item_at(0).map(|item| item.name().to_string()),
+            Some("Notes App".to_string())
+        );
+
+        delegate.set_script_items("notes", vec![script_item("todo", 0)]);
+        assert_eq!(delegate.selected_index(), Some(1));
+
+        delegate.clear_query();
+        assert!(delegate.script_request().is_none());
+        assert_eq!(delegate.filtered_count(), 1);
+    }
+
+    fn file_delegate(modules: Vec<ConfigModule>) -> ItemListDelegate {
+        ItemListDelegate::from_config(
+            vec![ListItem::Application(mock_application("Notes App"))],
+            modules,
+            &AppConfig::default(),
+        )
+    }
+
+    fn file_item(name: &str) -> FileItem {
+        FileItem::new(PathBuf::from("/home/user").join(name), false)
+    }
+
+    #[test]
+    fn test_file_items_need_prefix_in_combined_view() {
+        let mut delegate = file_delegate(vec![ConfigModule::Applications, ConfigModule::Files]);
+        delegate.set_query("notes".to_string());
+        assert!(delegate.file_request().is_none());
+
+        delegate.set_query("f:notes".to_string());
+        let (search, query) = delegate.file_request().unwrap();
+        assert_eq!(search.max_depth, 4);
+        assert_eq!(query, "notes");
+
+        // Results for an earlier query are dropped
+        delegate.set_file_items("note", vec![file_item("stale.md")]);
+        assert!(delegate.dynamic.file_items.is_empty());
+
+        delegate.set_file_items("notes", vec![file_item("notes.md")]);
+        let names: Vec<String> = visible_items(&delegate)
+            .iter()
+            .map(|item| item.name().to_string())
+            .collect();
+        assert_eq!(names, ["notes.md"]);
+
+        delegate.clear_query();
+        assert!(delegate.file_request().is_none());
+        assert!(delegate.dynamic.file_items.is_empty());
+    }
+
+    #[test]
+    fn test_files_mode_searches_without_prefix() {
+        let mut delegate = file_delegate(vec![ConfigModule::Files]);
+        delegate.set_query("notes".to_string());
+        assert_eq!(delegate.file_request().unwrap().1, "notes");
+
+        delegate.set_query("  ".to_string());
+        assert!(delegate.file_request().is_none());
     }
 }
@@ -6,13 +6,16 @@
 //! - Prefix matches (name starts with query)
 //! - Word prefix matches (query matches start of any word)
 //! - Contiguous character matches
+//! - The item last confirmed for the same query (learned selections)
 //!
 //! And penalizes:
+//! - Application-id-only matches (e.g. `org.gnome.Nautilus` for "Files")
+//! - Window class matches, weighted separately from title matches
 //! - Description-only matches (name doesn't match, only description does)
 //! - Action/submenu items in combined mode (demotes system actions)
 
 use crate::config::{ConfigModule, FuzzyMatchConfig};
-use crate::items::ListItem;
+use crate::items::{LearnedSelections, ListItem, WindowItem};
 use fuzzy_matcher::FuzzyMatcher;
 use fuzzy_matcher::skim::SkimMatcherV2;
 
@@ -25,12 +28,24 @@ pub struct FilteredItem {
     pub score: i64,
 }
 
+/// Which part of an item a query matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MatchField {
+    Name,
+    Id,
+    Description,
+}
+
 /// Fuzzy filter for list items with enhanced scoring.
 pub struct ItemFilter {
     /// The fuzzy matcher instance.
     matcher: SkimMatcherV2,
     /// Configuration for scoring adjustments.
     pub config: FuzzyMatchConfig,
+    /// Only match items containing the query as a substring.
+    substring_only: bool,
+    /// Items previously confirmed per query, boosted when matched again.
+    learned: LearnedSelections,
 }
 
 impl Default for ItemFilter {
@@ -45,9 +60,22 @@ impl ItemFilter {
         Self {
             matcher: SkimMatcherV2::default(),
             config,
+            substring_only: false,
+            learned: LearnedSelections::default(),
         }
     }
 
+    /// Set the learned selections to boost.
+    pub fn set_learned_selections(&mut self, learned: LearnedSelections) {
+        self.learned = learned;
+    }
+
+    /// Require the query to appear as a case-insensitive substring instead
+    /// of matching fuzzily. Matches are still ranked by the usual score.
+    pub fn set_substring_only(&mut self, substring_only: bool) {
+        self.substring_only = substring_only;
+    }
+
     /// Filter items by query, returning indices of matching items.
     ///
     /// This is a convenience method that wraps `filter_with_scores`
@@ -71,7 +99,8 @@ impl ItemFilter {
     /// the score of each item to determine which should be promoted.
     ///
     /// When query is empty, returns all items with score 0.
-    /// When query is non-empty, returns matching items sorted by:
+    /// When query is non-empty, drops matches scoring below `min_score` and
+    /// returns the rest sorted by:
     /// 1. Module position in combined_modules (primary)
     /// 2. Enhanced fuzzy score (secondary, higher is better)
     pub fn filter_with_scores(
@@ -86,12 +115,16 @@ impl ItemFilter {
                 .collect();
         }
 
+        let learned_id = self.learned.selection(query);
         let mut scored: Vec<FilteredItem> = items
             .iter()
             .enumerate()
             .filter_map(|(idx, item)| {
-                let score = self.score_item(item, query)?;
-                Some(FilteredItem { index: idx, score })
+                let mut score = self.score_item(item, query)?;
+                if learned_id == Some(item.id()) {
+                    score += self.config.learned_selection_bonus;
+                }
+                (score >= self.config.min_score).then_some(FilteredItem { index: idx, score })
             })
             .collect();
 
@@ -120,20 +153,29 @@ impl ItemFilter {
     ///
     /// The scoring algorithm:
     /// 1. Try matching against the name first (preferred)
-    /// 2. Fall back to description match with penalty
+    /// 2. Fall back to id match, then description match, each with penalty
     /// 3. Apply bonuses for exact/prefix/contiguous matches
     /// 4. Apply item type multipliers (demote actions/submenus)
     fn score_item(&self, item: &ListItem, query: &str) -> Option<i64> {
-        let name = item.name();
+        // Try name match first (preferred), windows also match on their class
+        let name_score = match item {
+            ListItem::Window(window) => self.score_window(window, query, item),
+            _ => self.score_text_match(item.name(), query, item, MatchField::Name),
+        };
+        if let Some(score) = name_score {
+            return Some(score);
+        }
 
-        // Try name match first (preferred)
-        if let Some(score) = self.score_text_match(name, query, item, false) {
+        // Fall back to id match (with penalty)
+        if let Some(id) = item.search_id()
+            && let Some(score) = self.score_text_match(id, query, item, MatchField::Id)
+        {
             return Some(score);
         }
 
         // Fall back to description match (with penalty)
         if let Some(desc) = item.description() {
-            if let Some(score) = self.score_text_match(desc, query, item, true) {
+            if let Some(score) = self.score_text_match(desc, query, item, MatchField::Description) {
                 return Some(score);
             }
         }
@@ -141,6 +183,31 @@ impl ItemFilter {
         None
     }
 
+    /// Score a window by its title and its class, each with its own weight.
+    ///
+    /// The class is matched both as the raw app id (`org.kde.dolphin`) and
+    /// the readable app name (`Dolphin`). The best weighted score wins.
+    fn score_window(&self, window: &WindowItem, query: &str, item: &ListItem) -> Option<i64> {
+        let weighted = |text: &str, weight: f64| {
+            if weight <= 0.0 {
+                return None;
+            }
+            self.score_text_match(text, query, item, MatchField::Name)
+                .map(|score| (score as f64 * weight) as i64)
+        };
+
+        let title_weight = self.config.window_title_weight;
+        let class_weight = self.config.window_class_weight;
+        [
+            weighted(&window.title, title_weight),
+            weighted(&window.app_id, class_weight),
+            weighted(&window.app_name, class_weight),
+        ]
+        .into_iter()
+        .flatten()
+        .max()
+    }
+
     /// Score a text match against a query, trying multiple query normalizations.
     ///
     /// Handles cases like "counter strike" matching "Counter-Strike" by:
@@ -152,11 +219,15 @@ impl ItemFilter {
         text: &str,
         query: &str,
         item: &ListItem,
-        is_description: bool,
+        field: MatchField,
     ) -> Option<i64> {
         let query_lower = query.to_lowercase();
         let text_lower = text.to_lowercase();
 
+        if self.substring_only && !text_lower.contains(&query_lower) {
+            return None;
+        }
+
         // Try original query first
         let match_result = self.matcher.fuzzy_indices(text, query);
 
@@ -181,8 +252,8 @@ impl ItemFilter {
         let (base_score, indices) = match_result?;
         let mut score = base_score;
 
-        // Apply bonuses only for name matches, not descriptions
-        if !is_description {
+        // Apply bonuses only for name matches, not ids or descriptions
+        if field == MatchField::Name {
             // Exact match bonus (highest priority)
             if text_lower == query_lower {
                 score += self.config.exact_match_bonus;
@@ -200,9 +271,13 @@ impl ItemFilter {
         // Contiguity bonus based on how adjacent matched characters are
         score += self.calculate_contiguity_bonus(&indices);
 
-        // Apply description penalty if this is a description match
-        if is_description {
-            score = (score as f64 * self.config.description_penalty) as i64;
+        // Apply id/description penalty for matches outside the name
+        match field {
+            MatchField::Name => {}
+            MatchField::Id => score = (score as f64 * self.config.id_penalty) as i64,
+            MatchField::Description => {
+                score = (score as f64 * self.config.description_penalty) as i64
+            }
         }
 
         // Apply item type multiplier (demotes actions/submenus)
@@ -253,8 +328,8 @@ impl ItemFilter {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::items::{ActionItem, ActionKind};
-    use crate::test_utils::{mock_application, mock_application_with_desc};
+    use crate::items::{ActionItem, ActionKind, ApplicationItem};
+    use crate::test_utils::{mock_application, mock_application_with_desc, mock_window};
 
     #[test]
     fn test_empty_query_returns_all() {
@@ -535,4 +610,178 @@ mod tests {
         let result2 = filter.filter_indices(&items, "android studio", &[]);
         assert!(result2.contains(&1), "Should match 'Android Studio'");
     }
+
+    fn app_with_id(id: &str, name: &str) -> ApplicationItem {
+        ApplicationItem::new(
+            id.to_string(),
+            name.to_string(),
+            format!("/usr/bin/{}", name.to_lowercase()),
+            None,
+            None,
+            false,
+            std::path::PathBuf::from(format!("/usr/share/applications/{}.desktop", id)),
+        )
+    }
+
+    #[test]
+    fn test_match_by_application_id() {
+        let filter = ItemFilter::default();
+        let items: Vec<ListItem> = vec![
+            ListItem::Application(app_with_id("firefox", "Firefox")),
+            ListItem::Application(app_with_id("org.gnome.Nautilus", "Files")),
+        ];
+
+        let result = filter.filter_indices(&items, "nautilus", &[]);
+        assert_eq!(result, vec![1]);
+    }
+
+    #[test]
+    fn test_name_match_ranks_above_id_match() {
+        let filter = ItemFilter::default();
+        let items: Vec<ListItem> = vec![
+            ListItem::Application(app_with_id("org.gnome.Nautilus", "Files")),
+            ListItem::Application(app_with_id("nautilus-helper", "Nautilus")),
+        ];
+
+        let result = filter.filter_indices(&items, "nautilus", &[]);
+        assert_eq!(result, vec![1, 0]);
+    }
+
+    #[test]
+    fn test_id_not_matched_for_non_applications() {
+        let filter = ItemFilter::default();
+        let items: Vec<ListItem> = vec![ListItem::Action(ActionItem::builtin(ActionKind::Logout))];
+
+        // Builtin action ids are internal and must not be search sources
+        assert!(filter.filter_indices(&items, "action", &[]).is_empty());
+    }
+
+    #[test]
+    fn test_min_score_drops_weak_matches() {
+        let items: Vec<ListItem> = vec![
+            ListItem::Application(mock_application("Firefox")),
+            ListItem::Application(mock_application("Fixture Reader")),
+        ];
+
+        // Without a threshold the scattered match is kept
+        let filter = ItemFilter::default();
+        assert_eq!(filter.filter_indices(&items, "fire", &[]).len(), 2);
+
+        // The prefix match clears the threshold, the scattered one doesn't
+        let filter = ItemFilter::new(FuzzyMatchConfig {
+            min_score: 20_000,
+            ..Default::default()
+        });
+        assert_eq!(filter.filter_indices(&items, "fire", &[]), vec![0]);
+    }
+
+    #[test]
+    fn test_min_score_ignored_for_empty_query() {
+        let filter = ItemFilter::new(FuzzyMatchConfig {
+            min_score: 20_000,
+            ..Default::default()
+        });
+        let items = crate::test_utils::mock_list_items();
+        assert_eq!(filter.filter_indices(&items, "", &[]).len(), items.len());
+    }
+
+    #[test]
+    fn test_window_class_only_query_finds_all_windows() {
+        let filter = ItemFilter::default();
+        let items: Vec<ListItem> = vec![
+            ListItem::Window(mock_window("GitHub - Pull requests", "firefox")),
+            ListItem::Window(mock_window("~/src/zlaunch", "kitty")),
+            ListItem::Window(mock_window("YouTube", "firefox")),
+        ];
+
+        let mut result = filter.filter_indices(&items, "firefox", &[]);
+        result.sort();
+        assert_eq!(result, vec![0, 2]);
+    }
+
+    #[test]
+    fn test_window_title_match_ranks_above_class_match() {
+        let filter = ItemFilter::default();
+        let items: Vec<ListItem> = vec![
+            ListItem::Window(mock_window("Mail", "kitty")),
+            ListItem::Window(mock_window("kitty", "neovide")),
+        ];
+
+        // Both match exactly, but the class weight is lower by default
+        let result = filter.filter_with_scores(&items, "kitty", &[]);
+        assert_eq!(result[0].index, 1);
+    }
+
+    #[test]
+    fn test_window_match_weights_configurable() {
+        let items: Vec<ListItem> = vec![
+            ListItem::Window(mock_window("Mail", "kitty")),
+            ListItem::Window(mock_window("kitty", "neovide")),
+        ];
+
+        let filter = ItemFilter::new(FuzzyMatchConfig {
+            window_title_weight: 0.5,
+            window_class_weight: 1.0,
+            ..Default::default()
+        });
+        let result = filter.filter_with_scores(&items, "kitty", &[]);
+        assert_eq!(result[0].index, 0);
+    }
+
+    #[test]
+    fn test_substring_only_rejects_scattered_matches() {
+        let mut filter = ItemFilter::default();
+        let items: Vec<ListItem> = vec![
+            ListItem::Application(mock_application("Firefox")),
+            ListItem::Application(mock_application("Foot Extras")),
+        ];
+
+        assert_eq!(filter.filter_indices(&items, "fox", &[]), vec![0, 1]);
+
+        filter.set_substring_only(true);
+        assert_eq!(filter.filter_indices(&items, "fox", &[]), vec![0]);
+        assert_eq!(filter.filter_indices(&items, "FIRE", &[]), vec![0]);
+        assert!(filter.filter_indices(&items, "ffx", &[]).is_empty());
+    }
+
+    #[test]
+    fn test_learned_selection_is_boosted() {
+        let items: Vec<ListItem> = vec![
+            ListItem::Application(mock_application("Firefox")),
+            ListItem::Application(mock_application("Files")),
+        ];
+        let mut filter = ItemFilter::default();
+        let unlearned = filter.filter_indices(&items, "fi", &[]);
+        assert_eq!(unlearned.len(), 2);
+
+        // Learn whichever ranked second and it moves to the top
+        let second = unlearned[1];
+        let mut learned = LearnedSelections::default();
+        learned.learn("fi", items[second].id(), 10);
+        filter.set_learned_selections(learned);
+        assert_eq!(filter.filter_indices(&items, "FI ", &[])[0], second);
+
+        // Other queries are unaffected
+        assert_eq!(
+            filter.filter_indices(&items, "f", &[]),
+            ItemFilter::default().filter_indices(&items, "f", &[])
+        );
+    }
+
+    #[test]
+    fn test_learned_selection_bonus_can_be_disabled() {
+        let items: Vec<ListItem> = vec![
+            ListItem::Application(mock_application("Firefox")),
+            ListItem::Application(mock_application("Files")),
+        ];
+        let mut filter = ItemFilter::new(FuzzyMatchConfig {
+            learned_selection_bonus: 0,
+            ..FuzzyMatchConfig::default()
+        });
+        let unlearned = filter.filter_indices(&items, "fi", &[]);
+        let mut learned = LearnedSelections::default();
+        learned.learn("fi", items[unlearned[1]].id(), 10);
+        filter.set_learned_selections(learned);
+        assert_eq!(filter.filter_indices(&items, "fi", &[]), unlearned);
+    }
 }
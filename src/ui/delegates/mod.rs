@@ -14,6 +14,7 @@
 //! - [`EmojiGridDelegate`] - Grid-based emoji picker
 //! - [`ClipboardListDelegate`] - Clipboard history with preview panel
 //! - [`ThemeListDelegate`] - Theme selection list
+//! - [`DmenuListDelegate`] - Lines read from stdin by `zlaunch dmenu`
 //!
 //! # Architecture
 //!
@@ -25,11 +26,15 @@
 //! Filtering is handled by [`item_filter::ItemFilter`] which provides fuzzy matching
 //! and respects module ordering from configuration.
 //!
+//! Category browsing (`cat:` queries) is parsed by [`category_filter`].
+//!
 //! Section grouping (e.g., "Applications", "Windows") is managed by
 //! [`section_manager::SectionManager`] for the main list delegate.
 
 mod base;
+mod category_filter;
 mod clipboard_delegate;
+mod dmenu_delegate;
 mod dynamic_items;
 mod emoji_delegate;
 mod item_delegate;
@@ -39,7 +44,8 @@ mod theme_delegate;
 
 pub use base::BaseDelegate;
 pub use clipboard_delegate::ClipboardListDelegate;
+pub use dmenu_delegate::DmenuListDelegate;
 pub use emoji_delegate::EmojiGridDelegate;
 pub use item_delegate::ItemListDelegate;
-pub use item_filter::FilteredItem;
+pub use item_filter::{FilteredItem, ItemFilter};
 pub use theme_delegate::ThemeListDelegate;
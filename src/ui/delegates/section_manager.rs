@@ -3,7 +3,7 @@
 //! Handles organizing items into sections and converting between
 //! global indices and section-based IndexPaths.
 
-use crate::config::ConfigModule;
+use crate::config::{ConfigModule, SectionHeaderVisibility};
 use crate::items::ListItem;
 use gpui_component::IndexPath;
 
@@ -14,16 +14,28 @@ use super::item_filter::FilteredItem;
 pub enum SectionType {
     /// Best match item promoted to top (when enabled).
     BestMatch,
+    /// Pinned applications (first, after best match).
+    Pinned,
+    /// File or folder typed as a path (first if present, after best match).
+    Path,
     /// Calculator result (always first if present, after best match).
     Calculator,
     /// Open windows.
     Windows,
+    /// Workspaces.
+    Workspaces,
     /// Submenus and actions (emojis, clipboard, themes, actions).
     Commands,
+    /// Recently launched applications (empty query only).
+    Recent,
     /// Desktop applications.
     Applications,
     /// Combined Search + AI section (positioned by first occurrence in combined_modules).
     SearchAndAi,
+    /// Items listed by script sources.
+    Scripts,
+    /// Files found by the file search.
+    Files,
 }
 
 impl SectionType {
@@ -31,15 +43,43 @@ impl SectionType {
     pub fn title(&self) -> &'static str {
         match self {
             SectionType::BestMatch => "Best Match",
+            SectionType::Pinned => "Pinned",
+            SectionType::Path => "Path",
             SectionType::Calculator => "Calculator",
             SectionType::Windows => "Windows",
+            SectionType::Workspaces => "Workspaces",
             SectionType::Commands => "Commands",
+            SectionType::Recent => "Recent",
             SectionType::Applications => "Applications",
             SectionType::SearchAndAi => "Search and AI",
+            SectionType::Scripts => "Scripts",
+            SectionType::Files => "Files",
         }
     }
 }
 
+/// What occupies a global list position.
+///
+/// Resolved from section state alone, so the index math can be tested
+/// without the items themselves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ItemSlot {
+    /// A base item, by its position in the filtered results.
+    Filtered(usize),
+    /// The path item.
+    Path,
+    /// The calculator result.
+    Calculator,
+    /// The AI item.
+    Ai,
+    /// A search item, by its index among the search items.
+    Search(usize),
+    /// A script item, by its index among the script items.
+    Script(usize),
+    /// A file item, by its index among the file items.
+    File(usize),
+}
+
 /// Section information for tracking item counts by type.
 #[derive(Clone, Debug, Default)]
 pub struct SectionInfo {
@@ -47,10 +87,16 @@ pub struct SectionInfo {
     pub search_count: usize,
     /// Number of window items.
     pub window_count: usize,
+    /// Number of workspace items.
+    pub workspace_count: usize,
     /// Number of command items (submenus + actions).
     pub command_count: usize,
     /// Number of application items.
     pub app_count: usize,
+    /// Number of application items shown in the Recent section instead.
+    pub recent_count: usize,
+    /// Number of application items shown in the Pinned section instead.
+    pub pinned_count: usize,
 }
 
 impl SectionInfo {
@@ -62,6 +108,11 @@ impl SectionInfo {
             if let Some(item) = items.get(idx) {
                 if item.is_window() {
                     info.window_count += 1;
+                } else if item.is_workspace() {
+                    info.workspace_count += 1;
+                } else if item.is_category_submenu() {
+                    // Category submenus are listed with the applications
+                    info.app_count += 1;
                 } else if item.is_submenu() || item.is_action() {
                     info.command_count += 1;
                 } else if item.is_application() {
@@ -80,12 +131,20 @@ pub struct SectionManager {
     section_info: SectionInfo,
     /// Modules in order for combined view.
     combined_modules: Vec<ConfigModule>,
+    /// Whether there's a path item present.
+    has_path: bool,
     /// Whether there's a calculator item present.
     has_calculator: bool,
     /// Whether there's an AI item present.
     has_ai: bool,
     /// Number of search items.
     search_count: usize,
+    /// Number of script items.
+    script_count: usize,
+    /// Number of file items.
+    file_count: usize,
+    /// Number of pinned applications leading the filtered results.
+    pinned_count: usize,
     /// Whether best match feature is enabled.
     show_best_match: bool,
     /// Index of best match in filtered results (if promoted).
@@ -101,9 +160,13 @@ impl SectionManager {
         Self {
             section_info: SectionInfo::default(),
             combined_modules,
+            has_path: false,
             has_calculator: false,
             has_ai: false,
             search_count: 0,
+            script_count: 0,
+            file_count: 0,
+            pinned_count: 0,
             show_best_match,
             best_match_filtered_pos: None,
             best_match_original_section: None,
@@ -111,6 +174,7 @@ impl SectionManager {
     }
 
     /// Update the section info from filtered items and dynamic item state.
+    #[cfg(test)]
     pub fn update(
         &mut self,
         items: &[ListItem],
@@ -139,6 +203,9 @@ impl SectionManager {
         let filtered_indices: Vec<usize> = filtered.iter().map(|f| f.index).collect();
         self.section_info = SectionInfo::compute(items, &filtered_indices);
         self.section_info.search_count = search_count;
+        let pinned_count = self.pinned_count.min(self.section_info.app_count);
+        self.section_info.pinned_count = pinned_count;
+        self.section_info.app_count -= pinned_count;
         self.has_calculator = has_calculator;
         self.has_ai = has_ai;
         self.search_count = search_count;
@@ -153,6 +220,45 @@ impl SectionManager {
         }
     }
 
+    /// Set whether a path item is shown.
+    ///
+    /// Call before updating, so best match promotion accounts for it.
+    pub fn set_has_path(&mut self, has_path: bool) {
+        self.has_path = has_path;
+    }
+
+    /// Set the number of script items shown.
+    ///
+    /// Call before updating, so best match promotion accounts for them.
+    pub fn set_script_count(&mut self, count: usize) {
+        self.script_count = count;
+    }
+
+    /// Set the number of file items shown.
+    ///
+    /// Call before updating, so best match promotion accounts for them.
+    pub fn set_file_count(&mut self, count: usize) {
+        self.file_count = count;
+    }
+
+    /// Show the first `count` filtered results in the Pinned section.
+    ///
+    /// Call before updating; the pinned applications must lead the
+    /// filtered results.
+    pub fn set_pinned_count(&mut self, count: usize) {
+        self.pinned_count = count;
+    }
+
+    /// Show the first `count` applications in the Recent section.
+    ///
+    /// Call after updating; the recent applications must directly precede
+    /// the other applications in the filtered results.
+    pub fn set_recent_count(&mut self, count: usize) {
+        let count = count.min(self.section_info.app_count);
+        self.section_info.recent_count = count;
+        self.section_info.app_count -= count;
+    }
+
     /// Compute best match promotion if applicable.
     fn compute_best_match(&mut self, items: &[ListItem], filtered: &[FilteredItem]) {
         // Find the highest scoring item
@@ -182,7 +288,11 @@ impl SectionManager {
         };
 
         // Determine which section the best match belongs to
-        let best_section = self.section_type_for_module(&best_module);
+        let best_section = if best_pos < self.section_info.pinned_count {
+            SectionType::Pinned
+        } else {
+            self.section_type_for_module(&best_module)
+        };
 
         // Only promote if best match is NOT in the first section
         if best_section != first_section {
@@ -195,13 +305,17 @@ impl SectionManager {
     fn section_type_for_module(&self, module: &ConfigModule) -> SectionType {
         match module {
             ConfigModule::Windows => SectionType::Windows,
+            ConfigModule::Workspaces => SectionType::Workspaces,
             ConfigModule::Applications => SectionType::Applications,
             ConfigModule::Search | ConfigModule::Ai => SectionType::SearchAndAi,
             ConfigModule::Actions
             | ConfigModule::Emojis
+            | ConfigModule::Glyphs
             | ConfigModule::Clipboard
             | ConfigModule::Themes => SectionType::Commands,
             ConfigModule::Calculator => SectionType::Calculator,
+            ConfigModule::Scripts => SectionType::Scripts,
+            ConfigModule::Files => SectionType::Files,
         }
     }
 
@@ -210,16 +324,6 @@ impl SectionManager {
         self.best_match_filtered_pos.is_some()
     }
 
-    /// Get the filtered position of the best match (if promoted).
-    pub fn best_match_filtered_pos(&self) -> Option<usize> {
-        self.best_match_filtered_pos
-    }
-
-    /// Get the section the best match was promoted from.
-    pub fn best_match_original_section(&self) -> Option<SectionType> {
-        self.best_match_original_section
-    }
-
     /// Internal helper to get ordered sections without BestMatch.
     fn ordered_section_types_internal(&self) -> Vec<SectionType> {
         let mut sections = Vec::new();
@@ -227,6 +331,16 @@ impl SectionManager {
         let mut seen_search_and_ai = false;
         let has_search_and_ai = self.has_ai || self.search_count > 0;
 
+        // Pinned applications stay on top whenever they match
+        if self.section_info.pinned_count > 0 {
+            sections.push(SectionType::Pinned);
+        }
+
+        // A query naming an existing path is most likely meant as one
+        if self.has_path {
+            sections.push(SectionType::Path);
+        }
+
         for module in &self.combined_modules {
             match module {
                 ConfigModule::Calculator if self.has_calculator => {
@@ -234,13 +348,36 @@ impl SectionManager {
                         sections.push(SectionType::Calculator);
                     }
                 }
+                ConfigModule::Scripts if self.script_count > 0 => {
+                    if !sections.contains(&SectionType::Scripts) {
+                        sections.push(SectionType::Scripts);
+                    }
+                }
+                ConfigModule::Files if self.file_count > 0 => {
+                    if !sections.contains(&SectionType::Files) {
+                        sections.push(SectionType::Files);
+                    }
+                }
                 ConfigModule::Windows if self.section_info.window_count > 0 => {
                     if !sections.contains(&SectionType::Windows) {
                         sections.push(SectionType::Windows);
                     }
                 }
-                ConfigModule::Applications if self.section_info.app_count > 0 => {
-                    if !sections.contains(&SectionType::Applications) {
+                ConfigModule::Workspaces if self.section_info.workspace_count > 0 => {
+                    if !sections.contains(&SectionType::Workspaces) {
+                        sections.push(SectionType::Workspaces);
+                    }
+                }
+                ConfigModule::Applications => {
+                    // Recent applications are listed right before the others
+                    if self.section_info.recent_count > 0
+                        && !sections.contains(&SectionType::Recent)
+                    {
+                        sections.push(SectionType::Recent);
+                    }
+                    if self.section_info.app_count > 0
+                        && !sections.contains(&SectionType::Applications)
+                    {
                         sections.push(SectionType::Applications);
                     }
                 }
@@ -251,9 +388,10 @@ impl SectionManager {
                     sections.push(SectionType::SearchAndAi);
                     seen_search_and_ai = true;
                 }
-                // Actions, Emojis, Clipboard, Themes all map to Commands section
+                // Actions, Emojis, Glyphs, Clipboard, Themes all map to Commands section
                 ConfigModule::Actions
                 | ConfigModule::Emojis
+                | ConfigModule::Glyphs
                 | ConfigModule::Clipboard
                 | ConfigModule::Themes
                     if self.section_info.command_count > 0 && !seen_commands =>
@@ -284,6 +422,22 @@ impl SectionManager {
         sections
     }
 
+    /// Whether the header for a section should be rendered.
+    ///
+    /// `Auto` only shows headers when results span more than one section;
+    /// `Always` shows the header of every non-empty section.
+    pub fn show_section_header(
+        &self,
+        section_type: SectionType,
+        visibility: SectionHeaderVisibility,
+    ) -> bool {
+        match visibility {
+            SectionHeaderVisibility::Never => false,
+            SectionHeaderVisibility::Auto => self.sections_count() > 1,
+            SectionHeaderVisibility::Always => self.section_item_count(section_type) > 0,
+        }
+    }
+
     /// Get the total number of sections (including calculator and best match if present).
     pub fn sections_count(&self) -> usize {
         let mut count = 0;
@@ -328,6 +482,13 @@ impl SectionManager {
                     0
                 }
             }
+            SectionType::Path => {
+                if self.has_path {
+                    1
+                } else {
+                    0
+                }
+            }
             SectionType::Calculator => {
                 if self.has_calculator {
                     1
@@ -344,6 +505,14 @@ impl SectionManager {
                     count
                 }
             }
+            SectionType::Workspaces => {
+                let count = self.section_info.workspace_count;
+                if self.best_match_original_section == Some(SectionType::Workspaces) {
+                    count.saturating_sub(1)
+                } else {
+                    count
+                }
+            }
             SectionType::Commands => {
                 let count = self.section_info.command_count;
                 if self.best_match_original_section == Some(SectionType::Commands) {
@@ -352,6 +521,8 @@ impl SectionManager {
                     count
                 }
             }
+            SectionType::Pinned => self.section_info.pinned_count,
+            SectionType::Recent => self.section_info.recent_count,
             SectionType::Applications => {
                 let count = self.section_info.app_count;
                 if self.best_match_original_section == Some(SectionType::Applications) {
@@ -360,6 +531,8 @@ impl SectionManager {
                     count
                 }
             }
+            SectionType::Scripts => self.script_count,
+            SectionType::Files => self.file_count,
             SectionType::SearchAndAi => {
                 let ai_count = if self.has_ai { 1 } else { 0 };
                 let count = ai_count + self.search_count;
@@ -372,6 +545,83 @@ impl SectionManager {
         }
     }
 
+    /// Resolve a global index to the item slot it shows.
+    ///
+    /// The promoted best match is skipped in its original section, so rows
+    /// after it map one filtered position further.
+    pub fn resolve(&self, global_index: usize) -> Option<ItemSlot> {
+        // Track offset within regular items (excluding best match)
+        let mut regular_item_offset = 0;
+        let mut current_start = 0;
+
+        for section_type in self.ordered_section_types() {
+            let section_count = self.section_item_count(section_type);
+            let section_end = current_start + section_count;
+
+            if global_index >= current_start && global_index < section_end {
+                let row = global_index - current_start;
+
+                return match section_type {
+                    SectionType::BestMatch => self.best_match_filtered_pos.map(ItemSlot::Filtered),
+                    SectionType::Path => Some(ItemSlot::Path),
+                    SectionType::Calculator => Some(ItemSlot::Calculator),
+                    SectionType::Scripts => Some(ItemSlot::Script(row)),
+                    SectionType::Files => Some(ItemSlot::File(row)),
+                    SectionType::Pinned
+                    | SectionType::Windows
+                    | SectionType::Workspaces
+                    | SectionType::Commands
+                    | SectionType::Recent
+                    | SectionType::Applications => Some(ItemSlot::Filtered(
+                        self.adjusted_filtered_pos(regular_item_offset + row, section_type),
+                    )),
+                    SectionType::SearchAndAi => {
+                        if !self.has_ai {
+                            Some(ItemSlot::Search(row))
+                        } else if row == 0 {
+                            Some(ItemSlot::Ai)
+                        } else {
+                            Some(ItemSlot::Search(row - 1))
+                        }
+                    }
+                };
+            }
+
+            // Track offset for regular items (excluding BestMatch and Calculator)
+            if matches!(
+                section_type,
+                SectionType::Pinned
+                    | SectionType::Windows
+                    | SectionType::Workspaces
+                    | SectionType::Commands
+                    | SectionType::Recent
+                    | SectionType::Applications
+            ) {
+                regular_item_offset += section_count;
+                // Add 1 if best match was from this section (since we subtracted it from count)
+                if self.best_match_original_section == Some(section_type) {
+                    regular_item_offset += 1;
+                }
+            }
+            current_start = section_end;
+        }
+
+        None
+    }
+
+    /// Map a position within the regular sections to a filtered position,
+    /// skipping the best match if it was promoted out of this section.
+    fn adjusted_filtered_pos(&self, pos: usize, section_type: SectionType) -> usize {
+        match self.best_match_filtered_pos {
+            Some(best_pos)
+                if self.best_match_original_section == Some(section_type) && pos >= best_pos =>
+            {
+                pos + 1
+            }
+            _ => pos,
+        }
+    }
+
     /// Get the starting global index for a given section type.
     pub fn section_start_index(&self, section_type: SectionType) -> usize {
         let mut offset = 0;
@@ -583,4 +833,211 @@ mod tests {
         // Applications has 2 - 1 (promoted) = 1 item
         assert_eq!(manager.section_item_count(SectionType::Applications), 1);
     }
+
+    fn multi_section_manager() -> SectionManager {
+        let mut manager = SectionManager::new(
+            vec![ConfigModule::Windows, ConfigModule::Applications],
+            false,
+        );
+        let items: Vec<ListItem> = vec![
+            ListItem::Window(mock_window("Terminal", "kitty")),
+            ListItem::Application(mock_application("Firefox")),
+        ];
+        manager.update(&items, &[0, 1], false, false, 0);
+        manager
+    }
+
+    fn single_section_manager() -> SectionManager {
+        let mut manager = SectionManager::new(
+            vec![ConfigModule::Windows, ConfigModule::Applications],
+            false,
+        );
+        let items: Vec<ListItem> = vec![ListItem::Application(mock_application("Firefox"))];
+        manager.update(&items, &[0], false, false, 0);
+        manager
+    }
+
+    #[test]
+    fn test_section_headers_auto() {
+        let manager = multi_section_manager();
+        assert!(manager.show_section_header(SectionType::Windows, SectionHeaderVisibility::Auto));
+        assert!(
+            manager.show_section_header(SectionType::Applications, SectionHeaderVisibility::Auto)
+        );
+
+        let manager = single_section_manager();
+        assert!(
+            !manager.show_section_header(SectionType::Applications, SectionHeaderVisibility::Auto)
+        );
+    }
+
+    #[test]
+    fn test_section_headers_always() {
+        let manager = multi_section_manager();
+        assert!(manager.show_section_header(SectionType::Windows, SectionHeaderVisibility::Always));
+        assert!(
+            manager.show_section_header(SectionType::Applications, SectionHeaderVisibility::Always)
+        );
+
+        let manager = single_section_manager();
+        assert!(
+            manager.show_section_header(SectionType::Applications, SectionHeaderVisibility::Always)
+        );
+        assert!(
+            !manager.show_section_header(SectionType::Windows, SectionHeaderVisibility::Always)
+        );
+    }
+
+    #[test]
+    fn test_section_headers_never() {
+        let manager = multi_section_manager();
+        assert!(!manager.show_section_header(SectionType::Windows, SectionHeaderVisibility::Never));
+        assert!(
+            !manager.show_section_header(SectionType::Applications, SectionHeaderVisibility::Never)
+        );
+    }
+
+    fn scored(index: usize, score: i64) -> FilteredItem {
+        FilteredItem { index, score }
+    }
+
+    #[test]
+    fn test_resolve_without_best_match() {
+        let items = vec![
+            ListItem::Window(mock_window("Term", "kitty")),
+            ListItem::Application(mock_application("Firefox")),
+            ListItem::Application(mock_application("Files")),
+        ];
+        let mut manager = SectionManager::new(
+            vec![
+                ConfigModule::Calculator,
+                ConfigModule::Windows,
+                ConfigModule::Applications,
+                ConfigModule::Ai,
+                ConfigModule::Search,
+            ],
+            false,
+        );
+        manager.update(&items, &[0, 1, 2], true, true, 2);
+
+        let slots: Vec<_> = (0..7).map(|i| manager.resolve(i)).collect();
+        assert_eq!(
+            slots,
+            vec![
+                Some(ItemSlot::Calculator),
+                Some(ItemSlot::Filtered(0)),
+                Some(ItemSlot::Filtered(1)),
+                Some(ItemSlot::Filtered(2)),
+                Some(ItemSlot::Ai),
+                Some(ItemSlot::Search(0)),
+                Some(ItemSlot::Search(1)),
+            ]
+        );
+        assert_eq!(manager.resolve(7), None);
+    }
+
+    #[test]
+    fn test_resolve_skips_promoted_best_match() {
+        let items = vec![
+            ListItem::Window(mock_window("Term", "kitty")),
+            ListItem::Application(mock_application("Firefox")),
+            ListItem::Application(mock_application("Files")),
+        ];
+        let mut manager = SectionManager::new(
+            vec![ConfigModule::Windows, ConfigModule::Applications],
+            true,
+        );
+        // "Files" (filtered position 2) scores highest and is promoted
+        manager.update_with_scores(
+            &items,
+            &[scored(0, 10), scored(1, 20), scored(2, 90)],
+            false,
+            false,
+            0,
+        );
+
+        let slots: Vec<_> = (0..3).map(|i| manager.resolve(i)).collect();
+        assert_eq!(
+            slots,
+            vec![
+                Some(ItemSlot::Filtered(2)),
+                Some(ItemSlot::Filtered(0)),
+                Some(ItemSlot::Filtered(1)),
+            ]
+        );
+        assert_eq!(manager.resolve(3), None);
+    }
+
+    #[test]
+    fn test_resolve_search_without_ai() {
+        let mut manager = SectionManager::new(vec![ConfigModule::Search], false);
+        manager.update(&[], &[], false, false, 2);
+        assert_eq!(manager.resolve(0), Some(ItemSlot::Search(0)));
+        assert_eq!(manager.resolve(1), Some(ItemSlot::Search(1)));
+    }
+
+    #[test]
+    fn test_resolve_path_first() {
+        let items = vec![ListItem::Application(mock_application("Files"))];
+        let mut manager = SectionManager::new(
+            vec![ConfigModule::Applications, ConfigModule::Search],
+            false,
+        );
+        manager.set_has_path(true);
+        manager.update(&items, &[0], false, false, 1);
+
+        assert_eq!(manager.section_type_at(0), SectionType::Path);
+        let slots: Vec<_> = (0..3).map(|i| manager.resolve(i)).collect();
+        assert_eq!(
+            slots,
+            vec![
+                Some(ItemSlot::Path),
+                Some(ItemSlot::Filtered(0)),
+                Some(ItemSlot::Search(0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_scripts_in_module_order() {
+        let items = vec![ListItem::Application(mock_application("Files"))];
+        let mut manager = SectionManager::new(
+            vec![
+                ConfigModule::Applications,
+                ConfigModule::Scripts,
+                ConfigModule::Search,
+            ],
+            false,
+        );
+        manager.set_script_count(2);
+        manager.update(&items, &[0], false, false, 1);
+
+        assert_eq!(manager.section_type_at(1), SectionType::Scripts);
+        let slots: Vec<_> = (0..4).map(|i| manager.resolve(i)).collect();
+        assert_eq!(
+            slots,
+            vec![
+                Some(ItemSlot::Filtered(0)),
+                Some(ItemSlot::Script(0)),
+                Some(ItemSlot::Script(1)),
+                Some(ItemSlot::Search(0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_files_in_module_order() {
+        let items = vec![ListItem::Application(mock_application("Files"))];
+        let mut manager =
+            SectionManager::new(vec![ConfigModule::Files, ConfigModule::Applications], false);
+        manager.set_file_count(1);
+        manager.update(&items, &[0], false, false, 0);
+
+        assert_eq!(manager.section_type_at(0), SectionType::Files);
+        let slots: Vec<_> = (0..2).map(|i| manager.resolve(i)).collect();
+        assert_eq!(
+            slots,
+            vec![Some(ItemSlot::File(0)), Some(ItemSlot::Filtered(0))]
+        );
+    }
 }
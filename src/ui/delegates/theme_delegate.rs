@@ -1,8 +1,10 @@
+use crate::config::config;
 use crate::items::ThemeItem;
+use crate::ui::components::render_empty_preview;
 use crate::ui::delegates::BaseDelegate;
 use crate::ui::theme::theme;
-use crate::ui::views::render_theme_item;
-use gpui::{App, Context, SharedString, Task, Window, div, prelude::*};
+use crate::ui::views::{TextMeasure, render_theme_item};
+use gpui::{App, Context, Task, Window, prelude::*};
 use gpui_component::IndexPath;
 use gpui_component::list::{ListDelegate, ListItem as GpuiListItem, ListState};
 
@@ -136,13 +138,14 @@ impl ListDelegate for ThemeListDelegate {
     fn render_item(
         &mut self,
         ix: IndexPath,
-        _window: &mut Window,
-        _cx: &mut Context<'_, ListState<Self>>,
+        window: &mut Window,
+        cx: &mut Context<'_, ListState<Self>>,
     ) -> Option<Self::Item> {
         let item = self.base.get_filtered_item(ix.row)?;
         let is_selected = self.base.selected_index() == Some(ix.row);
 
-        let element = render_theme_item(item, is_selected, ix.row);
+        let measure = TextMeasure::new(window, cx);
+        let element = render_theme_item(item, is_selected, &measure, ix.row);
 
         // Reset ListItem default padding - we handle all styling ourselves
         Some(
@@ -190,18 +193,12 @@ impl ListDelegate for ThemeListDelegate {
         _window: &mut Window,
         _cx: &mut Context<'_, ListState<Self>>,
     ) -> impl IntoElement {
-        let theme = theme();
-        div()
-            .w_full()
-            .h(theme.empty_state_height)
-            .flex()
-            .items_center()
-            .justify_center()
-            .child(
-                div()
-                    .text_sm()
-                    .text_color(theme.empty_state_color)
-                    .child(SharedString::from("No themes found")),
-            )
+        let messages = config().empty_state;
+        let message = if self.items().is_empty() {
+            messages.themes()
+        } else {
+            messages.no_matches()
+        };
+        render_empty_preview(message).h(theme().empty_state_height)
     }
 }
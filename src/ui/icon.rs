@@ -107,16 +107,55 @@ pub fn resolve_icon_path(icon_name: &str) -> Option<PathBuf> {
     path
 }
 
-fn resolve_icon_internal(icon_name: &str) -> Option<PathBuf> {
-    // Absolute path - use directly
-    if icon_name.starts_with('/') {
-        let path = PathBuf::from(icon_name);
-        if path.exists() {
-            return Some(path);
-        }
-        return None;
+/// How an `Icon=` value should be resolved.
+#[derive(Debug, PartialEq, Eq)]
+enum IconSource {
+    /// An existing image file to use directly.
+    File(PathBuf),
+    /// A name to look up in the icon theme.
+    Themed(String),
+}
+
+/// Image extensions that may appear on `Icon=` values.
+const ICON_EXTENSIONS: [&str; 4] = ["png", "svg", "xpm", "jpg"];
+
+/// Decide whether an `Icon=` value is a file or a themed icon name.
+///
+/// Values containing a `/` are paths (`~/` is expanded); an existing file is
+/// used directly. Missing files, and names with an image extension, fall back
+/// to a theme lookup of the file stem.
+fn classify_icon(icon: &str) -> IconSource {
+    let path = match icon.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir()
+            .map(|home| home.join(rest))
+            .unwrap_or_else(|| PathBuf::from(icon)),
+        None => PathBuf::from(icon),
+    };
+
+    if icon.contains('/') && path.is_file() {
+        return IconSource::File(path);
     }
 
+    let has_image_ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| ICON_EXTENSIONS.contains(&ext.to_lowercase().as_str()));
+
+    if icon.contains('/') || has_image_ext {
+        let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or(icon);
+        return IconSource::Themed(stem.to_string());
+    }
+
+    IconSource::Themed(icon.to_string())
+}
+
+fn resolve_icon_internal(icon_name: &str) -> Option<PathBuf> {
+    let icon_name = match classify_icon(icon_name) {
+        IconSource::File(path) => return Some(path),
+        IconSource::Themed(name) => name,
+    };
+    let icon_name = icon_name.as_str();
+
     // Try configured theme first
     if let Some(theme) = get_icon_theme() {
         let icon = freedesktop_icons::lookup(icon_name)
@@ -144,3 +183,47 @@ fn resolve_icon_internal(icon_name: &str) -> Option<PathBuf> {
         .with_size(ICON_SIZE)
         .find()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_absolute_path_icon_used_directly() {
+        let path = std::env::temp_dir().join(format!("zlaunch-icon-{}.png", std::process::id()));
+        fs::write(&path, b"").unwrap();
+
+        let source = classify_icon(path.to_str().unwrap());
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(source, IconSource::File(path));
+    }
+
+    #[test]
+    fn test_missing_path_icon_falls_back_to_themed_stem() {
+        assert_eq!(
+            classify_icon("/nonexistent/zlaunch/firefox.png"),
+            IconSource::Themed("firefox".to_string())
+        );
+    }
+
+    #[test]
+    fn test_themed_name_icon() {
+        assert_eq!(
+            classify_icon("org.mozilla.firefox"),
+            IconSource::Themed("org.mozilla.firefox".to_string())
+        );
+        assert_eq!(
+            classify_icon("utilities-terminal"),
+            IconSource::Themed("utilities-terminal".to_string())
+        );
+    }
+
+    #[test]
+    fn test_themed_name_with_extension_is_stripped() {
+        assert_eq!(
+            classify_icon("firefox.png"),
+            IconSource::Themed("firefox".to_string())
+        );
+    }
+}
@@ -1,25 +1,47 @@
 //! Action handlers for LauncherView.
 //!
-//! Handles confirm, cancel, and go_back actions.
+//! Handles confirm, cancel, go_back and copy actions.
 
 use std::sync::Arc;
 
-use gpui::{Context, Window};
+use gpui::{Context, ModifiersChangedEvent, Window};
 
 use crate::clipboard::copy_to_clipboard;
-use crate::compositor::Compositor;
-use crate::config::LauncherMode;
+use crate::compositor::base::find_app_window;
+use crate::compositor::{Compositor, WindowInfo};
+use crate::config::{AppConfig, EmptyConfirmAction, LauncherMode};
+use crate::desktop::exec::clean_exec_string;
 use crate::desktop::launch_application;
-use crate::items::{Executable, ListItem};
+use crate::items::{ApplicationItem, Executable, ListItem};
+use crate::process::{launch_in_terminal, open_url, run_shell_command};
+use crate::search::get_providers;
+use crate::ui::delegates::ItemListDelegate;
+use crate::ui::modes::AiModeHandler;
+use crate::ui::modes::clipboard_mode::{CONFIRM_CLEAR_PROMPT, CONFIRM_CLEAR_TIMEOUT};
 
 use super::state::ViewMode;
-use super::{Cancel, Confirm, GoBack, LauncherView};
+use super::{
+    Cancel, ClearClipboardHistory, Confirm, CopyDetails, CopyName, CycleClipboardSort,
+    CycleEmojiFormat, DeleteClipboardItem, GoBack, LauncherView, SecondaryConfirm, ShowAppActions,
+    TerminalConfirm,
+};
 
 impl LauncherView {
     /// Handle confirming the selected item.
     pub fn confirm(&mut self, _: &Confirm, window: &mut Window, cx: &mut Context<Self>) {
+        // Let the input method commit its composition first
+        if self.is_composing(window, cx) {
+            cx.propagate();
+            return;
+        }
+
         match self.view_mode {
             ViewMode::Main => {
+                if self.list_state.read(cx).delegate().filtered_count() == 0 {
+                    self.confirm_empty_results(window, cx);
+                    return;
+                }
+
                 // Check if a submenu or AI item is selected
                 if let Some(item) = self.list_state.read(cx).delegate().get_item_at(
                     self.list_state
@@ -35,6 +57,11 @@ impl LauncherView {
                                 self.enter_emoji_mode(window, cx);
                                 return;
                             }
+                            "submenu-glyphs" => {
+                                self.navigated_into_submenu = true;
+                                self.enter_glyph_mode(window, cx);
+                                return;
+                            }
                             "submenu-clipboard" => {
                                 self.navigated_into_submenu = true;
                                 self.enter_clipboard_mode(window, cx);
@@ -45,8 +72,17 @@ impl LauncherView {
                                 self.enter_theme_mode(window, cx);
                                 return;
                             }
-                            _ => {}
+                            _ => {
+                                if let Some(category) = submenu.category_name() {
+                                    self.browse_category(category, window, cx);
+                                    return;
+                                }
+                            }
                         },
+                        ListItem::Ai(ai) if crate::config::config().ai_answer_inline => {
+                            self.answer_ai_inline(ai.query, cx);
+                            return;
+                        }
                         ListItem::Ai(_) => {
                             self.navigated_into_submenu = true;
                             self.enter_ai_mode(window, cx);
@@ -87,6 +123,14 @@ impl LauncherView {
                 // Exit theme mode after confirming
                 self.exit_theme_mode(window, cx);
             }
+            ViewMode::Dmenu | ViewMode::AppActions => {
+                if let Some(dmenu_state) = self.dmenu_mode_handler.as_ref().map(|h| h.list_state())
+                {
+                    dmenu_state.update(cx, |state, _cx| {
+                        state.delegate().do_confirm();
+                    });
+                }
+            }
             ViewMode::AiResponse => {
                 // If already in AI mode, then send a new prompt
                 self.update_ai_mode(window, cx);
@@ -94,23 +138,389 @@ impl LauncherView {
         }
     }
 
+    /// Handle the secondary confirm action (Shift+Enter).
+    ///
+    /// Applications copy their `.desktop` file path and web searches a link
+    /// to their results, per `link_copy_format`. Calculator results are
+    /// copied and the launcher hides, whatever `calculator_close_on_copy`
+    /// says. Views and items without a secondary action treat it as a
+    /// regular confirm.
+    pub fn secondary_confirm(
+        &mut self,
+        _: &SecondaryConfirm,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.is_composing(window, cx) {
+            cx.propagate();
+            return;
+        }
+
+        match self.view_mode {
+            ViewMode::Main => match self.selected_main_item(cx) {
+                Some(ListItem::Application(app)) => {
+                    self.copy_with_status(&app.desktop_path.to_string_lossy(), cx);
+                }
+                Some(ListItem::Search(search)) => {
+                    let format = crate::config::config().link_copy_format;
+                    self.copy_with_status(&search.link_text(format), cx);
+                }
+                Some(ListItem::Calculator(calc)) => {
+                    match copy_to_clipboard(calc.text_for_clipboard()) {
+                        Ok(()) => (self.on_hide)(),
+                        Err(e) => {
+                            tracing::warn!(%e, "Failed to copy to clipboard");
+                            self.set_status_message("Failed to copy".into(), true, cx);
+                        }
+                    }
+                }
+                Some(ListItem::File(file)) => {
+                    let on_hide = self.on_hide.clone();
+                    let confirm_tx = self.confirm_tx.clone();
+                    // Asking the file manager is a blocking D-Bus call
+                    crate::tokio_runtime::spawn(cx, async move {
+                        let result = tokio::task::spawn_blocking(move || file.reveal())
+                            .await
+                            .unwrap_or_else(|e| Err(e.into()));
+                        match result {
+                            Ok(()) => on_hide(),
+                            Err(e) => {
+                                tracing::warn!(%e, "Failed to show file in folder");
+                                let _ = confirm_tx.send(ConfirmOutcome::ShowError(
+                                    "Failed to show in folder".to_string(),
+                                ));
+                            }
+                        }
+                    });
+                }
+                _ => self.confirm(&Confirm, window, cx),
+            },
+            ViewMode::ClipboardHistory => {
+                if let Some(handler) = self.clipboard_mode_handler.as_ref() {
+                    handler.secondary_confirm(cx);
+                }
+            }
+            _ => self.confirm(&Confirm, window, cx),
+        }
+    }
+
+    /// Handle the terminal confirm action (`terminal_confirm_key`).
+    ///
+    /// Applications are launched in a terminal even if their entry does not
+    /// set `Terminal`. Other items treat it as a regular confirm.
+    pub fn terminal_confirm(
+        &mut self,
+        _: &TerminalConfirm,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.is_composing(window, cx) {
+            cx.propagate();
+            return;
+        }
+
+        let Some(item) = self.selected_main_item(cx) else {
+            self.confirm(&Confirm, window, cx);
+            return;
+        };
+        let TerminalConfirmDecision::LaunchInTerminal(exec) =
+            TerminalConfirmDecision::decide(&item)
+        else {
+            self.confirm(&Confirm, window, cx);
+            return;
+        };
+
+        let result = launch_in_terminal(&exec).map_err(anyhow::Error::from);
+        match ConfirmOutcome::from_result(&item, result) {
+            ConfirmOutcome::ShowError(text) => self.set_status_message(text.into(), true, cx),
+            _ => {
+                let config = crate::config::config();
+                crate::desktop::recent::record_launch(item.id(), config.recent_apps_count);
+                (self.on_hide)();
+            }
+        }
+    }
+
+    /// Handle the application actions key (`alt-enter`).
+    ///
+    /// Lists the selected application's desktop actions to launch one of
+    /// them. Other items treat it as a regular confirm.
+    pub fn show_app_actions(
+        &mut self,
+        _: &ShowAppActions,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.is_composing(window, cx) {
+            cx.propagate();
+            return;
+        }
+
+        match self.selected_main_item(cx) {
+            Some(ListItem::Application(app)) if !app.actions.is_empty() => {
+                self.navigated_into_submenu = true;
+                self.enter_app_actions_mode(app, window, cx);
+            }
+            _ => self.confirm(&Confirm, window, cx),
+        }
+    }
+
+    /// Handle Enter when the query matches no items, per `empty_confirm_action`.
+    fn confirm_empty_results(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let query = self
+            .list_state
+            .read(cx)
+            .delegate()
+            .query()
+            .trim()
+            .to_string();
+        if query.is_empty() {
+            return;
+        }
+
+        match crate::config::config().empty_confirm_action {
+            EmptyConfirmAction::None => {}
+            EmptyConfirmAction::Search => {
+                let Some(provider) = get_providers().into_iter().next() else {
+                    tracing::warn!("No search provider configured for empty_confirm_action");
+                    self.set_status_message("No search provider configured".into(), true, cx);
+                    return;
+                };
+                match open_url(&provider.build_url(&query)) {
+                    Ok(()) => (self.on_hide)(),
+                    Err(e) => {
+                        tracing::warn!(%e, "Failed to open search URL");
+                        let text = format!("Failed to search {}: {}", provider.name, e);
+                        self.set_status_message(text.into(), true, cx);
+                    }
+                }
+            }
+            EmptyConfirmAction::Ai => {
+                self.navigated_into_submenu = true;
+                self.enter_ai_mode_with_query(query, window, cx);
+            }
+            EmptyConfirmAction::Command => match run_shell_command(&query) {
+                Ok(()) => (self.on_hide)(),
+                Err(e) => {
+                    tracing::warn!(%e, "Failed to run command");
+                    let text = format!("Failed to run {}: {}", query, e);
+                    self.set_status_message(text.into(), true, cx);
+                }
+            },
+        }
+    }
+
+    /// Complete the query to list the applications in a category.
+    fn browse_category(&mut self, category: &str, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(query) = self.list_state.read(cx).delegate().category_query(category) else {
+            return;
+        };
+
+        self.input_state.update(cx, |input, cx| {
+            input.set_value(&query, window, cx);
+        });
+        self.list_state.update(cx, |state, cx| {
+            let delegate = state.delegate_mut();
+            delegate.set_query(query);
+            delegate.set_selected(0);
+            cx.notify();
+        });
+        cx.notify();
+    }
+
+    /// Redraw the list when modifiers change, so selected items can show
+    /// their Shift+Enter or terminal action while its modifiers are held.
+    pub fn modifiers_changed(
+        &mut self,
+        _: &ModifiersChangedEvent,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.list_state.update(cx, |_, cx| cx.notify());
+        cx.notify();
+    }
+
+    /// Copy the selected item's name without launching it.
+    pub fn copy_name(&mut self, _: &CopyName, _window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(item) = self.selected_main_item(cx) {
+            self.copy_with_status(item.name(), cx);
+        }
+    }
+
+    /// Copy the selected item's command, URL or result without launching it.
+    pub fn copy_details(&mut self, _: &CopyDetails, _window: &mut Window, cx: &mut Context<Self>) {
+        let Some(item) = self.selected_main_item(cx) else {
+            return;
+        };
+        match item.copy_details() {
+            Some(details) => self.copy_with_status(&details, cx),
+            None => self.show_status("Nothing to copy", cx),
+        }
+    }
+
+    /// Cycle the emoji copy format for this session (emoji mode only).
+    pub fn cycle_emoji_format(
+        &mut self,
+        _: &CycleEmojiFormat,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.view_mode != ViewMode::EmojiPicker {
+            cx.propagate();
+            return;
+        }
+        if let Some(handler) = self.emoji_mode_handler.as_ref() {
+            let format = handler.cycle_copy_format();
+            self.show_status(
+                format!("Copy as: {}", handler.set().format_label(format)),
+                cx,
+            );
+        }
+    }
+
+    /// Cycle the clipboard history sort order (clipboard mode only).
+    pub fn cycle_clipboard_sort(
+        &mut self,
+        _: &CycleClipboardSort,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.view_mode != ViewMode::ClipboardHistory {
+            cx.propagate();
+            return;
+        }
+        if let Some(handler) = self.clipboard_mode_handler.as_ref() {
+            let order = handler.cycle_sort_order(cx);
+            self.show_status(format!("Sort: {}", order.label()), cx);
+        }
+    }
+
+    /// Delete the selected clipboard history item (clipboard mode only).
+    pub fn delete_clipboard_item(
+        &mut self,
+        _: &DeleteClipboardItem,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.view_mode != ViewMode::ClipboardHistory {
+            cx.propagate();
+            return;
+        }
+        if let Some(handler) = self.clipboard_mode_handler.as_ref()
+            && handler.delete_selected(cx)
+        {
+            self.show_status("Deleted from clipboard history", cx);
+        }
+    }
+
+    /// Clear the clipboard history after confirmation (clipboard mode only).
+    pub fn clear_clipboard_history(
+        &mut self,
+        _: &ClearClipboardHistory,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.view_mode != ViewMode::ClipboardHistory {
+            cx.propagate();
+            return;
+        }
+        let Some(handler) = self.clipboard_mode_handler.as_ref() else {
+            return;
+        };
+        if handler.clear_history(cx) {
+            self.show_status("Clipboard history cleared", cx);
+        } else {
+            self.set_status_message_for(
+                CONFIRM_CLEAR_PROMPT.into(),
+                false,
+                CONFIRM_CLEAR_TIMEOUT,
+                cx,
+            );
+        }
+    }
+
+    /// Get the selected item in Main mode.
+    fn selected_main_item(&self, cx: &Context<Self>) -> Option<ListItem> {
+        if self.view_mode != ViewMode::Main {
+            return None;
+        }
+        let delegate = self.list_state.read(cx).delegate();
+        delegate.get_item_at(delegate.selected_index()?)
+    }
+
+    /// Copy text to the clipboard and confirm it in the status line.
+    fn copy_with_status(&mut self, text: &str, cx: &mut Context<Self>) {
+        match copy_to_clipboard(text) {
+            Ok(()) => self.show_status("Copied to clipboard", cx),
+            Err(e) => {
+                tracing::warn!(%e, "Failed to copy to clipboard");
+                self.show_status("Failed to copy", cx);
+            }
+        }
+    }
+
     /// Handle cancel action.
     pub fn cancel(&mut self, _: &Cancel, window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_composing(window, cx) {
+            cx.propagate();
+            return;
+        }
+
         match self.view_mode {
             ViewMode::Main => {
                 self.list_state.update(cx, |state, _cx| {
                     state.delegate().do_cancel();
                 });
             }
+            // The first Escape stops a streaming answer, the next one leaves
+            ViewMode::AiResponse
+                if self
+                    .ai_mode_handler
+                    .as_mut()
+                    .is_some_and(AiModeHandler::cancel_stream) =>
+            {
+                self.show_status("Response stopped", cx);
+            }
             _ => {
                 // In subviews, cancel goes back
-                self.go_back(&GoBack, window, cx);
+                self.navigate_back(window, cx);
             }
         }
     }
 
     /// Handle go back action.
+    ///
+    /// Bound to Backspace, so it only navigates once the input is empty.
+    /// Otherwise the key is left to the input to delete text at the cursor
+    /// or the selection.
     pub fn go_back(&mut self, _: &GoBack, window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_composing(window, cx) || self.input_has_text(cx) {
+            cx.propagate();
+            return;
+        }
+
+        // A view switched to by a prefix gives the prefix back, as if only
+        // its last character was deleted
+        let prefix = self.mode_prefix.clone();
+        self.navigate_back(window, cx);
+        if let Some(mut prefix) = prefix
+            && self.view_mode == ViewMode::Main
+        {
+            prefix.pop();
+            self.input_state.update(cx, |input, cx| {
+                input.set_value(prefix.clone(), window, cx);
+            });
+            // Setting the value emits no change event
+            self.list_state.update(cx, |state, cx| {
+                state.delegate_mut().set_query(prefix);
+                cx.notify();
+            });
+        }
+    }
+
+    /// Return to the previous view, or hide the launcher in direct mode.
+    fn navigate_back(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.mode_prefix = None;
         // In direct mode (non-Combined), going back hides the launcher
         let is_direct_mode = !matches!(self.mode_state.current_mode(), LauncherMode::Combined);
 
@@ -147,13 +557,69 @@ impl LauncherView {
             ViewMode::AiResponse => {
                 self.exit_ai_mode(window, cx);
             }
+            ViewMode::AppActions => {
+                self.exit_app_actions_mode(window, cx);
+            }
+            ViewMode::Dmenu => {
+                // There is no view to return to, cancelling hides
+                if let Some(dmenu_state) = self.dmenu_mode_handler.as_ref().map(|h| h.list_state())
+                {
+                    dmenu_state.update(cx, |state, _cx| {
+                        state.delegate().do_cancel();
+                    });
+                }
+            }
         }
     }
 
+    /// Install the main list's confirm callback.
+    ///
+    /// The launcher hides after a successful confirm; otherwise it stays
+    /// open and the outcome is sent on `confirm_tx` to be shown.
+    pub(crate) fn set_item_confirm_callback(
+        delegate: &mut ItemListDelegate,
+        compositor: Arc<dyn Compositor>,
+        on_hide: Arc<dyn Fn() + Send + Sync>,
+        confirm_tx: flume::Sender<ConfirmOutcome>,
+    ) {
+        delegate.set_on_confirm(move |item| {
+            let result = Self::handle_item_confirm(item, &compositor);
+            match ConfirmOutcome::for_config(item, result, &crate::config::config()) {
+                ConfirmOutcome::Hide => on_hide(),
+                outcome => {
+                    let _ = confirm_tx.send(outcome);
+                }
+            }
+        });
+    }
+
     /// Handle confirming an item (static method for callbacks).
-    pub fn handle_item_confirm(item: &ListItem, compositor: &Arc<dyn Compositor>) {
+    pub fn handle_item_confirm(
+        item: &ListItem,
+        compositor: &Arc<dyn Compositor>,
+    ) -> anyhow::Result<()> {
         match item {
             ListItem::Application(app) => {
+                let config = crate::config::config();
+                // Only launches that worked count as recent
+                let record_launch =
+                    || crate::desktop::recent::record_launch(&app.id, config.recent_apps_count);
+
+                if config.raise_or_launch {
+                    match compositor.list_windows() {
+                        Ok(windows) => {
+                            if let LaunchDecision::Raise(address) =
+                                LaunchDecision::decide(app, &windows)
+                            {
+                                compositor.focus_window(&address)?;
+                                record_launch();
+                                return Ok(());
+                            }
+                        }
+                        Err(e) => tracing::warn!(%e, "Failed to list windows, launching"),
+                    }
+                }
+
                 // Convert to DesktopEntry and launch
                 let entry = crate::desktop::DesktopEntry::new(
                     app.id.clone(),
@@ -161,32 +627,40 @@ impl LauncherView {
                     app.exec.clone(),
                     None,
                     app.icon_path.clone(),
+                    None,
                     app.description.clone(),
-                    vec![],
+                    app.categories.clone(),
                     app.terminal,
                     app.desktop_path.clone(),
-                );
-                let _ = launch_application(&entry);
+                )
+                .with_dbus_activatable(app.dbus_activatable);
+                launch_application(&entry)?;
+                record_launch();
             }
             ListItem::Window(win) => {
-                if let Err(e) = compositor.focus_window(&win.address) {
-                    tracing::warn!(%e, "Failed to focus window");
-                }
+                compositor.focus_window(&win.address)?;
+            }
+            ListItem::Workspace(ws) => {
+                compositor.switch_workspace(&ws.workspace_id)?;
             }
             ListItem::Calculator(calc) => {
-                if let Err(e) = copy_to_clipboard(calc.text_for_clipboard()) {
-                    tracing::warn!(%e, "Failed to copy to clipboard");
-                }
+                // Whether the launcher stays open is decided by `ConfirmOutcome`
+                copy_to_clipboard(calc.text_for_clipboard())?;
             }
             ListItem::Action(act) => {
-                if let Err(e) = act.execute() {
-                    tracing::warn!(%e, "Failed to execute action");
-                }
+                act.execute()?;
             }
             ListItem::Search(search) => {
-                if let Err(e) = search.execute() {
-                    tracing::warn!(%e, "Failed to open search URL");
-                }
+                search.execute()?;
+            }
+            ListItem::Path(path) => {
+                path.execute()?;
+            }
+            ListItem::File(file) => {
+                file.execute()?;
+            }
+            ListItem::Script(script) => {
+                script.execute()?;
             }
             ListItem::Submenu(submenu) => {
                 // Submenu items are handled separately (e.g., enter_emoji_mode)
@@ -201,5 +675,224 @@ impl LauncherView {
                 tracing::debug!("Theme item selected");
             }
         }
+        Ok(())
+    }
+}
+
+/// What the launcher does after an item was confirmed.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum ConfirmOutcome {
+    /// The item was handled; hide the launcher.
+    Hide,
+    /// A calculator result was copied; stay open and mark its row.
+    Copied,
+    /// Handling failed; stay open and show this message.
+    ShowError(String),
+}
+
+impl ConfirmOutcome {
+    /// Decide the outcome from the result of handling `item`.
+    pub(crate) fn from_result(item: &ListItem, result: anyhow::Result<()>) -> Self {
+        match result {
+            Ok(()) => Self::Hide,
+            Err(e) => {
+                tracing::warn!(error = %e, item = item.name(), "Failed to confirm item");
+                Self::ShowError(format!(
+                    "Failed to {} {}: {}",
+                    item.action_label().to_lowercase(),
+                    item.name(),
+                    e
+                ))
+            }
+        }
+    }
+
+    /// Decide the outcome, honoring settings that keep the launcher open.
+    ///
+    /// With `calculator_close_on_copy` off, copying a calculator result
+    /// keeps the launcher open so the user can continue calculating.
+    pub(crate) fn for_config(
+        item: &ListItem,
+        result: anyhow::Result<()>,
+        config: &AppConfig,
+    ) -> Self {
+        match Self::from_result(item, result) {
+            Self::Hide
+                if matches!(item, ListItem::Calculator(_)) && !config.calculator_close_on_copy =>
+            {
+                Self::Copied
+            }
+            outcome => outcome,
+        }
+    }
+}
+
+/// Whether confirming an application raises a running window or launches it.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum LaunchDecision {
+    /// Focus the open window with this address.
+    Raise(String),
+    /// No window of the application is open; start it.
+    Launch,
+}
+
+impl LaunchDecision {
+    /// Decide between raising and launching from the open windows.
+    pub(crate) fn decide(app: &ApplicationItem, windows: &[WindowInfo]) -> Self {
+        match find_app_window(windows, &app.id, app.startup_wm_class.as_deref()) {
+            Some(window) => Self::Raise(window.address.clone()),
+            None => Self::Launch,
+        }
+    }
+}
+
+/// What the terminal confirm key does with the selected item.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum TerminalConfirmDecision {
+    /// Run this command in a terminal.
+    LaunchInTerminal(String),
+    /// The item has no terminal launch; confirm it normally.
+    Confirm,
+}
+
+impl TerminalConfirmDecision {
+    /// Decide from the selected item. Applications always run in a
+    /// terminal, whatever their `Terminal` flag.
+    pub(crate) fn decide(item: &ListItem) -> Self {
+        match item {
+            ListItem::Application(app) => Self::LaunchInTerminal(clean_exec_string(&app.exec)),
+            _ => Self::Confirm,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ProcessError;
+    use crate::items::CalculatorItem;
+    use crate::test_utils::{mock_application, mock_window_info};
+
+    /// Stand-in for a launch that fails to spawn.
+    fn failing_launch() -> anyhow::Result<()> {
+        Err(ProcessError::SpawnFailed(std::io::Error::from(std::io::ErrorKind::NotFound)).into())
+    }
+
+    #[test]
+    fn test_successful_confirm_hides() {
+        let item = ListItem::Application(mock_application("Firefox"));
+        assert_eq!(
+            ConfirmOutcome::from_result(&item, Ok(())),
+            ConfirmOutcome::Hide
+        );
+    }
+
+    #[test]
+    fn test_failed_launch_shows_error() {
+        let item = ListItem::Application(mock_application("Firefox"));
+        let ConfirmOutcome::ShowError(message) =
+            ConfirmOutcome::from_result(&item, failing_launch())
+        else {
+            panic!("failed launch should keep the launcher open");
+        };
+        assert!(message.starts_with("Failed to open Firefox: "));
+        assert!(message.contains("Failed to spawn process"));
+    }
+
+    #[test]
+    fn test_missing_terminal_shows_error() {
+        let item = ListItem::Application(mock_application("htop"));
+        let outcome = ConfirmOutcome::from_result(&item, Err(ProcessError::NoTerminal.into()));
+        assert!(matches!(outcome, ConfirmOutcome::ShowError(msg) if msg.contains("$TERMINAL")));
+    }
+
+    #[test]
+    fn test_raise_running_application() {
+        let app = mock_application("Firefox").with_startup_wm_class(Some("firefox".to_string()));
+        let windows = vec![
+            mock_window_info("0x1", "kitty"),
+            mock_window_info("0x2", "firefox"),
+        ];
+        assert_eq!(
+            LaunchDecision::decide(&app, &windows),
+            LaunchDecision::Raise("0x2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_launch_when_not_running() {
+        let app = mock_application("Firefox").with_startup_wm_class(Some("firefox".to_string()));
+        let windows = vec![mock_window_info("0x1", "kitty")];
+        assert_eq!(
+            LaunchDecision::decide(&app, &windows),
+            LaunchDecision::Launch
+        );
+        assert_eq!(LaunchDecision::decide(&app, &[]), LaunchDecision::Launch);
+    }
+
+    fn calculator_item() -> ListItem {
+        ListItem::Calculator(CalculatorItem {
+            id: "calculator".to_string(),
+            expression: "2+2".to_string(),
+            display_result: "4".to_string(),
+            clipboard_result: Some("4".to_string()),
+            is_error: false,
+            unit_label: None,
+            copied: false,
+        })
+    }
+
+    #[test]
+    fn test_calculator_copy_keeps_open_by_default() {
+        let config = AppConfig::default();
+        let item = calculator_item();
+        assert_eq!(
+            ConfirmOutcome::for_config(&item, Ok(()), &config),
+            ConfirmOutcome::Copied
+        );
+
+        // Other items still hide
+        let app = ListItem::Application(mock_application("Firefox"));
+        assert_eq!(
+            ConfirmOutcome::for_config(&app, Ok(()), &config),
+            ConfirmOutcome::Hide
+        );
+    }
+
+    #[test]
+    fn test_calculator_copy_hides_when_configured() {
+        let config = AppConfig {
+            calculator_close_on_copy: true,
+            ..AppConfig::default()
+        };
+        assert_eq!(
+            ConfirmOutcome::for_config(&calculator_item(), Ok(()), &config),
+            ConfirmOutcome::Hide
+        );
+    }
+
+    #[test]
+    fn test_terminal_confirm_forces_terminal_launch() {
+        let mut app = mock_application("Helix");
+        app.exec = "hx %F".to_string();
+        assert!(!app.terminal);
+        assert_eq!(
+            TerminalConfirmDecision::decide(&ListItem::Application(app.clone())),
+            TerminalConfirmDecision::LaunchInTerminal("hx".to_string())
+        );
+
+        app.terminal = true;
+        assert_eq!(
+            TerminalConfirmDecision::decide(&ListItem::Application(app)),
+            TerminalConfirmDecision::LaunchInTerminal("hx".to_string())
+        );
+    }
+
+    #[test]
+    fn test_terminal_confirm_on_other_items_confirms() {
+        assert_eq!(
+            TerminalConfirmDecision::decide(&calculator_item()),
+            TerminalConfirmDecision::Confirm
+        );
     }
 }
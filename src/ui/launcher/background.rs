@@ -0,0 +1,82 @@
+//! Debounced item sources run in the background for LauncherView.
+//!
+//! Script sources and file search both run on the shared tokio runtime
+//! after the query settles, and hand their items to the delegate unless a
+//! newer query replaced them first.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use gpui::{AsyncApp, Context, Entity, Task, WeakEntity};
+use gpui_component::list::ListState;
+
+use super::LauncherView;
+use crate::ui::delegates::ItemListDelegate;
+
+/// The run in progress of one background item source.
+#[derive(Default)]
+pub(crate) struct BackgroundRun {
+    /// The run on the tokio runtime
+    run: Option<tokio::task::AbortHandle>,
+    /// Flag asking work the abort can't reach, like a blocking walk, to stop
+    cancelled: Option<Arc<AtomicBool>>,
+    /// Task handing the run's items to the list
+    _task: Option<Task<()>>,
+}
+
+impl BackgroundRun {
+    /// Start a run, cancelling the one in progress.
+    ///
+    /// After `debounce`, `fetch` runs on the tokio runtime with the run's
+    /// cancel flag. Its result is passed to `apply` on the list's delegate.
+    pub(crate) fn start<T, F>(
+        &mut self,
+        list_state: Entity<ListState<ItemListDelegate>>,
+        debounce: Duration,
+        fetch: impl FnOnce(Arc<AtomicBool>) -> F,
+        apply: impl FnOnce(&mut ItemListDelegate, T) + 'static,
+        cx: &mut Context<LauncherView>,
+    ) where
+        F: Future<Output = T> + Send + 'static,
+        T: Send + 'static,
+    {
+        self.cancel();
+
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let fetch = fetch(cancelled.clone());
+        let handle = crate::tokio_runtime::spawn(cx, async move {
+            tokio::time::sleep(debounce).await;
+            fetch.await
+        });
+        self.run = Some(handle.abort_handle());
+        self.cancelled = Some(cancelled);
+
+        self._task = Some(cx.spawn(
+            async move |_this: WeakEntity<LauncherView>, cx: &mut AsyncApp| {
+                // An error means the run was cancelled
+                let Ok(items) = handle.await else {
+                    return;
+                };
+                let _ = cx.update(|cx| {
+                    list_state.update(cx, |state, cx| {
+                        apply(state.delegate_mut(), items);
+                        cx.notify();
+                    });
+                });
+            },
+        ));
+    }
+
+    /// Cancel the run in progress, if any.
+    pub(crate) fn cancel(&mut self) {
+        if let Some(run) = self.run.take() {
+            run.abort();
+        }
+        if let Some(cancelled) = self.cancelled.take() {
+            cancelled.store(true, Ordering::Relaxed);
+        }
+        self._task = None;
+    }
+}
@@ -0,0 +1,40 @@
+//! File search for LauncherView.
+//!
+//! Walks the file search root on the shared tokio runtime whenever the main
+//! list's query asks for files, and hands the results to the delegate.
+
+use std::time::Duration;
+
+use gpui::Context;
+
+use super::LauncherView;
+use super::state::ViewMode;
+
+/// Delay before searching, so fast typing doesn't start a walk per
+/// keystroke.
+const FILE_SEARCH_DEBOUNCE: Duration = Duration::from_millis(120);
+
+impl LauncherView {
+    /// Search files for the main list's current query.
+    ///
+    /// The results of a search still in progress for an earlier query are
+    /// discarded, and its walk is stopped.
+    pub fn refresh_file_items(&mut self, cx: &mut Context<Self>) {
+        self.file_search.cancel();
+        if self.view_mode != ViewMode::Main {
+            return;
+        }
+        let Some((search, query)) = self.list_state.read(cx).delegate().file_request() else {
+            return;
+        };
+
+        let search_query = query.clone();
+        self.file_search.start(
+            self.list_state.clone(),
+            FILE_SEARCH_DEBOUNCE,
+            |cancelled| crate::files::fetch_items(search, search_query, cancelled),
+            move |delegate, items| delegate.set_file_items(&query, items),
+            cx,
+        );
+    }
+}
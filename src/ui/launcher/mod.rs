@@ -9,19 +9,24 @@
 //!
 //! - [`state`] - View state management ([`ViewMode`], [`ModeState`])
 //! - [`actions`] - Action handlers for keyboard/mouse events
+//! - [`background`] - Debounced item sources run in the background
+//! - [`files`] - Searching files for the query
 //! - [`mode_switching`] - Logic for switching between launcher modes
 //! - [`navigation`] - Item selection and list navigation
 //! - [`render`] - UI rendering implementation
+//! - [`scripts`] - Running script item sources for the query
 //!
 //! # View Modes
 //!
 //! The launcher supports multiple view modes:
 //!
 //! - **Main** - Combined view showing applications, windows, calculator, etc.
-//! - **EmojiPicker** - Grid-based emoji selection
+//! - **EmojiPicker** - Grid-based emoji and Nerd Font glyph selection
 //! - **ClipboardHistory** - List of recent clipboard entries with preview
 //! - **AiResponse** - Streaming AI chat interface
 //! - **ThemePicker** - Theme selection with live preview
+//! - **Dmenu** - Lines read from stdin by `zlaunch dmenu`
+//! - **AppActions** - Desktop actions of an application
 //! - **Combined** - Customizable combined view with module ordering
 //!
 //! # Key Bindings
@@ -29,33 +34,56 @@
 //! - `Up/Down` - Navigate items
 //! - `Tab/Shift+Tab` - Grid navigation (emoji mode)
 //! - `Ctrl+Tab/Ctrl+Shift+Tab` - Switch between modes
+//! - `Ctrl+1..Ctrl+9` - Switch directly to the Nth configured mode
 //! - `Enter` - Execute selected item
-//! - `Escape` - Hide launcher or go back
+//! - `Shift+Enter` - Secondary action (e.g. open a clipboard URL or file,
+//!   copy an application's `.desktop` path, show a file in its folder,
+//!   copy a calculator result and close)
+//! - `Alt+Enter` - List an application's desktop actions (e.g. "New
+//!   Private Window") to launch one
+//! - `Ctrl+Shift+C` - Copy the selected item's name
+//! - `Ctrl+Alt+C` - Copy the selected item's command, URL or result
+//! - `Ctrl+E` - Cycle the emoji copy format (emoji mode)
+//! - `Escape` - Hide launcher or go back (first stops a streaming AI answer)
 //! - `Backspace` (empty input) - Return to previous mode
+//!
+//! Typing a configured mode prefix (e.g. `>e `) in the combined view switches
+//! to that mode.
 
 mod actions;
+mod background;
+mod files;
 mod mode_switching;
 mod navigation;
 mod render;
+mod scripts;
 mod state;
 
-pub use state::{ModeState, ViewMode};
-
+pub(crate) use render::panel_size;
+pub use state::{BackdropClick, ModeState, StatusMessage, ViewMode, fit_panel_size};
 use std::sync::Arc;
 
-use gpui::{App, AppContext, Context, Entity, FocusHandle, Focusable, KeyBinding, Window, actions};
-use gpui_component::input::{InputEvent, InputState};
+use gpui::{
+    Action, App, AppContext, Context, Entity, FocusHandle, Focusable, KeyBinding, SharedString,
+    Task, Window, actions,
+};
+use gpui_component::input::{InputEvent, InputState, Position};
 use gpui_component::list::ListState;
 
 use crate::compositor::Compositor;
 use crate::config::{ConfigModule, LauncherMode, get_combined_modules};
+use crate::emoji::PickerSet;
 use crate::items::ListItem;
 use crate::ui::delegates::ItemListDelegate;
 use crate::ui::modes::{
-    AiModeAccess, AiModeHandler, ClipboardModeHandler, EmojiModeHandler, ThemeModeHandler,
+    AiModeAccess, AiModeHandler, ClipboardModeHandler, DmenuModeHandler, EmojiModeHandler,
+    ThemeModeHandler, committed_input_text,
 };
 use crate::ui::theme::LauncherTheme;
 
+use self::actions::ConfirmOutcome;
+use self::background::BackgroundRun;
+
 // Action definitions
 actions!(
     launcher,
@@ -65,13 +93,41 @@ actions!(
         SelectTab,
         SelectTabPrev,
         Confirm,
+        SecondaryConfirm,
+        TerminalConfirm,
+        ShowAppActions,
         Cancel,
         GoBack,
         SwitchModeNext,
-        SwitchModePrev
+        SwitchModePrev,
+        CopyName,
+        CopyDetails,
+        CycleEmojiFormat,
+        CycleClipboardSort,
+        DeleteClipboardItem,
+        ClearClipboardHistory,
+        ScrollPageUp,
+        ScrollPageDown
     ]
 );
 
+/// Switch directly to the mode at this (zero-based) index of the configured modes.
+#[derive(Clone, Debug, PartialEq, Eq, Action)]
+#[action(namespace = launcher, no_json)]
+pub struct SwitchToMode(pub usize);
+
+/// How long transient status messages stay visible.
+const STATUS_MESSAGE_DURATION: std::time::Duration = std::time::Duration::from_millis(1500);
+/// How long error messages stay visible.
+const ERROR_MESSAGE_DURATION: std::time::Duration = std::time::Duration::from_secs(4);
+
+/// Keystroke bound to [`Confirm`], shown in the action indicator's key badge.
+pub const CONFIRM_KEY: &str = "enter";
+/// Keystroke bound to [`SecondaryConfirm`], shown in the secondary action hints.
+pub const SECONDARY_CONFIRM_KEY: &str = "shift-enter";
+/// Keystroke bound to [`ShowAppActions`], shown in the held action hints.
+pub const APP_ACTIONS_KEY: &str = "alt-enter";
+
 /// Initialize key bindings for the launcher view.
 pub fn init(cx: &mut App) {
     cx.bind_keys([
@@ -79,12 +135,54 @@ pub fn init(cx: &mut App) {
         KeyBinding::new("down", SelectNext, Some("LauncherView")),
         KeyBinding::new("tab", SelectTab, Some("LauncherView")),
         KeyBinding::new("shift-tab", SelectTabPrev, Some("LauncherView")),
-        KeyBinding::new("enter", Confirm, Some("LauncherView")),
+        KeyBinding::new(CONFIRM_KEY, Confirm, Some("LauncherView")),
+        KeyBinding::new(
+            SECONDARY_CONFIRM_KEY,
+            SecondaryConfirm,
+            Some("LauncherView"),
+        ),
+        KeyBinding::new(APP_ACTIONS_KEY, ShowAppActions, Some("LauncherView")),
         KeyBinding::new("escape", Cancel, Some("LauncherView")),
         KeyBinding::new("backspace", GoBack, Some("LauncherView")),
         KeyBinding::new("ctrl-tab", SwitchModeNext, Some("LauncherView")),
         KeyBinding::new("ctrl-shift-tab", SwitchModePrev, Some("LauncherView")),
+        // Plain ctrl-c stays with the input for copying selected text
+        KeyBinding::new("ctrl-shift-c", CopyName, Some("LauncherView")),
+        KeyBinding::new("ctrl-alt-c", CopyDetails, Some("LauncherView")),
+        KeyBinding::new("ctrl-e", CycleEmojiFormat, Some("LauncherView")),
+        KeyBinding::new(
+            "ctrl-o",
+            CycleClipboardSort,
+            Some("LauncherView && ClipboardHistory"),
+        ),
+        KeyBinding::new(
+            "ctrl-d",
+            DeleteClipboardItem,
+            Some("LauncherView && ClipboardHistory"),
+        ),
+        KeyBinding::new(
+            "ctrl-shift-d",
+            ClearClipboardHistory,
+            Some("LauncherView && ClipboardHistory"),
+        ),
+        // Paging only scrolls the AI response; lists keep their own keys
+        KeyBinding::new("pageup", ScrollPageUp, Some("LauncherView && AiResponse")),
+        KeyBinding::new(
+            "pagedown",
+            ScrollPageDown,
+            Some("LauncherView && AiResponse"),
+        ),
     ]);
+    if let Some(key) = crate::config::config().terminal_confirm_key() {
+        cx.bind_keys([KeyBinding::new(key, TerminalConfirm, Some("LauncherView"))]);
+    }
+    cx.bind_keys((1..=9).map(|n| {
+        KeyBinding::new(
+            &format!("ctrl-{}", n),
+            SwitchToMode(n - 1),
+            Some("LauncherView"),
+        )
+    }));
 }
 
 /// The main launcher view.
@@ -95,6 +193,9 @@ pub struct LauncherView {
     pub(crate) mode_state: ModeState,
     /// Whether we navigated into a submenu from combined view (vs direct mode)
     pub(crate) navigated_into_submenu: bool,
+    /// Query prefix that switched to the current view, restored in the
+    /// input when backspacing out of it
+    pub(crate) mode_prefix: Option<String>,
     /// Main list state
     pub(crate) list_state: Entity<ListState<ItemListDelegate>>,
     /// Original items (for recreating filtered delegates)
@@ -107,18 +208,40 @@ pub struct LauncherView {
     pub(crate) clipboard_mode_handler: Option<ClipboardModeHandler>,
     /// AI mode handler (created on demand)
     pub(crate) ai_mode_handler: Option<AiModeHandler>,
+    /// Task streaming an answer into the AI item's row
+    pub(crate) _inline_answer_task: Option<Task<()>>,
+    /// Script sources running for the current query
+    pub(crate) script_run: BackgroundRun,
+    /// File search running for the current query
+    pub(crate) file_search: BackgroundRun,
     /// Theme mode handler (created on demand)
     pub(crate) theme_mode_handler: Option<ThemeModeHandler>,
     /// Current theme (for live preview)
     pub(crate) current_theme: LauncherTheme,
     /// Theme preview subscription
     pub(crate) _theme_preview_subscription: Option<gpui::Subscription>,
+    /// Line list handler of the dmenu and application actions views
+    pub(crate) dmenu_mode_handler: Option<DmenuModeHandler>,
     /// Input state
     pub(crate) input_state: Entity<InputState>,
     /// Focus handle
     pub(crate) focus_handle: FocusHandle,
+    /// Keystroke observer that restores input focus if it was lost
+    pub(crate) _keystroke_subscription: gpui::Subscription,
     /// Callback to hide the launcher
     pub(crate) on_hide: Arc<dyn Fn() + Send + Sync>,
+    /// Transient confirmation or error shown at the bottom of the panel
+    pub(crate) status_message: Option<StatusMessage>,
+    /// Task that clears the status message
+    pub(crate) _status_task: Option<Task<()>>,
+    /// Sender for outcomes of item confirms that keep the launcher open
+    pub(crate) confirm_tx: flume::Sender<ConfirmOutcome>,
+    /// Task showing the outcomes sent on `confirm_tx`
+    pub(crate) _confirm_task: Task<()>,
+    /// Task that clears the "Copied" mark of the calculator result
+    pub(crate) _copied_task: Option<Task<()>>,
+    /// Pending mouse press on the backdrop
+    pub(crate) backdrop_click: BackdropClick,
 }
 
 impl LauncherView {
@@ -139,13 +262,28 @@ impl LauncherView {
 
         // Create main delegate with callbacks
         let mut delegate = ItemListDelegate::new(items.clone(), modules_for_delegate);
-        let on_hide_for_confirm = on_hide.clone();
-        let compositor_for_confirm = compositor.clone();
-
-        delegate.set_on_confirm(move |item| {
-            Self::handle_item_confirm(item, &compositor_for_confirm);
-            on_hide_for_confirm();
-        });
+        let (confirm_tx, confirm_rx) = flume::unbounded();
+        Self::set_item_confirm_callback(
+            &mut delegate,
+            compositor.clone(),
+            on_hide.clone(),
+            confirm_tx.clone(),
+        );
+
+        // List callbacks (also run on clicks) can't reach the view, so they
+        // send what to show here
+        let confirm_task = cx.spawn(
+            async move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                while let Ok(outcome) = confirm_rx.recv_async().await {
+                    if this
+                        .update(cx, |this, cx| this.show_confirm_outcome(outcome, cx))
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+            },
+        );
 
         let on_hide_for_cancel = on_hide.clone();
         delegate.set_on_cancel(move || on_hide_for_cancel());
@@ -157,26 +295,39 @@ impl LauncherView {
         let input_state = cx.new(|cx| InputState::new(window, cx).placeholder(initial_placeholder));
 
         // Subscribe to input changes
-        let list_state_for_subscribe = list_state.clone();
-        cx.subscribe(
+        cx.subscribe_in(
             &input_state,
-            move |_this, input: Entity<InputState>, event: &InputEvent, cx: &mut Context<Self>| {
+            window,
+            move |this,
+                  input: &Entity<InputState>,
+                  event: &InputEvent,
+                  window: &mut Window,
+                  cx: &mut Context<Self>| {
                 if let InputEvent::Change = event {
-                    let text = input.read(cx).value().to_string();
-                    // Update the delegate's query directly (synchronous filtering)
-                    list_state_for_subscribe.update(
-                        cx,
-                        |state: &mut ListState<ItemListDelegate>,
-                         cx: &mut Context<ListState<ItemListDelegate>>| {
-                            state.delegate_mut().set_query(text);
-                            cx.notify();
-                        },
-                    );
+                    // Ignore in-progress IME composition until it is committed
+                    let text = committed_input_text(input, window, cx);
+                    this.handle_query_change(text, window, cx);
                 }
             },
         )
         .detach();
 
+        // Fallback for compositors where the initial focus request is lost
+        // (e.g. niri on Nvidia): if a key arrives in the main search while
+        // the input isn't focused, move focus to the input so subsequent
+        // typing reaches it.
+        let input_for_refocus = input_state.clone();
+        let view_for_refocus = cx.entity().downgrade();
+        let keystroke_subscription = cx.observe_keystrokes(move |_event, window, cx| {
+            let in_main = view_for_refocus
+                .upgrade()
+                .is_some_and(|view| view.read(cx).view_mode == ViewMode::Main);
+            if in_main && !input_for_refocus.focus_handle(cx).is_focused(window) {
+                tracing::debug!("Search input lost focus, refocusing");
+                input_for_refocus.update(cx, |input, cx| input.focus(window, cx));
+            }
+        });
+
         let focus_handle = cx.focus_handle();
 
         // Hide when the view loses focus
@@ -189,7 +340,7 @@ impl LauncherView {
         // Determine initial view mode based on current launcher mode
         let initial_view_mode = match mode_state.current_mode() {
             LauncherMode::Combined => ViewMode::Main,
-            LauncherMode::Emojis => ViewMode::EmojiPicker,
+            LauncherMode::Emojis | LauncherMode::Glyphs => ViewMode::EmojiPicker,
             LauncherMode::Clipboard => ViewMode::ClipboardHistory,
             LauncherMode::Themes => ViewMode::ThemePicker,
             LauncherMode::Ai => ViewMode::AiResponse,
@@ -202,18 +353,30 @@ impl LauncherView {
             view_mode: initial_view_mode,
             mode_state,
             navigated_into_submenu: false,
+            mode_prefix: None,
             list_state,
             original_items: items,
             compositor,
             emoji_mode_handler: None,
             clipboard_mode_handler: None,
             ai_mode_handler: None,
+            _inline_answer_task: None,
+            script_run: BackgroundRun::default(),
+            file_search: BackgroundRun::default(),
             theme_mode_handler: None,
             current_theme: crate::config::load_configured_theme(),
             _theme_preview_subscription: None,
+            dmenu_mode_handler: None,
             input_state,
             focus_handle,
+            _keystroke_subscription: keystroke_subscription,
             on_hide,
+            status_message: None,
+            _status_task: None,
+            confirm_tx,
+            _confirm_task: confirm_task,
+            _copied_task: None,
+            backdrop_click: BackdropClick::default(),
         };
 
         // Initialize mode handler if starting in a direct mode
@@ -229,6 +392,7 @@ impl LauncherView {
             // Modes with dedicated handlers - return combined modules
             // (they don't use the main delegate anyway)
             LauncherMode::Emojis
+            | LauncherMode::Glyphs
             | LauncherMode::Clipboard
             | LauncherMode::Themes
             | LauncherMode::Ai => get_combined_modules(),
@@ -238,6 +402,9 @@ impl LauncherView {
             LauncherMode::Actions => vec![ConfigModule::Actions],
             LauncherMode::Search => vec![ConfigModule::Search],
             LauncherMode::Calculator => vec![ConfigModule::Calculator],
+            LauncherMode::Scripts => vec![ConfigModule::Scripts],
+            LauncherMode::Workspaces => vec![ConfigModule::Workspaces],
+            LauncherMode::Files => vec![ConfigModule::Files],
         }
     }
 
@@ -248,12 +415,16 @@ impl LauncherView {
             LauncherMode::Applications => "Search applications...",
             LauncherMode::Windows => "Search windows...",
             LauncherMode::Actions => "Search actions...",
-            LauncherMode::Emojis => "Search emojis...",
+            LauncherMode::Emojis => PickerSet::Emojis.placeholder(),
+            LauncherMode::Glyphs => PickerSet::Glyphs.placeholder(),
             LauncherMode::Clipboard => "Search clipboard...",
             LauncherMode::Themes => "Search themes...",
             LauncherMode::Ai => "Ask AI...",
             LauncherMode::Search => "Search the web...",
             LauncherMode::Calculator => "Calculate...",
+            LauncherMode::Scripts => "Search scripts...",
+            LauncherMode::Workspaces => "Search workspaces...",
+            LauncherMode::Files => "Search files...",
         }
     }
 
@@ -264,6 +435,9 @@ impl LauncherView {
             LauncherMode::Emojis => {
                 self.enter_emoji_mode(window, cx);
             }
+            LauncherMode::Glyphs => {
+                self.enter_glyph_mode(window, cx);
+            }
             LauncherMode::Clipboard => {
                 self.enter_clipboard_mode(window, cx);
             }
@@ -300,11 +474,155 @@ impl LauncherView {
         cx.notify();
     }
 
+    /// Rebuild the main list after the combined module order changed.
+    pub fn refresh_modules(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.recreate_delegate_for_mode(window, cx);
+        cx.notify();
+    }
+
     /// Focus the launcher input.
+    ///
+    /// Focus is requested immediately. In the main search it is requested
+    /// again on the next frame, since a request made before the layer
+    /// surface is mapped can be dropped.
     pub fn focus(&self, window: &mut Window, cx: &mut Context<Self>) {
         self.input_state.update(cx, |input: &mut InputState, cx| {
             input.focus(window, cx);
         });
+        cx.on_next_frame(window, |this, window, cx| {
+            if this.view_mode == ViewMode::Main
+                && !this.input_state.focus_handle(cx).is_focused(window)
+            {
+                this.input_state.update(cx, |input: &mut InputState, cx| {
+                    input.focus(window, cx);
+                });
+            }
+            cx.notify();
+        });
+    }
+
+    /// Apply a changed search query: switch modes on a mode prefix, or
+    /// filter the list and refresh scripts and file search.
+    fn handle_query_change(&mut self, text: String, window: &mut Window, cx: &mut Context<Self>) {
+        if self.switch_mode_by_prefix(&text, window, cx) {
+            return;
+        }
+        // Update the delegate's query directly (synchronous filtering)
+        self.list_state.update(
+            cx,
+            |state: &mut ListState<ItemListDelegate>,
+             cx: &mut Context<ListState<ItemListDelegate>>| {
+                state.delegate_mut().set_query(text);
+                cx.notify();
+            },
+        );
+        self.refresh_script_items(cx);
+        self.refresh_file_items(cx);
+        // Redraw the launcher itself too, some compositors won't
+        // present the updated input otherwise
+        cx.notify();
+    }
+
+    /// Fill the search input with `query`, as if the user had typed it.
+    ///
+    /// `set_value` doesn't report a change, so the query is applied like a
+    /// typed one: mode prefixes, the delegate's `set_query` (with its
+    /// calculator, AI and search detection), scripts and file search. The
+    /// cursor is moved to the end of the text.
+    pub fn prefill_query(&mut self, query: &str, window: &mut Window, cx: &mut Context<Self>) {
+        self.input_state.update(cx, |input: &mut InputState, cx| {
+            input.set_value(query.to_string(), window, cx);
+            // The query is a single line, so this puts the cursor at its end
+            input.set_cursor_position(
+                Position {
+                    line: 0,
+                    character: query.len() as u32,
+                },
+                window,
+                cx,
+            );
+            input.focus(window, cx);
+        });
+        self.handle_query_change(query.to_string(), window, cx);
+    }
+
+    /// Show a transient confirmation message at the bottom of the panel.
+    pub(crate) fn show_status(&mut self, message: impl Into<SharedString>, cx: &mut Context<Self>) {
+        self.set_status_message(message.into(), false, cx);
+    }
+
+    /// Show the outcome of an item confirm that kept the launcher open.
+    fn show_confirm_outcome(&mut self, outcome: ConfirmOutcome, cx: &mut Context<Self>) {
+        match outcome {
+            ConfirmOutcome::ShowError(text) => self.set_status_message(text.into(), true, cx),
+            ConfirmOutcome::Copied => self.mark_calculator_copied(cx),
+            ConfirmOutcome::Hide => {}
+        }
+    }
+
+    /// Show "Copied" in the calculator row for a moment.
+    fn mark_calculator_copied(&mut self, cx: &mut Context<Self>) {
+        self.list_state.update(cx, |state, _cx| {
+            state.delegate_mut().set_calculator_copied(true);
+        });
+        self._copied_task = Some(cx.spawn(
+            async move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                cx.background_executor()
+                    .timer(STATUS_MESSAGE_DURATION)
+                    .await;
+                let _ = this.update(cx, |this, cx| {
+                    this.list_state.update(cx, |state, _cx| {
+                        state.delegate_mut().set_calculator_copied(false);
+                    });
+                    cx.notify();
+                });
+            },
+        ));
+        cx.notify();
+    }
+
+    fn set_status_message(&mut self, text: SharedString, is_error: bool, cx: &mut Context<Self>) {
+        let duration = if is_error {
+            ERROR_MESSAGE_DURATION
+        } else {
+            STATUS_MESSAGE_DURATION
+        };
+        self.set_status_message_for(text, is_error, duration, cx);
+    }
+
+    /// Show a status message for `duration`, e.g. a prompt that stays
+    /// valid that long.
+    fn set_status_message_for(
+        &mut self,
+        text: SharedString,
+        is_error: bool,
+        duration: std::time::Duration,
+        cx: &mut Context<Self>,
+    ) {
+        self.status_message = Some(StatusMessage { text, is_error });
+        self._status_task = Some(cx.spawn(
+            async move |this: gpui::WeakEntity<Self>, cx: &mut gpui::AsyncApp| {
+                cx.background_executor().timer(duration).await;
+                let _ = this.update(cx, |this, cx| {
+                    this.status_message = None;
+                    cx.notify();
+                });
+            },
+        ));
+        cx.notify();
+    }
+
+    /// Check whether the input has an in-progress IME composition.
+    ///
+    /// Key bindings like enter and backspace must not be handled by the
+    /// launcher while composing, as the input method owns them.
+    pub(crate) fn is_composing(&self, window: &mut Window, cx: &mut Context<Self>) -> bool {
+        crate::ui::modes::is_composing(&self.input_state, window, cx)
+    }
+
+    /// Check whether the search input holds any text.
+    pub(crate) fn input_has_text(&self, cx: &Context<Self>) -> bool {
+        crate::ui::modes::input_has_text(&self.input_state, cx)
     }
 
     /// Reset search to empty state.
@@ -1,30 +1,48 @@
 //! Mode switching and management for LauncherView.
 //!
-//! Handles entering/exiting different modes (emoji, clipboard, AI, theme)
+//! Handles entering/exiting different modes (emoji, clipboard, AI, theme,
+//! dmenu, application actions)
 //! and switching between launcher modes.
 
 use std::sync::Arc;
 
-use gpui::{Context, IntoElement, Window};
+use gpui::{Context, Window, div, prelude::*};
 
 use crate::config::LauncherMode;
+use crate::emoji::PickerSet;
+use crate::items::ApplicationItem;
 use crate::ui::delegates::ItemListDelegate;
-use crate::ui::modes::{AiModeHandler, ClipboardModeHandler, EmojiModeHandler, ThemeModeHandler};
+use crate::ui::modes::{
+    AiModeHandler, ClipboardModeHandler, DmenuModeHandler, EmojiModeHandler, ThemeModeHandler,
+    stream_inline_answer,
+};
 use crate::ui::theme::LauncherTheme;
 use gpui_component::list::ListState;
 
+use super::actions::ConfirmOutcome;
 use super::state::ViewMode;
-use super::{LauncherView, SwitchModeNext, SwitchModePrev};
+use super::{LauncherView, SwitchModeNext, SwitchModePrev, SwitchToMode};
 
 impl LauncherView {
     /// Enter emoji picker mode.
     pub fn enter_emoji_mode(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.enter_picker_mode(PickerSet::Emojis, window, cx);
+    }
+
+    /// Enter the Nerd Font glyph picker, which reuses the emoji grid.
+    pub fn enter_glyph_mode(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.enter_picker_mode(PickerSet::Glyphs, window, cx);
+    }
+
+    /// Enter the emoji picker grid showing `set`.
+    fn enter_picker_mode(&mut self, set: PickerSet, window: &mut Window, cx: &mut Context<Self>) {
         // Create emoji mode handler
-        let handler = EmojiModeHandler::new(&self.input_state, self.on_hide.clone(), window, cx);
+        let handler =
+            EmojiModeHandler::new(set, &self.input_state, self.on_hide.clone(), window, cx);
 
         // Update input
         self.input_state.update(cx, |input, cx| {
-            EmojiModeHandler::setup_input(input, window, cx);
+            EmojiModeHandler::setup_input(set, input, window, cx);
         });
 
         self.emoji_mode_handler = Some(handler);
@@ -84,6 +102,22 @@ impl LauncherView {
             return;
         };
 
+        self.enter_ai_mode_with_query(query, window, cx);
+    }
+
+    /// Answer the query in the AI item's row, staying in the main view.
+    pub fn answer_ai_inline(&mut self, query: String, cx: &mut Context<Self>) {
+        self._inline_answer_task = stream_inline_answer(query, self.list_state.clone(), cx);
+        cx.notify();
+    }
+
+    /// Enter AI response mode, asking the given query.
+    pub fn enter_ai_mode_with_query(
+        &mut self,
+        query: String,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
         // Create AI mode handler (it starts streaming internally)
         let entity = cx.entity().downgrade();
         let handler = AiModeHandler::new(query.clone(), entity, cx);
@@ -139,6 +173,102 @@ impl LauncherView {
         cx.notify();
     }
 
+    /// Enter dmenu mode, choosing one of `lines`.
+    ///
+    /// `on_select` gets the chosen line and the launcher hides; cancelling
+    /// hides it without a selection. `prompt` replaces the placeholder.
+    pub fn enter_dmenu_mode(
+        &mut self,
+        lines: Vec<String>,
+        prompt: Option<&str>,
+        on_select: Arc<dyn Fn(String) + Send + Sync>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let on_hide = self.on_hide.clone();
+        let on_select = Arc::new(move |_index: usize, line: String| {
+            on_select(line);
+            on_hide();
+        });
+        let on_hide = self.on_hide.clone();
+        let on_cancel = Arc::new(move || on_hide());
+
+        let handler =
+            DmenuModeHandler::new(&self.input_state, lines, on_select, on_cancel, window, cx);
+
+        self.input_state.update(cx, |input, cx| {
+            DmenuModeHandler::setup_input(prompt, input, window, cx);
+        });
+
+        self.dmenu_mode_handler = Some(handler);
+        self.view_mode = ViewMode::Dmenu;
+        cx.notify();
+    }
+
+    /// List the desktop actions of `app` to launch one of them.
+    ///
+    /// Actions are launched through their own `Exec` line; the launcher hides
+    /// afterwards, or stays open to show why launching failed.
+    pub fn enter_app_actions_mode(
+        &mut self,
+        app: ApplicationItem,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let prompt = format!("{} actions...", app.name);
+        let names = app
+            .actions
+            .iter()
+            .map(|action| action.name.clone())
+            .collect();
+
+        let on_hide = self.on_hide.clone();
+        let confirm_tx = self.confirm_tx.clone();
+        // Actions are looked up by position, as names need not be unique
+        let on_select = Arc::new(move |index: usize, _name: String| {
+            let Some(action) = app.actions.get(index) else {
+                return;
+            };
+            match crate::desktop::launch_action(action, app.terminal) {
+                Ok(()) => {
+                    let config = crate::config::config();
+                    crate::desktop::recent::record_launch(&app.id, config.recent_apps_count);
+                    on_hide();
+                }
+                Err(e) => {
+                    tracing::warn!(%e, app = %app.id, action = %action.id, "Failed to launch action");
+                    let _ = confirm_tx.send(ConfirmOutcome::ShowError(format!(
+                        "Failed to open {}: {}",
+                        action.name, e
+                    )));
+                }
+            }
+        });
+        // Escape goes back to the list via navigate_back
+        let on_cancel = Arc::new(|| {});
+
+        let handler =
+            DmenuModeHandler::new(&self.input_state, names, on_select, on_cancel, window, cx);
+
+        self.input_state.update(cx, |input, cx| {
+            DmenuModeHandler::setup_input(Some(&prompt), input, window, cx);
+        });
+
+        self.dmenu_mode_handler = Some(handler);
+        self.view_mode = ViewMode::AppActions;
+        cx.notify();
+    }
+
+    /// Leave the application actions list for the main view.
+    pub fn exit_app_actions_mode(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.view_mode = ViewMode::Main;
+        self.dmenu_mode_handler = None;
+        self.navigated_into_submenu = false;
+
+        self.reset_search(window, cx);
+        cx.notify();
+    }
+
     /// Enter theme picker mode.
     pub fn enter_theme_mode(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         let current_theme_name = self.current_theme.name.clone();
@@ -198,12 +328,98 @@ impl LauncherView {
         cx.notify();
     }
 
+    /// Switch to the mode whose prefix starts the combined view's query.
+    ///
+    /// The rest of the query is carried over into the mode's input. Returns
+    /// whether the mode was switched.
+    pub(crate) fn switch_mode_by_prefix(
+        &mut self,
+        text: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        if self.view_mode != ViewMode::Main
+            || *self.mode_state.current_mode() != LauncherMode::Combined
+        {
+            return false;
+        }
+        let config = crate::config::config();
+        let Some((prefix, mode)) = config.mode_prefix_match(text) else {
+            return false;
+        };
+
+        self.cleanup_mode_handlers(window, cx);
+        match mode {
+            LauncherMode::Emojis => self.enter_emoji_mode(window, cx),
+            LauncherMode::Glyphs => self.enter_glyph_mode(window, cx),
+            LauncherMode::Clipboard => self.enter_clipboard_mode(window, cx),
+            LauncherMode::Themes => self.enter_theme_mode(window, cx),
+            _ => {
+                tracing::debug!(?mode, "Mode can't be switched to by prefix");
+                return false;
+            }
+        }
+        self.navigated_into_submenu = true;
+        self.mode_prefix = Some(prefix.to_string());
+
+        // The main list goes back to showing everything for when the mode
+        // is left
+        self.list_state.update(cx, |state, _cx| {
+            state.delegate_mut().clear_query();
+        });
+        let rest = &text[prefix.len()..];
+        if !rest.is_empty() {
+            self.input_state.update(cx, |input, cx| {
+                input.set_value(rest.to_string(), window, cx);
+            });
+            // Setting the value emits no change event, so the mode's list
+            // is filtered here
+            let query = rest.to_string();
+            match self.view_mode {
+                ViewMode::EmojiPicker => {
+                    if let Some(handler) = &self.emoji_mode_handler {
+                        handler.list_state().update(cx, |state, cx| {
+                            state.delegate_mut().set_query(query);
+                            cx.notify();
+                        });
+                    }
+                }
+                ViewMode::ClipboardHistory => {
+                    if let Some(handler) = &self.clipboard_mode_handler {
+                        handler.list_state().update(cx, |state, cx| {
+                            state.delegate_mut().set_query(query);
+                            cx.notify();
+                        });
+                    }
+                }
+                ViewMode::ThemePicker => {
+                    if let Some(handler) = &self.theme_mode_handler {
+                        handler.list_state().update(cx, |state, cx| {
+                            state.delegate_mut().set_query(query);
+                            cx.notify();
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+        true
+    }
+
     /// Render clipboard preview panel.
     pub fn render_clipboard_preview(
         &self,
         item: Option<&crate::clipboard::ClipboardItem>,
     ) -> impl IntoElement {
-        crate::ui::views::clipboard_rendering::render_preview_panel(item)
+        use crate::ui::views::clipboard_rendering::{
+            render_preview_panel, render_secondary_action_hint,
+        };
+
+        div()
+            .relative()
+            .size_full()
+            .child(render_preview_panel(item))
+            .children(item.and_then(render_secondary_action_hint))
     }
 
     /// Switch to the next mode.
@@ -234,6 +450,19 @@ impl LauncherView {
         self.apply_current_mode(window, cx);
     }
 
+    /// Switch directly to the Nth configured mode (does nothing if out of range).
+    pub fn switch_to_mode(
+        &mut self,
+        action: &SwitchToMode,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.mode_state.select_mode(action.0) {
+            return;
+        }
+        self.apply_current_mode(window, cx);
+    }
+
     /// Apply the current mode by switching view modes and setting up handlers.
     pub fn apply_current_mode(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         // Clean up current mode handlers
@@ -252,6 +481,9 @@ impl LauncherView {
             LauncherMode::Emojis => {
                 self.enter_emoji_mode(window, cx);
             }
+            LauncherMode::Glyphs => {
+                self.enter_glyph_mode(window, cx);
+            }
             LauncherMode::Clipboard => {
                 self.enter_clipboard_mode(window, cx);
             }
@@ -278,12 +510,12 @@ impl LauncherView {
         let mut delegate = ItemListDelegate::new(self.original_items.clone(), modules);
 
         // Set up callbacks
-        let on_hide = self.on_hide.clone();
-        let compositor = self.compositor.clone();
-        delegate.set_on_confirm(move |item| {
-            Self::handle_item_confirm(item, &compositor);
-            on_hide();
-        });
+        Self::set_item_confirm_callback(
+            &mut delegate,
+            self.compositor.clone(),
+            self.on_hide.clone(),
+            self.confirm_tx.clone(),
+        );
 
         let on_hide_for_cancel = self.on_hide.clone();
         delegate.set_on_cancel(move || on_hide_for_cancel());
@@ -296,10 +528,15 @@ impl LauncherView {
 
     /// Clean up all mode handlers.
     pub fn cleanup_mode_handlers(&mut self, _window: &mut Window, _cx: &mut Context<Self>) {
+        self.mode_prefix = None;
         self.emoji_mode_handler = None;
         self.clipboard_mode_handler = None;
         self.ai_mode_handler = None;
+        self._inline_answer_task = None;
+        self.script_run.cancel();
+        self.file_search.cancel();
         self.theme_mode_handler = None;
         self._theme_preview_subscription = None;
+        self.dmenu_mode_handler = None;
     }
 }
@@ -6,7 +6,14 @@ use gpui::{Context, ScrollStrategy, Window};
 use gpui_component::IndexPath;
 
 use super::state::ViewMode;
-use super::{LauncherView, SelectNext, SelectPrev, SelectTab, SelectTabPrev};
+use super::{
+    LauncherView, ScrollPageDown, ScrollPageUp, SelectNext, SelectPrev, SelectTab, SelectTabPrev,
+};
+
+/// The configured strategy for scrolling the selection into view.
+fn scroll_strategy() -> ScrollStrategy {
+    crate::config::config().scroll_strategy.into()
+}
 
 impl LauncherView {
     /// Navigate to the next item.
@@ -20,7 +27,7 @@ impl LauncherView {
                     {
                         // Update the List's internal selection
                         state.set_selected_index(Some(index_path), window, cx);
-                        state.scroll_to_item(index_path, ScrollStrategy::Top, window, cx);
+                        state.scroll_to_item(index_path, scroll_strategy(), window, cx);
                     }
                     cx.notify();
                 });
@@ -33,7 +40,7 @@ impl LauncherView {
                         if let Some(row) = state.delegate().selected_row() {
                             state.scroll_to_item(
                                 IndexPath::new(row),
-                                ScrollStrategy::Top,
+                                scroll_strategy(),
                                 window,
                                 cx,
                             );
@@ -51,7 +58,7 @@ impl LauncherView {
                         if let Some(idx) = state.delegate().selected_index() {
                             state.scroll_to_item(
                                 IndexPath::new(idx),
-                                ScrollStrategy::Top,
+                                scroll_strategy(),
                                 window,
                                 cx,
                             );
@@ -68,7 +75,24 @@ impl LauncherView {
                         if let Some(idx) = state.delegate().selected_index() {
                             state.scroll_to_item(
                                 IndexPath::new(idx),
-                                ScrollStrategy::Top,
+                                scroll_strategy(),
+                                window,
+                                cx,
+                            );
+                        }
+                        cx.notify();
+                    });
+                }
+            }
+            ViewMode::Dmenu | ViewMode::AppActions => {
+                if let Some(dmenu_state) = self.dmenu_mode_handler.as_ref().map(|h| h.list_state())
+                {
+                    dmenu_state.update(cx, |state, cx| {
+                        state.delegate_mut().select_down();
+                        if let Some(idx) = state.delegate().selected_index() {
+                            state.scroll_to_item(
+                                IndexPath::new(idx),
+                                scroll_strategy(),
                                 window,
                                 cx,
                             );
@@ -94,7 +118,7 @@ impl LauncherView {
                     {
                         // Update the List's internal selection
                         state.set_selected_index(Some(index_path), window, cx);
-                        state.scroll_to_item(index_path, ScrollStrategy::Top, window, cx);
+                        state.scroll_to_item(index_path, scroll_strategy(), window, cx);
                     }
                     cx.notify();
                 });
@@ -107,7 +131,7 @@ impl LauncherView {
                         if let Some(row) = state.delegate().selected_row() {
                             state.scroll_to_item(
                                 IndexPath::new(row),
-                                ScrollStrategy::Top,
+                                scroll_strategy(),
                                 window,
                                 cx,
                             );
@@ -125,7 +149,7 @@ impl LauncherView {
                         if let Some(idx) = state.delegate().selected_index() {
                             state.scroll_to_item(
                                 IndexPath::new(idx),
-                                ScrollStrategy::Top,
+                                scroll_strategy(),
                                 window,
                                 cx,
                             );
@@ -142,7 +166,24 @@ impl LauncherView {
                         if let Some(idx) = state.delegate().selected_index() {
                             state.scroll_to_item(
                                 IndexPath::new(idx),
-                                ScrollStrategy::Top,
+                                scroll_strategy(),
+                                window,
+                                cx,
+                            );
+                        }
+                        cx.notify();
+                    });
+                }
+            }
+            ViewMode::Dmenu | ViewMode::AppActions => {
+                if let Some(dmenu_state) = self.dmenu_mode_handler.as_ref().map(|h| h.list_state())
+                {
+                    dmenu_state.update(cx, |state, cx| {
+                        state.delegate_mut().select_up();
+                        if let Some(idx) = state.delegate().selected_index() {
+                            state.scroll_to_item(
+                                IndexPath::new(idx),
+                                scroll_strategy(),
                                 window,
                                 cx,
                             );
@@ -173,7 +214,7 @@ impl LauncherView {
 
                     if let Some(index_path) = delegate.global_to_index_path(next) {
                         state.set_selected_index(Some(index_path), window, cx);
-                        state.scroll_to_item(index_path, ScrollStrategy::Top, window, cx);
+                        state.scroll_to_item(index_path, scroll_strategy(), window, cx);
                     }
                     cx.notify();
                 });
@@ -186,7 +227,7 @@ impl LauncherView {
                         if let Some(row) = state.delegate().selected_row() {
                             state.scroll_to_item(
                                 IndexPath::new(row),
-                                ScrollStrategy::Top,
+                                scroll_strategy(),
                                 window,
                                 cx,
                             );
@@ -204,7 +245,7 @@ impl LauncherView {
                         if let Some(idx) = state.delegate().selected_index() {
                             state.scroll_to_item(
                                 IndexPath::new(idx),
-                                ScrollStrategy::Top,
+                                scroll_strategy(),
                                 window,
                                 cx,
                             );
@@ -221,7 +262,24 @@ impl LauncherView {
                         if let Some(idx) = state.delegate().selected_index() {
                             state.scroll_to_item(
                                 IndexPath::new(idx),
-                                ScrollStrategy::Top,
+                                scroll_strategy(),
+                                window,
+                                cx,
+                            );
+                        }
+                        cx.notify();
+                    });
+                }
+            }
+            ViewMode::Dmenu | ViewMode::AppActions => {
+                if let Some(dmenu_state) = self.dmenu_mode_handler.as_ref().map(|h| h.list_state())
+                {
+                    dmenu_state.update(cx, |state, cx| {
+                        state.delegate_mut().select_down();
+                        if let Some(idx) = state.delegate().selected_index() {
+                            state.scroll_to_item(
+                                IndexPath::new(idx),
+                                scroll_strategy(),
                                 window,
                                 cx,
                             );
@@ -257,7 +315,7 @@ impl LauncherView {
 
                     if let Some(index_path) = delegate.global_to_index_path(prev) {
                         state.set_selected_index(Some(index_path), window, cx);
-                        state.scroll_to_item(index_path, ScrollStrategy::Top, window, cx);
+                        state.scroll_to_item(index_path, scroll_strategy(), window, cx);
                     }
                     cx.notify();
                 });
@@ -270,7 +328,7 @@ impl LauncherView {
                         if let Some(row) = state.delegate().selected_row() {
                             state.scroll_to_item(
                                 IndexPath::new(row),
-                                ScrollStrategy::Top,
+                                scroll_strategy(),
                                 window,
                                 cx,
                             );
@@ -288,7 +346,7 @@ impl LauncherView {
                         if let Some(idx) = state.delegate().selected_index() {
                             state.scroll_to_item(
                                 IndexPath::new(idx),
-                                ScrollStrategy::Top,
+                                scroll_strategy(),
                                 window,
                                 cx,
                             );
@@ -305,7 +363,24 @@ impl LauncherView {
                         if let Some(idx) = state.delegate().selected_index() {
                             state.scroll_to_item(
                                 IndexPath::new(idx),
-                                ScrollStrategy::Top,
+                                scroll_strategy(),
+                                window,
+                                cx,
+                            );
+                        }
+                        cx.notify();
+                    });
+                }
+            }
+            ViewMode::Dmenu | ViewMode::AppActions => {
+                if let Some(dmenu_state) = self.dmenu_mode_handler.as_ref().map(|h| h.list_state())
+                {
+                    dmenu_state.update(cx, |state, cx| {
+                        state.delegate_mut().select_up();
+                        if let Some(idx) = state.delegate().selected_index() {
+                            state.scroll_to_item(
+                                IndexPath::new(idx),
+                                scroll_strategy(),
                                 window,
                                 cx,
                             );
@@ -319,4 +394,24 @@ impl LauncherView {
             }
         }
     }
+
+    /// Scroll the AI response up by a page.
+    pub fn scroll_page_up(&mut self, _: &ScrollPageUp, _: &mut Window, cx: &mut Context<Self>) {
+        self.scroll_ai_response(false, cx);
+    }
+
+    /// Scroll the AI response down by a page.
+    pub fn scroll_page_down(&mut self, _: &ScrollPageDown, _: &mut Window, cx: &mut Context<Self>) {
+        self.scroll_ai_response(true, cx);
+    }
+
+    fn scroll_ai_response(&mut self, down: bool, cx: &mut Context<Self>) {
+        if self.view_mode != ViewMode::AiResponse {
+            return;
+        }
+        if let Some(handler) = &self.ai_mode_handler {
+            handler.view().scroll_page(down);
+            cx.notify();
+        }
+    }
 }
@@ -1,24 +1,45 @@
 //! Rendering implementation for LauncherView.
 
-use gpui::{Context, Length, Window, div, image_cache, prelude::*, px, retain_all};
+use gpui::{
+    App, Context, KeyContext, Length, PlatformDisplay, Window, div, image_cache, prelude::*, px,
+    retain_all,
+};
 use gpui_component::list::List;
 use gpui_component::{ActiveTheme, Icon, IconName};
 
 use super::LauncherView;
-use super::state::ViewMode;
+use super::state::{ViewMode, fit_panel_size, stack_clipboard_preview};
+use crate::ui::components::render_empty_preview;
+use crate::ui::theme::LauncherTheme;
+
+/// Get the size of the launcher panel with `theme` on the current display.
+pub(crate) fn panel_size(theme: &LauncherTheme, window: &Window, cx: &App) -> (f32, f32) {
+    let config = crate::config::config();
+    // Without a backdrop the window is only as large as the panel, so
+    // measure the display rather than the viewport when possible
+    let screen = window
+        .display(cx)
+        .map(|display| display.bounds().size)
+        .unwrap_or_else(|| window.viewport_size());
+    fit_panel_size(
+        theme.panel_size(&config),
+        (f32::from(screen.width), f32::from(screen.height)),
+        config.max_screen_fraction,
+    )
+}
 
 impl gpui::Render for LauncherView {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         // Clone theme to avoid borrow conflicts
         let theme = self.current_theme.clone();
         let config = crate::config::config();
-        let (launcher_w, launcher_h) = config.get_launcher_size();
+        let (launcher_w, launcher_h) = panel_size(&theme, window, cx);
 
         // Input prefix (icon based on mode and navigation state)
         let input_prefix = self.render_input_prefix(cx);
 
         // List content based on mode
-        let list_content = self.render_list_content(window, cx);
+        let list_content = self.render_list_content(launcher_w, window, cx);
 
         // Build the launcher panel
         let launcher_panel = div()
@@ -27,15 +48,15 @@ impl gpui::Render for LauncherView {
             .h(px(launcher_h))
             .flex()
             .flex_col()
-            .bg(if config.enable_transparency {
-                theme.window_background
-            } else {
-                theme.window_background.alpha(1.0)
+            .bg(theme.panel_background(config.enable_transparency))
+            .when(theme.window_border_width > px(0.0), |panel| {
+                with_border(panel, theme.window_border_width).border_color(theme.window_border)
             })
-            .border_1()
-            .border_color(theme.window_border)
             .rounded(theme.window_border_radius)
+            // The content mask follows the rounded inner edge of the border,
+            // so children stay clipped at any border width
             .overflow_hidden()
+            .relative()
             // Input section
             .child(
                 div()
@@ -52,59 +73,156 @@ impl gpui::Render for LauncherView {
                     ),
             )
             // List content
-            .child(list_content);
+            .child(list_content)
+            // Transient status message (e.g. "Copied to clipboard")
+            .children(self.status_message.clone().map(|message| {
+                let (background, border, color) = if message.is_error {
+                    (
+                        theme.status.error_background,
+                        theme.status.error_border,
+                        theme.status.error_color,
+                    )
+                } else {
+                    (
+                        theme.status.background,
+                        theme.status.border,
+                        theme.status.color,
+                    )
+                };
+                div()
+                    .absolute()
+                    .bottom_3()
+                    .left_0()
+                    .right_0()
+                    .flex()
+                    .justify_center()
+                    .px_3()
+                    .child(
+                        div()
+                            .px_3()
+                            .py_1()
+                            .rounded(theme.item_border_radius)
+                            .bg(background)
+                            .border_1()
+                            .border_color(border)
+                            .text_xs()
+                            .text_color(color)
+                            .child(message.text),
+                    )
+            }));
 
         if config.enable_backdrop {
             // With backdrop: fullscreen container with centered panel and click-outside-to-close
-            let on_hide = self.on_hide.clone();
+            let close_on_click = config.close_on_backdrop_click;
             div()
+                .bg(config.backdrop_background())
                 .track_focus(&self.focus_handle)
-                .key_context("LauncherView")
+                .key_context(self.key_context())
                 .on_action(cx.listener(Self::select_next))
                 .on_action(cx.listener(Self::select_prev))
                 .on_action(cx.listener(Self::select_tab))
                 .on_action(cx.listener(Self::select_tab_prev))
                 .on_action(cx.listener(Self::confirm))
+                .on_action(cx.listener(Self::secondary_confirm))
+                .on_action(cx.listener(Self::terminal_confirm))
+                .on_action(cx.listener(Self::show_app_actions))
                 .on_action(cx.listener(Self::cancel))
                 .on_action(cx.listener(Self::go_back))
                 .on_action(cx.listener(Self::switch_mode_next))
                 .on_action(cx.listener(Self::switch_mode_prev))
+                .on_action(cx.listener(Self::switch_to_mode))
+                .on_action(cx.listener(Self::copy_name))
+                .on_action(cx.listener(Self::copy_details))
+                .on_action(cx.listener(Self::cycle_emoji_format))
+                .on_action(cx.listener(Self::cycle_clipboard_sort))
+                .on_action(cx.listener(Self::delete_clipboard_item))
+                .on_action(cx.listener(Self::clear_clipboard_history))
+                .on_action(cx.listener(Self::scroll_page_up))
+                .on_action(cx.listener(Self::scroll_page_down))
+                .on_modifiers_changed(cx.listener(Self::modifiers_changed))
                 .size_full()
                 .flex()
                 .items_center()
                 .justify_center()
-                // Click on backdrop to close
-                .on_mouse_down(gpui::MouseButton::Left, move |_event, _window, _cx| {
-                    on_hide();
-                })
-                // Stop click propagation on the panel
-                .child(launcher_panel.on_mouse_down(
+                // Close on a click that starts and ends on the backdrop
+                .on_mouse_down(
+                    gpui::MouseButton::Left,
+                    cx.listener(|this, _event, _window, _cx| {
+                        this.backdrop_click.press_backdrop();
+                    }),
+                )
+                .on_mouse_up(
                     gpui::MouseButton::Left,
-                    |_event, _window, cx| {
-                        cx.stop_propagation();
-                    },
-                ))
+                    cx.listener(move |this, _event, _window, _cx| {
+                        if this.backdrop_click.release_backdrop() && close_on_click {
+                            (this.on_hide)();
+                        }
+                    }),
+                )
+                // Keep panel clicks from reaching the backdrop
+                .child(
+                    launcher_panel
+                        .on_mouse_down(
+                            gpui::MouseButton::Left,
+                            cx.listener(|this, _event, _window, cx| {
+                                this.backdrop_click.panel_event();
+                                cx.stop_propagation();
+                            }),
+                        )
+                        .on_mouse_up(
+                            gpui::MouseButton::Left,
+                            cx.listener(|this, _event, _window, cx| {
+                                this.backdrop_click.panel_event();
+                                cx.stop_propagation();
+                            }),
+                        ),
+                )
                 .into_any_element()
         } else {
             // No backdrop: just the launcher panel filling the window
             launcher_panel
                 .track_focus(&self.focus_handle)
-                .key_context("LauncherView")
+                .key_context(self.key_context())
                 .on_action(cx.listener(Self::select_next))
                 .on_action(cx.listener(Self::select_prev))
                 .on_action(cx.listener(Self::select_tab))
                 .on_action(cx.listener(Self::select_tab_prev))
                 .on_action(cx.listener(Self::confirm))
+                .on_action(cx.listener(Self::secondary_confirm))
+                .on_action(cx.listener(Self::terminal_confirm))
+                .on_action(cx.listener(Self::show_app_actions))
                 .on_action(cx.listener(Self::cancel))
                 .on_action(cx.listener(Self::go_back))
                 .on_action(cx.listener(Self::switch_mode_next))
                 .on_action(cx.listener(Self::switch_mode_prev))
+                .on_action(cx.listener(Self::switch_to_mode))
+                .on_action(cx.listener(Self::copy_name))
+                .on_action(cx.listener(Self::copy_details))
+                .on_action(cx.listener(Self::cycle_emoji_format))
+                .on_action(cx.listener(Self::cycle_clipboard_sort))
+                .on_action(cx.listener(Self::delete_clipboard_item))
+                .on_action(cx.listener(Self::clear_clipboard_history))
+                .on_action(cx.listener(Self::scroll_page_up))
+                .on_action(cx.listener(Self::scroll_page_down))
+                .on_modifiers_changed(cx.listener(Self::modifiers_changed))
                 .into_any_element()
         }
     }
 }
 
 impl LauncherView {
+    /// Key context of the view, marking AI response mode for its bindings.
+    fn key_context(&self) -> KeyContext {
+        let mut context = KeyContext::default();
+        context.add("LauncherView");
+        match self.view_mode {
+            ViewMode::AiResponse => context.add("AiResponse"),
+            ViewMode::ClipboardHistory => context.add("ClipboardHistory"),
+            _ => {}
+        }
+        context
+    }
+
     /// Render the input prefix icon based on current mode and navigation state.
     fn render_input_prefix(&self, cx: &mut Context<Self>) -> gpui::AnyElement {
         match self.view_mode {
@@ -114,9 +232,11 @@ impl LauncherView {
                     crate::config::LauncherMode::Combined => IconName::Search,
                     crate::config::LauncherMode::Applications => IconName::Search,
                     crate::config::LauncherMode::Windows => IconName::LayoutDashboard,
+                    crate::config::LauncherMode::Workspaces => IconName::LayoutDashboard,
                     crate::config::LauncherMode::Actions => IconName::Settings,
                     crate::config::LauncherMode::Search => IconName::Globe,
                     crate::config::LauncherMode::Calculator => IconName::Search,
+                    crate::config::LauncherMode::Files => IconName::Folder,
                     _ => IconName::Search,
                 };
                 Icon::new(icon)
@@ -186,6 +306,19 @@ impl LauncherView {
                         .into_any_element()
                 }
             }
+            ViewMode::AppActions => div()
+                .id("back-app-actions")
+                .cursor_pointer()
+                .mr_2()
+                .on_click(cx.listener(|this, _, window, cx| {
+                    this.exit_app_actions_mode(window, cx);
+                }))
+                .child(Icon::new(IconName::ArrowLeft).text_color(cx.theme().muted_foreground))
+                .into_any_element(),
+            ViewMode::Dmenu => Icon::new(IconName::Search)
+                .text_color(cx.theme().muted_foreground)
+                .mr_2()
+                .into_any_element(),
             ViewMode::AiResponse => {
                 if self.navigated_into_submenu {
                     div()
@@ -210,12 +343,17 @@ impl LauncherView {
     }
 
     /// Render the list content based on current view mode.
+    ///
+    /// `panel_width` decides whether the clipboard preview fits beside the
+    /// list or is stacked below it.
     fn render_list_content(
         &mut self,
+        panel_width: f32,
         window: &mut Window,
         cx: &mut Context<Self>,
     ) -> gpui::AnyElement {
         let theme = &self.current_theme;
+        let messages = crate::config::config().empty_state;
 
         match self.view_mode {
             ViewMode::Main => image_cache(retain_all("app-icons"))
@@ -234,15 +372,46 @@ impl LauncherView {
                         .child(List::new(emoji_state))
                         .into_any_element()
                 } else {
-                    div().flex_1().into_any_element()
+                    empty_sub_view(messages.no_matches())
                 }
             }
             ViewMode::ClipboardHistory => {
                 if let Some(clipboard_state) =
                     self.clipboard_mode_handler.as_ref().map(|h| h.list_state())
                 {
-                    let selected_item =
-                        clipboard_state.read(cx).delegate().selected_item().cloned();
+                    let delegate = clipboard_state.read(cx).delegate();
+                    // Nothing to preview yet, so the hint gets the whole panel
+                    if delegate.items().is_empty() {
+                        return empty_sub_view(messages.clipboard());
+                    }
+                    let selected_item = delegate.selected_item().cloned();
+                    let half = Length::Definite(gpui::DefiniteLength::Fraction(0.5));
+
+                    if stack_clipboard_preview(panel_width) {
+                        // Too narrow for two columns: preview below the list
+                        return div()
+                            .flex_1()
+                            .overflow_hidden()
+                            .flex()
+                            .flex_col()
+                            .child(div().h(half).w_full().child(List::new(clipboard_state)))
+                            .child(
+                                div()
+                                    .h(theme.layout.separator_width)
+                                    .w_full()
+                                    .bg(theme.window_border),
+                            )
+                            .child(
+                                div()
+                                    .flex_1()
+                                    .w_full()
+                                    .bg(theme.item_background)
+                                    .rounded(theme.item_border_radius)
+                                    .overflow_hidden()
+                                    .child(self.render_clipboard_preview(selected_item.as_ref())),
+                            )
+                            .into_any_element();
+                    }
 
                     div()
                         .flex_1()
@@ -250,12 +419,7 @@ impl LauncherView {
                         .flex()
                         .flex_row()
                         // List column
-                        .child(
-                            div()
-                                .w(Length::Definite(gpui::DefiniteLength::Fraction(0.5)))
-                                .h_full()
-                                .child(List::new(clipboard_state)),
-                        )
+                        .child(div().w(half).h_full().child(List::new(clipboard_state)))
                         // Separator
                         .child(
                             div()
@@ -275,7 +439,7 @@ impl LauncherView {
                         )
                         .into_any_element()
                 } else {
-                    div().flex_1().into_any_element()
+                    empty_sub_view(messages.clipboard())
                 }
             }
             ViewMode::ThemePicker => {
@@ -288,7 +452,20 @@ impl LauncherView {
                         .child(List::new(theme_state))
                         .into_any_element()
                 } else {
-                    div().flex_1().into_any_element()
+                    empty_sub_view(messages.themes())
+                }
+            }
+            ViewMode::Dmenu | ViewMode::AppActions => {
+                if let Some(dmenu_state) = self.dmenu_mode_handler.as_ref().map(|h| h.list_state())
+                {
+                    div()
+                        .flex_1()
+                        .overflow_hidden()
+                        .py_2()
+                        .child(List::new(dmenu_state))
+                        .into_any_element()
+                } else {
+                    empty_sub_view(messages.no_matches())
                 }
             }
             ViewMode::AiResponse => {
@@ -305,3 +482,21 @@ impl LauncherView {
         }
     }
 }
+
+/// Give `element` a border of `width` on every edge.
+fn with_border<E: Styled>(mut element: E, width: gpui::Pixels) -> E {
+    let widths = &mut element.style().border_widths;
+    widths.top = Some(width.into());
+    widths.right = Some(width.into());
+    widths.bottom = Some(width.into());
+    widths.left = Some(width.into());
+    element
+}
+
+/// Fill a sub-view's list area with an empty state message.
+fn empty_sub_view(message: &str) -> gpui::AnyElement {
+    div()
+        .flex_1()
+        .child(render_empty_preview(message))
+        .into_any_element()
+}
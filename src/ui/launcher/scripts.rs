@@ -0,0 +1,40 @@
+//! Script item sources for LauncherView.
+//!
+//! Runs the configured script sources on the shared tokio runtime whenever
+//! the main list's query changes, and hands their items to the delegate.
+
+use std::time::Duration;
+
+use gpui::Context;
+
+use super::LauncherView;
+use super::state::ViewMode;
+
+/// Delay before running the scripts, so fast typing doesn't start a run
+/// per keystroke.
+const SCRIPT_DEBOUNCE: Duration = Duration::from_millis(80);
+
+impl LauncherView {
+    /// Run the script sources for the main list's current query.
+    ///
+    /// A run still in progress for an earlier query is cancelled, which
+    /// kills its scripts.
+    pub fn refresh_script_items(&mut self, cx: &mut Context<Self>) {
+        self.script_run.cancel();
+        if self.view_mode != ViewMode::Main {
+            return;
+        }
+        let Some((sources, query)) = self.list_state.read(cx).delegate().script_request() else {
+            return;
+        };
+
+        let run_query = query.clone();
+        self.script_run.start(
+            self.list_state.clone(),
+            SCRIPT_DEBOUNCE,
+            |_| crate::script::fetch_items(sources, run_query),
+            move |delegate, items| delegate.set_script_items(&query, items),
+            cx,
+        );
+    }
+}
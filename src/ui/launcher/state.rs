@@ -3,6 +3,7 @@
 //! Contains mode state tracking and view mode definitions.
 
 use crate::config::LauncherMode;
+use gpui::SharedString;
 
 /// Tracks the active modes list and current mode index.
 #[derive(Clone, Debug)]
@@ -41,6 +42,18 @@ impl ModeState {
         };
     }
 
+    /// Switch to the mode at `index`.
+    ///
+    /// Returns `false` (leaving the current mode unchanged) if `index` is
+    /// out of range or already the current mode.
+    pub fn select_mode(&mut self, index: usize) -> bool {
+        if index >= self.modes.len() || index == self.current_index {
+            return false;
+        }
+        self.current_index = index;
+        true
+    }
+
     /// Check if there are multiple modes (mode switching enabled).
     pub fn has_multiple_modes(&self) -> bool {
         self.modes.len() > 1
@@ -67,4 +80,170 @@ pub enum ViewMode {
     AiResponse,
     /// Theme picker view.
     ThemePicker,
+    /// Lines from `zlaunch dmenu` to choose from.
+    Dmenu,
+    /// Desktop actions of an application to launch.
+    AppActions,
+}
+
+/// A transient message shown at the bottom of the launcher panel.
+#[derive(Clone, Debug)]
+pub struct StatusMessage {
+    /// Text to display
+    pub text: SharedString,
+    /// Whether this reports a failure (styled as an error)
+    pub is_error: bool,
+}
+
+/// Tracks a mouse press on the backdrop to decide whether its release
+/// should close the launcher.
+///
+/// Only a click that both starts and ends on the backdrop counts, so
+/// dragging out of the panel (e.g. selecting text) doesn't close it.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BackdropClick {
+    pressed_on_backdrop: bool,
+}
+
+impl BackdropClick {
+    /// A press landed on the backdrop, outside the panel.
+    pub fn press_backdrop(&mut self) {
+        self.pressed_on_backdrop = true;
+    }
+
+    /// A press or release landed on the panel.
+    pub fn panel_event(&mut self) {
+        self.pressed_on_backdrop = false;
+    }
+
+    /// A release landed on the backdrop. Returns whether this completes a
+    /// backdrop click.
+    pub fn release_backdrop(&mut self) -> bool {
+        std::mem::take(&mut self.pressed_on_backdrop)
+    }
+}
+
+/// Panel width below which the clipboard preview is stacked under the list
+/// instead of shown beside it.
+pub const STACKED_PREVIEW_MAX_WIDTH: f32 = 520.0;
+
+/// Shrink a panel `size` to at most `max_fraction` of the `screen` size in
+/// each dimension.
+///
+/// The fraction is clamped to 0.1 - 1.0. Dimensions of an unknown (zero)
+/// screen size are left as they are.
+pub fn fit_panel_size(size: (f32, f32), screen: (f32, f32), max_fraction: f32) -> (f32, f32) {
+    let fraction = max_fraction.clamp(0.1, 1.0);
+    let fit = |size: f32, screen: f32| {
+        if screen > 0.0 {
+            size.min(screen * fraction)
+        } else {
+            size
+        }
+    };
+    (fit(size.0, screen.0), fit(size.1, screen.1))
+}
+
+/// Whether a panel of `panel_width` is too narrow to show the clipboard
+/// list and preview side by side.
+pub fn stack_clipboard_preview(panel_width: f32) -> bool {
+    panel_width < STACKED_PREVIEW_MAX_WIDTH
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn three_modes() -> ModeState {
+        ModeState::new(vec![
+            LauncherMode::Combined,
+            LauncherMode::Emojis,
+            LauncherMode::Clipboard,
+        ])
+    }
+
+    #[test]
+    fn test_select_mode() {
+        let mut state = three_modes();
+        assert!(state.select_mode(2));
+        assert_eq!(state.current_mode(), &LauncherMode::Clipboard);
+    }
+
+    #[test]
+    fn test_select_mode_out_of_range() {
+        let mut state = three_modes();
+        state.next_mode();
+        assert!(!state.select_mode(3));
+        assert_eq!(state.current_index, 1);
+    }
+
+    #[test]
+    fn test_select_current_mode_is_noop() {
+        let mut state = three_modes();
+        assert!(!state.select_mode(0));
+        assert_eq!(state.current_index, 0);
+    }
+
+    #[test]
+    fn test_backdrop_click_closes() {
+        let mut click = BackdropClick::default();
+        click.press_backdrop();
+        assert!(click.release_backdrop());
+        // A release without a new press does nothing
+        assert!(!click.release_backdrop());
+    }
+
+    #[test]
+    fn test_drag_out_of_panel_does_not_close() {
+        let mut click = BackdropClick::default();
+        click.panel_event();
+        assert!(!click.release_backdrop());
+    }
+
+    #[test]
+    fn test_drag_into_panel_does_not_close() {
+        let mut click = BackdropClick::default();
+        click.press_backdrop();
+        click.panel_event();
+        assert!(!click.release_backdrop());
+    }
+
+    #[test]
+    fn test_fit_panel_size_shrinks_to_screen() {
+        assert_eq!(
+            fit_panel_size((600.0, 400.0), (480.0, 800.0), 0.9),
+            (432.0, 400.0)
+        );
+        assert_eq!(
+            fit_panel_size((600.0, 400.0), (1920.0, 1080.0), 0.9),
+            (600.0, 400.0)
+        );
+    }
+
+    #[test]
+    fn test_fit_panel_size_clamps_fraction() {
+        assert_eq!(
+            fit_panel_size((600.0, 400.0), (500.0, 300.0), 2.0),
+            (500.0, 300.0)
+        );
+        assert_eq!(
+            fit_panel_size((600.0, 400.0), (1000.0, 1000.0), 0.0),
+            (100.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn test_fit_panel_size_unknown_screen() {
+        assert_eq!(
+            fit_panel_size((600.0, 400.0), (0.0, 0.0), 0.9),
+            (600.0, 400.0)
+        );
+    }
+
+    #[test]
+    fn test_stack_clipboard_preview_threshold() {
+        assert!(stack_clipboard_preview(400.0));
+        assert!(!stack_clipboard_preview(STACKED_PREVIEW_MAX_WIDTH));
+        assert!(!stack_clipboard_preview(600.0));
+    }
 }
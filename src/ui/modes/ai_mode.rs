@@ -9,10 +9,15 @@
 //! containing AI logic directly.
 
 use crate::ai;
+use crate::items::InlineAnswer;
+use crate::ui::delegates::ItemListDelegate;
 use crate::ui::views::AiResponseView;
 use flume::Receiver;
-use gpui::{AsyncApp, Context, Task, WeakEntity, Window};
+use futures::future::AbortHandle;
+use gpui::{AsyncApp, Context, Entity, Task, WeakEntity, Window};
 use gpui_component::input::InputState;
+use gpui_component::list::ListState;
+use llm::chat::ChatMessage;
 
 /// Handler for AI response mode.
 ///
@@ -24,6 +29,8 @@ pub struct AiModeHandler {
     /// Task for polling the streaming channel
     /// (stored to keep it alive, but never read)
     stream_task: Task<()>,
+    /// Aborts the request being streamed, if any
+    stream_abort: Option<AbortHandle>,
 }
 
 impl AiModeHandler {
@@ -35,8 +42,9 @@ impl AiModeHandler {
     /// * `cx` - GPUI context
     ///
     /// # Returns
-    /// - `Some(handler)` if streaming started successfully
-    /// - `None` if AI is unavailable or streaming failed to start
+    /// - `Some(handler)` if streaming started successfully, or showing how
+    ///   to set an API key if none is set
+    /// - `None` if streaming failed to start
     pub fn new<T>(
         query: String,
         launcher_entity: WeakEntity<T>,
@@ -46,18 +54,44 @@ impl AiModeHandler {
         T: AiModeAccess + 'static,
     {
         // Create the view
-        let view = AiResponseView::new(query.clone());
+        let mut view = AiResponseView::new(query.clone());
+
+        // Explain a missing key instead of failing to stream
+        if let Err(e) = ai::LLMClient::check_key() {
+            view.set_error(e.to_string());
+            return Some(Self {
+                view,
+                stream_task: Task::ready(()),
+                stream_abort: None,
+            });
+        }
 
         // Start streaming from the AI module
-        let rx = ai::spawn_stream(view.messages().clone())?;
+        let (rx, abort) = ai::spawn_stream(Self::request_messages(&view))?;
 
         // Create task to poll the channel
         let stream_task = Self::spawn_polling_task(rx, launcher_entity, cx);
 
-        Some(Self { view, stream_task })
+        Some(Self {
+            view,
+            stream_task,
+            stream_abort: Some(abort),
+        })
+    }
+
+    /// Get the messages to send for the view's latest question, with the
+    /// earlier exchanges capped by `ai_history_max_turns`.
+    fn request_messages(view: &AiResponseView) -> Vec<ChatMessage> {
+        ai::request_messages(
+            view.messages(),
+            crate::config::config().ai_history_max_turns,
+        )
     }
 
-    /// Send a new user message. Cancels the current streaming task.
+    /// Send a follow-up message. Cancels the current streaming task.
+    ///
+    /// The conversation so far is sent along with it; it lasts until the AI
+    /// view is left and the handler dropped.
     pub fn send_message<T>(
         &mut self,
         message: String,
@@ -66,19 +100,42 @@ impl AiModeHandler {
     ) where
         T: AiModeAccess + 'static,
     {
-        // Abort the current task by replacing it with a ready task
-        self.stream_task = Task::ready(());
-
-        self.view.finish_streaming();
+        self.stop_stream();
         self.view.add_user_message(message);
 
+        if let Err(e) = ai::LLMClient::check_key() {
+            self.view.set_error(e.to_string());
+            return;
+        }
+
         // Start streaming from the AI module
-        if let Some(rx) = ai::spawn_stream(self.view.messages().clone()) {
+        if let Some((rx, abort)) = ai::spawn_stream(Self::request_messages(&self.view)) {
             // Create task to poll the channel
             self.stream_task = Self::spawn_polling_task(rx, launcher_entity, cx);
+            self.stream_abort = Some(abort);
         }
     }
 
+    /// Stop the response being streamed, keeping the partial answer.
+    ///
+    /// Returns `false` if no response was streaming.
+    pub fn cancel_stream(&mut self) -> bool {
+        if !self.view.is_streaming() {
+            return false;
+        }
+        self.stop_stream();
+        true
+    }
+
+    /// Abort the request and stop polling, so no more tokens are appended.
+    fn stop_stream(&mut self) {
+        if let Some(abort) = self.stream_abort.take() {
+            abort.abort();
+        }
+        self.stream_task = Task::ready(());
+        self.view.finish_streaming();
+    }
+
     /// Spawn a task that polls the streaming channel and updates the view.
     fn spawn_polling_task<T>(
         rx: Receiver<Result<String, String>>,
@@ -174,6 +231,63 @@ impl AiModeHandler {
     }
 }
 
+impl Drop for AiModeHandler {
+    fn drop(&mut self) {
+        // Leaving AI mode drops the request instead of streaming into nothing
+        if let Some(abort) = self.stream_abort.take() {
+            abort.abort();
+        }
+    }
+}
+
+/// Stream an answer to `query` into the AI item's row of the main list.
+///
+/// Used instead of the AI response view with `ai_answer_inline`. Returns the
+/// task polling the stream, or `None` if AI is unavailable. Dropping the task
+/// stops updating the row.
+pub fn stream_inline_answer<T: 'static>(
+    query: String,
+    list_state: Entity<ListState<ItemListDelegate>>,
+    cx: &mut Context<T>,
+) -> Option<Task<()>> {
+    // The stream ends once the returned task is dropped and stops receiving
+    let (rx, _abort) = ai::spawn_stream(vec![ChatMessage::user().content(query.clone()).build()])?;
+
+    list_state.update(cx, |state, cx| {
+        state
+            .delegate_mut()
+            .set_inline_answer(InlineAnswer::new(query.clone()));
+        cx.notify();
+    });
+
+    Some(
+        cx.spawn(async move |_entity: WeakEntity<T>, cx: &mut AsyncApp| {
+            while let Ok(msg) = rx.recv_async().await {
+                let is_complete = matches!(msg, Ok(ref s) if s.is_empty());
+                let is_error = msg.is_err();
+
+                let _ = cx.update(|cx| {
+                    list_state.update(cx, |state, cx| {
+                        // The answer is dropped once the query asks something else
+                        if let Some(answer) = state
+                            .delegate_mut()
+                            .inline_answer_mut()
+                            .filter(|answer| answer.query == query)
+                        {
+                            answer.apply(msg);
+                            cx.notify();
+                        }
+                    });
+                });
+
+                if is_complete || is_error {
+                    break;
+                }
+            }
+        }),
+    )
+}
+
 /// Trait for types that can provide access to the AI mode handler.
 ///
 /// This allows the polling task to update the handler through the launcher.
@@ -12,7 +12,9 @@
 //!
 //! Instead, we provide shared utility functions that mode handlers can use.
 
-use gpui::{Context, Window};
+use std::ops::Range;
+
+use gpui::{App, Context, Entity, EntityInputHandler, Window};
 use gpui_component::input::InputState;
 
 /// Default placeholder text for the main launcher view.
@@ -70,10 +72,86 @@ pub fn clear_input_value(
     input_state.set_value("", window, cx);
 }
 
+/// Get the committed input text, excluding any in-progress IME composition.
+///
+/// While an input method is composing (e.g. CJK input or compose sequences),
+/// the preedit text is already part of the input value. Filtering on it makes
+/// the list jump around on every keystroke, so queries only use the committed
+/// part. The full text is used again once the composition is committed.
+pub fn committed_input_text(
+    input_state: &Entity<InputState>,
+    window: &mut Window,
+    cx: &mut App,
+) -> String {
+    input_state.update(cx, |input, cx| {
+        let marked_range = input.marked_text_range(window, cx);
+        strip_marked_text(&input.value(), marked_range)
+    })
+}
+
+/// Check whether the input currently has an in-progress IME composition.
+pub fn is_composing(input_state: &Entity<InputState>, window: &mut Window, cx: &mut App) -> bool {
+    input_state.update(cx, |input, cx| {
+        input.marked_text_range(window, cx).is_some()
+    })
+}
+
+/// Check whether the input holds any text.
+///
+/// Editing keys that double as navigation (Backspace) only navigate once
+/// the input is empty; until then they edit the text at the cursor.
+pub fn input_has_text(input_state: &Entity<InputState>, cx: &App) -> bool {
+    !input_state.read(cx).value().is_empty()
+}
+
+/// Remove the marked (preedit) range from an input value.
+///
+/// `marked_range_utf16` is in UTF-16 code units, as reported by the platform
+/// input handler.
+pub fn strip_marked_text(value: &str, marked_range_utf16: Option<Range<usize>>) -> String {
+    let Some(marked) = marked_range_utf16 else {
+        return value.to_string();
+    };
+
+    let mut result = String::with_capacity(value.len());
+    let mut utf16_offset = 0;
+    for c in value.chars() {
+        if !marked.contains(&utf16_offset) {
+            result.push(c);
+        }
+        utf16_offset += c.len_utf16();
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_strip_marked_text_without_composition() {
+        assert_eq!(strip_marked_text("firefox", None), "firefox");
+    }
+
+    #[test]
+    fn test_strip_marked_text_trailing_preedit() {
+        // "fi" committed, "re" still being composed
+        assert_eq!(strip_marked_text("fire", Some(2..4)), "fi");
+    }
+
+    #[test]
+    fn test_strip_marked_text_multibyte() {
+        // "日本" committed, "ご" (1 UTF-16 unit) being composed
+        assert_eq!(strip_marked_text("日本ご", Some(2..3)), "日本");
+        // Surrogate pairs count as two UTF-16 units
+        assert_eq!(strip_marked_text("😀ab", Some(2..3)), "😀b");
+    }
+
+    #[test]
+    fn test_strip_marked_text_empty_range() {
+        assert_eq!(strip_marked_text("abc", Some(1..1)), "abc");
+    }
+
     #[test]
     fn test_default_placeholder() {
         assert_eq!(DEFAULT_PLACEHOLDER, "Search applications...");
@@ -4,20 +4,41 @@
 //! - Creating and managing clipboard list state
 //! - Setting up input filtering
 //! - Handling clipboard item selection and pasting
+//! - Deleting items and clearing the history
 
 use crate::clipboard::{
-    ClipboardContent, copy_image_to_clipboard, copy_to_clipboard, data::search_items,
+    ClipboardContent, ClipboardItem, copy_image_to_clipboard, copy_to_clipboard, data,
+    data::search_items,
 };
+use crate::config::{ClipboardSortOrder, update_config};
+use crate::process::open_url;
 use crate::ui::delegates::ClipboardListDelegate;
-use gpui::{AppContext, Context, Entity, Subscription, Window};
+use gpui::{App, AppContext, Context, Entity, Subscription, Window};
 use gpui_component::input::{InputEvent, InputState};
 use gpui_component::list::ListState;
+use std::cell::Cell;
+use std::rc::Rc;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Placeholder text while searching clipboard history.
+const SEARCH_PLACEHOLDER: &str = "Search clipboard history...";
+
+/// Prompt shown while waiting for the history clear to be confirmed.
+pub const CONFIRM_CLEAR_PROMPT: &str = "Press Ctrl+Shift+D again to clear clipboard history";
+
+/// How long a request to clear the history waits for its confirmation.
+pub const CONFIRM_CLEAR_TIMEOUT: Duration = Duration::from_secs(3);
 
 /// Handler for clipboard history mode.
 pub struct ClipboardModeHandler {
     /// The clipboard list state
     list_state: Entity<ListState<ClipboardListDelegate>>,
+    /// When clearing the history was asked for, while it waits for
+    /// confirmation
+    clear_requested: Rc<Cell<Option<Instant>>>,
+    /// Callback to hide the launcher
+    on_hide: Arc<dyn Fn() + Send + Sync>,
     /// Subscription to input changes (for filtering)
     _input_subscription: Subscription,
 }
@@ -34,62 +55,114 @@ impl ClipboardModeHandler {
         let mut delegate = ClipboardListDelegate::new(search_items(""));
 
         // Set up confirm callback (copy item and hide)
+        let on_hide_for_confirm = on_hide.clone();
         delegate.set_on_confirm(move |item| {
-            match &item.content {
-                ClipboardContent::Text(t) => {
-                    if let Err(e) = copy_to_clipboard(t) {
-                        tracing::warn!(%e, "Failed to copy text to clipboard");
-                    }
-                }
-                ClipboardContent::Image {
-                    width,
-                    height,
-                    rgba_bytes,
-                } => {
-                    if let Err(e) = copy_image_to_clipboard(*width, *height, rgba_bytes) {
-                        tracing::warn!(%e, "Failed to copy image to clipboard");
-                    }
-                }
-                ClipboardContent::FilePaths(paths) => {
-                    let text = paths
-                        .iter()
-                        .filter_map(|p| p.to_str())
-                        .collect::<Vec<_>>()
-                        .join("\n");
-                    if let Err(e) = copy_to_clipboard(&text) {
-                        tracing::warn!(%e, "Failed to copy file paths to clipboard");
-                    }
-                }
-                ClipboardContent::RichText { plain, .. } => {
-                    if let Err(e) = copy_to_clipboard(plain) {
-                        tracing::warn!(%e, "Failed to copy rich text to clipboard");
-                    }
-                }
-            }
-            on_hide();
+            copy_item(item);
+            on_hide_for_confirm();
         });
 
         // Create list state
         let list_state = cx.new(|cx| ListState::new(delegate, window, cx));
 
-        // Subscribe to input for filtering
+        // Subscribe to input for filtering; typing cancels a pending clear
         let list_state_for_search = list_state.clone();
-        let subscription = cx.subscribe(input_state, move |_this, input, event, cx| {
-            if let InputEvent::Change = event {
-                let query = input.read(cx).value().to_string();
-                list_state_for_search.update(cx, |state, cx| {
-                    state.delegate_mut().set_query(query);
-                    cx.notify();
-                });
-            }
-        });
+        let clear_requested = Rc::new(Cell::new(None));
+        let clear_requested_for_search = clear_requested.clone();
+        let subscription = cx.subscribe_in(
+            input_state,
+            window,
+            move |_this, input, event, window, cx| {
+                if let InputEvent::Change = event {
+                    clear_requested_for_search.set(None);
+                    let query = super::base::committed_input_text(input, window, cx);
+                    list_state_for_search.update(cx, |state, cx| {
+                        state.delegate_mut().set_query(query);
+                        cx.notify();
+                    });
+                }
+            },
+        );
 
         Self {
             list_state,
+            clear_requested,
+            on_hide,
             _input_subscription: subscription,
         }
     }
 
+    /// Run the secondary action (Shift+Enter) on the selected item.
+    ///
+    /// URLs and files are opened with the system handler, anything else is
+    /// copied like a regular confirm.
+    pub fn secondary_confirm(&self, cx: &App) {
+        let Some(item) = self.list_state.read(cx).delegate().selected_item() else {
+            return;
+        };
+
+        match item.open_target() {
+            Some(target) => {
+                if let Err(e) = open_url(&target) {
+                    tracing::warn!(%e, "Failed to open clipboard item");
+                }
+            }
+            None => copy_item(item),
+        }
+        (self.on_hide)();
+    }
+
+    /// Switch to the next sort order and remember it in the config.
+    pub fn cycle_sort_order(&self, cx: &mut App) -> ClipboardSortOrder {
+        let order = self.list_state.update(cx, |state, cx| {
+            let delegate = state.delegate_mut();
+            let order = delegate.sort_order().next();
+            delegate.set_sort_order(order);
+            cx.notify();
+            order
+        });
+        update_config(|config| config.clipboard_sort = order);
+        order
+    }
+
+    /// Delete the selected item from the history and select its neighbour.
+    ///
+    /// Returns whether an item was deleted.
+    pub fn delete_selected(&self, cx: &mut App) -> bool {
+        let removed = self.list_state.update(cx, |state, cx| {
+            let removed = state.delegate_mut().remove_selected();
+            cx.notify();
+            removed
+        });
+        let Some(item) = removed else {
+            return false;
+        };
+        data::remove_item(&item);
+        true
+    }
+
+    /// Clear the whole history, asking for confirmation first.
+    ///
+    /// The first call only asks; the caller shows [`CONFIRM_CLEAR_PROMPT`].
+    /// A second call within [`CONFIRM_CLEAR_TIMEOUT`], without typing in
+    /// between, clears. Returns whether the history was cleared.
+    pub fn clear_history(&self, cx: &mut App) -> bool {
+        let confirmed = self
+            .clear_requested
+            .take()
+            .is_some_and(|requested| requested.elapsed() < CONFIRM_CLEAR_TIMEOUT);
+        if !confirmed {
+            self.clear_requested.set(Some(Instant::now()));
+            return false;
+        }
+
+        data::clear_history();
+        self.list_state.update(cx, |state, cx| {
+            state.delegate_mut().clear_items();
+            cx.notify();
+        });
+        true
+    }
+
     /// Get the list state for rendering.
     pub fn list_state(&self) -> &Entity<ListState<ClipboardListDelegate>> {
         &self.list_state
@@ -101,7 +174,7 @@ impl ClipboardModeHandler {
         window: &mut Window,
         cx: &mut Context<InputState>,
     ) {
-        super::base::setup_list_mode_input(input_state, "Search clipboard history...", window, cx);
+        super::base::setup_list_mode_input(input_state, SEARCH_PLACEHOLDER, window, cx);
     }
 
     /// Restore input placeholder when exiting clipboard mode.
@@ -113,3 +186,39 @@ impl ClipboardModeHandler {
         super::base::restore_main_input(input_state, window, cx);
     }
 }
+
+/// Copy a clipboard history item back to the system clipboard.
+fn copy_item(item: &ClipboardItem) {
+    match &item.content {
+        ClipboardContent::Text(t) => {
+            if let Err(e) = copy_to_clipboard(t) {
+                tracing::warn!(%e, "Failed to copy text to clipboard");
+            }
+        }
+        ClipboardContent::Image {
+            width,
+            height,
+            rgba_bytes,
+            ..
+        } => {
+            if let Err(e) = copy_image_to_clipboard(*width, *height, rgba_bytes) {
+                tracing::warn!(%e, "Failed to copy image to clipboard");
+            }
+        }
+        ClipboardContent::FilePaths(paths) => {
+            let text = paths
+                .iter()
+                .filter_map(|p| p.to_str())
+                .collect::<Vec<_>>()
+                .join("\n");
+            if let Err(e) = copy_to_clipboard(&text) {
+                tracing::warn!(%e, "Failed to copy file paths to clipboard");
+            }
+        }
+        ClipboardContent::RichText { plain, .. } => {
+            if let Err(e) = copy_to_clipboard(plain) {
+                tracing::warn!(%e, "Failed to copy rich text to clipboard");
+            }
+        }
+    }
+}
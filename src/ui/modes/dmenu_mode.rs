@@ -0,0 +1,87 @@
+//! Dmenu mode handler.
+//!
+//! Shows the lines `zlaunch dmenu` read from stdin as a plain list. The
+//! chosen line is handed to a callback that sends it back to the client;
+//! none of the main list's sources or detection take part. The desktop
+//! actions submenu of applications uses the same list.
+
+use crate::ui::delegates::DmenuListDelegate;
+use gpui::{AppContext, Context, Entity, Subscription, Window};
+use gpui_component::input::{InputEvent, InputState};
+use gpui_component::list::ListState;
+use std::sync::Arc;
+
+/// Placeholder shown when no `--prompt` is given.
+pub const DMENU_PLACEHOLDER: &str = "Select...";
+
+/// Handler for dmenu mode.
+pub struct DmenuModeHandler {
+    /// The line list state
+    list_state: Entity<ListState<DmenuListDelegate>>,
+    /// Subscription to input changes (for filtering)
+    _input_subscription: Subscription,
+}
+
+impl DmenuModeHandler {
+    /// Create a new dmenu mode handler.
+    ///
+    /// # Parameters
+    /// - `input_state`: The input field state
+    /// - `lines`: The lines to choose from, in input order
+    /// - `on_select`: Callback with the position in `lines` and text of the
+    ///   chosen line (Enter)
+    /// - `on_cancel`: Callback when user cancels (ESC)
+    /// - `window`: The window context
+    /// - `cx`: The GPUI context
+    pub fn new<T: 'static>(
+        input_state: &Entity<InputState>,
+        lines: Vec<String>,
+        on_select: Arc<dyn Fn(usize, String) + Send + Sync>,
+        on_cancel: Arc<dyn Fn() + Send + Sync>,
+        window: &mut Window,
+        cx: &mut Context<T>,
+    ) -> Self {
+        let mut delegate = DmenuListDelegate::new(lines);
+        delegate.set_on_confirm(move |index, line| on_select(index, line.clone()));
+        delegate.set_on_cancel(move || on_cancel());
+
+        let list_state = cx.new(|cx| ListState::new(delegate, window, cx));
+
+        // Subscribe to input for filtering
+        let list_state_for_search = list_state.clone();
+        let input_subscription = cx.subscribe_in(
+            input_state,
+            window,
+            move |_this, input, event, window, cx| {
+                if let InputEvent::Change = event {
+                    let query = super::base::committed_input_text(input, window, cx);
+                    list_state_for_search.update(cx, |state, cx| {
+                        state.delegate_mut().set_query(query);
+                        cx.notify();
+                    });
+                }
+            },
+        );
+
+        Self {
+            list_state,
+            _input_subscription: input_subscription,
+        }
+    }
+
+    /// Get the list state for rendering.
+    pub fn list_state(&self) -> &Entity<ListState<DmenuListDelegate>> {
+        &self.list_state
+    }
+
+    /// Clear the input and show `prompt` as its placeholder.
+    pub fn setup_input(
+        prompt: Option<&str>,
+        input_state: &mut InputState,
+        window: &mut Window,
+        cx: &mut Context<InputState>,
+    ) {
+        input_state.set_value("", window, cx);
+        input_state.set_placeholder(prompt.unwrap_or(DMENU_PLACEHOLDER).to_string(), window, cx);
+    }
+}
@@ -4,26 +4,34 @@
 //! - Creating and managing emoji grid state
 //! - Setting up input filtering
 //! - Handling emoji selection and copying
+//!
+//! The same grid also serves the Nerd Font glyph picker (see [`PickerSet`]).
 
 use crate::clipboard::copy_to_clipboard;
-use crate::emoji::all_emojis;
+use crate::config::EmojiCopyFormat;
+use crate::emoji::PickerSet;
 use crate::ui::delegates::EmojiGridDelegate;
 use gpui::{AppContext, Context, Entity, Subscription, Window};
 use gpui_component::input::{InputEvent, InputState};
 use gpui_component::list::ListState;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 /// Handler for emoji picker mode.
 pub struct EmojiModeHandler {
+    /// The items shown in the grid
+    set: PickerSet,
     /// The emoji grid list state
     list_state: Entity<ListState<EmojiGridDelegate>>,
+    /// Copy format for this session (starts at `emoji_copy_format`)
+    copy_format: Arc<Mutex<EmojiCopyFormat>>,
     /// Subscription to input changes (for filtering)
     _input_subscription: Subscription,
 }
 
 impl EmojiModeHandler {
-    /// Create a new emoji mode handler.
+    /// Create a new picker mode handler for `set`.
     pub fn new<T: 'static>(
+        set: PickerSet,
         input_state: &Entity<InputState>,
         on_hide: Arc<dyn Fn() + Send + Sync>,
         window: &mut Window,
@@ -31,14 +39,20 @@ impl EmojiModeHandler {
     ) -> Self {
         // Create delegate with theme-based column count
         let mut delegate = EmojiGridDelegate::new(
-            all_emojis().to_vec(),
+            set.items().to_vec(),
             crate::ui::theme::theme().emoji.columns,
         );
 
+        let copy_format = Arc::new(Mutex::new(
+            set.initial_copy_format(crate::config::config().emoji_copy_format),
+        ));
+
         // Set up confirm callback (copy emoji and hide)
+        let copy_format_for_confirm = copy_format.clone();
         delegate.set_on_confirm(move |emoji| {
-            if let Err(e) = copy_to_clipboard(&emoji.emoji) {
-                tracing::warn!(%e, "Failed to copy emoji to clipboard");
+            let format = *copy_format_for_confirm.lock().unwrap();
+            if let Err(e) = copy_to_clipboard(&emoji.copy_text(format)) {
+                tracing::warn!(%e, "Failed to copy to clipboard");
             }
             on_hide();
         });
@@ -48,22 +62,40 @@ impl EmojiModeHandler {
 
         // Subscribe to input for filtering
         let list_state_for_search = list_state.clone();
-        let subscription = cx.subscribe(input_state, move |_this, input, event, cx| {
-            if let InputEvent::Change = event {
-                let query = input.read(cx).value().to_string();
-                list_state_for_search.update(cx, |state, cx| {
-                    state.delegate_mut().set_query(query);
-                    cx.notify();
-                });
-            }
-        });
+        let subscription = cx.subscribe_in(
+            input_state,
+            window,
+            move |_this, input, event, window, cx| {
+                if let InputEvent::Change = event {
+                    let query = super::base::committed_input_text(input, window, cx);
+                    list_state_for_search.update(cx, |state, cx| {
+                        state.delegate_mut().set_query(query);
+                        cx.notify();
+                    });
+                }
+            },
+        );
 
         Self {
+            set,
             list_state,
+            copy_format,
             _input_subscription: subscription,
         }
     }
 
+    /// Switch to the next copy format for this session and return it.
+    pub fn cycle_copy_format(&self) -> EmojiCopyFormat {
+        let mut format = self.copy_format.lock().unwrap();
+        *format = self.set.next_copy_format(*format);
+        *format
+    }
+
+    /// Get the items shown in the grid.
+    pub fn set(&self) -> PickerSet {
+        self.set
+    }
+
     /// Get the list state for rendering.
     pub fn list_state(&self) -> &Entity<ListState<EmojiGridDelegate>> {
         &self.list_state
@@ -71,11 +103,12 @@ impl EmojiModeHandler {
 
     /// Update input placeholder when entering emoji mode.
     pub fn setup_input(
+        set: PickerSet,
         input_state: &mut InputState,
         window: &mut Window,
         cx: &mut Context<InputState>,
     ) {
-        super::base::setup_list_mode_input(input_state, "Search emojis...", window, cx);
+        super::base::setup_list_mode_input(input_state, set.placeholder(), window, cx);
     }
 
     /// Restore input placeholder when exiting emoji mode.
@@ -1,6 +1,6 @@
 //! Mode-specific handlers for the launcher.
 //!
-//! Each mode (AI, Emoji, Clipboard, Theme) has its own handler that encapsulates
+//! Each mode (AI, Emoji, Clipboard, Theme, Dmenu) has its own handler that encapsulates
 //! the mode-specific logic, state, and UI coordination. This keeps the main
 //! launcher clean and focused on routing/coordination.
 //!
@@ -19,11 +19,16 @@
 pub mod ai_mode;
 pub mod base;
 pub mod clipboard_mode;
+pub mod dmenu_mode;
 pub mod emoji_mode;
 pub mod theme_mode;
 
-pub use ai_mode::{AiModeAccess, AiModeHandler};
-pub use base::{DEFAULT_PLACEHOLDER, clear_input_value, restore_main_input, setup_list_mode_input};
+pub use ai_mode::{AiModeAccess, AiModeHandler, stream_inline_answer};
+pub use base::{
+    DEFAULT_PLACEHOLDER, clear_input_value, committed_input_text, input_has_text, is_composing,
+    restore_main_input, setup_list_mode_input,
+};
 pub use clipboard_mode::ClipboardModeHandler;
+pub use dmenu_mode::DmenuModeHandler;
 pub use emoji_mode::EmojiModeHandler;
 pub use theme_mode::ThemeModeHandler;
@@ -102,15 +102,19 @@ impl ThemeModeHandler {
 
         // Subscribe to input for filtering
         let list_state_for_search = list_state.clone();
-        let input_subscription = cx.subscribe(input_state, move |_this, input, event, cx| {
-            if let InputEvent::Change = event {
-                let query = input.read(cx).value().to_string();
-                list_state_for_search.update(cx, |state, cx| {
-                    state.delegate_mut().set_query(query);
-                    cx.notify();
-                });
-            }
-        });
+        let input_subscription = cx.subscribe_in(
+            input_state,
+            window,
+            move |_this, input, event, window, cx| {
+                if let InputEvent::Change = event {
+                    let query = super::base::committed_input_text(input, window, cx);
+                    list_state_for_search.update(cx, |state, cx| {
+                        state.delegate_mut().set_query(query);
+                        cx.notify();
+                    });
+                }
+            },
+        );
 
         Self {
             list_state,
@@ -1,9 +1,9 @@
-use crate::config::FontConfig;
-use gpui::{Hsla, Pixels, hsla, px};
+use crate::config::{AppConfig, FontConfig};
+use gpui::{Background, Hsla, Pixels, hsla, linear_color_stop, linear_gradient, px};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Custom serde module for Hsla colors
-mod hsla_serde {
+pub(crate) mod hsla_serde {
     use super::*;
 
     pub fn serialize<S>(color: &Hsla, serializer: S) -> Result<S::Ok, S::Error>
@@ -247,6 +247,8 @@ pub struct ActionIndicatorTheme {
     /// Line height for key badge text
     #[serde(with = "pixels_serde")]
     pub key_line_height: Pixels,
+    /// Show the confirm key as a badge next to the action label
+    pub show_key_badge: bool,
 }
 
 /// Emoji picker grid styling.
@@ -366,6 +368,57 @@ pub struct SectionHeaderTheme {
     pub padding_y: Pixels,
 }
 
+/// Transient status banner styling (confirmations and errors).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatusTheme {
+    /// Background color for confirmations
+    #[serde(with = "hsla_serde")]
+    pub background: Hsla,
+    /// Border color for confirmations
+    #[serde(with = "hsla_serde")]
+    pub border: Hsla,
+    /// Text color for confirmations
+    #[serde(with = "hsla_serde")]
+    pub color: Hsla,
+    /// Background color for errors
+    #[serde(with = "hsla_serde")]
+    pub error_background: Hsla,
+    /// Border color for errors
+    #[serde(with = "hsla_serde")]
+    pub error_border: Hsla,
+    /// Text color for errors
+    #[serde(with = "hsla_serde")]
+    pub error_color: Hsla,
+}
+
+/// Trailing item badge styling ("Flatpak", running state, window count).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BadgeTheme {
+    /// Background color for text badges
+    #[serde(with = "hsla_serde")]
+    pub background: Hsla,
+    /// Text color for text badges
+    #[serde(with = "hsla_serde")]
+    pub color: Hsla,
+    /// Color of the running indicator dot
+    #[serde(with = "hsla_serde")]
+    pub running_color: Hsla,
+    /// Horizontal padding for text badges
+    #[serde(with = "pixels_serde")]
+    pub padding_x: Pixels,
+    /// Border radius for text badges
+    #[serde(with = "pixels_serde")]
+    pub border_radius: Pixels,
+    /// Font size for badge text
+    #[serde(with = "pixels_serde")]
+    pub font_size: Pixels,
+    /// Gap between badges
+    #[serde(with = "pixels_serde")]
+    pub gap: Pixels,
+}
+
 /// General layout values.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -376,6 +429,65 @@ pub struct LayoutTheme {
     /// Fixed height for item description text (to fit descenders)
     #[serde(with = "pixels_serde")]
     pub item_description_height: Pixels,
+    /// Draw a divider line between sections of the main list
+    pub section_separator: bool,
+    /// Extra vertical space between sections of the main list
+    #[serde(with = "pixels_serde")]
+    pub section_gap: Pixels,
+}
+
+/// A color stop of a [`GradientTheme`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GradientStop {
+    /// Color at this stop
+    #[serde(with = "hsla_serde")]
+    pub color: Hsla,
+    /// Position along the gradient (0.0 - 1.0). Stops without one are
+    /// spread evenly.
+    #[serde(default)]
+    pub position: Option<f32>,
+}
+
+/// A linear gradient fill.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GradientTheme {
+    /// Direction in degrees: 0 runs bottom to top, 90 left to right
+    #[serde(default)]
+    pub angle: f32,
+    /// Color stops (at least two; only the first and last are drawn)
+    pub stops: Vec<GradientStop>,
+}
+
+impl GradientTheme {
+    /// Get the first and last stops with resolved positions, or `None`
+    /// with fewer than two stops.
+    ///
+    /// GPUI gradients blend between two stops, so intermediate stops
+    /// are not drawn.
+    pub fn endpoints(&self) -> Option<((Hsla, f32), (Hsla, f32))> {
+        if self.stops.len() < 2 {
+            return None;
+        }
+        let last_index = self.stops.len() - 1;
+        let resolve = |index: usize| {
+            let stop = &self.stops[index];
+            let even = index as f32 / last_index as f32;
+            (stop.color, stop.position.unwrap_or(even).clamp(0.0, 1.0))
+        };
+        Some((resolve(0), resolve(last_index)))
+    }
+
+    /// Build the GPUI fill. With `opaque`, stop colors lose their alpha,
+    /// like the solid background does without transparency.
+    pub fn to_background(&self, opaque: bool) -> Option<Background> {
+        let ((from, from_pos), (to, to_pos)) = self.endpoints()?;
+        let color = |c: Hsla| if opaque { c.alpha(1.0) } else { c };
+        Some(linear_gradient(
+            self.angle,
+            linear_color_stop(color(from), from_pos),
+            linear_color_stop(color(to), to_pos),
+        ))
+    }
 }
 
 /// Centralized theme configuration for the launcher UI.
@@ -386,11 +498,18 @@ pub struct LauncherTheme {
     /// Theme name identifier
     pub name: String,
 
-    // Window colors and styling (size is configured in AppConfig)
+    // Window colors and styling
+    /// Panel size (width, height), overriding `launcher_size` from the config
+    pub launcher_size: Option<(f32, f32)>,
     #[serde(with = "hsla_serde")]
     pub window_background: Hsla,
+    /// Gradient fill for the panel, drawn instead of `window_background`
+    pub window_background_gradient: Option<GradientTheme>,
     #[serde(with = "hsla_serde")]
     pub window_border: Hsla,
+    /// Width of the panel border; 0 draws no border
+    #[serde(with = "pixels_serde")]
+    pub window_border_width: Pixels,
     #[serde(with = "pixels_serde")]
     pub window_border_radius: Pixels,
 
@@ -419,6 +538,9 @@ pub struct LauncherTheme {
     pub item_title_line_height: Pixels,
     #[serde(with = "pixels_serde")]
     pub item_content_height: Pixels,
+    /// Color of the dot on windows requesting attention
+    #[serde(with = "hsla_serde")]
+    pub urgent_indicator_color: Hsla,
 
     // Icons
     #[serde(with = "pixels_serde")]
@@ -448,6 +570,8 @@ pub struct LauncherTheme {
     pub markdown: MarkdownTheme,
     pub clipboard: ClipboardTheme,
     pub section_header: SectionHeaderTheme,
+    pub status: StatusTheme,
+    pub badge: BadgeTheme,
     pub layout: LayoutTheme,
 }
 
@@ -476,6 +600,7 @@ impl Default for ActionIndicatorTheme {
             key_border_radius: px(3.0),
             key_font_size: px(10.0),
             key_line_height: px(10.0),
+            show_key_badge: true,
         }
     }
 }
@@ -546,11 +671,40 @@ impl Default for SectionHeaderTheme {
     }
 }
 
+impl Default for StatusTheme {
+    fn default() -> Self {
+        Self {
+            background: hsla(0.0, 0.0, 0.1, 0.95),
+            border: hsla(0.0, 0.0, 1.0, 0.094),
+            color: hsla(0.0, 0.0, 1.0, 0.9),
+            error_background: hsla(0.0, 0.55, 0.22, 0.95),
+            error_border: hsla(0.0, 0.6, 0.5, 0.4),
+            error_color: hsla(0.0, 0.8, 0.88, 1.0),
+        }
+    }
+}
+
+impl Default for BadgeTheme {
+    fn default() -> Self {
+        Self {
+            background: hsla(0.0, 0.0, 1.0, 0.06),
+            color: hsla(0.0, 0.0, 1.0, 0.5),
+            running_color: hsla(140.0 / 360.0, 0.55, 0.55, 1.0), // green
+            padding_x: px(5.0),
+            border_radius: px(3.0),
+            font_size: px(10.0),
+            gap: px(4.0),
+        }
+    }
+}
+
 impl Default for LayoutTheme {
     fn default() -> Self {
         Self {
             separator_width: px(1.0),
             item_description_height: px(18.0),
+            section_separator: false,
+            section_gap: px(0.0),
         }
     }
 }
@@ -561,8 +715,11 @@ impl Default for LauncherTheme {
             name: "default".to_string(),
 
             // Window colors and styling
+            launcher_size: None,
             window_background: hsla(0.0, 0.0, 0.06, 0.7), // ~70% opaque dark
-            window_border: hsla(0.0, 0.0, 1.0, 0.094),    // ~9% white
+            window_background_gradient: None,
+            window_border: hsla(0.0, 0.0, 1.0, 0.094), // ~9% white
+            window_border_width: px(1.0),
             window_border_radius: px(12.0),
 
             // List items
@@ -579,6 +736,7 @@ impl Default for LauncherTheme {
             item_description_color: hsla(0.0, 0.0, 1.0, 0.4), // 40% white
             item_title_line_height: px(16.0),
             item_content_height: px(34.0),
+            urgent_indicator_color: hsla(30.0 / 360.0, 0.9, 0.6, 1.0), // amber
 
             // Icons
             icon_size: px(24.0),
@@ -601,12 +759,36 @@ impl Default for LauncherTheme {
             markdown: MarkdownTheme::default(),
             clipboard: ClipboardTheme::default(),
             section_header: SectionHeaderTheme::default(),
+            status: StatusTheme::default(),
+            badge: BadgeTheme::default(),
             layout: LayoutTheme::default(),
         }
     }
 }
 
 impl LauncherTheme {
+    /// Get the launcher panel size: the theme's own size if it sets one,
+    /// otherwise the configured size.
+    pub fn panel_size(&self, config: &AppConfig) -> (f32, f32) {
+        self.launcher_size
+            .unwrap_or_else(|| config.get_launcher_size())
+    }
+
+    /// Get the panel fill: the gradient if one is set, otherwise the solid
+    /// background. With `transparent` false, alpha is dropped.
+    pub fn panel_background(&self, transparent: bool) -> Background {
+        self.window_background_gradient
+            .as_ref()
+            .and_then(|gradient| gradient.to_background(!transparent))
+            .unwrap_or_else(|| {
+                if transparent {
+                    self.window_background.into()
+                } else {
+                    self.window_background.alpha(1.0).into()
+                }
+            })
+    }
+
     /// Calculate the maximum text width for item content.
     /// Accounts for window width, margins, padding, icon, and optionally action indicator.
     pub fn max_text_width(&self, window_width: Pixels, with_action_indicator: bool) -> Pixels {
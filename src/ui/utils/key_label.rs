@@ -0,0 +1,68 @@
+//! Display text for keybindings.
+
+/// Format a GPUI keystroke (e.g. `"ctrl-shift-enter"`) for a key badge.
+///
+/// Common keys get their usual symbols, so `"enter"` becomes `"↵"` and
+/// `"shift-tab"` becomes `"⇧⇥"`.
+pub fn key_badge_text(keystroke: &str) -> String {
+    // The key itself may be "-", so split the modifiers off its front
+    let (modifiers, key) = match keystroke.rsplit_once('-') {
+        Some((modifiers, "")) => (modifiers.strip_suffix('-').unwrap_or(modifiers), "-"),
+        Some((modifiers, key)) => (modifiers, key),
+        None => ("", keystroke),
+    };
+
+    let mut text = String::new();
+    for modifier in modifiers.split('-').filter(|m| !m.is_empty()) {
+        text.push_str(match modifier {
+            "ctrl" => "Ctrl+",
+            "alt" => "Alt+",
+            "shift" => "⇧",
+            "cmd" | "super" | "win" => "Super+",
+            "fn" => "Fn+",
+            other => other,
+        });
+    }
+
+    match key {
+        "enter" => text.push('↵'),
+        "tab" => text.push('⇥'),
+        "backspace" => text.push('⌫'),
+        "escape" => text.push_str("Esc"),
+        "space" => text.push_str("Space"),
+        "up" => text.push('↑'),
+        "down" => text.push('↓'),
+        "left" => text.push('←'),
+        "right" => text.push('→'),
+        key if key.chars().count() == 1 => text.push_str(&key.to_uppercase()),
+        key => {
+            let mut chars = key.chars();
+            if let Some(first) = chars.next() {
+                text.extend(first.to_uppercase());
+                text.push_str(chars.as_str());
+            }
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_keys() {
+        assert_eq!(key_badge_text("enter"), "↵");
+        assert_eq!(key_badge_text("escape"), "Esc");
+        assert_eq!(key_badge_text("o"), "O");
+        assert_eq!(key_badge_text("f5"), "F5");
+    }
+
+    #[test]
+    fn test_modifiers() {
+        assert_eq!(key_badge_text("shift-enter"), "⇧↵");
+        assert_eq!(key_badge_text("ctrl-shift-c"), "Ctrl+⇧C");
+        assert_eq!(key_badge_text("ctrl--"), "Ctrl+-");
+        assert_eq!(key_badge_text("-"), "-");
+    }
+}
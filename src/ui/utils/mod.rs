@@ -1,8 +1,16 @@
 pub mod color;
 mod file_type;
+mod key_label;
+mod svg_tint;
+mod time;
+mod truncate;
 
 pub use color::{Color, parse_color};
 pub use file_type::{
     FileType, classify_file, is_image_ext, is_text_ext, should_preview_as_image,
     should_preview_as_text,
 };
+pub use key_label::key_badge_text;
+pub use svg_tint::icon_file_image;
+pub use time::format_local_time;
+pub use truncate::{end_ellipsis, fit_text, middle_ellipsis};
@@ -0,0 +1,166 @@
+//! Tinting for monochrome SVG icons.
+//!
+//! Symbolic icons draw with `currentColor`, which has no inherited color when
+//! rendered as an image and ends up black, i.e. invisible on dark themes.
+//! Such icons are rewritten to use the theme's foreground color; multicolor
+//! icons are left alone.
+
+use super::color::Color;
+use gpui::{Hsla, ImageFormat, ImageSource, Rgba};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+const CURRENT_COLOR: &str = "currentcolor";
+
+lazy_static::lazy_static! {
+    /// Sources of icons using `currentColor` by path; `None` for icons that
+    /// need no tint or can't be read.
+    static ref SOURCE_CACHE: Mutex<HashMap<PathBuf, Option<Arc<str>>>> =
+        Mutex::new(HashMap::new());
+    /// Tinted icons by path and color; `None` for icons that need no tint.
+    static ref TINT_CACHE: Mutex<HashMap<(PathBuf, String), Option<Arc<gpui::Image>>>> =
+        Mutex::new(HashMap::new());
+}
+
+/// Replace `currentColor` in SVG source with a fixed color.
+///
+/// The keyword is matched case-insensitively, like CSS does. Returns `None`
+/// if the SVG doesn't use `currentColor`.
+pub fn tint_current_color(svg: &str, color: Color) -> Option<String> {
+    let lower = svg.to_ascii_lowercase();
+    if !lower.contains(CURRENT_COLOR) {
+        return None;
+    }
+
+    let hex = color.to_hex();
+    let mut tinted = String::with_capacity(svg.len());
+    let mut rest = 0;
+    // ASCII lowercasing keeps byte offsets, so matches index `svg` directly
+    for (start, _) in lower.match_indices(CURRENT_COLOR) {
+        tinted.push_str(&svg[rest..start]);
+        tinted.push_str(&hex);
+        rest = start + CURRENT_COLOR.len();
+    }
+    tinted.push_str(&svg[rest..]);
+    Some(tinted)
+}
+
+/// Load an SVG icon tinted with `color`, if it uses `currentColor`.
+///
+/// Returns `None` for unreadable files and SVGs that should be rendered
+/// as-is. Results are cached per path and color.
+pub fn tinted_svg(path: &Path, color: Hsla) -> Option<Arc<gpui::Image>> {
+    let color = opaque_color(color);
+    let key = (path.to_path_buf(), color.to_hex());
+
+    let mut cache = TINT_CACHE.lock().unwrap();
+    cache
+        .entry(key)
+        .or_insert_with(|| {
+            let source = tintable_source(path)?;
+            let tinted = tint_current_color(&source, color)?;
+            Some(Arc::new(gpui::Image::from_bytes(
+                ImageFormat::Svg,
+                tinted.into_bytes(),
+            )))
+        })
+        .clone()
+}
+
+/// Get the image to render for an icon file, or `None` if it isn't an
+/// image format that can be shown.
+///
+/// Monochrome SVGs take `color` instead of rendering black; other files
+/// are loaded as-is.
+pub fn icon_file_image(path: &Path, color: Hsla) -> Option<ImageSource> {
+    let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    if ext == "svg"
+        && let Some(image) = tinted_svg(path, color)
+    {
+        return Some(image.into());
+    }
+    matches!(ext, "png" | "jpg" | "jpeg" | "svg").then(|| path.to_path_buf().into())
+}
+
+/// Read an SVG icon's source if it uses `currentColor`, once per path.
+fn tintable_source(path: &Path) -> Option<Arc<str>> {
+    let mut cache = SOURCE_CACHE.lock().unwrap();
+    cache
+        .entry(path.to_path_buf())
+        .or_insert_with(|| {
+            let source = std::fs::read_to_string(path).ok()?;
+            source
+                .to_ascii_lowercase()
+                .contains(CURRENT_COLOR)
+                .then(|| source.into())
+        })
+        .clone()
+}
+
+/// Convert a theme color to an opaque RGB color.
+fn opaque_color(color: Hsla) -> Color {
+    let rgba = Rgba::from(color);
+    let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    Color::from_rgb(channel(rgba.r), channel(rgba.g), channel(rgba.b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A symbolic icon drawn with `currentColor`.
+    const SYMBOLIC_ICON: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 16 16"><path fill="currentColor" d="M2 2h12v12H2z"/><circle stroke="currentColor" cx="8" cy="8" r="3"/></svg>"#;
+
+    /// A multicolor icon with fixed fills.
+    const COLORED_ICON: &str = r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 16 16"><path fill="#e66100" d="M2 2h12v12H2z"/></svg>"##;
+
+    #[test]
+    fn test_current_color_is_tinted() {
+        let tinted = tint_current_color(SYMBOLIC_ICON, Color::from_rgb(255, 255, 255)).unwrap();
+        assert!(!tinted.contains("currentColor"));
+        assert_eq!(tinted.matches("#FFFFFF").count(), 2);
+        assert!(tinted.ends_with("r=\"3\"/></svg>"));
+    }
+
+    #[test]
+    fn test_current_color_matched_case_insensitively() {
+        let svg = r#"<svg style="color: red"><path style="fill: CurrentColor"/></svg>"#;
+        let tinted = tint_current_color(svg, Color::from_rgb(0x20, 0x40, 0x60)).unwrap();
+        assert_eq!(
+            tinted,
+            r#"<svg style="color: red"><path style="fill: #204060"/></svg>"#
+        );
+    }
+
+    #[test]
+    fn test_multicolor_icon_left_alone() {
+        assert!(tint_current_color(COLORED_ICON, Color::from_rgb(255, 255, 255)).is_none());
+    }
+
+    #[test]
+    fn test_tinted_svg_from_file() {
+        let dir = std::env::temp_dir().join(format!("zlaunch-svg-tint-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let symbolic = dir.join("symbolic.svg");
+        let colored = dir.join("colored.svg");
+        std::fs::write(&symbolic, SYMBOLIC_ICON).unwrap();
+        std::fs::write(&colored, COLORED_ICON).unwrap();
+
+        let white = gpui::hsla(0.0, 0.0, 1.0, 0.9);
+        let image = tinted_svg(&symbolic, white).unwrap();
+        assert_eq!(image.format(), ImageFormat::Svg);
+        assert!(String::from_utf8_lossy(image.bytes()).contains("#FFFFFF"));
+        assert!(tinted_svg(&colored, white).is_none());
+        assert!(tinted_svg(&dir.join("missing.svg"), white).is_none());
+
+        assert!(matches!(
+            icon_file_image(&symbolic, white),
+            Some(ImageSource::Image(_))
+        ));
+        assert!(icon_file_image(&colored, white).is_some());
+        assert!(icon_file_image(&dir.join("icon.xpm"), white).is_none());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
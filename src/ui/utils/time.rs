@@ -0,0 +1,62 @@
+//! Local time formatting.
+
+use std::ffi::CString;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Format `time` in the local timezone using a strftime pattern.
+///
+/// Returns `None` if the time predates the epoch, the pattern contains a
+/// NUL byte, or the result does not fit the output buffer.
+pub fn format_local_time(time: SystemTime, pattern: &str) -> Option<String> {
+    let secs = time.duration_since(UNIX_EPOCH).ok()?.as_secs() as libc::time_t;
+    let pattern = CString::new(pattern).ok()?;
+
+    // SAFETY: `tm` is plain data that localtime_r fully initializes on
+    // success, and strftime writes at most `buf.len()` bytes into `buf`.
+    unsafe {
+        let mut tm: libc::tm = std::mem::zeroed();
+        if libc::localtime_r(&secs, &mut tm).is_null() {
+            return None;
+        }
+
+        let mut buf = [0u8; 256];
+        let len = libc::strftime(
+            buf.as_mut_ptr().cast::<libc::c_char>(),
+            buf.len(),
+            pattern.as_ptr(),
+            &tm,
+        );
+        if len == 0 {
+            return None;
+        }
+
+        Some(String::from_utf8_lossy(&buf[..len]).into_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_literal_pattern() {
+        assert_eq!(
+            format_local_time(SystemTime::now(), "copied").as_deref(),
+            Some("copied")
+        );
+    }
+
+    #[test]
+    fn test_year_pattern() {
+        // Mid-year, so the local timezone cannot shift the year
+        let time = UNIX_EPOCH + Duration::from_secs(1_750_000_000);
+        assert_eq!(format_local_time(time, "%Y").as_deref(), Some("2025"));
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        assert!(format_local_time(SystemTime::now(), "bad\0pattern").is_none());
+        assert!(format_local_time(SystemTime::now(), "").is_none());
+    }
+}
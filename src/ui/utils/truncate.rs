@@ -0,0 +1,232 @@
+use std::borrow::Cow;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Shorten `text` with `shorten` to the largest budget whose result `fits`.
+///
+/// `shorten` cuts text to a budget, like [`middle_ellipsis`] or
+/// [`end_ellipsis`]; `fits` measures a candidate against the space there
+/// is. Budgets are bisected, so only a few candidates get measured. Text
+/// that already fits is returned unchanged.
+pub fn fit_text<'a>(
+    text: &'a str,
+    shorten: impl Fn(&'a str, usize) -> Cow<'a, str>,
+    fits: impl Fn(&str) -> bool,
+) -> Cow<'a, str> {
+    if fits(text) {
+        return Cow::Borrowed(text);
+    }
+
+    // Budgets of end_ellipsis are columns, up to two per grapheme
+    let mut low = 0;
+    let mut high = text.graphemes(true).count() * 2;
+    while low < high {
+        let mid = (low + high).div_ceil(2);
+        if fits(&shorten(text, mid)) {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    shorten(text, low)
+}
+
+/// Shorten `text` to at most `max_chars` characters by replacing its middle
+/// with an ellipsis, keeping both the start and the (often distinguishing)
+/// end visible.
+///
+/// Cuts fall on grapheme boundaries, so combining marks and emoji sequences
+/// stay intact. Text that already fits is returned unchanged.
+pub fn middle_ellipsis(text: &str, max_chars: usize) -> Cow<'_, str> {
+    let graphemes: Vec<&str> = text.graphemes(true).collect();
+    let len = graphemes.len();
+    if len <= max_chars {
+        return Cow::Borrowed(text);
+    }
+    if max_chars == 0 {
+        return Cow::Borrowed("");
+    }
+
+    // One character is taken by the ellipsis; favour the start on odd splits
+    let keep = max_chars - 1;
+    let head = keep.div_ceil(2);
+    let tail = keep - head;
+
+    let start = graphemes[..head].concat();
+    let end = graphemes[len - tail..].concat();
+    Cow::Owned(format!("{}…{}", start.trim_end(), end.trim_start()))
+}
+
+/// Shorten `text` to fit `max_columns` by cutting its end and appending an
+/// ellipsis.
+///
+/// Cuts fall on grapheme boundaries. Wide graphemes (CJK ideographs, emoji)
+/// take two columns, so a budget found for narrow text isn't overrun by
+/// double-width scripts. Text that already fits is returned
+/// unchanged.
+pub fn end_ellipsis(text: &str, max_columns: usize) -> Cow<'_, str> {
+    let total: usize = text.graphemes(true).map(grapheme_columns).sum();
+    if total <= max_columns {
+        return Cow::Borrowed(text);
+    }
+    if max_columns == 0 {
+        return Cow::Borrowed("");
+    }
+
+    // One column is taken by the ellipsis
+    let mut used = 0;
+    let mut end = 0;
+    for (offset, grapheme) in text.grapheme_indices(true) {
+        let columns = grapheme_columns(grapheme);
+        if used + columns > max_columns - 1 {
+            break;
+        }
+        used += columns;
+        end = offset + grapheme.len();
+    }
+
+    Cow::Owned(format!("{}…", text[..end].trim_end()))
+}
+
+/// Estimate how many columns a grapheme occupies.
+fn grapheme_columns(grapheme: &str) -> usize {
+    let mut chars = grapheme.chars();
+    let Some(first) = chars.next() else {
+        return 0;
+    };
+    // Emoji presentation selectors and ZWJ sequences render as one wide glyph
+    let emoji_sequence = chars.any(|c| c == '\u{FE0F}' || c == '\u{200D}');
+    if emoji_sequence || is_wide_char(first) {
+        2
+    } else {
+        1
+    }
+}
+
+/// Check whether a character is rendered double-width (East Asian wide or
+/// emoji).
+fn is_wide_char(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x1100..=0x115F
+            | 0x2E80..=0x303E
+            | 0x3041..=0x33FF
+            | 0x3400..=0x4DBF
+            | 0x4E00..=0x9FFF
+            | 0xA000..=0xA4CF
+            | 0xAC00..=0xD7A3
+            | 0xF900..=0xFAFF
+            | 0xFE30..=0xFE4F
+            | 0xFF00..=0xFF60
+            | 0xFFE0..=0xFFE6
+            | 0x1F300..=0x1F64F
+            | 0x1F680..=0x1F6FF
+            | 0x1F900..=0x1FAFF
+            | 0x20000..=0x3FFFD
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_middle_ellipsis_short_text_unchanged() {
+        assert_eq!(middle_ellipsis("Firefox", 20), "Firefox");
+        assert_eq!(middle_ellipsis("Firefox", 7), "Firefox");
+        assert_eq!(middle_ellipsis("", 5), "");
+    }
+
+    #[test]
+    fn test_middle_ellipsis_keeps_both_ends() {
+        let title = "Pull requests · zortax/zlaunch — Mozilla Firefox";
+        let shortened = middle_ellipsis(title, 35);
+        assert_eq!(shortened.chars().count(), 35);
+        assert!(shortened.starts_with("Pull requests"));
+        assert!(shortened.ends_with("Mozilla Firefox"));
+        assert!(shortened.contains('…'));
+    }
+
+    #[test]
+    fn test_middle_ellipsis_odd_and_even_budgets() {
+        assert_eq!(middle_ellipsis("abcdefghij", 5), "ab…ij");
+        assert_eq!(middle_ellipsis("abcdefghij", 6), "abc…ij");
+    }
+
+    #[test]
+    fn test_middle_ellipsis_tiny_budgets() {
+        assert_eq!(middle_ellipsis("abcdef", 1), "…");
+        assert_eq!(middle_ellipsis("abcdef", 0), "");
+    }
+
+    #[test]
+    fn test_middle_ellipsis_multibyte() {
+        assert_eq!(middle_ellipsis("日本語のウィンドウ", 5), "日本…ドウ");
+    }
+
+    #[test]
+    fn test_fit_text_uses_largest_fitting_budget() {
+        // Pretend every character is 10px wide and 100px are available
+        let fits = |text: &str| text.chars().count() * 10 <= 100;
+        assert_eq!(fit_text("Firefox", middle_ellipsis, fits), "Firefox");
+        assert_eq!(
+            fit_text("abcdefghijklmnopqrst", middle_ellipsis, fits),
+            "abcde…qrst"
+        );
+        assert_eq!(
+            fit_text("Browse the World Wide Web", end_ellipsis, fits),
+            "Browse th…"
+        );
+        // Nothing fits: the text is dropped entirely
+        assert_eq!(fit_text("abc", middle_ellipsis, |t: &str| t.is_empty()), "");
+    }
+
+    #[test]
+    fn test_end_ellipsis_short_text_unchanged() {
+        assert_eq!(end_ellipsis("Web Browser", 20), "Web Browser");
+        assert_eq!(end_ellipsis("", 0), "");
+    }
+
+    #[test]
+    fn test_end_ellipsis_ascii() {
+        assert_eq!(end_ellipsis("Browse the World Wide Web", 11), "Browse the…");
+        assert_eq!(end_ellipsis("abcdef", 1), "…");
+        assert_eq!(end_ellipsis("abcdef", 0), "");
+    }
+
+    #[test]
+    fn test_end_ellipsis_cjk_counts_double_width() {
+        // Each ideograph takes two columns
+        assert_eq!(end_ellipsis("ウェブブラウザ", 9), "ウェブブ…");
+        assert_eq!(end_ellipsis("网页浏览器", 10), "网页浏览器");
+        assert_eq!(end_ellipsis("网页浏览器", 6), "网页…");
+    }
+
+    #[test]
+    fn test_end_ellipsis_keeps_emoji_sequences_whole() {
+        // Family emoji is a single ZWJ grapheme of several code points
+        let text = "Chat 👨‍👩‍👧 with friends";
+        for budget in 0..text.len() {
+            let shortened = end_ellipsis(text, budget);
+            let kept = shortened.trim_end_matches('…');
+            assert!(text.starts_with(kept));
+            assert!(!kept.ends_with('\u{200D}'));
+            assert!(!kept.contains('👨') || kept.contains("👨‍👩‍👧"));
+        }
+    }
+
+    #[test]
+    fn test_end_ellipsis_keeps_combining_marks() {
+        // "e" followed by a combining acute accent is one grapheme
+        let text = "Cafe\u{301} au lait";
+        assert_eq!(end_ellipsis(text, 5), "Cafe\u{301}…");
+        assert_eq!(end_ellipsis(text, 4), "Caf…");
+    }
+
+    #[test]
+    fn test_middle_ellipsis_keeps_graphemes() {
+        let text = "🇩🇪 Deutsch — Übersetzung 🇫🇷";
+        let shortened = middle_ellipsis(text, 9);
+        assert!(shortened.starts_with("🇩🇪"));
+        assert!(shortened.ends_with("🇫🇷"));
+    }
+}
@@ -1,10 +1,53 @@
 //! AI response view for displaying streaming responses.
 
+use crate::config::config;
 use crate::ui::markdown::render_markdown_with_id;
 use crate::ui::theme::theme;
-use gpui::{App, Div, ElementId, SharedString, Window, div, prelude::*};
+use gpui::{App, Div, ElementId, ScrollHandle, SharedString, Window, div, point, prelude::*, px};
 use gpui_component::scroll::ScrollableElement;
 use llm::chat::ChatMessage;
+use std::cell::Cell;
+
+/// Distance from the bottom (in pixels) that still counts as "at the bottom".
+const FOLLOW_THRESHOLD: f32 = 24.0;
+
+/// Part of the previous page (in pixels) kept in view when paging.
+const PAGE_OVERLAP: f32 = 40.0;
+
+/// Decides whether the response keeps scrolling to the newest text.
+///
+/// Following stops once the user scrolls up and resumes when they scroll
+/// back to the bottom.
+#[derive(Clone, Copy, Debug)]
+struct ScrollFollow {
+    following: bool,
+    /// Offset the view was at after the previous update.
+    last_offset: f32,
+}
+
+impl Default for ScrollFollow {
+    fn default() -> Self {
+        Self {
+            following: true,
+            last_offset: 0.0,
+        }
+    }
+}
+
+impl ScrollFollow {
+    /// Update with the current scroll offset (distance scrolled down) and
+    /// the maximum offset. Returns whether to scroll to the bottom.
+    fn update(&mut self, offset: f32, max_offset: f32) -> bool {
+        if offset >= max_offset - FOLLOW_THRESHOLD {
+            self.following = true;
+        } else if offset < self.last_offset - FOLLOW_THRESHOLD {
+            self.following = false;
+        }
+
+        self.last_offset = if self.following { max_offset } else { offset };
+        self.following
+    }
+}
 
 /// View for displaying AI response with streaming support.
 #[derive(Clone)]
@@ -15,6 +58,10 @@ pub struct AiResponseView {
     is_streaming: bool,
     /// Error message if the request failed
     error: Option<String>,
+    /// Scroll state of the message list
+    scroll_handle: ScrollHandle,
+    /// Whether the message list follows streamed text
+    follow: Cell<ScrollFollow>,
 }
 
 impl AiResponseView {
@@ -27,6 +74,8 @@ impl AiResponseView {
             ],
             is_streaming: true,
             error: None,
+            scroll_handle: ScrollHandle::new(),
+            follow: Cell::new(ScrollFollow::default()),
         }
     }
 
@@ -40,14 +89,32 @@ impl AiResponseView {
         self.is_streaming = false;
     }
 
-    /// Add a new user message.
+    /// Add a new user message, awaiting its answer.
+    ///
+    /// Sending a message clears an earlier error and scrolls back to the
+    /// bottom to follow the answer.
     pub fn add_user_message(&mut self, message: String) {
+        self.follow.set(ScrollFollow::default());
+        self.error = None;
+        self.is_streaming = true;
         self.messages
             .push(ChatMessage::user().content(message).build());
         self.messages
             .push(ChatMessage::assistant().content("").build());
     }
 
+    /// Scroll by a page of the visible height, down or up.
+    ///
+    /// Paging up stops following streamed text until the bottom is reached
+    /// again.
+    pub fn scroll_page(&self, down: bool) {
+        let offset = -f32::from(self.scroll_handle.offset().y);
+        let max_offset = f32::from(self.scroll_handle.max_offset().height);
+        let page = f32::from(self.scroll_handle.bounds().size.height);
+        let target = page_offset(offset, max_offset, page, down);
+        self.scroll_handle.set_offset(point(px(0.0), px(-target)));
+    }
+
     /// Set an error message.
     pub fn set_error(&mut self, error: String) {
         self.error = Some(error);
@@ -77,6 +144,10 @@ impl AiResponseView {
 
         // Show response or error
         if let Some(error) = &self.error {
+            // A failed follow-up keeps the earlier turns in view above it
+            if self.messages.len() > 2 {
+                container = container.child(self.render_chat_messages(window, cx, &t));
+            }
             container = container.child(
                 div()
                     .id("ai-error-scroll")
@@ -145,12 +216,28 @@ impl AiResponseView {
             }
         }
 
-        div()
+        // Keep the newest text in view unless the user scrolled up
+        let offset = -f32::from(self.scroll_handle.offset().y);
+        let max_offset = f32::from(self.scroll_handle.max_offset().height);
+        let mut follow = self.follow.get();
+        if follow.update(offset, max_offset) {
+            self.scroll_handle.scroll_to_bottom();
+        }
+        self.follow.set(follow);
+
+        let scroll_area = div()
             .id("ai-response-scroll")
-            .flex_1()
             .w_full()
-            .overflow_y_scrollbar()
-            .child(messages_container)
+            .overflow_y_scroll()
+            .track_scroll(&self.scroll_handle)
+            .vertical_scrollbar(&self.scroll_handle)
+            .child(messages_container);
+
+        // Either grow with the answer up to a maximum, or fill the panel
+        match config().ai_response_max_height {
+            Some(max_height) => scroll_area.flex_shrink().max_h(px(max_height)),
+            None => scroll_area.flex_1(),
+        }
     }
 
     /// Render a user message as a right-aligned bubble.
@@ -226,3 +313,52 @@ impl AiResponseView {
         }
     }
 }
+
+/// Get the offset one page down or up from `offset`, within the content.
+fn page_offset(offset: f32, max_offset: f32, page: f32, down: bool) -> f32 {
+    let step = (page - PAGE_OVERLAP).max(PAGE_OVERLAP);
+    let target = if down { offset + step } else { offset - step };
+    target.clamp(0.0, max_offset.max(0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_follows_growing_content() {
+        let mut follow = ScrollFollow::default();
+        assert!(follow.update(0.0, 0.0));
+        // Content grew while we were at the previous bottom
+        assert!(follow.update(0.0, 100.0));
+        assert!(follow.update(100.0, 250.0));
+    }
+
+    #[test]
+    fn test_stops_following_when_scrolled_up() {
+        let mut follow = ScrollFollow::default();
+        follow.update(0.0, 300.0);
+        assert!(!follow.update(120.0, 320.0));
+        // More text arrives while the user reads further up
+        assert!(!follow.update(120.0, 400.0));
+    }
+
+    #[test]
+    fn test_resumes_following_at_bottom() {
+        let mut follow = ScrollFollow::default();
+        follow.update(0.0, 300.0);
+        follow.update(100.0, 300.0);
+        assert!(follow.update(290.0, 300.0));
+        assert!(follow.update(300.0, 380.0));
+    }
+
+    #[test]
+    fn test_page_offset_clamps_to_content() {
+        assert_eq!(page_offset(0.0, 1000.0, 300.0, true), 260.0);
+        assert_eq!(page_offset(260.0, 1000.0, 300.0, false), 0.0);
+        assert_eq!(page_offset(900.0, 1000.0, 300.0, true), 1000.0);
+        assert_eq!(page_offset(100.0, 1000.0, 300.0, false), 0.0);
+        // Nothing to scroll when the content fits
+        assert_eq!(page_offset(0.0, 0.0, 300.0, true), 0.0);
+    }
+}
@@ -1,14 +1,20 @@
 //! Rendering functions for clipboard history view.
 
 use crate::assets::PhosphorIcon;
-use crate::clipboard::{ClipboardContent, ClipboardItem};
+use crate::clipboard::{ClipboardContent, ClipboardItem, MASKED_CONTENT, parse_file_url};
+use crate::config::{TimestampFormat, config};
+use crate::ui::launcher::SECONDARY_CONFIRM_KEY;
 use crate::ui::theme::theme;
 use crate::ui::utils::color::{Color, parse_color};
+use crate::ui::utils::format_local_time;
+use crate::ui::views::render_key_hint;
 use gpui::{Div, ElementId, SharedString, Stateful, div, img, prelude::*, px, svg};
 use std::fs;
-use std::path::PathBuf;
 use std::time::SystemTime;
 
+/// Maximum number of bytes of text shown in the preview panel.
+const MAX_PREVIEW_BYTES: usize = 10000;
+
 /// Render a clipboard item in the list.
 pub fn render_clipboard_item(item: &ClipboardItem, selected: bool, row: usize) -> Stateful<Div> {
     let t = theme();
@@ -20,7 +26,13 @@ pub fn render_clipboard_item(item: &ClipboardItem, selected: bool, row: usize) -
     };
 
     // Format timestamp
-    let timestamp_str = format_timestamp(&item.timestamp);
+    let app_config = config();
+    let timestamp_str = format_timestamp(
+        &item.timestamp,
+        SystemTime::now(),
+        app_config.clipboard_timestamp_format,
+        app_config.clipboard_timestamp_pattern(),
+    );
 
     // Get preview text
     let preview = get_item_preview(item);
@@ -83,6 +95,11 @@ fn get_item_preview(item: &ClipboardItem) -> String {
 fn render_item_icon(item: &ClipboardItem) -> Div {
     let t = theme();
 
+    // The content of a secret mustn't leak through its icon either
+    if item.sensitive {
+        return render_icon_container(PhosphorIcon::Lock);
+    }
+
     // Check if this is text content
     if let ClipboardContent::Text(text) = &item.content {
         // Check if it's a color
@@ -218,24 +235,48 @@ fn render_icon_container(icon: PhosphorIcon) -> Div {
         )
 }
 
-/// Format a SystemTime as a relative or absolute timestamp.
-fn format_timestamp(time: &SystemTime) -> String {
-    let now = SystemTime::now();
-    if let Ok(duration) = now.duration_since(*time) {
-        let secs = duration.as_secs();
-        if secs < 60 {
-            return "Just now".to_string();
-        } else if secs < 3600 {
-            let mins = secs / 60;
-            return format!("{} min{} ago", mins, if mins > 1 { "s" } else { "" });
-        } else if secs < 86400 {
-            let hours = secs / 3600;
-            return format!("{} hour{} ago", hours, if hours > 1 { "s" } else { "" });
-        }
+/// Format a SystemTime as a relative and/or absolute timestamp.
+///
+/// Relative times are only used within a day; older entries always show
+/// the date formatted with `pattern`.
+fn format_timestamp(
+    time: &SystemTime,
+    now: SystemTime,
+    format: TimestampFormat,
+    pattern: &str,
+) -> String {
+    let relative = format_relative(time, now);
+    let absolute = || format_local_time(*time, pattern).unwrap_or_else(|| "Earlier".to_string());
+
+    match (format, relative) {
+        (TimestampFormat::Relative, Some(relative)) => relative,
+        (TimestampFormat::Both, Some(relative)) => format!("{} · {}", relative, absolute()),
+        _ => absolute(),
     }
+}
 
-    // Fall back to a simple format
-    "Earlier".to_string()
+/// Format the time since `time` if it was less than a day ago.
+fn format_relative(time: &SystemTime, now: SystemTime) -> Option<String> {
+    let secs = now.duration_since(*time).ok()?.as_secs();
+    if secs < 60 {
+        Some("Just now".to_string())
+    } else if secs < 3600 {
+        let mins = secs / 60;
+        Some(format!(
+            "{} min{} ago",
+            mins,
+            if mins > 1 { "s" } else { "" }
+        ))
+    } else if secs < 86400 {
+        let hours = secs / 3600;
+        Some(format!(
+            "{} hour{} ago",
+            hours,
+            if hours > 1 { "s" } else { "" }
+        ))
+    } else {
+        None
+    }
 }
 
 /// Render the preview panel for the selected clipboard item.
@@ -261,6 +302,10 @@ pub fn render_preview_panel(item: Option<&ClipboardItem>) -> Div {
         );
     };
 
+    if item.sensitive {
+        return render_text_preview(panel, MASKED_CONTENT);
+    }
+
     match &item.content {
         ClipboardContent::Text(text) => {
             // Check if this is a color string
@@ -302,44 +347,32 @@ pub fn render_preview_panel(item: Option<&ClipboardItem>) -> Div {
                     ) {
                         // Try to read and display file content
                         if let Ok(content) = fs::read_to_string(&path) {
-                            let preview_content = if content.len() > 10000 {
-                                format!(
-                                    "{}...\n\n[Content truncated - {} bytes total]",
-                                    &content[..10000],
-                                    content.len()
-                                )
-                            } else {
-                                content
-                            };
-
-                            return panel.items_start().child(
-                                div()
-                                    .w_full()
-                                    .text_sm()
-                                    .text_color(t.item_title_color)
-                                    .child(SharedString::from(preview_content)),
-                            );
+                            return render_text_preview(panel, &content);
                         }
                     }
                 }
             }
 
-            // Show full text with wrapping
-            panel.items_start().child(
-                div()
-                    .w_full()
-                    .text_sm()
-                    .text_color(t.item_title_color)
-                    .child(SharedString::from(text.clone())),
-            )
+            render_text_preview(panel, text)
         }
         ClipboardContent::Image {
             width,
             height,
             rgba_bytes,
+            gif_bytes,
         } => {
-            // Try to render the image
-            render_image_preview_full(panel, *width, *height, rgba_bytes)
+            // Play animations from the original GIF, static images from RGBA
+            if let Some(gif_bytes) = gif_bytes
+                && let Some(image) = super::png_cache::animated_image(item.hash, gif_bytes)
+            {
+                return panel.child(
+                    img(image)
+                        .w_full()
+                        .h_full()
+                        .object_fit(gpui::ObjectFit::Contain),
+                );
+            }
+            render_image_preview_full(panel, item.hash, *width, *height, rgba_bytes)
         }
         ClipboardContent::FilePaths(paths) => {
             if paths.len() == 1 {
@@ -377,24 +410,7 @@ pub fn render_preview_panel(item: Option<&ClipboardItem>) -> Div {
                     ) {
                         // Try to read and display file content
                         if let Ok(content) = fs::read_to_string(path) {
-                            // Limit content size to prevent performance issues
-                            let preview_content = if content.len() > 10000 {
-                                format!(
-                                    "{}...\n\n[Content truncated - {} bytes total]",
-                                    &content[..10000],
-                                    content.len()
-                                )
-                            } else {
-                                content
-                            };
-
-                            return panel.items_start().child(
-                                div()
-                                    .w_full()
-                                    .text_sm()
-                                    .text_color(t.item_title_color)
-                                    .child(SharedString::from(preview_content)),
-                            );
+                            return render_text_preview(panel, &content);
                         }
                     }
                 }
@@ -425,44 +441,79 @@ pub fn render_preview_panel(item: Option<&ClipboardItem>) -> Div {
         }
         ClipboardContent::RichText { plain, .. } => {
             // Show plain text version
-            panel.items_start().child(
-                div()
-                    .w_full()
-                    .text_sm()
-                    .text_color(t.item_title_color)
-                    .child(SharedString::from(plain.clone())),
-            )
+            render_text_preview(panel, plain)
         }
     }
 }
 
+/// Render text in the preview panel, wrapped and capped in length.
+fn render_text_preview(panel: Div, text: &str) -> Div {
+    let t = theme();
+    panel.items_start().child(
+        div()
+            .w_full()
+            .min_w_0()
+            .whitespace_normal()
+            .overflow_hidden()
+            .text_sm()
+            .text_color(t.item_title_color)
+            .child(SharedString::from(preview_text(text))),
+    )
+}
+
+/// Cap text shown in the preview panel, noting the full size when cut.
+///
+/// Laying out huge texts is slow, especially single lines without any break
+/// opportunities such as minified JSON or base64 blobs.
+fn preview_text(text: &str) -> String {
+    if text.len() <= MAX_PREVIEW_BYTES {
+        return text.to_string();
+    }
+
+    let mut end = MAX_PREVIEW_BYTES;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!(
+        "{}...\n\n[Content truncated - {} bytes total]",
+        &text[..end],
+        text.len()
+    )
+}
+
+/// Render the secondary action hint for items that can be opened.
+///
+/// Returns `None` for items without a secondary action.
+pub fn render_secondary_action_hint(item: &ClipboardItem) -> Option<Div> {
+    let t = theme();
+    item.open_target().map(|_| {
+        render_key_hint("Open", SECONDARY_CONFIRM_KEY)
+            .absolute()
+            .right(t.clipboard.preview_padding)
+            .bottom(t.clipboard.preview_padding)
+    })
+}
+
 /// Render an image from raw RGBA bytes in the preview panel.
-fn render_image_preview_full(panel: Div, width: usize, height: usize, rgba_bytes: &[u8]) -> Div {
-    use image::{ImageBuffer, ImageFormat, Rgba};
-    use std::io::Cursor;
-    use std::sync::Arc;
+///
+/// `key` is the clipboard item's hash, identifying the image in the cache.
+fn render_image_preview_full(
+    panel: Div,
+    key: u64,
+    width: usize,
+    height: usize,
+    rgba_bytes: &[u8],
+) -> Div {
     let t = theme();
 
-    // Create ImageBuffer from raw RGBA pixel data
-    if let Some(img_buffer) =
-        ImageBuffer::<Rgba<u8>, _>::from_raw(width as u32, height as u32, rgba_bytes.to_vec())
-    {
-        // Encode image as PNG in memory
-        let mut png_bytes = Vec::new();
-        let mut cursor = Cursor::new(&mut png_bytes);
-
-        if img_buffer.write_to(&mut cursor, ImageFormat::Png).is_ok() {
-            // Create GPUI Image from PNG bytes and wrap in Arc
-            let gpui_image = Arc::new(gpui::Image::from_bytes(gpui::ImageFormat::Png, png_bytes));
-
-            // Use the in-memory image directly
-            return panel.child(
-                img(gpui_image)
-                    .w_full()
-                    .h_full()
-                    .object_fit(gpui::ObjectFit::Contain),
-            );
-        }
+    // Encoded once per image and reused while navigating
+    if let Some(gpui_image) = super::png_cache::preview_image(key, width, height, rgba_bytes) {
+        return panel.child(
+            img(gpui_image)
+                .w_full()
+                .h_full()
+                .object_fit(gpui::ObjectFit::Contain),
+        );
     }
 
     // Fallback: show error message
@@ -565,21 +616,87 @@ fn render_color_preview(panel: Div, color: &Color) -> Div {
         )
 }
 
-/// Parse a file:// URL and return the path.
-fn parse_file_url(text: &str) -> Option<PathBuf> {
-    let text = text.trim();
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DEFAULT_TIMESTAMP_PATTERN;
+    use std::time::Duration;
+
+    fn relative(now: SystemTime, ago_secs: u64) -> String {
+        let time = now - Duration::from_secs(ago_secs);
+        format_timestamp(
+            &time,
+            now,
+            TimestampFormat::Relative,
+            DEFAULT_TIMESTAMP_PATTERN,
+        )
+    }
 
-    // Handle file:// URLs
-    if let Some(path_str) = text.strip_prefix("file://") {
-        // Remove the file:// prefix
-        // URL decode the path (handle %20 for spaces, etc.)
-        if let Ok(decoded) = urlencoding::decode(path_str) {
-            return Some(PathBuf::from(decoded.as_ref()));
-        }
+    fn date_of(now: SystemTime, ago_secs: u64) -> String {
+        let time = now - Duration::from_secs(ago_secs);
+        format_local_time(time, DEFAULT_TIMESTAMP_PATTERN).unwrap()
+    }
+
+    #[test]
+    fn test_relative_boundaries() {
+        let now = SystemTime::now();
+        assert_eq!(relative(now, 59), "Just now");
+        assert_eq!(relative(now, 61), "1 min ago");
+        assert_eq!(relative(now, 3 * 3600), "3 hours ago");
+    }
 
-        // Fallback: use the path as-is
-        return Some(PathBuf::from(path_str));
+    #[test]
+    fn test_older_than_a_day_shows_date() {
+        let now = SystemTime::now();
+        assert_eq!(relative(now, 25 * 3600), date_of(now, 25 * 3600));
+        assert_eq!(relative(now, 2 * 86400), date_of(now, 2 * 86400));
     }
 
-    None
+    #[test]
+    fn test_absolute_format() {
+        let now = SystemTime::now();
+        let time = now - Duration::from_secs(61);
+        assert_eq!(
+            format_timestamp(&time, now, TimestampFormat::Absolute, "%Y"),
+            format_local_time(time, "%Y").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_both_format() {
+        let now = SystemTime::now();
+        let time = now - Duration::from_secs(61);
+        assert_eq!(
+            format_timestamp(&time, now, TimestampFormat::Both, "%Y"),
+            format!("1 min ago · {}", format_local_time(time, "%Y").unwrap())
+        );
+
+        // Past a day only the date is shown
+        let old = now - Duration::from_secs(2 * 86400);
+        assert_eq!(
+            format_timestamp(&old, now, TimestampFormat::Both, "%Y"),
+            format_local_time(old, "%Y").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_long_single_line_text_is_truncated() {
+        let text = "a".repeat(1024 * 1024);
+        let preview = preview_text(&text);
+        assert!(preview.len() < MAX_PREVIEW_BYTES + 100);
+        assert!(preview.starts_with(&text[..MAX_PREVIEW_BYTES]));
+        assert!(preview.ends_with("[Content truncated - 1048576 bytes total]"));
+
+        assert_eq!(preview_text("short"), "short");
+    }
+
+    #[test]
+    fn test_truncation_respects_char_boundaries() {
+        // Multi-byte characters straddle the byte limit
+        let text = format!("a{}", "é".repeat(MAX_PREVIEW_BYTES));
+        let preview = preview_text(&text);
+        let kept = preview.split("...").next().unwrap();
+        assert_eq!(kept.len(), MAX_PREVIEW_BYTES - 1);
+        assert!(kept.ends_with('é'));
+    }
 }
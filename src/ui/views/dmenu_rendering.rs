@@ -0,0 +1,19 @@
+use crate::ui::views::{TextMeasure, item_container, render_action_indicator, render_text_content};
+use gpui::{Div, Stateful, prelude::*};
+
+/// Render a line of `zlaunch dmenu` input.
+pub fn render_dmenu_item(
+    line: &str,
+    selected: bool,
+    measure: &TextMeasure,
+    row: usize,
+) -> Stateful<Div> {
+    let mut item =
+        item_container(row, selected).child(render_text_content(line, None, selected, measure));
+
+    if selected {
+        item = item.child(render_action_indicator("Select"));
+    }
+
+    item
+}
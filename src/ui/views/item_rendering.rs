@@ -4,52 +4,135 @@
 //! maintaining visual equivalence with the old implementation.
 
 use crate::assets::PhosphorIcon;
-use crate::items::{DisplayItem, IconProvider, ListItem};
+use crate::config::{BadgeConfig, TitleTruncation};
+use crate::items::{Badge, DisplayItem, IconProvider, ListItem};
+use crate::ui::launcher::{APP_ACTIONS_KEY, CONFIRM_KEY, SECONDARY_CONFIRM_KEY};
 use crate::ui::theme::theme;
-use gpui::{Div, ElementId, ImageFormat, SharedString, Stateful, div, img, prelude::*, px, svg};
+use crate::ui::utils::{end_ellipsis, fit_text, icon_file_image, key_badge_text, middle_ellipsis};
+use gpui::{
+    App, Div, ElementId, ImageFormat, Keystroke, Modifiers, Pixels, Rems, SharedString, Stateful,
+    TextRun, Window, div, img, prelude::*, px, svg,
+};
 use std::path::PathBuf;
 use std::sync::Arc;
 
+/// Font size of item titles (`text_sm`).
+const TITLE_FONT_SIZE: Rems = Rems(0.875);
+/// Font size of item descriptions (`text_xs`).
+const DESCRIPTION_FONT_SIZE: Rems = Rems(0.75);
+
 /// Render any list item based on its type.
 /// This is the main dispatch function for item rendering.
-pub fn render_item(item: &ListItem, selected: bool, row: usize) -> Stateful<Div> {
+///
+/// While Shift or the terminal confirm key's modifiers are held, a selected
+/// item shows that action instead of its default one. Badges enabled in
+/// `badge_config` follow the item's text.
+pub fn render_item(
+    item: &ListItem,
+    selected: bool,
+    modifiers: &Modifiers,
+    badge_config: &BadgeConfig,
+    measure: &TextMeasure,
+    row: usize,
+) -> Stateful<Div> {
+    let held_action = held_action(item, modifiers);
     match item {
-        ListItem::Application(app) => render_application(app, selected, row),
-        ListItem::Window(win) => render_window(win, selected, row),
-        ListItem::Action(act) => render_action(act, selected, row),
-        ListItem::Submenu(sub) => render_submenu(sub, selected, row),
+        ListItem::Application(app) => {
+            let badges = item.badges(badge_config);
+            render_application(app, selected, measure, held_action, &badges, row)
+        }
+        ListItem::Window(win) => render_window(win, selected, measure, row),
+        ListItem::Workspace(ws) => render_workspace(ws, selected, measure, row),
+        ListItem::Action(act) => render_action(act, selected, measure, row),
+        ListItem::Submenu(sub) => render_submenu(sub, selected, measure, row),
         ListItem::Calculator(calc) => render_calculator(calc, selected, row),
-        ListItem::Search(search) => render_search(search, selected, row),
-        ListItem::Ai(ai) => render_ai(ai, selected, row),
-        ListItem::Theme(theme) => crate::ui::views::render_theme_item(theme, selected, row),
+        ListItem::Search(search) => render_search(search, selected, measure, held_action, row),
+        ListItem::Path(path) => render_path(path, selected, measure, row),
+        ListItem::File(file) => render_file(file, selected, measure, held_action, row),
+        ListItem::Ai(ai) => render_ai(ai, selected, measure, row),
+        ListItem::Script(script) => render_script(script, selected, measure, row),
+        ListItem::Theme(theme) => {
+            crate::ui::views::render_theme_item(theme, selected, measure, row)
+        }
+    }
+}
+
+/// Get the action label and keystroke to show for the held modifiers.
+fn held_action(item: &ListItem, modifiers: &Modifiers) -> Option<(&'static str, String)> {
+    if modifiers.shift {
+        return item
+            .secondary_action_label()
+            .map(|label| (label, SECONDARY_CONFIRM_KEY.to_string()));
+    }
+
+    let held = |key: &str| {
+        modifiers.modified()
+            && Keystroke::parse(key).is_ok_and(|keystroke| keystroke.modifiers == *modifiers)
+    };
+    let config = crate::config::config();
+    if let Some(key) = config.terminal_confirm_key()
+        && held(key)
+    {
+        return item
+            .terminal_action_label()
+            .map(|label| (label, key.to_string()));
     }
+    if held(APP_ACTIONS_KEY) {
+        return item
+            .app_actions_label()
+            .map(|label| (label, APP_ACTIONS_KEY.to_string()));
+    }
+    None
 }
 
 /// Render an application item.
+///
+/// `badges` go between the text and the action indicator.
 fn render_application(
     app: &crate::items::ApplicationItem,
     selected: bool,
+    measure: &TextMeasure,
+    held_action: Option<(&str, String)>,
+    badges: &[Badge],
     row: usize,
 ) -> Stateful<Div> {
+    let icon = render_icon_override(&app.id).unwrap_or_else(|| render_icon(app.icon_path.as_ref()));
+
     let mut item = item_container(row, selected)
-        .child(render_icon(app.icon_path.as_ref()))
+        .child(icon)
         .child(render_text_content(
             &app.name,
             app.description.as_deref(),
             selected,
+            measure,
         ));
 
+    if !badges.is_empty() {
+        item = item.child(render_badges(badges));
+    }
+
     if selected {
-        item = item.child(render_action_indicator("Open"));
+        item = item.child(match held_action {
+            Some((label, keystroke)) => render_key_indicator(label, &keystroke),
+            None => render_action_indicator("Open"),
+        });
     }
 
     item
 }
 
 /// Render a window item.
-fn render_window(win: &crate::items::WindowItem, selected: bool, row: usize) -> Stateful<Div> {
-    // Use in-memory icon data if available, otherwise fall back to icon path
-    let icon = if let Some(ref data) = win.icon_data {
+fn render_window(
+    win: &crate::items::WindowItem,
+    selected: bool,
+    measure: &TextMeasure,
+    row: usize,
+) -> Stateful<Div> {
+    // Prefer a configured override (by app id), then in-memory icon data,
+    // then the icon path
+    let icon = if let Some(icon) = render_icon_override(&win.app_id) {
+        icon
+    } else if let Some(ref data) = win.icon_data {
         render_icon_from_data(data)
     } else {
         render_icon(win.icon_path.as_ref())
@@ -57,10 +140,48 @@ fn render_window(win: &crate::items::WindowItem, selected: bool, row: usize) ->
 
     let mut item = item_container(row, selected)
         .child(icon)
-        .child(render_text_content(
+        .child(render_text_content_truncated(
             &win.title,
             Some(&win.description),
             selected,
+            measure,
+            crate::config::config().window_title_truncation,
+        ));
+
+    if win.urgent {
+        let theme = theme();
+        item = item.child(
+            div()
+                .flex_shrink_0()
+                .size(px(8.0))
+                .rounded_full()
+                .bg(theme.urgent_indicator_color),
+        );
+    }
+
+    if selected {
+        item = item.child(render_action_indicator("Switch"));
+    }
+
+    item
+}
+
+/// Render a workspace item.
+fn render_workspace(
+    ws: &crate::items::WorkspaceItem,
+    selected: bool,
+    measure: &TextMeasure,
+    row: usize,
+) -> Stateful<Div> {
+    let icon = render_icon_override(&ws.id)
+        .unwrap_or_else(|| render_phosphor_icon(ws.icon_name().and_then(PhosphorIcon::from_name)));
+    let mut item = item_container(row, selected)
+        .child(icon)
+        .child(render_text_content(
+            &ws.name,
+            ws.description.as_deref(),
+            selected,
+            measure,
         ));
 
     if selected {
@@ -71,14 +192,21 @@ fn render_window(win: &crate::items::WindowItem, selected: bool, row: usize) ->
 }
 
 /// Render an action item.
-fn render_action(act: &crate::items::ActionItem, selected: bool, row: usize) -> Stateful<Div> {
-    let icon = act.icon_name().and_then(PhosphorIcon::from_name);
+fn render_action(
+    act: &crate::items::ActionItem,
+    selected: bool,
+    measure: &TextMeasure,
+    row: usize,
+) -> Stateful<Div> {
+    let icon = render_icon_override(&act.id)
+        .unwrap_or_else(|| render_phosphor_icon(act.icon_name().and_then(PhosphorIcon::from_name)));
     let mut item = item_container(row, selected)
-        .child(render_phosphor_icon(icon))
+        .child(icon)
         .child(render_text_content(
             &act.name,
             act.description.as_deref(),
             selected,
+            measure,
         ));
 
     if selected {
@@ -89,14 +217,21 @@ fn render_action(act: &crate::items::ActionItem, selected: bool, row: usize) ->
 }
 
 /// Render a submenu item.
-fn render_submenu(sub: &crate::items::SubmenuItem, selected: bool, row: usize) -> Stateful<Div> {
-    let icon = sub.icon_name().and_then(PhosphorIcon::from_name);
+fn render_submenu(
+    sub: &crate::items::SubmenuItem,
+    selected: bool,
+    measure: &TextMeasure,
+    row: usize,
+) -> Stateful<Div> {
+    let icon = render_icon_override(&sub.id)
+        .unwrap_or_else(|| render_phosphor_icon(sub.icon_name().and_then(PhosphorIcon::from_name)));
     let mut item = item_container(row, selected)
-        .child(render_phosphor_icon(icon))
+        .child(icon)
         .child(render_text_content(
             &sub.name,
             sub.description.as_deref(),
             selected,
+            measure,
         ));
 
     if selected {
@@ -113,6 +248,7 @@ fn render_submenu(sub: &crate::items::SubmenuItem, selected: bool, row: usize) -
 /// - A custom "=" icon in a colored circle
 /// - The expression as muted smaller text
 /// - The result (or error) with "= " prefix in larger text
+/// - The units of a unit conversion below the result
 fn render_calculator(
     calc: &crate::items::CalculatorItem,
     selected: bool,
@@ -147,8 +283,10 @@ fn render_calculator(
     // Add text content
     container = container.child(render_calculator_content(calc, selected));
 
-    // Add action indicator when selected
-    if selected {
+    // Add action indicator when selected, or the confirmation of a copy
+    if calc.copied {
+        container = container.child(render_status_indicator("Copied"));
+    } else if selected {
         container = container.child(render_action_indicator("Copy"));
     }
 
@@ -182,7 +320,7 @@ fn render_calculator_icon() -> Div {
         )
 }
 
-/// Render the calculator text content (result only).
+/// Render the calculator text content (result, with units if any).
 fn render_calculator_content(calc: &crate::items::CalculatorItem, selected: bool) -> Div {
     let theme = theme();
 
@@ -212,13 +350,58 @@ fn render_calculator_content(calc: &crate::items::CalculatorItem, selected: bool
                 .text_ellipsis()
                 .child(SharedString::from(calc.display_result.clone())),
         )
+        .when_some(calc.unit_label.clone(), |content, unit_label| {
+            content.child(
+                div()
+                    .w_full()
+                    .text_xs()
+                    .h(theme.layout.item_description_height)
+                    .text_color(theme.item_description_color)
+                    .whitespace_nowrap()
+                    .overflow_hidden()
+                    .text_ellipsis()
+                    .child(SharedString::from(unit_label)),
+            )
+        })
 }
 
 /// Render a search item.
-fn render_search(search: &crate::items::SearchItem, selected: bool, row: usize) -> Stateful<Div> {
+fn render_search(
+    search: &crate::items::SearchItem,
+    selected: bool,
+    measure: &TextMeasure,
+    held_action: Option<(&str, String)>,
+    row: usize,
+) -> Stateful<Div> {
     let mut item = item_container(row, selected)
         .child(render_phosphor_icon(Some(search.icon())))
-        .child(render_text_content(&search.name, None, selected));
+        .child(render_text_content(&search.name, None, selected, measure));
+
+    if selected {
+        item = item.child(match held_action {
+            Some((label, keystroke)) => render_key_indicator(label, &keystroke),
+            None => render_action_indicator("Open"),
+        });
+    }
+
+    item
+}
+
+/// Render a path item.
+fn render_path(
+    path: &crate::items::PathItem,
+    selected: bool,
+    measure: &TextMeasure,
+    row: usize,
+) -> Stateful<Div> {
+    let mut item = item_container(row, selected)
+        .child(render_phosphor_icon(Some(path.icon())))
+        .child(render_text_content(
+            &path.name,
+            path.description(),
+            selected,
+            measure,
+        ));
 
     if selected {
         item = item.child(render_action_indicator("Open"));
@@ -227,11 +410,48 @@ fn render_search(search: &crate::items::SearchItem, selected: bool, row: usize)
     item
 }
 
+/// Render a file found by the file search.
+fn render_file(
+    file: &crate::items::FileItem,
+    selected: bool,
+    measure: &TextMeasure,
+    held_action: Option<(&str, String)>,
+    row: usize,
+) -> Stateful<Div> {
+    let mut item = item_container(row, selected)
+        .child(render_phosphor_icon(Some(file.icon())))
+        .child(render_text_content(
+            &file.name,
+            Some(&file.description),
+            selected,
+            measure,
+        ));
+
+    if selected {
+        item = item.child(match held_action {
+            Some((label, keystroke)) => render_key_indicator(label, &keystroke),
+            None => render_action_indicator("Open"),
+        });
+    }
+
+    item
+}
+
 /// Render an AI item.
-fn render_ai(ai: &crate::items::AiItem, selected: bool, row: usize) -> Stateful<Div> {
+fn render_ai(
+    ai: &crate::items::AiItem,
+    selected: bool,
+    measure: &TextMeasure,
+    row: usize,
+) -> Stateful<Div> {
     let mut item = item_container(row, selected)
         .child(render_phosphor_icon(Some(ai.icon())))
-        .child(render_text_content(&ai.name, ai.description(), selected));
+        .child(render_text_content(
+            &ai.name,
+            ai.description(),
+            selected,
+            measure,
+        ));
 
     if selected {
         item = item.child(render_action_indicator("Ask"));
@@ -240,6 +460,85 @@ fn render_ai(ai: &crate::items::AiItem, selected: bool, row: usize) -> Stateful<
     item
 }
 
+/// Render an item listed by a script source.
+fn render_script(
+    script: &crate::items::ScriptItem,
+    selected: bool,
+    measure: &TextMeasure,
+    row: usize,
+) -> Stateful<Div> {
+    let icon = match script.icon_path.as_ref() {
+        Some(path) => render_icon(Some(path)),
+        None => render_phosphor_icon(Some(script.icon())),
+    };
+    let mut item = item_container(row, selected)
+        .child(icon)
+        .child(render_text_content(
+            &script.name,
+            Some(&script.description),
+            selected,
+            measure,
+        ));
+
+    if selected {
+        item = item.child(render_action_indicator("Run"));
+    }
+
+    item
+}
+
+/// Render an AI item with its inline answer below the title.
+///
+/// The row grows with the answer as it streams in.
+pub fn render_ai_answer(
+    ai: &crate::items::AiItem,
+    answer: &crate::items::InlineAnswer,
+    selected: bool,
+    measure: &TextMeasure,
+    row: usize,
+) -> Stateful<Div> {
+    let theme = theme();
+
+    let mut header = div()
+        .w_full()
+        .relative()
+        .flex()
+        .flex_row()
+        .items_center()
+        .gap_2()
+        .child(render_phosphor_icon(Some(ai.icon())))
+        .child(render_text_content(
+            &ai.name,
+            Some(&ai.query),
+            selected,
+            measure,
+        ));
+    if selected && !answer.streaming {
+        header = header.child(render_action_indicator("Ask again"));
+    }
+
+    let (text, color) = match &answer.error {
+        Some(error) => (error.clone(), theme.ai.error_message_color),
+        None if answer.text.is_empty() => ("Thinking...".to_string(), theme.item_description_color),
+        None => (answer.text.clone(), theme.item_title_color),
+    };
+
+    item_container(row, selected)
+        .flex_col()
+        .items_start()
+        .child(header)
+        .child(
+            div()
+                .w_full()
+                .pt_1()
+                .pb_1()
+                .text_sm()
+                .text_color(color)
+                .whitespace_normal()
+                .child(SharedString::from(text)),
+        )
+}
+
 /// Create the base container for a list item with selection styling.
 pub fn item_container(row: usize, selected: bool) -> Stateful<Div> {
     let theme = theme();
@@ -266,6 +565,33 @@ pub fn item_container(row: usize, selected: bool) -> Stateful<Div> {
         .gap_2()
 }
 
+/// Render the icon configured in `[icon_overrides]` for an item id, if any.
+///
+/// Phosphor icon names take precedence; anything else is resolved as a
+/// filesystem path (`~` is expanded) or an icon theme name. Returns `None`
+/// when there is no override or it cannot be resolved, so callers fall back
+/// to the item's own icon.
+fn render_icon_override(id: &str) -> Option<Div> {
+    let value = crate::config::icon_override(id)?;
+
+    if let Some(icon) = PhosphorIcon::from_name(&value) {
+        return Some(render_phosphor_icon(Some(icon)));
+    }
+
+    let expanded = match value.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir()?.join(rest).to_string_lossy().into_owned(),
+        None => value.clone(),
+    };
+
+    match crate::ui::icon::resolve_icon_path(&expanded) {
+        Some(path) => Some(render_icon(Some(&path))),
+        None => {
+            tracing::debug!("Icon override '{}' for '{}' not found", value, id);
+            None
+        }
+    }
+}
+
 /// Render an icon from PNG bytes in memory.
 fn render_icon_from_data(data: &[u8]) -> Div {
     let theme = theme();
@@ -296,16 +622,8 @@ pub fn render_icon(icon_path: Option<&PathBuf>) -> Div {
         .items_center()
         .justify_center();
 
-    if let Some(path) = icon_path {
-        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
-        if matches!(ext, "png" | "jpg" | "jpeg" | "svg") {
-            return icon_container.child(
-                img(path.clone())
-                    .w(size)
-                    .h(size)
-                    .rounded(theme.icon_border_radius),
-            );
-        }
+    if let Some(image) = icon_path.and_then(|path| icon_file_image(path, theme.item_title_color)) {
+        return icon_container.child(img(image).w(size).h(size).rounded(theme.icon_border_radius));
     }
 
     // Fallback: show a subtle placeholder
@@ -353,9 +671,71 @@ pub fn render_phosphor_icon(icon: Option<PhosphorIcon>) -> Div {
     }
 }
 
+/// Measures text the way list items render it, to shorten titles and
+/// descriptions to the width left in the panel.
+#[derive(Clone, Copy)]
+pub struct TextMeasure<'a> {
+    window: &'a Window,
+    /// Width of the launcher panel on the current display
+    panel_width: Pixels,
+}
+
+impl<'a> TextMeasure<'a> {
+    /// Measure text in `window`, for the panel size on its display.
+    pub fn new(window: &'a Window, cx: &App) -> Self {
+        let (panel_width, _) = crate::ui::launcher::panel_size(&theme(), window, cx);
+        Self {
+            window,
+            panel_width: px(panel_width),
+        }
+    }
+
+    /// Get the width left for an item's text.
+    fn max_text_width(&self, selected: bool) -> Pixels {
+        theme().max_text_width(self.panel_width, selected)
+    }
+
+    /// Check whether `text` at `font_size` fits into `width`.
+    fn fits(&self, text: &str, font_size: Rems, width: Pixels) -> bool {
+        let style = self.window.text_style();
+        let run = TextRun {
+            len: text.len(),
+            font: style.font(),
+            color: style.color,
+            background_color: None,
+            underline: None,
+            strikethrough: None,
+        };
+        let line = self.window.text_system().shape_line(
+            SharedString::from(text.to_string()),
+            font_size.to_pixels(self.window.rem_size()),
+            &[run],
+            None,
+        );
+        line.width <= width
+    }
+}
+
 /// Render the text content (title and optional description).
-pub fn render_text_content(name: &str, description: Option<&str>, selected: bool) -> Div {
+pub fn render_text_content(
+    name: &str,
+    description: Option<&str>,
+    selected: bool,
+    measure: &TextMeasure,
+) -> Div {
+    render_text_content_truncated(name, description, selected, measure, TitleTruncation::End)
+}
+
+/// Render the text content, shortening an overlong title as configured.
+pub fn render_text_content_truncated(
+    name: &str,
+    description: Option<&str>,
+    selected: bool,
+    measure: &TextMeasure,
+    truncation: TitleTruncation,
+) -> Div {
     let theme = theme();
+    let max_width = measure.max_text_width(selected);
 
     let name_element = div()
         .w_full()
@@ -363,11 +743,21 @@ pub fn render_text_content(name: &str, description: Option<&str>, selected: bool
         .line_height(theme.item_title_line_height)
         .text_color(theme.item_title_color)
         .whitespace_nowrap()
-        .overflow_hidden()
-        .text_ellipsis()
-        .child(SharedString::from(name.to_string()));
+        .overflow_hidden();
 
-    let max_width = theme.max_text_width(px(crate::config::launcher_size().0), selected);
+    let name_element = match truncation {
+        TitleTruncation::End => name_element
+            .text_ellipsis()
+            .child(SharedString::from(name.to_string())),
+        TitleTruncation::Middle => {
+            let fits = |text: &str| measure.fits(text, TITLE_FONT_SIZE, max_width);
+            // Keep the end ellipsis as a fallback should the measure be off
+            name_element.text_ellipsis().child(SharedString::from(
+                fit_text(name, middle_ellipsis, fits).into_owned(),
+            ))
+        }
+        TitleTruncation::None => name_element.child(SharedString::from(name.to_string())),
+    };
 
     let mut content = div()
         .h(theme.item_content_height)
@@ -380,6 +770,8 @@ pub fn render_text_content(name: &str, description: Option<&str>, selected: bool
     content = content.child(name_element);
 
     if let Some(desc) = description {
+        let fits = |text: &str| measure.fits(text, DESCRIPTION_FONT_SIZE, max_width);
+        let desc = fit_text(desc, end_ellipsis, fits);
         let description_element = div()
             .w_full()
             .text_xs()
@@ -388,7 +780,7 @@ pub fn render_text_content(name: &str, description: Option<&str>, selected: bool
             .whitespace_nowrap()
             .overflow_hidden()
             .text_ellipsis()
-            .child(SharedString::from(desc.to_string()));
+            .child(SharedString::from(desc.into_owned()));
 
         content = content.child(description_element);
     }
@@ -396,8 +788,49 @@ pub fn render_text_content(name: &str, description: Option<&str>, selected: bool
     content
 }
 
+/// Render badges in a row, kept at their natural width.
+fn render_badges(badges: &[Badge]) -> Div {
+    let theme = theme();
+
+    div()
+        .flex_shrink_0()
+        .flex()
+        .flex_row()
+        .items_center()
+        .gap(theme.badge.gap)
+        .children(badges.iter().map(|badge| {
+            match badge {
+                Badge::Source(label) => render_text_badge(label),
+                Badge::WindowCount(count) => render_text_badge(&count.to_string()),
+                Badge::Running => div()
+                    .size(px(6.0))
+                    .rounded_full()
+                    .bg(theme.badge.running_color),
+            }
+        }))
+}
+
+/// Render a text badge, like a source tag or window count.
+fn render_text_badge(text: &str) -> Div {
+    let theme = theme();
+
+    div()
+        .px(theme.badge.padding_x)
+        .rounded(theme.badge.border_radius)
+        .bg(theme.badge.background)
+        .text_size(theme.badge.font_size)
+        .text_color(theme.badge.color)
+        .child(SharedString::from(text.to_string()))
+}
+
 /// Render the action indicator shown on selected items.
 pub fn render_action_indicator(label: &str) -> Div {
+    render_key_indicator(label, CONFIRM_KEY)
+}
+
+/// Render a plain status label (no key badge) where the action indicator
+/// would be, e.g. "Copied" after a calculator result was copied.
+fn render_status_indicator(label: &str) -> Div {
     let theme = theme();
 
     div()
@@ -406,28 +839,53 @@ pub fn render_action_indicator(label: &str) -> Div {
         .top_0()
         .bottom_0()
         .flex()
-        .flex_row()
         .items_center()
-        .gap_2()
-        .child(
-            div()
-                .text_xs()
-                .text_color(theme.action_indicator.label_color)
-                .child(SharedString::from(label.to_string())),
-        )
-        .child(
-            // Kbd-style box for Enter key
-            div()
-                .px(theme.action_indicator.key_padding_x)
-                .pt(theme.action_indicator.key_padding_top)
-                .pb(theme.action_indicator.key_padding_bottom)
-                .bg(theme.action_indicator.key_background)
-                .border_1()
-                .border_color(theme.action_indicator.key_border)
-                .rounded(theme.action_indicator.key_border_radius)
-                .text_size(theme.action_indicator.key_font_size)
-                .line_height(theme.action_indicator.key_line_height)
-                .text_color(theme.action_indicator.key_color)
-                .child(SharedString::from("↵")),
-        )
+        .text_xs()
+        .text_color(theme.action_indicator.label_color)
+        .child(SharedString::from(label.to_string()))
+}
+
+/// Render the action indicator for an action bound to `keystroke`.
+fn render_key_indicator(label: &str, keystroke: &str) -> Div {
+    let theme = theme();
+
+    render_key_hint(label, keystroke)
+        .absolute()
+        .right(theme.action_indicator.right_position)
+        .top_0()
+        .bottom_0()
+}
+
+/// Render a label followed by a kbd-style badge for `keystroke`
+/// (e.g. "Open ⇧↵" for `shift-enter`).
+///
+/// The badge is left out if the theme disables key badges.
+pub fn render_key_hint(label: &str, keystroke: &str) -> Div {
+    let theme = theme();
+
+    let hint = div().flex().flex_row().items_center().gap_2().child(
+        div()
+            .text_xs()
+            .text_color(theme.action_indicator.label_color)
+            .child(SharedString::from(label.to_string())),
+    );
+    if !theme.action_indicator.show_key_badge {
+        return hint;
+    }
+
+    hint.child(
+        // Kbd-style box for the key
+        div()
+            .px(theme.action_indicator.key_padding_x)
+            .pt(theme.action_indicator.key_padding_top)
+            .pb(theme.action_indicator.key_padding_bottom)
+            .bg(theme.action_indicator.key_background)
+            .border_1()
+            .border_color(theme.action_indicator.key_border)
+            .rounded(theme.action_indicator.key_border_radius)
+            .text_size(theme.action_indicator.key_font_size)
+            .line_height(theme.action_indicator.key_line_height)
+            .text_color(theme.action_indicator.key_color)
+            .child(SharedString::from(key_badge_text(keystroke))),
+    )
 }
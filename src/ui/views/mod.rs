@@ -5,15 +5,18 @@
 
 pub mod ai_view;
 pub mod clipboard_rendering;
+mod dmenu_rendering;
 mod emoji_rendering;
 mod item_rendering;
+mod png_cache;
 mod theme_rendering;
 
 pub use ai_view::AiResponseView;
 pub use clipboard_rendering::render_clipboard_item;
+pub use dmenu_rendering::render_dmenu_item;
 pub use emoji_rendering::{render_emoji_cell, render_emoji_row};
 pub use item_rendering::{
-    item_container, render_action_indicator, render_icon, render_item, render_phosphor_icon,
-    render_text_content,
+    TextMeasure, item_container, render_action_indicator, render_ai_answer, render_icon,
+    render_item, render_key_hint, render_phosphor_icon, render_text_content,
 };
 pub use theme_rendering::render_theme_item;
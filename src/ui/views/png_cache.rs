@@ -0,0 +1,180 @@
+//! Cache of PNG-encoded clipboard images for the preview panel.
+//!
+//! Clipboard images are stored as raw RGBA pixels, but GPUI needs an encoded
+//! image. Encoding large images is expensive, so each image is encoded once
+//! on first preview and reused while navigating the list. Animated GIFs keep
+//! their original encoding and are cached the same way.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+/// Number of encoded previews kept around.
+const CACHE_CAPACITY: usize = 16;
+
+/// Number of animated previews kept around (these can be large).
+const ANIMATED_CACHE_CAPACITY: usize = 4;
+
+lazy_static::lazy_static! {
+    static ref PREVIEW_CACHE: Mutex<PngCache> = Mutex::new(PngCache::new(CACHE_CAPACITY));
+    static ref ANIMATED_CACHE: Mutex<PngCache> = Mutex::new(
+        PngCache::new(ANIMATED_CACHE_CAPACITY).with_format(gpui::ImageFormat::Gif)
+    );
+}
+
+/// Get the encoded preview image for raw RGBA pixels, encoding on first use.
+///
+/// `key` identifies the content, e.g. the clipboard item's hash, so the
+/// pixels need not be hashed on every render. Returns `None` if the pixel
+/// data doesn't match the dimensions or the image can't be encoded.
+pub fn preview_image(
+    key: u64,
+    width: usize,
+    height: usize,
+    rgba_bytes: &[u8],
+) -> Option<Arc<gpui::Image>> {
+    PREVIEW_CACHE
+        .lock()
+        .unwrap()
+        .get_or_encode(key, || encode_png(width, height, rgba_bytes))
+}
+
+/// Get the preview image for an animated GIF, avoiding a copy of its bytes
+/// on every render.
+///
+/// `key` identifies the content like for [`preview_image`].
+pub fn animated_image(key: u64, gif_bytes: &[u8]) -> Option<Arc<gpui::Image>> {
+    ANIMATED_CACHE
+        .lock()
+        .unwrap()
+        .get_or_encode(key, || Some(gif_bytes.to_vec()))
+}
+
+/// Encode raw RGBA pixels as PNG.
+fn encode_png(width: usize, height: usize, rgba_bytes: &[u8]) -> Option<Vec<u8>> {
+    use image::{ImageBuffer, ImageFormat, Rgba};
+    use std::io::Cursor;
+
+    let img_buffer =
+        ImageBuffer::<Rgba<u8>, _>::from_raw(width as u32, height as u32, rgba_bytes.to_vec())?;
+    let mut png_bytes = Vec::new();
+    img_buffer
+        .write_to(&mut Cursor::new(&mut png_bytes), ImageFormat::Png)
+        .ok()?;
+    Some(png_bytes)
+}
+
+/// Bounded cache of encoded images keyed by a hash of their content.
+struct PngCache {
+    entries: HashMap<u64, Arc<gpui::Image>>,
+    /// Keys in insertion order, oldest first.
+    order: VecDeque<u64>,
+    capacity: usize,
+    /// Format of the encoded bytes.
+    format: gpui::ImageFormat,
+}
+
+impl PngCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+            capacity,
+            format: gpui::ImageFormat::Png,
+        }
+    }
+
+    /// Store images already encoded in another format.
+    fn with_format(mut self, format: gpui::ImageFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Return the cached image for `key`, or encode and cache it.
+    fn get_or_encode(
+        &mut self,
+        key: u64,
+        encode: impl FnOnce() -> Option<Vec<u8>>,
+    ) -> Option<Arc<gpui::Image>> {
+        if let Some(image) = self.entries.get(&key) {
+            return Some(image.clone());
+        }
+
+        let encoded = encode()?;
+        let image = Arc::new(gpui::Image::from_bytes(self.format, encoded));
+
+        if self.order.len() >= self.capacity
+            && let Some(oldest) = self.order.pop_front()
+        {
+            self.entries.remove(&oldest);
+        }
+        self.order.push_back(key);
+        self.entries.insert(key, image.clone());
+
+        Some(image)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn counting_encode<'a>(
+        count: &'a Cell<usize>,
+        rgba_bytes: &'a [u8],
+    ) -> impl FnOnce() -> Option<Vec<u8>> + 'a {
+        move || {
+            count.set(count.get() + 1);
+            encode_png(1, 1, rgba_bytes)
+        }
+    }
+
+    #[test]
+    fn test_cache_hit_skips_encoding() {
+        let mut cache = PngCache::new(4);
+        let pixels = [255u8; 4];
+        let encodes = Cell::new(0);
+
+        let first = cache.get_or_encode(1, counting_encode(&encodes, &pixels));
+        let second = cache.get_or_encode(1, counting_encode(&encodes, &pixels));
+
+        assert_eq!(encodes.get(), 1);
+        assert!(Arc::ptr_eq(&first.unwrap(), &second.unwrap()));
+    }
+
+    #[test]
+    fn test_different_keys_are_encoded_separately() {
+        let mut cache = PngCache::new(4);
+        let encodes = Cell::new(0);
+
+        cache.get_or_encode(1, counting_encode(&encodes, &[0, 0, 0, 255]));
+        cache.get_or_encode(2, counting_encode(&encodes, &[255, 0, 0, 255]));
+
+        assert_eq!(encodes.get(), 2);
+    }
+
+    #[test]
+    fn test_oldest_entry_evicted() {
+        let mut cache = PngCache::new(1);
+        let encodes = Cell::new(0);
+        let black = [0, 0, 0, 255];
+
+        cache.get_or_encode(1, counting_encode(&encodes, &black));
+        cache.get_or_encode(2, counting_encode(&encodes, &[255, 255, 255, 255]));
+        cache.get_or_encode(1, counting_encode(&encodes, &black));
+
+        assert_eq!(encodes.get(), 3);
+        assert_eq!(cache.entries.len(), 1);
+    }
+
+    #[test]
+    fn test_mismatched_dimensions_not_cached() {
+        let mut cache = PngCache::new(4);
+        assert!(
+            cache
+                .get_or_encode(1, || encode_png(4, 4, &[0; 4]))
+                .is_none()
+        );
+        assert!(cache.entries.is_empty());
+    }
+}
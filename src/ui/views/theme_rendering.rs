@@ -1,17 +1,23 @@
 use crate::items::ThemeItem;
 use crate::ui::styled::lighten_color;
 use crate::ui::theme::{LauncherTheme, theme};
-use crate::ui::views::{item_container, render_action_indicator, render_text_content};
+use crate::ui::views::{TextMeasure, item_container, render_action_indicator, render_text_content};
 use gpui::{Div, Stateful, div, prelude::*, px};
 
 /// Render a theme item with a dynamic color preview icon.
-pub fn render_theme_item(theme_item: &ThemeItem, selected: bool, row: usize) -> Stateful<Div> {
+pub fn render_theme_item(
+    theme_item: &ThemeItem,
+    selected: bool,
+    measure: &TextMeasure,
+    row: usize,
+) -> Stateful<Div> {
     let mut item = item_container(row, selected)
         .child(render_theme_icon(&theme_item.theme))
         .child(render_text_content(
             &theme_item.name,
             Some(theme_item.description.as_str()),
             selected,
+            measure,
         ));
 
     if selected {